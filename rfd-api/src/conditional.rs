@@ -0,0 +1,309 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, Utc};
+use dropshot::{
+    ApiEndpointResponse, Body, HttpHandlerResult, HttpResponse, HttpResponseHeaders, HttpResponseOk,
+};
+use http::{header, HeaderValue, Response, StatusCode};
+use rfd_model::{schema_ext::Visibility, CommitSha, FileSha};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use v_model::permissions::Caller;
+
+use crate::{
+    context::{RfdWithPdf, RfdWithRaw, RfdWithoutContent},
+    permissions::RfdPermission,
+};
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ConditionalHeaders {
+    /// An ETag previously returned for this resource. If it still matches the resource then a
+    /// 304 Not Modified response is returned without a body
+    #[serde(rename = "if-none-match")]
+    pub if_none_match: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ETagHeader {
+    /// Identifies the version of the resource that was returned
+    pub etag: String,
+}
+
+/// A representation of an RFD that can be versioned by the revision that it was built from
+pub trait RfdVersion {
+    fn rfd_number(&self) -> i32;
+    fn commit(&self) -> Option<&CommitSha>;
+    fn sha(&self) -> Option<&FileSha>;
+    fn latest_major_change_at(&self) -> Option<&DateTime<Utc>>;
+    fn visibility(&self) -> &Visibility;
+
+    /// Parts of the representation that may change without a new revision being created
+    fn variant(&self) -> Option<String> {
+        None
+    }
+}
+
+impl RfdVersion for RfdWithRaw {
+    fn rfd_number(&self) -> i32 {
+        self.rfd_number
+    }
+
+    fn commit(&self) -> Option<&CommitSha> {
+        self.commit.as_ref()
+    }
+
+    fn sha(&self) -> Option<&FileSha> {
+        self.sha.as_ref()
+    }
+
+    fn latest_major_change_at(&self) -> Option<&DateTime<Utc>> {
+        self.latest_major_change_at.as_ref()
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+}
+
+impl RfdVersion for RfdWithoutContent {
+    fn rfd_number(&self) -> i32 {
+        self.rfd_number
+    }
+
+    fn commit(&self) -> Option<&CommitSha> {
+        self.commit.as_ref()
+    }
+
+    fn sha(&self) -> Option<&FileSha> {
+        self.sha.as_ref()
+    }
+
+    fn latest_major_change_at(&self) -> Option<&DateTime<Utc>> {
+        self.latest_major_change_at.as_ref()
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+}
+
+impl RfdVersion for RfdWithPdf {
+    fn rfd_number(&self) -> i32 {
+        self.rfd_number
+    }
+
+    fn commit(&self) -> Option<&CommitSha> {
+        self.commit.as_ref()
+    }
+
+    fn sha(&self) -> Option<&FileSha> {
+        self.sha.as_ref()
+    }
+
+    fn latest_major_change_at(&self) -> Option<&DateTime<Utc>> {
+        self.latest_major_change_at.as_ref()
+    }
+
+    fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    // PDFs are generated asynchronously after a revision is created
+    fn variant(&self) -> Option<String> {
+        Some(format!("pdf{}", self.content.len()))
+    }
+}
+
+/// Compute the ETag of an RFD representation. The ETag covers the revision that the
+/// representation was built from, the time of the latest major change to the RFD, and the level of
+/// access that the caller has to the RFD, so that a change in any of them is never reported as
+/// unmodified
+pub fn rfd_etag<T>(caller: &Caller<RfdPermission>, rfd: &T) -> String
+where
+    T: RfdVersion,
+{
+    let access = if caller.can(&RfdPermission::GetRfdsAll) {
+        "all"
+    } else if caller.can(&RfdPermission::GetRfd(rfd.rfd_number())) {
+        "rfd"
+    } else {
+        "public"
+    };
+
+    let visibility = match rfd.visibility() {
+        Visibility::Public => "public",
+        Visibility::Private => "private",
+    };
+
+    let mut parts = vec![
+        rfd.commit()
+            .map(|commit| commit.0.as_str())
+            .unwrap_or("none")
+            .to_string(),
+        rfd.sha()
+            .map(|sha| sha.0.as_str())
+            .unwrap_or("none")
+            .to_string(),
+        rfd.latest_major_change_at()
+            .map(|time| time.timestamp_micros().to_string())
+            .unwrap_or_else(|| "none".to_string()),
+        visibility.to_string(),
+        access.to_string(),
+    ];
+    parts.extend(rfd.variant());
+
+    format!("\"{}\"", parts.join("-"))
+}
+
+/// Check if an If-None-Match header value matches an ETag. Weak comparison is used as defined by
+/// RFC 9110 for If-None-Match
+fn if_none_match(header: &str, etag: &str) -> bool {
+    let etag = etag.trim_start_matches("W/");
+    header
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+/// A successful response that is replaced by a 304 Not Modified response when the client already
+/// holds the current version of the resource
+pub enum HttpResponseConditional<T> {
+    Modified(HttpResponseHeaders<HttpResponseOk<T>, ETagHeader>),
+    NotModified(String),
+}
+
+impl<T> HttpResponseConditional<T>
+where
+    T: JsonSchema + Serialize + Send + Sync + 'static,
+{
+    pub fn new(etag: String, headers: &ConditionalHeaders, body: T) -> Self {
+        match &headers.if_none_match {
+            Some(header) if if_none_match(header, &etag) => Self::NotModified(etag),
+            _ => Self::Modified(HttpResponseHeaders::new(
+                HttpResponseOk(body),
+                ETagHeader { etag },
+            )),
+        }
+    }
+}
+
+impl<T> HttpResponse for HttpResponseConditional<T>
+where
+    T: JsonSchema + Serialize + Send + Sync + 'static,
+{
+    fn to_result(self) -> HttpHandlerResult {
+        match self {
+            Self::Modified(response) => response.to_result(),
+            Self::NotModified(etag) => {
+                let mut response = Response::new(Body::empty());
+                *response.status_mut() = StatusCode::NOT_MODIFIED;
+                if let Ok(etag) = HeaderValue::from_str(&etag) {
+                    response.headers_mut().insert(header::ETAG, etag);
+                }
+
+                Ok(response)
+            }
+        }
+    }
+
+    fn response_metadata() -> ApiEndpointResponse {
+        HttpResponseHeaders::<HttpResponseOk<T>, ETagHeader>::response_metadata()
+    }
+}
+
+impl<T> From<&HttpResponseConditional<T>> for StatusCode {
+    fn from(value: &HttpResponseConditional<T>) -> Self {
+        match value {
+            HttpResponseConditional::Modified(_) => StatusCode::OK,
+            HttpResponseConditional::NotModified(_) => StatusCode::NOT_MODIFIED,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use http::StatusCode;
+    use newtype_uuid::TypedUuid;
+    use rfd_model::{schema_ext::Visibility, CommitSha, FileSha};
+    use v_model::{permissions::Caller, Permissions};
+
+    use crate::{context::RfdWithoutContent, permissions::RfdPermission};
+
+    use super::{if_none_match, rfd_etag, ConditionalHeaders, HttpResponseConditional};
+
+    fn rfd() -> RfdWithoutContent {
+        RfdWithoutContent {
+            id: TypedUuid::new_v4(),
+            rfd_number: 123,
+            link: None,
+            discussion: None,
+            title: Some("Test RFD".to_string()),
+            state: None,
+            authors: None,
+            labels: None,
+            format: None,
+            sha: Some(FileSha("file-sha".to_string())),
+            commit: Some(CommitSha("commit-sha".to_string())),
+            committed_at: Some(Utc::now()),
+            latest_major_change_at: None,
+            visibility: Visibility::Private,
+        }
+    }
+
+    #[test]
+    fn etag_varies_by_access() {
+        let all = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+        let single = Caller::from(Permissions::from(vec![RfdPermission::GetRfd(123)]));
+
+        assert_eq!(
+            "\"commit-sha-file-sha-none-private-all\"",
+            rfd_etag(&all, &rfd())
+        );
+        assert_eq!(
+            "\"commit-sha-file-sha-none-private-rfd\"",
+            rfd_etag(&single, &rfd())
+        );
+    }
+
+    #[test]
+    fn etag_varies_by_latest_major_change() {
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let mut changed = rfd();
+        changed.latest_major_change_at = Some(Utc::now());
+
+        assert_ne!(rfd_etag(&caller, &rfd()), rfd_etag(&caller, &changed));
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        assert!(if_none_match("\"a\"", "\"a\""));
+        assert!(if_none_match("\"b\", W/\"a\"", "\"a\""));
+        assert!(if_none_match("*", "\"a\""));
+        assert!(!if_none_match("\"b\"", "\"a\""));
+    }
+
+    #[test]
+    fn not_modified_when_etag_matches() {
+        let headers = ConditionalHeaders {
+            if_none_match: Some("\"a\"".to_string()),
+        };
+
+        let response = HttpResponseConditional::new("\"a\"".to_string(), &headers, rfd());
+        assert_eq!(StatusCode::NOT_MODIFIED, StatusCode::from(&response));
+        match response {
+            HttpResponseConditional::NotModified(etag) => assert_eq!("\"a\"", etag),
+            HttpResponseConditional::Modified(_) => panic!("Expected a not modified response"),
+        }
+
+        let response = HttpResponseConditional::new("\"b\"".to_string(), &headers, rfd());
+        assert_eq!(StatusCode::OK, StatusCode::from(&response));
+        match response {
+            HttpResponseConditional::NotModified(_) => panic!("Expected a modified response"),
+            HttpResponseConditional::Modified(_) => (),
+        }
+    }
+}
@@ -5,7 +5,7 @@
 use std::{collections::HashMap, path::PathBuf};
 
 use config::{Config, ConfigError, Environment, File};
-use rfd_data::content::RfdTemplate;
+use rfd_data::{content::RfdTemplate, state::RfdStateTransitions};
 use serde::Deserialize;
 use v_api::config::{AsymmetricKey, AuthnProviders, JwtConfig};
 use v_model::schema_ext::MagicLinkMedium;
@@ -25,6 +25,7 @@ pub struct AppConfig {
     pub spec: Option<SpecConfig>,
     pub authn: AuthnProviders,
     pub magic_link: MagicLinkConfig,
+    pub notification: Option<NotificationConfig>,
     pub search: SearchConfig,
     pub content: ContentConfig,
     pub services: ServicesConfig,
@@ -47,6 +48,8 @@ pub struct SearchConfig {
 #[derive(Debug, Default, Deserialize)]
 pub struct ContentConfig {
     pub templates: HashMap<String, RfdTemplate>,
+    #[serde(default)]
+    pub state_transitions: RfdStateTransitions,
 }
 
 #[derive(Debug, Deserialize)]
@@ -99,6 +102,27 @@ pub enum EmailService {
     Resend { key: String },
 }
 
+#[derive(Debug, Deserialize)]
+pub struct NotificationConfig {
+    /// The address that watch notification emails are sent from
+    pub from: String,
+    pub email_service: EmailService,
+    /// Seconds to wait between checks for pending notifications
+    #[serde(default = "default_notification_interval")]
+    pub interval: u64,
+    /// Maximum number of notifications to process per check
+    #[serde(default = "default_notification_batch_size")]
+    pub batch_size: i64,
+}
+
+fn default_notification_interval() -> u64 {
+    30
+}
+
+fn default_notification_batch_size() -> i64 {
+    50
+}
+
 const DEFAULT_CONFIG_PATHS: &[&str] = &["/etc/rfd-api/config.toml", "rfd-api/config.toml"];
 
 impl AppConfig {
@@ -3,38 +3,59 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use chrono::{DateTime, Utc};
-use newtype_uuid::TypedUuid;
+use dropshot::{RequestContext, ServerContext};
+use http::StatusCode;
+use newtype_uuid::{GenericUuid, TypedUuid};
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
     http_cache::NoCache,
-    Client as GitHubClient,
+    Client as GitHubClient, ClientError,
 };
 use partial_struct::partial;
 use rfd_data::{
-    content::{RfdContent, RfdContentError, RfdDocument, RfdTemplate, TemplateError},
-    RfdNumber,
+    content::{
+        RfdAsciidoc, RfdContent, RfdContentError, RfdDocument, RfdMarkdown, RfdTemplate,
+        TemplateError,
+    },
+    diff::{RfdAttributeChange, RfdDiff, RfdDiffHunk},
+    merge::merge_lines,
+    state::RfdStateTransitions,
+    RfdNumber, RfdState,
+};
+use rfd_github::{
+    GitHubError, GitHubNewRfdNumber, GitHubRfdFile, GitHubRfdLocation, GitHubRfdRepo,
 };
-use rfd_github::{GitHubError, GitHubNewRfdNumber, GitHubRfdRepo};
 use rfd_model::{
-    schema_ext::{ContentFormat, Visibility},
+    schema_ext::{ContentFormat, Visibility, WebhookEvent},
     storage::{
-        JobFilter, JobStore, RfdFilter, RfdMetaStore, RfdPdfsStore, RfdRevisionFilter,
-        RfdRevisionStore, RfdStorage, RfdStore,
+        AuditEventFilter, AuditEventStore, JobFilter, JobStore, RfdFilter, RfdMetaStore,
+        RfdNumberReservationFilter, RfdNumberReservationStore, RfdPdfsStore, RfdReferenceFilter,
+        RfdReferenceStore, RfdRevisionFilter, RfdRevisionHtmlStore, RfdRevisionMetaStore,
+        RfdRevisionStore, RfdSort, RfdStorage, RfdStore, RfdWatchFilter, RfdWatchNotificationStore,
+        RfdWatchStore, SortDirection, WebhookSubscriptionDeliveryFilter,
+        WebhookSubscriptionDeliveryStore, WebhookSubscriptionFilter, WebhookSubscriptionStore,
     },
-    CommitSha, FileSha, Job, NewJob, NewRfdRevision, Rfd, RfdId, RfdMeta, RfdPdf, RfdPdfs,
-    RfdRevision, RfdRevisionId,
+    AuditEvent, CommitSha, FileSha, Job, NewAuditEvent, NewJob, NewRfdNumberReservation,
+    NewRfdRevision, NewRfdWatch, NewRfdWatchNotification, NewWebhookSubscription, Rfd, RfdId,
+    RfdMeta, RfdNumberReservation, RfdPdf, RfdPdfs, RfdRevision, RfdRevisionId, RfdWatch,
+    RfdWatchId, WebhookEventPayload, WebhookSubscription, WebhookSubscriptionDelivery,
+    WebhookSubscriptionId,
 };
 use rsa::{
     pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey},
+    rand_core::{OsRng, RngCore},
     RsaPrivateKey,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::{cmp::Ordering, sync::Arc};
+use serde_json::json;
+use std::{cmp::Ordering, collections::HashMap, sync::Arc, time::Duration};
 use tap::TapFallible;
 use thiserror::Error;
 use tracing::instrument;
+use uuid::Uuid;
 use v_api::{
+    messenger::Messenger,
     response::{
         resource_not_found, resource_restricted, OptionalResource, ResourceError,
         ResourceErrorInner, ResourceResult,
@@ -47,13 +68,19 @@ use v_model::{
 };
 
 use crate::{
+    caller::CallerExt,
     config::{ContentConfig, GitHubAuthConfig, SearchConfig, ServicesConfig},
+    discussion::{DiscussionCache, RfdDiscussion},
     error::AppError,
+    export::RfdExport,
+    feed::RfdFeedEntry,
+    notification::RfdWatchNotifier,
     permissions::RfdPermission,
     search::SearchClient,
 };
 
-static UNLIMITED: i64 = 9999999;
+static DISCUSSION_CACHE_TTL: Duration = Duration::from_secs(300);
+static RFD_NUMBER_ALLOCATION_ATTEMPTS: usize = 5;
 
 pub struct RfdContext {
     pub public_url: String,
@@ -61,6 +88,7 @@ pub struct RfdContext {
     pub search: SearchContext,
     pub content: ContentContext,
     pub github: GitHubRfdRepo,
+    pub discussions: DiscussionCache,
 
     v_context: Arc<VContext<RfdPermission>>,
 }
@@ -79,6 +107,51 @@ pub struct SearchContext {
 pub struct ContentContext {
     pub placeholder_template: RfdTemplate,
     pub new_template: RfdTemplate,
+    pub templates: HashMap<String, RfdTemplate>,
+    pub state_transitions: RfdStateTransitions,
+}
+
+/// Fields that are filled in by the API when a new RFD is constructed from a template
+static GENERATED_TEMPLATE_FIELDS: [&str; 3] = ["number", "title", "body"];
+
+impl ContentContext {
+    /// Construct the template for a new RFD. A named template must be one of the configured
+    /// templates, otherwise the new or placeholder template is selected depending on whether
+    /// content was supplied. Returns `None` if the named template does not exist
+    pub fn new_rfd_template(
+        &self,
+        name: Option<&str>,
+        title: String,
+        content: Option<String>,
+        fields: HashMap<String, String>,
+    ) -> Option<RfdTemplate> {
+        let mut template = match (name, &content) {
+            (Some(name), _) => self.templates.get(name)?.clone(),
+            (None, Some(_)) => self.new_template.clone(),
+            (None, None) => self.placeholder_template.clone(),
+        };
+
+        // Generated fields are assigned last so that they can not be overridden by the caller
+        for (field, value) in fields {
+            template = template.field(field, value);
+        }
+
+        template = template.field("title".to_string(), title);
+        if let Some(content) = content {
+            template = template.field("body".to_string(), content);
+        }
+
+        Some(template)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdTemplateSummary {
+    pub name: String,
+    pub description: Option<String>,
+    /// Fields that must be supplied when reserving an RFD with this template. The number, title,
+    /// and body fields are filled in from the reservation itself and are not included
+    pub fields: Vec<String>,
 }
 
 #[derive(Debug, Error)]
@@ -87,8 +160,14 @@ pub enum UpdateRfdContentError {
     GitHub(#[from] GitHubError),
     #[error("Internal GitHub state does not currently allow for update. This commit appears as the head commit on multiple branches.")]
     InternalState,
+    #[error("RFD has been updated since the expected base commit. The latest commit is {head}")]
+    Conflict { head: CommitSha },
+    #[error("RFD can not move from the {from} state to the {to} state")]
+    InvalidStateTransition { from: RfdState, to: RfdState },
     #[error("Unable to parse RFD contents")]
     InvalidContent(#[from] RfdContentError),
+    #[error("Unable to parse the updated RFD document")]
+    InvalidDocument(RfdContentError),
     #[error("Failed to construct new RFD template")]
     InvalidTemplate(#[from] TemplateError),
     #[error("Unable to perform action. Unable to find the default branch on GitHub.")]
@@ -97,6 +176,41 @@ pub enum UpdateRfdContentError {
     Storage(#[from] StoreError),
 }
 
+/// A change to a supporting file stored in the directory of an RFD
+#[derive(Debug, Clone, Copy)]
+pub enum RfdFileChange<'a> {
+    /// Add a file that does not yet exist
+    Create(&'a [u8]),
+    /// Overwrite the contents of an existing file
+    Replace(&'a [u8]),
+    /// Remove an existing file
+    Delete,
+}
+
+/// The revision of an RFD that an update was written against
+#[derive(Debug, Clone)]
+pub struct UpdateBase {
+    pub commit: CommitSha,
+    /// Attempt a three-way merge when the RFD has changed since the base commit
+    pub merge: bool,
+}
+
+#[derive(Debug, Error)]
+pub enum RfdNumberReservationError {
+    #[error(transparent)]
+    GitHub(#[from] GitHubError),
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+}
+
+#[derive(Debug, Error)]
+pub enum GetRfdDiscussionError {
+    #[error(transparent)]
+    GitHub(#[from] GitHubError),
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct RfdRevisionMeta {
     pub id: TypedUuid<RfdRevisionId>,
@@ -116,6 +230,39 @@ impl From<RfdRevision> for RfdRevisionMeta {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdHtml {
+    pub rfd_number: i32,
+    pub revision: TypedUuid<RfdRevisionId>,
+    /// The rendered body of the RFD revision
+    pub html: String,
+    pub rendered_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdRevisionDiff {
+    pub from: RfdRevisionMeta,
+    pub to: RfdRevisionMeta,
+    /// Header attributes that differ between the two revisions
+    pub attributes: Vec<RfdAttributeChange>,
+    /// The line differences between the two revisions in the unified diff format
+    pub unified: String,
+    pub hunks: Vec<RfdDiffHunk>,
+}
+
+impl RfdRevisionDiff {
+    pub fn new(from: RfdRevision, to: RfdRevision) -> Self {
+        let diff = RfdDiff::new(&from, &to);
+        Self {
+            from: from.into(),
+            to: to.into(),
+            attributes: diff.attributes,
+            unified: diff.unified,
+            hunks: diff.hunks,
+        }
+    }
+}
+
 #[partial(RfdWithoutContent)]
 #[partial(RfdWithPdf)]
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
@@ -281,11 +428,50 @@ impl From<TypedUuid<RfdRevisionId>> for RfdRevisionIdentifier {
     }
 }
 
+impl From<String> for RfdRevisionIdentifier {
+    /// Values that parse as a revision id are treated as one, anything else is assumed to be a
+    /// commit sha
+    fn from(value: String) -> Self {
+        match value.parse::<TypedUuid<RfdRevisionId>>() {
+            Ok(id) => Self::Id(id),
+            Err(_) => Self::Commit(CommitSha(value)),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum RfdRevisionMetadataChange {
     MajorChange(bool),
 }
 
+/// A newly created webhook subscription along with the secret used to sign its deliveries. The
+/// secret is only ever returned at creation time
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct CreatedWebhookSubscription {
+    pub subscription: WebhookSubscription,
+    pub secret: String,
+}
+
+/// The request that a write is performed on behalf of. Writes are recorded in the audit log along
+/// with the operation and id of the request that triggered them
+#[derive(Debug, Clone)]
+pub struct AuditRequest {
+    pub id: String,
+    pub operation: String,
+}
+
+impl<T> From<&RequestContext<T>> for AuditRequest
+where
+    T: ServerContext,
+{
+    fn from(rqctx: &RequestContext<T>) -> Self {
+        Self {
+            id: rqctx.request_id.clone(),
+            operation: rqctx.endpoint.operation_id.clone(),
+        }
+    }
+}
+
 impl RfdContext {
     pub async fn new(
         public_url: String,
@@ -309,6 +495,15 @@ impl RfdContext {
             ))
             .build();
 
+        for (name, template) in &content.templates {
+            template
+                .validate()
+                .map_err(|err| AppError::InvalidRfdTemplate {
+                    name: name.clone(),
+                    err,
+                })?;
+        }
+
         Ok(Self {
             public_url,
             storage,
@@ -326,6 +521,8 @@ impl RfdContext {
                     .get("new")
                     .cloned()
                     .ok_or(AppError::MissingNewRfdTemplate)?,
+                templates: content.templates,
+                state_transitions: content.state_transitions,
             },
             github: GitHubRfdRepo::new(
                 &match services.github.auth {
@@ -361,6 +558,7 @@ impl RfdContext {
                 services.github.default_branch,
             )
             .await?,
+            discussions: DiscussionCache::new(DISCUSSION_CACHE_TTL),
             v_context: Arc::new(v_context),
         })
     }
@@ -371,16 +569,20 @@ impl RfdContext {
     pub async fn create_rfd(
         &self,
         caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
         title: String,
-        content: Option<String>,
+        template: RfdTemplate,
+        number: Option<i32>,
     ) -> ResourceResult<RfdNumber, UpdateRfdContentError> {
         if caller.can(&RfdPermission::CreateRfd) {
             tracing::info!("Reserving new RFD");
 
-            // We acknowledge that there are race conditions here, as there would be if an end user
-            // were to attempt to reserve a RFD number manually
+            // GitHub is only consulted for the lowest number that does not yet have a published
+            // RFD or an open branch. The reservation table is the source of truth for allocation,
+            // and its unique constraint ensures that concurrent requests can not be handed the
+            // same number
             let GitHubNewRfdNumber {
-                number: next_rfd_number,
+                number: github_next_rfd_number,
                 commit,
             } = self
                 .github
@@ -389,41 +591,84 @@ impl RfdContext {
                 .map_err(UpdateRfdContentError::GitHub)
                 .map_err(ResourceError::InternalError)?;
 
-            let content = match content {
-                Some(content) => self
-                    .content
-                    .new_template
-                    .clone()
-                    .field("number".to_string(), next_rfd_number.to_string())
-                    .field("title".to_string(), title)
-                    .field("body".to_string(), content),
-                None => self
-                    .content
-                    .placeholder_template
-                    .clone()
-                    .field("number".to_string(), next_rfd_number.to_string())
-                    .field("title".to_string(), title),
+            let reservation = match number {
+                Some(number) => {
+                    self.claim_rfd_number(caller, number, github_next_rfd_number.into())
+                        .await
+                }
+                None => {
+                    self.allocate_rfd_number(caller, github_next_rfd_number.into())
+                        .await
+                }
+            }
+            .inner_err_into()?;
+            let next_rfd_number = RfdNumber::from(reservation.rfd_number);
+
+            let result = self
+                .push_reserved_rfd(caller, next_rfd_number, template, commit)
+                .await;
+
+            // Return the number to the pool if the RFD could not be pushed to GitHub so that it
+            // is not left allocated to an RFD that does not exist
+            if result.is_err() {
+                self.release_rfd_number(&reservation).await;
             }
+
+            let reserved_commit = result?;
+
+            self.record_audit_event(
+                caller,
+                request,
+                Some(next_rfd_number.into()),
+                None,
+                Some(json!({ "title": title, "commit": reserved_commit })),
+            )
+            .await;
+
+            self.emit_webhook_event(
+                WebhookEvent::Reserved,
+                next_rfd_number.into(),
+                json!({ "title": title, "commit": reserved_commit }),
+            )
+            .await;
+
+            Ok(next_rfd_number)
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// Create the branch for a newly reserved RFD and push its initial document
+    async fn push_reserved_rfd(
+        &self,
+        caller: &Caller<RfdPermission>,
+        next_rfd_number: RfdNumber,
+        template: RfdTemplate,
+        commit: CommitSha,
+    ) -> ResourceResult<Option<CommitSha>, UpdateRfdContentError> {
+        let content = template
+            .field("number".to_string(), next_rfd_number.to_string())
             .build()
             .map_err(UpdateRfdContentError::InvalidTemplate)
             .map_err(ResourceError::InternalError)?;
 
-            tracing::info!(?next_rfd_number, ?commit, "Creating new RFD branch");
+        tracing::info!(?next_rfd_number, ?commit, "Creating new RFD branch");
 
-            // Branch off of the default branch with a new branch with the padded form of the RFD number
-            self.github
-                .create_branch(&next_rfd_number, &commit)
-                .await
-                .map_err(UpdateRfdContentError::GitHub)
-                .map_err(ResourceError::InternalError)?;
+        // Branch off of the default branch with a new branch with the padded form of the RFD number
+        self.github
+            .create_branch(&next_rfd_number, &commit)
+            .await
+            .map_err(UpdateRfdContentError::GitHub)
+            .map_err(ResourceError::InternalError)?;
 
-            tracing::info!(
-                ?next_rfd_number,
-                ?commit,
-                "Created new branch for reserving RFD off of default branch"
-            );
+        tracing::info!(
+            ?next_rfd_number,
+            ?commit,
+            "Created new branch for reserving RFD off of default branch"
+        );
 
-            self.commit_rfd_document(
+        let reserved_commit = self
+            .commit_rfd_document(
                 caller,
                 next_rfd_number,
                 &content.render(),
@@ -433,49 +678,372 @@ impl RfdContext {
             )
             .await?;
 
-            tracing::info!(
-                ?next_rfd_number,
-                "Pushed placeholder RFD to reserved branch"
+        tracing::info!(
+            ?next_rfd_number,
+            "Pushed placeholder RFD to reserved branch"
+        );
+
+        Ok(reserved_commit)
+    }
+
+    pub fn list_rfd_templates(
+        &self,
+        caller: &Caller<RfdPermission>,
+    ) -> ResourceResult<Vec<RfdTemplateSummary>, StoreError> {
+        if caller.can(&RfdPermission::CreateRfd) {
+            let mut templates = self
+                .content
+                .templates
+                .iter()
+                .map(|(name, template)| RfdTemplateSummary {
+                    name: name.clone(),
+                    description: template.description().map(|d| d.to_string()),
+                    fields: template
+                        .required_fields()
+                        .iter()
+                        .filter(|field| !GENERATED_TEMPLATE_FIELDS.contains(&field.as_str()))
+                        .cloned()
+                        .collect(),
+                })
+                .collect::<Vec<_>>();
+            templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+            Ok(templates)
+        } else {
+            resource_restricted()
+        }
+    }
+
+    // RFD Number Reservation Operations
+
+    /// Allocate the lowest number at or above `min_rfd_number` that is not already reserved. If
+    /// another request reserves the same number first, allocation is retried with a fresh view
+    /// of the reservation table
+    #[instrument(skip(self, caller), err(Debug))]
+    async fn allocate_rfd_number(
+        &self,
+        caller: &Caller<RfdPermission>,
+        min_rfd_number: i32,
+    ) -> ResourceResult<RfdNumberReservation, StoreError> {
+        for attempt in 0..RFD_NUMBER_ALLOCATION_ATTEMPTS {
+            let reserved = RfdNumberReservationStore::list(
+                &*self.storage,
+                vec![RfdNumberReservationFilter::default().min_rfd_number(Some(min_rfd_number))],
+                &ListPagination::unlimited(),
+            )
+            .await?
+            .into_iter()
+            .map(|reservation| reservation.rfd_number)
+            .collect::<Vec<_>>();
+
+            let rfd_number = next_unreserved_rfd_number(min_rfd_number, &reserved);
+            let reservation = RfdNumberReservationStore::create(
+                &*self.storage,
+                vec![NewRfdNumberReservation {
+                    id: TypedUuid::new_v4(),
+                    rfd_number,
+                    group_id: None,
+                    created_by: caller.id.into_untyped_uuid(),
+                    claimed_by: Some(caller.id.into_untyped_uuid()),
+                    claimed_at: Some(Utc::now()),
+                }],
+            )
+            .await?
+            .pop();
+
+            match reservation {
+                Some(reservation) => return Ok(reservation),
+                None => tracing::info!(
+                    ?rfd_number,
+                    ?attempt,
+                    "RFD number was reserved by a concurrent request"
+                ),
+            }
+        }
+
+        Err(ResourceError::Conflict)
+    }
+
+    /// Claim a specific RFD number. Numbers held for a group may be claimed by members of the
+    /// group, while unreserved numbers may only be claimed by callers that manage reservations
+    #[instrument(skip(self, caller), err(Debug))]
+    async fn claim_rfd_number(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+        min_rfd_number: i32,
+    ) -> ResourceResult<RfdNumberReservation, StoreError> {
+        let existing = RfdNumberReservationStore::list(
+            &*self.storage,
+            vec![RfdNumberReservationFilter::default().rfd_number(Some(vec![rfd_number]))],
+            &ListPagination::default().limit(1),
+        )
+        .await?
+        .pop();
+
+        match existing {
+            Some(reservation) if reservation.claimed_at.is_some() => Err(ResourceError::Conflict),
+            Some(reservation) => {
+                let permitted = caller.can(&RfdPermission::ManageRfdReservations)
+                    || match reservation.group_id {
+                        Some(group_id) => self.is_group_member(caller, group_id).await,
+                        None => false,
+                    };
+
+                if permitted {
+                    RfdNumberReservationStore::claim(
+                        &*self.storage,
+                        rfd_number,
+                        caller.id.into_untyped_uuid(),
+                    )
+                    .await?
+                    .ok_or(ResourceError::Conflict)
+                } else {
+                    resource_restricted()
+                }
+            }
+            None => {
+                if !caller.can(&RfdPermission::ManageRfdReservations) {
+                    resource_restricted()
+                } else if rfd_number < min_rfd_number {
+                    // Numbers below the next number on GitHub already have a published RFD or an
+                    // open branch
+                    Err(ResourceError::Conflict)
+                } else {
+                    RfdNumberReservationStore::create(
+                        &*self.storage,
+                        vec![NewRfdNumberReservation {
+                            id: TypedUuid::new_v4(),
+                            rfd_number,
+                            group_id: None,
+                            created_by: caller.id.into_untyped_uuid(),
+                            claimed_by: Some(caller.id.into_untyped_uuid()),
+                            claimed_at: Some(Utc::now()),
+                        }],
+                    )
+                    .await?
+                    .pop()
+                    .ok_or(ResourceError::Conflict)
+                }
+            }
+        }
+    }
+
+    /// Undo a claim made while reserving an RFD. Numbers that were held for a group are returned
+    /// to the group, all other numbers are made available for allocation again
+    async fn release_rfd_number(&self, reservation: &RfdNumberReservation) {
+        let result = if reservation.group_id.is_some() {
+            RfdNumberReservationStore::unclaim(&*self.storage, reservation.rfd_number).await
+        } else {
+            RfdNumberReservationStore::delete(&*self.storage, reservation.rfd_number).await
+        };
+
+        if let Err(err) = result {
+            tracing::error!(
+                ?err,
+                rfd_number = reservation.rfd_number,
+                "Failed to release RFD number reservation"
             );
+        }
+    }
 
-            Ok(next_rfd_number)
+    async fn is_group_member(&self, caller: &Caller<RfdPermission>, group_id: Uuid) -> bool {
+        let registration = self.v_ctx().builtin_registration_user();
+        self.v_ctx()
+            .user
+            .get_api_user(&registration, &caller.id)
+            .await
+            .map(|info| {
+                info.user
+                    .groups
+                    .contains(&TypedUuid::from_untyped_uuid(group_id))
+            })
+            .unwrap_or(false)
+    }
+
+    pub async fn list_rfd_number_reservations(
+        &self,
+        caller: &Caller<RfdPermission>,
+        filter: RfdNumberReservationFilter,
+        pagination: &ListPagination,
+    ) -> ResourceResult<Vec<RfdNumberReservation>, StoreError> {
+        if caller.can(&RfdPermission::ManageRfdReservations) {
+            Ok(RfdNumberReservationStore::list(&*self.storage, vec![filter], pagination).await?)
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// Hold a range of RFD numbers for a group. Held numbers are skipped during allocation and can
+    /// only be claimed by members of the group
+    #[instrument(skip(self, caller), err(Debug))]
+    pub async fn hold_rfd_numbers(
+        &self,
+        caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
+        start: i32,
+        end: i32,
+        group_id: Uuid,
+    ) -> ResourceResult<Vec<RfdNumberReservation>, RfdNumberReservationError> {
+        if caller.can(&RfdPermission::ManageRfdReservations) {
+            let GitHubNewRfdNumber {
+                number: github_next_rfd_number,
+                ..
+            } = self
+                .github
+                .next_rfd_number()
+                .await
+                .map_err(RfdNumberReservationError::GitHub)
+                .map_err(ResourceError::InternalError)?;
+
+            if start < i32::from(github_next_rfd_number) {
+                tracing::info!(
+                    ?start,
+                    ?github_next_rfd_number,
+                    "Requested range overlaps with RFDs that exist on GitHub"
+                );
+                return Err(ResourceError::Conflict);
+            }
+
+            let new_reservations = (start..=end)
+                .map(|rfd_number| NewRfdNumberReservation {
+                    id: TypedUuid::new_v4(),
+                    rfd_number,
+                    group_id: Some(group_id),
+                    created_by: caller.id.into_untyped_uuid(),
+                    claimed_by: None,
+                    claimed_at: None,
+                })
+                .collect::<Vec<_>>();
+
+            // A range is held in its entirety or not at all. Nothing is held if any number in the
+            // range was already reserved
+            let reservations =
+                RfdNumberReservationStore::create_all(&*self.storage, new_reservations)
+                    .await
+                    .map_err(RfdNumberReservationError::Storage)
+                    .map_err(ResourceError::InternalError)?;
+
+            if reservations.is_empty() {
+                tracing::info!(
+                    ?start,
+                    ?end,
+                    "Requested range overlaps with reserved numbers"
+                );
+                return Err(ResourceError::Conflict);
+            }
+
+            self.record_audit_event(
+                caller,
+                request,
+                None,
+                None,
+                Some(json!({ "start": start, "end": end, "group_id": group_id })),
+            )
+            .await;
+
+            Ok(reservations)
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// Release a held RFD number that has not yet been claimed
+    #[instrument(skip(self, caller), err(Debug))]
+    pub async fn release_held_rfd_number(
+        &self,
+        caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
+        rfd_number: i32,
+    ) -> ResourceResult<RfdNumberReservation, StoreError> {
+        if caller.can(&RfdPermission::ManageRfdReservations) {
+            let reservation = RfdNumberReservationStore::list(
+                &*self.storage,
+                vec![RfdNumberReservationFilter::default().rfd_number(Some(vec![rfd_number]))],
+                &ListPagination::default().limit(1),
+            )
+            .await?
+            .pop();
+
+            match reservation {
+                Some(reservation) if reservation.claimed_at.is_some() => {
+                    Err(ResourceError::Conflict)
+                }
+                Some(_) => {
+                    let reservation = RfdNumberReservationStore::delete(&*self.storage, rfd_number)
+                        .await?
+                        .ok_or(ResourceError::DoesNotExist)?;
+
+                    self.record_audit_event(
+                        caller,
+                        request,
+                        Some(rfd_number),
+                        Some(json!({ "reservation": reservation })),
+                        None,
+                    )
+                    .await;
+
+                    Ok(reservation)
+                }
+                None => resource_not_found(),
+            }
         } else {
             resource_restricted()
         }
     }
 
+    /// Restrict a filter to only the RFDs that the caller is allowed to access. Callers without
+    /// access to all RFDs are limited to public RFDs and those they have been granted direct
+    /// access to
+    fn rfd_access_filter(&self, caller: &Caller<RfdPermission>, filter: RfdFilter) -> RfdFilter {
+        if caller.can(&RfdPermission::GetRfdsAll) {
+            filter
+        } else {
+            let mut access = vec![RfdFilter::default().public(Some(true))];
+
+            let allowed_rfds = caller.allow_rfds();
+            if !allowed_rfds.is_empty() {
+                access.push(
+                    RfdFilter::default().rfd_number(Some(allowed_rfds.into_iter().collect())),
+                );
+            }
+
+            RfdFilter::default()
+                .deleted(filter.deleted)
+                .all(Some(vec![filter, RfdFilter::default().any(Some(access))]))
+        }
+    }
+
     pub async fn list_rfds(
         &self,
         caller: &Caller<RfdPermission>,
         filter: Option<RfdFilter>,
+        sort: &RfdSort,
+        pagination: &ListPagination,
     ) -> ResourceResult<Vec<RfdWithoutContent>, StoreError> {
-        // List all of the RFDs first and then perform filter. This should be be improved once
-        // filters can be combined to support OR expressions. Effectively we need to be able to
-        // express "Has access to OR is public" with a filter
-        let mut rfds = RfdMetaStore::list(
+        let rfds = RfdMetaStore::list(
             &*self.storage,
-            filter.map(|filter| vec![filter]).unwrap_or_default(),
-            &ListPagination::default().limit(UNLIMITED),
+            vec![self.rfd_access_filter(caller, filter.unwrap_or_default())],
+            sort,
+            pagination,
         )
         .await
         .tap_err(|err| tracing::error!(?err, "Failed to lookup RFDs"))?;
 
-        // Filter the list of RFDs down to only those that the caller is allowed to access
-        rfds.retain_mut(|rfd| {
-            caller.can(&RfdPermission::GetRfdsAll)
-                || caller.can(&RfdPermission::GetRfd(rfd.rfd_number))
-                || rfd.visibility == Visibility::Public
-        });
-
-        let mut rfd_list = rfds
-            .into_iter()
-            .map(RfdWithoutContent::from)
-            .collect::<Vec<_>>();
-
-        // Finally sort the RFD list by RFD number
-        rfd_list.sort_by_key(|b| std::cmp::Reverse(b.rfd_number));
+        Ok(rfds.into_iter().map(RfdWithoutContent::from).collect())
+    }
 
-        Ok(rfd_list)
+    pub async fn count_rfds(
+        &self,
+        caller: &Caller<RfdPermission>,
+        filter: Option<RfdFilter>,
+    ) -> ResourceResult<i64, StoreError> {
+        Ok(RfdMetaStore::count(
+            &*self.storage,
+            vec![self.rfd_access_filter(caller, filter.unwrap_or_default())],
+        )
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to count RFDs"))?)
     }
 
     #[instrument(skip(self, caller))]
@@ -504,45 +1072,135 @@ impl RfdContext {
         }
     }
 
+    /// List the RFDs referenced by the latest revision of an RFD. Referenced RFDs that the caller
+    /// does not have access to are omitted
     #[instrument(skip(self, caller))]
-    async fn get_rfd(
+    pub async fn list_rfd_references(
         &self,
         caller: &Caller<RfdPermission>,
         rfd_number: i32,
-        revision: Option<RfdRevisionIdentifier>,
-    ) -> ResourceResult<Rfd, StoreError> {
-        let mut filter = RfdFilter::default().rfd_number(Some(vec![rfd_number]));
-        filter = match revision {
-            Some(RfdRevisionIdentifier::Commit(commit)) => filter.commit(Some(vec![commit])),
-            Some(RfdRevisionIdentifier::Id(revision)) => filter.revision(Some(vec![revision])),
-            None => filter,
-        };
+    ) -> ResourceResult<Vec<RfdWithoutContent>, StoreError> {
+        let rfd = self.get_rfd_meta(caller, rfd_number, None).await?;
 
-        let rfd = RfdStore::list(&*self.storage, vec![filter], &ListPagination::latest())
+        let references = match rfd.content {
+            Some(revision) => RfdReferenceStore::list(
+                &*self.storage,
+                vec![RfdReferenceFilter::default().rfd_revision(Some(vec![revision.id]))],
+                &ListPagination::unlimited(),
+            )
             .await?
-            .pop();
+            .into_iter()
+            .map(|reference| reference.target_rfd_number)
+            .collect::<Vec<_>>(),
+            None => vec![],
+        };
 
-        if let Some(rfd) = rfd {
-            if caller.can(&RfdPermission::GetRfdsAll)
-                || caller.can(&RfdPermission::GetRfd(rfd.rfd_number))
-                || rfd.visibility == Visibility::Public
-            {
-                Ok(rfd)
-            } else {
-                resource_not_found()
-            }
+        if references.is_empty() {
+            Ok(vec![])
         } else {
-            resource_not_found()
+            self.list_rfds(
+                caller,
+                Some(RfdFilter::default().rfd_number(Some(references))),
+                &RfdSort::default(),
+                &ListPagination::unlimited(),
+            )
+            .await
         }
     }
 
+    /// List the RFDs whose latest revision references an RFD. Only RFDs that the caller has access
+    /// to are included
     #[instrument(skip(self, caller))]
-    async fn get_rfd_meta(
+    pub async fn list_rfd_backlinks(
         &self,
         caller: &Caller<RfdPermission>,
         rfd_number: i32,
-        revision: Option<RfdRevisionIdentifier>,
-    ) -> ResourceResult<RfdMeta, StoreError> {
+    ) -> ResourceResult<Vec<RfdWithoutContent>, StoreError> {
+        self.get_rfd_meta(caller, rfd_number, None).await?;
+
+        self.list_rfds(
+            caller,
+            Some(RfdFilter::default().references(Some(rfd_number))),
+            &RfdSort::default(),
+            &ListPagination::unlimited(),
+        )
+        .await
+    }
+
+    /// Prepare an archive of every RFD that the caller has access to. RFDs are written in
+    /// ascending order by number
+    #[instrument(skip(self, caller))]
+    pub async fn export_rfds(
+        &self,
+        caller: &Caller<RfdPermission>,
+        include_history: bool,
+    ) -> ResourceResult<RfdExport, StoreError> {
+        let rfds = self
+            .list_rfds(
+                caller,
+                None,
+                &RfdSort::default().direction(SortDirection::Asc),
+                &ListPagination::unlimited(),
+            )
+            .await?;
+
+        Ok(RfdExport::new(self.storage.clone(), rfds, include_history))
+    }
+
+    /// List the most recent activity of the RFDs that the caller has access to, newest first. When
+    /// an RFD number is given only the activity of that RFD is included
+    #[instrument(skip(self, caller))]
+    pub async fn list_rfd_feed_entries(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: Option<i32>,
+        limit: i64,
+    ) -> ResourceResult<Vec<RfdFeedEntry>, StoreError> {
+        let rfds = match rfd_number {
+            Some(rfd_number) => vec![self.get_rfd_meta(caller, rfd_number, None).await?.into()],
+            None => {
+                self.list_rfds(
+                    caller,
+                    None,
+                    &RfdSort::default(),
+                    &ListPagination::unlimited(),
+                )
+                .await?
+            }
+        };
+        let numbers = rfds
+            .into_iter()
+            .map(|rfd: RfdWithoutContent| (rfd.id, rfd.rfd_number))
+            .collect::<HashMap<_, _>>();
+
+        if numbers.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let revisions = RfdRevisionMetaStore::list_feed(
+            &*self.storage,
+            numbers.keys().copied().collect(),
+            &ListPagination::default().limit(limit),
+        )
+        .await?;
+
+        Ok(revisions
+            .into_iter()
+            .filter_map(|revision| {
+                numbers
+                    .get(&revision.revision.rfd_id)
+                    .map(|rfd_number| RfdFeedEntry::new(*rfd_number, revision))
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self, caller))]
+    async fn get_rfd(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+        revision: Option<RfdRevisionIdentifier>,
+    ) -> ResourceResult<Rfd, StoreError> {
         let mut filter = RfdFilter::default().rfd_number(Some(vec![rfd_number]));
         filter = match revision {
             Some(RfdRevisionIdentifier::Commit(commit)) => filter.commit(Some(vec![commit])),
@@ -550,7 +1208,7 @@ impl RfdContext {
             None => filter,
         };
 
-        let rfd = RfdMetaStore::list(&*self.storage, vec![filter], &ListPagination::latest())
+        let rfd = RfdStore::list(&*self.storage, vec![filter], &ListPagination::latest())
             .await?
             .pop();
 
@@ -568,6 +1226,43 @@ impl RfdContext {
         }
     }
 
+    #[instrument(skip(self, caller))]
+    async fn get_rfd_meta(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+        revision: Option<RfdRevisionIdentifier>,
+    ) -> ResourceResult<RfdMeta, StoreError> {
+        let mut filter = RfdFilter::default().rfd_number(Some(vec![rfd_number]));
+        filter = match revision {
+            Some(RfdRevisionIdentifier::Commit(commit)) => filter.commit(Some(vec![commit])),
+            Some(RfdRevisionIdentifier::Id(revision)) => filter.revision(Some(vec![revision])),
+            None => filter,
+        };
+
+        let rfd = RfdMetaStore::list(
+            &*self.storage,
+            vec![filter],
+            &RfdSort::default(),
+            &ListPagination::latest(),
+        )
+        .await?
+        .pop();
+
+        if let Some(rfd) = rfd {
+            if caller.can(&RfdPermission::GetRfdsAll)
+                || caller.can(&RfdPermission::GetRfd(rfd.rfd_number))
+                || rfd.visibility == Visibility::Public
+            {
+                Ok(rfd)
+            } else {
+                resource_not_found()
+            }
+        } else {
+            resource_not_found()
+        }
+    }
+
     #[instrument(skip(self, caller))]
     async fn get_rfd_pdf(
         &self,
@@ -622,6 +1317,33 @@ impl RfdContext {
         Ok(rfd.into())
     }
 
+    /// Get the rendered HTML of a revision of an RFD. Revisions that have not been rendered by the
+    /// processor are reported as not found
+    #[instrument(skip(self, caller))]
+    pub async fn view_rfd_html(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+        revision: Option<RfdRevisionIdentifier>,
+    ) -> ResourceResult<RfdHtml, StoreError> {
+        let rfd = self.get_rfd_meta(caller, rfd_number, revision).await?;
+
+        let html = match rfd.content {
+            Some(revision) => RfdRevisionHtmlStore::get(&*self.storage, &revision.id).await?,
+            None => None,
+        };
+
+        match html {
+            Some(html) => Ok(RfdHtml {
+                rfd_number: rfd.rfd_number,
+                revision: html.rfd_revision_id,
+                html: html.html,
+                rendered_at: html.updated_at,
+            }),
+            None => resource_not_found(),
+        }
+    }
+
     #[instrument(skip(self, caller))]
     pub async fn view_rfd_revision(
         &self,
@@ -633,6 +1355,21 @@ impl RfdContext {
         Ok::<_, StoreError>(rfd.content).optional()
     }
 
+    #[instrument(skip(self, caller))]
+    pub async fn view_rfd_revision_diff(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+        from: RfdRevisionIdentifier,
+        to: RfdRevisionIdentifier,
+    ) -> ResourceResult<RfdRevisionDiff, StoreError> {
+        let from = self
+            .view_rfd_revision(caller, rfd_number, Some(from))
+            .await?;
+        let to = self.view_rfd_revision(caller, rfd_number, Some(to)).await?;
+        Ok(RfdRevisionDiff::new(from, to))
+    }
+
     async fn get_latest_rfd_revision(
         &self,
         caller: &Caller<RfdPermission>,
@@ -651,14 +1388,66 @@ impl RfdContext {
         self.get_rfd_pdf(caller, rfd_number, revision).await
     }
 
+    #[instrument(skip(self, caller))]
+    pub async fn view_rfd_discussion(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+        revision: Option<RfdRevisionIdentifier>,
+    ) -> ResourceResult<RfdDiscussion, GetRfdDiscussionError> {
+        if caller.any(
+            [
+                RfdPermission::GetDiscussion(rfd_number),
+                RfdPermission::GetDiscussionsAll,
+            ]
+            .iter(),
+        ) {
+            let revision = self
+                .view_rfd_revision(caller, rfd_number, revision)
+                .await
+                .inner_err_into()?;
+
+            // Only discussions that are hosted on a pull request in the RFD repository can be
+            // retrieved. Any other link is treated as if the RFD has no discussion
+            let Some((url, pull_request)) = revision.discussion.and_then(|url| {
+                self.github
+                    .pull_request_number(&url)
+                    .map(|number| (url, number))
+            }) else {
+                return resource_not_found();
+            };
+
+            if let Some(discussion) = self.discussions.get(&revision.commit, pull_request).await {
+                tracing::debug!(?pull_request, "Found cached discussion");
+                return Ok(discussion);
+            }
+
+            let discussion = self
+                .github
+                .pull_request_comments(pull_request)
+                .discussion()
+                .await
+                .map_err(GetRfdDiscussionError::GitHub)
+                .map_err(ResourceError::InternalError)?;
+            let discussion = RfdDiscussion::new(url, revision.commit, discussion);
+
+            self.discussions.insert(discussion.clone()).await;
+
+            Ok(discussion)
+        } else {
+            resource_restricted()
+        }
+    }
+
     #[instrument(skip(self, caller, content))]
     pub async fn update_rfd_content(
         &self,
         caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
         rfd_number: i32,
         content: &str,
         message: Option<&str>,
-        branch_name: Option<&str>,
+        base: Option<UpdateBase>,
     ) -> ResourceResult<Option<CommitSha>, UpdateRfdContentError> {
         if caller.any(
             [
@@ -671,9 +1460,13 @@ impl RfdContext {
                 .get_latest_rfd_revision(caller, rfd_number)
                 .await
                 .inner_err_into()?;
+            let base_revision = self
+                .resolve_update_base(caller, rfd_number, &latest_revision, base.as_ref())
+                .await?;
 
             let sha = latest_revision.commit.clone();
-            let mut updated_content: RfdContent = latest_revision
+            let mut updated_content: RfdContent = base_revision
+                .clone()
                 .try_into()
                 .map_err(UpdateRfdContentError::InvalidContent)
                 .map_err(ResourceError::InternalError)?;
@@ -681,16 +1474,30 @@ impl RfdContext {
                 .update_body(content)
                 .map_err(UpdateRfdContentError::InvalidContent)
                 .map_err(ResourceError::InternalError)?;
+            let document = merge_update(&latest_revision, &base_revision, updated_content.raw())?;
+            self.check_rfd_state_transition(caller, &latest_revision, &document)?;
+
+            let commit = self
+                .commit_rfd_document(
+                    caller,
+                    rfd_number.into(),
+                    &document,
+                    message,
+                    sha.clone(),
+                    None,
+                )
+                .await?;
 
-            self.commit_rfd_document(
+            self.record_audit_event(
                 caller,
-                rfd_number.into(),
-                updated_content.raw(),
-                message,
-                sha,
-                branch_name,
+                request,
+                Some(rfd_number),
+                Some(json!({ "commit": sha })),
+                Some(json!({ "commit": commit })),
             )
-            .await
+            .await;
+
+            Ok(commit)
         } else {
             resource_restricted()
         }
@@ -700,10 +1507,11 @@ impl RfdContext {
     pub async fn update_rfd_document(
         &self,
         caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
         rfd_number: i32,
         document: &str,
         message: Option<&str>,
-        branch_name: Option<&str>,
+        base: Option<UpdateBase>,
     ) -> ResourceResult<Option<CommitSha>, UpdateRfdContentError> {
         if caller.any(
             [
@@ -716,24 +1524,157 @@ impl RfdContext {
                 .get_latest_rfd_revision(caller, rfd_number)
                 .await
                 .inner_err_into()?;
+            let base_revision = self
+                .resolve_update_base(caller, rfd_number, &latest_revision, base.as_ref())
+                .await?;
+            let document = merge_update(&latest_revision, &base_revision, document)?;
+            self.check_rfd_state_transition(caller, &latest_revision, &document)?;
             let sha = latest_revision.commit;
 
             tracing::info!(?sha, "Found commit to update from");
 
-            self.commit_rfd_document(
+            let commit = self
+                .commit_rfd_document(
+                    caller,
+                    rfd_number.into(),
+                    &document,
+                    message,
+                    sha.clone(),
+                    None,
+                )
+                .await?;
+
+            self.record_audit_event(
                 caller,
-                rfd_number.into(),
-                document,
-                message,
-                sha,
-                branch_name,
+                request,
+                Some(rfd_number),
+                Some(json!({ "commit": sha })),
+                Some(json!({ "commit": commit })),
             )
-            .await
+            .await;
+
+            Ok(commit)
         } else {
             resource_restricted()
         }
     }
 
+    /// Check that an updated document only moves an RFD between states that are allowed by the
+    /// configured transitions, and that the caller is permitted to move RFDs into the new state
+    fn check_rfd_state_transition(
+        &self,
+        caller: &Caller<RfdPermission>,
+        latest_revision: &RfdRevision,
+        document: &str,
+    ) -> ResourceResult<(), UpdateRfdContentError> {
+        let content = match latest_revision.content_format {
+            ContentFormat::Asciidoc => RfdContent::Asciidoc(
+                RfdAsciidoc::new(document)
+                    .map_err(|err| UpdateRfdContentError::InvalidDocument(err.into()))
+                    .map_err(ResourceError::InternalError)?,
+            ),
+            ContentFormat::Markdown => RfdContent::Markdown(RfdMarkdown::new(document)),
+        };
+
+        let from = latest_revision
+            .state
+            .as_deref()
+            .and_then(|state| RfdState::try_from(state).ok());
+        let to = content
+            .get_state()
+            .and_then(|state| RfdState::try_from(state).ok());
+
+        self.check_state_transition(caller, from, to)
+    }
+
+    fn check_state_transition(
+        &self,
+        caller: &Caller<RfdPermission>,
+        from: Option<RfdState>,
+        to: Option<RfdState>,
+    ) -> ResourceResult<(), UpdateRfdContentError> {
+        // Documents without a recognizable state are reported by the linter instead
+        let Some(to) = to else {
+            return Ok(());
+        };
+
+        if from == Some(to) {
+            return Ok(());
+        }
+
+        if let Some(from) = from
+            && !self.content.state_transitions.allows(from, to)
+        {
+            tracing::info!(?from, ?to, "Rejecting invalid RFD state transition");
+            return Err(ResourceError::InternalError(
+                UpdateRfdContentError::InvalidStateTransition { from, to },
+            ));
+        }
+
+        let permission = match to {
+            RfdState::Published => Some(RfdPermission::PublishRfds),
+            RfdState::Committed => Some(RfdPermission::CommitRfds),
+            RfdState::Abandoned => Some(RfdPermission::AbandonRfds),
+            _ => None,
+        };
+
+        match permission {
+            Some(permission) if !caller.can(&permission) => resource_restricted(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the revision that an update was written against. Updates that do not specify a base are
+    /// written against the latest revision. An update with a stale base is rejected unless a merge
+    /// was requested, in which case the base revision is loaded so that it can be merged
+    pub async fn view_rfd_update_base(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+        base: Option<&UpdateBase>,
+    ) -> ResourceResult<RfdRevision, UpdateRfdContentError> {
+        let latest_revision = self
+            .get_latest_rfd_revision(caller, rfd_number)
+            .await
+            .inner_err_into()?;
+        self.resolve_update_base(caller, rfd_number, &latest_revision, base)
+            .await
+    }
+
+    async fn resolve_update_base(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+        latest_revision: &RfdRevision,
+        base: Option<&UpdateBase>,
+    ) -> ResourceResult<RfdRevision, UpdateRfdContentError> {
+        let conflict = || {
+            ResourceError::InternalError(UpdateRfdContentError::Conflict {
+                head: latest_revision.commit.clone(),
+            })
+        };
+
+        match base {
+            Some(base) if base.commit != latest_revision.commit => {
+                if !base.merge {
+                    return Err(conflict());
+                }
+
+                tracing::info!(base = ?base.commit, head = ?latest_revision.commit, "Update base is stale, attempting to merge");
+
+                match self
+                    .view_rfd_revision(caller, rfd_number, Some(base.commit.clone().into()))
+                    .await
+                {
+                    Ok(revision) => Ok(revision),
+                    Err(ResourceError::DoesNotExist) => Err(conflict()),
+                    Err(err) => Err(err).inner_err_into(),
+                }
+            }
+            _ => Ok(latest_revision.clone()),
+        }
+    }
+
     #[instrument(skip(self, caller, document), err(Debug))]
     async fn commit_rfd_document(
         &self,
@@ -746,6 +1687,146 @@ impl RfdContext {
     ) -> ResourceResult<Option<CommitSha>, UpdateRfdContentError> {
         tracing::info!("Pushing update to GitHub");
 
+        let location = self.rfd_location(&rfd_number, head, branch_name).await?;
+        let message = self.commit_message(caller, message).await?;
+
+        tracing::info!("Pushing RFD commit to GitHub");
+
+        let commit = match location
+            .upsert(&rfd_number, document.as_bytes(), &message)
+            .await
+        {
+            Ok(commit) => commit,
+            Err(err) if is_github_conflict(&err) => {
+                // The RFD was modified on GitHub after the revision that the update was applied
+                // to. Report the commit that the caller needs to rebase their update on to
+                tracing::info!(?err, "GitHub rejected the update as conflicting");
+                let head = location
+                    .latest_commit(&rfd_number)
+                    .await
+                    .map_err(UpdateRfdContentError::GitHub)
+                    .map_err(ResourceError::InternalError)?;
+                return Err(ResourceError::InternalError(
+                    UpdateRfdContentError::Conflict { head },
+                ));
+            }
+            Err(err) => {
+                return Err(ResourceError::InternalError(UpdateRfdContentError::GitHub(
+                    err,
+                )));
+            }
+        };
+
+        // If we committed a change, immediately register a job as well
+        if let Some(commit) = commit.clone() {
+            self.register_commit_job(rfd_number, commit).await;
+        }
+
+        Ok(commit)
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn list_rfd_files(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+    ) -> ResourceResult<Vec<GitHubRfdFile>, UpdateRfdContentError> {
+        let latest_revision = self
+            .get_latest_rfd_revision(caller, rfd_number)
+            .await
+            .inner_err_into()?;
+        let rfd_number = RfdNumber::from(rfd_number);
+        let location = self
+            .rfd_location(&rfd_number, latest_revision.commit, None)
+            .await?;
+
+        location
+            .list_supporting_files(&rfd_number)
+            .await
+            .map_err(UpdateRfdContentError::GitHub)
+            .map_err(ResourceError::InternalError)
+    }
+
+    #[instrument(skip(self, caller, change))]
+    pub async fn update_rfd_file(
+        &self,
+        caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
+        rfd_number: i32,
+        path: &str,
+        change: RfdFileChange<'_>,
+        message: Option<&str>,
+    ) -> ResourceResult<CommitSha, UpdateRfdContentError> {
+        if caller.any(
+            [
+                RfdPermission::UpdateRfd(rfd_number),
+                RfdPermission::UpdateRfdsAll,
+            ]
+            .iter(),
+        ) {
+            let latest_revision = self
+                .get_latest_rfd_revision(caller, rfd_number)
+                .await
+                .inner_err_into()?;
+            let sha = latest_revision.commit;
+            let number = RfdNumber::from(rfd_number);
+
+            let location = self.rfd_location(&number, sha.clone(), None).await?;
+            let existing = location
+                .supporting_file_sha(&number, path)
+                .await
+                .map_err(UpdateRfdContentError::GitHub)
+                .map_err(ResourceError::InternalError)?;
+            let message = self.commit_message(caller, message).await?;
+
+            let commit = match (change, existing) {
+                (RfdFileChange::Create(_), Some(_)) => return Err(ResourceError::Conflict),
+                (RfdFileChange::Replace(_) | RfdFileChange::Delete, None) => {
+                    return resource_not_found()
+                }
+                (RfdFileChange::Create(content), None) => {
+                    location
+                        .write_supporting_file(&number, path, content, None, &message)
+                        .await
+                }
+                (RfdFileChange::Replace(content), Some(existing)) => {
+                    location
+                        .write_supporting_file(&number, path, content, Some(&existing), &message)
+                        .await
+                }
+                (RfdFileChange::Delete, Some(existing)) => {
+                    location
+                        .delete_supporting_file(&number, path, &existing, &message)
+                        .await
+                }
+            }
+            .map_err(UpdateRfdContentError::GitHub)
+            .map_err(ResourceError::InternalError)?;
+
+            self.register_commit_job(number, commit.clone()).await;
+
+            self.record_audit_event(
+                caller,
+                request,
+                Some(rfd_number),
+                Some(json!({ "commit": sha, "path": path })),
+                Some(json!({ "commit": commit, "path": path })),
+            )
+            .await;
+
+            Ok(commit)
+        } else {
+            resource_restricted()
+        }
+    }
+
+    /// Find the GitHub location that changes to an RFD at the given head commit should be pushed to
+    async fn rfd_location(
+        &self,
+        rfd_number: &RfdNumber,
+        head: CommitSha,
+        branch_name: Option<&str>,
+    ) -> ResourceResult<GitHubRfdLocation, UpdateRfdContentError> {
         let mut github_locations = self
             .github
             .locations_for_commit(head.clone())
@@ -761,6 +1842,29 @@ impl RfdContext {
             })
             .collect::<Vec<_>>();
 
+        match github_locations.len() {
+            0 => {
+                tracing::warn!(
+                    ?head,
+                    ?rfd_number,
+                    "Failed to find a GitHub location for most recent revision"
+                );
+                resource_not_found()
+            }
+            // Unwrap is checked by the location length
+            1 => Ok(github_locations.pop().unwrap()),
+            _ => Err(ResourceError::InternalError(
+                UpdateRfdContentError::InternalState,
+            )),
+        }
+    }
+
+    /// Build the message for a commit pushed on behalf of the caller
+    async fn commit_message(
+        &self,
+        caller: &Caller<RfdPermission>,
+        message: Option<&str>,
+    ) -> ResourceResult<String, UpdateRfdContentError> {
         let filter = ApiUserProviderFilter {
             api_user_id: Some(vec![caller.id]),
             ..Default::default()
@@ -790,58 +1894,31 @@ impl RfdContext {
             .and_then(|p| p.display_names.first().cloned())
             .unwrap_or_else(|| caller.id.to_string());
 
-        match github_locations.len() {
-            0 => {
-                tracing::warn!(
-                    ?head,
-                    ?rfd_number,
-                    "Failed to find a GitHub location for most recent revision"
-                );
-                resource_not_found()
-            }
-            1 => {
-                tracing::info!("Pushing RFD commit to GitHub");
-
-                let message = format!(
-                    "{}\n\nSubmitted by {}",
-                    message.unwrap_or("RFD API update"),
-                    display_name,
-                );
-
-                // Unwrap is checked by the location length
-                let location = github_locations.pop().unwrap();
-                let commit = location
-                    .upsert(&rfd_number, document.as_bytes(), &message)
-                    .await
-                    .map_err(UpdateRfdContentError::GitHub)
-                    .map_err(ResourceError::InternalError)?;
-
-                // If we committed a change, immediately register a job as well. This may conflict with
-                // a job already added by a webhook, this is fine and we can ignore the error
-                if let Some(commit) = commit.clone() {
-                    let new_job = NewJob {
-                        owner: self.github.owner.clone(),
-                        repository: self.github.repo.clone(),
-                        branch: rfd_number.as_number_string(),
-                        sha: commit.clone(),
-                        rfd: rfd_number.into(),
-                        // This job is not being triggered by a webhook
-                        webhook_delivery_id: None,
-                        committed_at: Utc::now(), // Use the current time or extract from commit if available
-                    };
+        Ok(format!(
+            "{}\n\nSubmitted by {}",
+            message.unwrap_or("RFD API update"),
+            display_name,
+        ))
+    }
 
-                    if let Err(err) = self.register_job(new_job).await {
-                        tracing::info!(?err, "Failed to register job for RFD update");
-                    } else {
-                        tracing::debug!(?rfd_number, ?commit, "Registered job for RFD update");
-                    }
-                }
+    /// Register a job for a commit pushed by the API. This may conflict with a job already added by
+    /// a webhook, this is fine and we can ignore the error
+    async fn register_commit_job(&self, rfd_number: RfdNumber, commit: CommitSha) {
+        let new_job = NewJob {
+            owner: self.github.owner.clone(),
+            repository: self.github.repo.clone(),
+            branch: rfd_number.as_number_string(),
+            sha: commit.clone(),
+            rfd: rfd_number.into(),
+            // This job is not being triggered by a webhook
+            webhook_delivery_id: None,
+            committed_at: Utc::now(), // Use the current time or extract from commit if available
+        };
 
-                Ok(commit)
-            }
-            _ => Err(ResourceError::InternalError(
-                UpdateRfdContentError::InternalState,
-            )),
+        if let Err(err) = self.register_job(new_job).await {
+            tracing::info!(?err, "Failed to register job for RFD update");
+        } else {
+            tracing::debug!(?rfd_number, ?commit, "Registered job for RFD update");
         }
     }
 
@@ -849,6 +1926,7 @@ impl RfdContext {
     pub async fn update_rfd_visibility(
         &self,
         caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
         rfd_number: i32,
         visibility: Visibility,
     ) -> ResourceResult<Rfd, StoreError> {
@@ -862,8 +1940,28 @@ impl RfdContext {
             )
         {
             let mut rfd = self.get_rfd_meta(caller, rfd_number, None).await?;
-            rfd.visibility = visibility;
-            Ok(RfdStore::upsert(&*self.storage, rfd.into()).await?)
+            let previous = std::mem::replace(&mut rfd.visibility, visibility);
+            let rfd = RfdStore::upsert(&*self.storage, rfd.into()).await?;
+
+            self.record_audit_event(
+                caller,
+                request,
+                Some(rfd_number),
+                Some(json!({ "visibility": previous })),
+                Some(json!({ "visibility": rfd.visibility })),
+            )
+            .await;
+
+            if previous != rfd.visibility {
+                self.emit_webhook_event(
+                    WebhookEvent::VisibilityChanged,
+                    rfd_number,
+                    json!({ "from": previous, "to": rfd.visibility }),
+                )
+                .await;
+            }
+
+            Ok(rfd)
         } else {
             resource_restricted()
         }
@@ -873,6 +1971,7 @@ impl RfdContext {
     pub async fn update_rfd_revision_metadata(
         &self,
         caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
         rfd_number: i32,
         id: TypedUuid<RfdRevisionId>,
         changes: &[RfdRevisionMetadataChange],
@@ -894,6 +1993,9 @@ impl RfdContext {
             return resource_not_found();
         }
 
+        let before = json!({ "revision": revision.id, "major_change": revision.major_change });
+        let was_major_change = revision.major_change;
+
         let mut to_update = NewRfdRevision::from(revision);
         for change in changes {
             match change {
@@ -903,7 +2005,30 @@ impl RfdContext {
             }
         }
 
-        Ok(RfdRevisionStore::upsert(&*self.storage, to_update).await?)
+        let revision = RfdRevisionStore::upsert(&*self.storage, to_update).await?;
+
+        self.record_audit_event(
+            caller,
+            request,
+            Some(rfd_number),
+            Some(before),
+            Some(json!({ "revision": revision.id, "major_change": revision.major_change })),
+        )
+        .await;
+
+        if !was_major_change && revision.major_change {
+            self.emit_webhook_event(
+                WebhookEvent::MajorRevision,
+                rfd_number,
+                json!({ "revision": revision.id, "commit": revision.commit }),
+            )
+            .await;
+
+            self.queue_watch_notification(WebhookEvent::MajorRevision, rfd_number, revision.id)
+                .await;
+        }
+
+        Ok(revision)
     }
 
     // Job Operations
@@ -913,23 +2038,423 @@ impl RfdContext {
         filter: Option<JobFilter>,
         pagination: &ListPagination,
     ) -> ResourceResult<Vec<Job>, StoreError> {
-        let mut jobs = JobStore::list(
+        let mut filter = filter.unwrap_or_default();
+
+        // Restrict the jobs to only those for RFDs that the caller is allowed to access
+        if !caller.can(&RfdPermission::GetRfdsAll) {
+            let allowed_rfds = caller.allow_rfds();
+            filter.rfd = Some(match filter.rfd {
+                Some(rfds) => rfds
+                    .into_iter()
+                    .filter(|rfd| allowed_rfds.contains(rfd))
+                    .collect(),
+                None => allowed_rfds.into_iter().collect(),
+            });
+        }
+
+        let mut jobs = JobStore::list(&*self.storage, vec![filter], pagination)
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to lookup jobs"))?;
+
+        // Finally sort the jobs list by create time
+        jobs.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+
+        Ok(jobs)
+    }
+
+    // Audit Operations
+
+    /// Record a write in the audit log. The write has already been applied by the time that it is
+    /// recorded, so a failure to record it is logged rather than returned to the caller
+    async fn record_audit_event(
+        &self,
+        caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
+        rfd_number: Option<i32>,
+        before: Option<serde_json::Value>,
+        after: Option<serde_json::Value>,
+    ) {
+        let event = NewAuditEvent {
+            id: TypedUuid::new_v4(),
+            caller_id: caller.id.into_untyped_uuid(),
+            operation: request.operation.clone(),
+            rfd_number,
+            before,
+            after,
+            request_id: request.id.clone(),
+        };
+
+        if let Err(err) = AuditEventStore::create(&*self.storage, event).await {
+            tracing::error!(?err, ?request, "Failed to record audit event");
+        }
+    }
+
+    pub async fn list_audit_events(
+        &self,
+        caller: &Caller<RfdPermission>,
+        filter: AuditEventFilter,
+        pagination: &ListPagination,
+    ) -> ResourceResult<Vec<AuditEvent>, StoreError> {
+        if caller.can(&RfdPermission::GetAuditEvents) {
+            Ok(AuditEventStore::list(&*self.storage, vec![filter], pagination).await?)
+        } else {
+            resource_restricted()
+        }
+    }
+
+    // Webhook Subscription Operations
+
+    pub async fn list_webhook_subscriptions(
+        &self,
+        caller: &Caller<RfdPermission>,
+        pagination: &ListPagination,
+    ) -> ResourceResult<Vec<WebhookSubscription>, StoreError> {
+        if caller.can(&RfdPermission::ManageWebhooks) {
+            Ok(WebhookSubscriptionStore::list(
+                &*self.storage,
+                vec![WebhookSubscriptionFilter::default()],
+                pagination,
+            )
+            .await?)
+        } else {
+            resource_restricted()
+        }
+    }
+
+    pub async fn get_webhook_subscription(
+        &self,
+        caller: &Caller<RfdPermission>,
+        id: &TypedUuid<WebhookSubscriptionId>,
+    ) -> ResourceResult<WebhookSubscription, StoreError> {
+        if caller.can(&RfdPermission::ManageWebhooks) {
+            WebhookSubscriptionStore::get(&*self.storage, id, false)
+                .await
+                .optional()
+        } else {
+            resource_restricted()
+        }
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn create_webhook_subscription(
+        &self,
+        caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
+        url: String,
+        events: Vec<WebhookEvent>,
+        rfd_numbers: Option<Vec<i32>>,
+    ) -> ResourceResult<CreatedWebhookSubscription, StoreError> {
+        if caller.can(&RfdPermission::ManageWebhooks) {
+            let mut secret = [0; 32];
+            OsRng.fill_bytes(&mut secret);
+            let secret = hex::encode(secret);
+
+            let subscription = WebhookSubscriptionStore::upsert(
+                &*self.storage,
+                NewWebhookSubscription {
+                    id: TypedUuid::new_v4(),
+                    url,
+                    events,
+                    rfd_numbers,
+                    secret: secret.clone(),
+                },
+            )
+            .await?;
+
+            self.record_audit_event(
+                caller,
+                request,
+                None,
+                None,
+                Some(json!({ "webhook_subscription": subscription })),
+            )
+            .await;
+
+            Ok(CreatedWebhookSubscription {
+                subscription,
+                secret,
+            })
+        } else {
+            resource_restricted()
+        }
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn update_webhook_subscription(
+        &self,
+        caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
+        id: &TypedUuid<WebhookSubscriptionId>,
+        url: String,
+        events: Vec<WebhookEvent>,
+        rfd_numbers: Option<Vec<i32>>,
+    ) -> ResourceResult<WebhookSubscription, StoreError> {
+        let existing = self.get_webhook_subscription(caller, id).await?;
+
+        let subscription = WebhookSubscriptionStore::upsert(
             &*self.storage,
-            filter.map(|filter| vec![filter]).unwrap_or_default(),
+            NewWebhookSubscription {
+                id: existing.id,
+                url,
+                events,
+                rfd_numbers,
+                secret: existing.secret.clone(),
+            },
+        )
+        .await?;
+
+        self.record_audit_event(
+            caller,
+            request,
+            None,
+            Some(json!({ "webhook_subscription": existing })),
+            Some(json!({ "webhook_subscription": subscription })),
+        )
+        .await;
+
+        Ok(subscription)
+    }
+
+    #[instrument(skip(self, caller))]
+    pub async fn delete_webhook_subscription(
+        &self,
+        caller: &Caller<RfdPermission>,
+        request: &AuditRequest,
+        id: &TypedUuid<WebhookSubscriptionId>,
+    ) -> ResourceResult<WebhookSubscription, StoreError> {
+        let existing = self.get_webhook_subscription(caller, id).await?;
+        let subscription = WebhookSubscriptionStore::delete(&*self.storage, &existing.id)
+            .await
+            .optional()?;
+
+        self.record_audit_event(
+            caller,
+            request,
+            None,
+            Some(json!({ "webhook_subscription": existing })),
+            None,
+        )
+        .await;
+
+        Ok(subscription)
+    }
+
+    pub async fn list_webhook_subscription_deliveries(
+        &self,
+        caller: &Caller<RfdPermission>,
+        id: &TypedUuid<WebhookSubscriptionId>,
+        pagination: &ListPagination,
+    ) -> ResourceResult<Vec<WebhookSubscriptionDelivery>, StoreError> {
+        let subscription = self.get_webhook_subscription(caller, id).await?;
+        Ok(WebhookSubscriptionDeliveryStore::list(
+            &*self.storage,
+            vec![WebhookSubscriptionDeliveryFilter::default()
+                .webhook_subscription(Some(vec![subscription.id]))],
+            pagination,
+        )
+        .await?)
+    }
+
+    /// Queue an event for delivery to every interested webhook subscription. As with audit events,
+    /// the write that triggered the event has already been applied, so failures are only logged
+    async fn emit_webhook_event(
+        &self,
+        event: WebhookEvent,
+        rfd_number: i32,
+        data: serde_json::Value,
+    ) {
+        let payload = WebhookEventPayload {
+            event,
+            rfd_number,
+            occurred_at: Utc::now(),
+            data,
+        };
+
+        match serde_json::to_value(&payload) {
+            Ok(payload) => {
+                match WebhookSubscriptionDeliveryStore::enqueue(
+                    &*self.storage,
+                    event,
+                    rfd_number,
+                    payload,
+                )
+                .await
+                {
+                    Ok(deliveries) => {
+                        tracing::debug!(
+                            ?event,
+                            rfd_number,
+                            count = deliveries.len(),
+                            "Queued webhook deliveries"
+                        )
+                    }
+                    Err(err) => {
+                        tracing::error!(
+                            ?err,
+                            ?event,
+                            rfd_number,
+                            "Failed to queue webhook deliveries"
+                        )
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::error!(
+                    ?err,
+                    ?event,
+                    rfd_number,
+                    "Failed to serialize webhook payload"
+                )
+            }
+        }
+    }
+
+    // Watch Operations
+
+    /// List the RFDs and labels that the caller is watching
+    pub async fn list_rfd_watches(
+        &self,
+        caller: &Caller<RfdPermission>,
+        pagination: &ListPagination,
+    ) -> ResourceResult<Vec<RfdWatch>, StoreError> {
+        Ok(RfdWatchStore::list(
+            &*self.storage,
+            vec![RfdWatchFilter::default().api_user_id(Some(vec![caller.id.into_untyped_uuid()]))],
             pagination,
         )
+        .await?)
+    }
+
+    /// Watch an RFD on behalf of the caller. The caller must be able to read the RFD at the time
+    /// that the watch is created, and watching an RFD that is already watched returns the existing
+    /// watch
+    #[instrument(skip(self, caller))]
+    pub async fn watch_rfd(
+        &self,
+        caller: &Caller<RfdPermission>,
+        rfd_number: i32,
+    ) -> ResourceResult<RfdWatch, StoreError> {
+        if caller.id == self.v_ctx().builtin_unauthenticated_caller().id {
+            return resource_restricted();
+        }
+
+        let rfd = self.get_rfd_meta(caller, rfd_number, None).await?;
+        self.create_rfd_watch(
+            caller,
+            RfdWatchFilter::default().rfd_number(Some(vec![rfd.rfd_number])),
+            Some(rfd.rfd_number),
+            None,
+        )
         .await
-        .tap_err(|err| tracing::error!(?err, "Failed to lookup jobs"))?;
+    }
 
-        // Filter the list of jobs down to only those that the caller is allowed to access
-        jobs.retain_mut(|job| {
-            caller.can(&RfdPermission::GetRfdsAll) || caller.can(&RfdPermission::GetRfd(job.rfd))
-        });
+    /// Watch every RFD with the given label on behalf of the caller. Notifications are only sent
+    /// for the labeled RFDs that the caller is able to read
+    #[instrument(skip(self, caller))]
+    pub async fn watch_rfd_label(
+        &self,
+        caller: &Caller<RfdPermission>,
+        label: String,
+    ) -> ResourceResult<RfdWatch, StoreError> {
+        if caller.id == self.v_ctx().builtin_unauthenticated_caller().id {
+            return resource_restricted();
+        }
 
-        // Finally sort the jobs list by create time
-        jobs.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+        self.create_rfd_watch(
+            caller,
+            RfdWatchFilter::default().label(Some(vec![label.clone()])),
+            None,
+            Some(label),
+        )
+        .await
+    }
 
-        Ok(jobs)
+    async fn create_rfd_watch(
+        &self,
+        caller: &Caller<RfdPermission>,
+        existing: RfdWatchFilter,
+        rfd_number: Option<i32>,
+        label: Option<String>,
+    ) -> ResourceResult<RfdWatch, StoreError> {
+        let api_user_id = caller.id.into_untyped_uuid();
+        let existing = RfdWatchStore::list(
+            &*self.storage,
+            vec![existing.api_user_id(Some(vec![api_user_id]))],
+            &ListPagination::default().limit(1),
+        )
+        .await?
+        .pop();
+
+        match existing {
+            Some(watch) => Ok(watch),
+            None => Ok(RfdWatchStore::create(
+                &*self.storage,
+                NewRfdWatch {
+                    id: TypedUuid::new_v4(),
+                    api_user_id,
+                    rfd_number,
+                    label,
+                },
+            )
+            .await?),
+        }
+    }
+
+    /// Stop watching. Callers can only delete their own watches
+    #[instrument(skip(self, caller))]
+    pub async fn delete_rfd_watch(
+        &self,
+        caller: &Caller<RfdPermission>,
+        id: &TypedUuid<RfdWatchId>,
+    ) -> ResourceResult<RfdWatch, StoreError> {
+        match RfdWatchStore::get(&*self.storage, id, false).await? {
+            Some(watch) if watch.api_user_id == caller.id.into_untyped_uuid() => {
+                RfdWatchStore::delete(&*self.storage, id).await.optional()
+            }
+            _ => resource_not_found(),
+        }
+    }
+
+    /// Queue an email notification for the watchers of an RFD. Recipients and their access are
+    /// determined when the notification is sent, so failures are only logged
+    async fn queue_watch_notification(
+        &self,
+        event: WebhookEvent,
+        rfd_number: i32,
+        rfd_revision_id: TypedUuid<RfdRevisionId>,
+    ) {
+        let notification = NewRfdWatchNotification {
+            rfd_number,
+            rfd_revision_id,
+            event,
+        };
+
+        if let Err(err) = RfdWatchNotificationStore::create(&*self.storage, notification).await {
+            tracing::error!(
+                ?err,
+                ?event,
+                rfd_number,
+                "Failed to queue watch notification"
+            );
+        }
+    }
+
+    /// Construct a notifier that emails watchers using the given messenger. The notifier shares
+    /// storage with this context and is expected to be run as a background task
+    pub fn watch_notifier(
+        &self,
+        messenger: Box<dyn Messenger>,
+        interval: Duration,
+        batch_size: i64,
+    ) -> RfdWatchNotifier {
+        RfdWatchNotifier {
+            public_url: self.public_url.clone(),
+            storage: self.storage.clone(),
+            v_context: self.v_context.clone(),
+            messenger,
+            interval,
+            batch_size,
+        }
     }
 
     // Webhook Operations
@@ -939,6 +2464,49 @@ impl RfdContext {
     }
 }
 
+/// Combine a document that was written against `base_revision` with the latest revision. Fails
+/// with a conflict when the changes made since the base overlap with the changes in the document
+fn merge_update(
+    latest_revision: &RfdRevision,
+    base_revision: &RfdRevision,
+    document: &str,
+) -> Result<String, ResourceError<UpdateRfdContentError>> {
+    if base_revision.commit == latest_revision.commit {
+        Ok(document.to_string())
+    } else {
+        merge_lines(&base_revision.content, &latest_revision.content, document).ok_or_else(|| {
+            ResourceError::InternalError(UpdateRfdContentError::Conflict {
+                head: latest_revision.commit.clone(),
+            })
+        })
+    }
+}
+
+/// Find the lowest RFD number at or above `min_rfd_number` that does not appear in `reserved`.
+/// Reserved numbers must be sorted in ascending order
+fn next_unreserved_rfd_number(min_rfd_number: i32, reserved: &[i32]) -> i32 {
+    let mut next = min_rfd_number;
+    for rfd_number in reserved {
+        if *rfd_number == next {
+            next += 1;
+        } else if *rfd_number > next {
+            break;
+        }
+    }
+
+    next
+}
+
+/// GitHub responds with a conflict when a file is written against a sha that is no longer the
+/// current sha of the file on the branch
+fn is_github_conflict(err: &GitHubError) -> bool {
+    matches!(
+        err,
+        GitHubError::ClientError(ClientError::HttpError { status, .. })
+            if *status == StatusCode::CONFLICT
+    )
+}
+
 #[cfg(test)]
 pub(crate) mod test_mocks {
     use rfd_data::content::RfdTemplate;
@@ -961,7 +2529,15 @@ pub(crate) mod test_mocks {
     };
     use crate::permissions::RfdPermission;
 
-    use super::RfdContext;
+    use super::{AuditRequest, RfdContext};
+
+    // Construct an audit request for the given operation that can be used in tests
+    pub fn mock_request(operation: &str) -> AuditRequest {
+        AuditRequest {
+            id: "request-id".to_string(),
+            operation: operation.to_string(),
+        }
+    }
 
     // Construct a mock context that can be used in tests
     pub async fn mock_context(storage: MockStorage) -> RfdContext {
@@ -1051,3 +2627,137 @@ pub(crate) mod test_mocks {
         .unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use octorust::ClientError;
+    use rfd_data::RfdState;
+    use rfd_github::GitHubError;
+    use rfd_model::storage::mock::MockStorage;
+    use v_api::response::ResourceError;
+    use v_model::{permissions::Caller, Permissions};
+
+    use crate::permissions::RfdPermission;
+
+    use super::{
+        is_github_conflict, next_unreserved_rfd_number, test_mocks::mock_context,
+        UpdateRfdContentError,
+    };
+
+    #[test]
+    fn next_unreserved_number_without_reservations() {
+        assert_eq!(12, next_unreserved_rfd_number(12, &[]));
+    }
+
+    #[test]
+    fn next_unreserved_number_skips_reserved_numbers() {
+        assert_eq!(14, next_unreserved_rfd_number(12, &[12, 13, 15, 16]));
+    }
+
+    #[test]
+    fn next_unreserved_number_ignores_numbers_below_minimum() {
+        assert_eq!(12, next_unreserved_rfd_number(12, &[3, 9, 13]));
+    }
+
+    #[test]
+    fn github_sha_mismatch_is_a_conflict() {
+        let github_error = |status| {
+            GitHubError::ClientError(ClientError::HttpError {
+                status,
+                headers: Default::default(),
+                error: "rfd/0123/README.adoc does not match 4d2c1a0".to_string(),
+            })
+        };
+
+        assert!(is_github_conflict(&github_error(StatusCode::CONFLICT)));
+        assert!(!is_github_conflict(&github_error(StatusCode::NOT_FOUND)));
+        assert!(!is_github_conflict(&GitHubError::NoCommitsFound));
+    }
+
+    #[tokio::test]
+    async fn state_transition_must_be_allowed() {
+        let ctx = mock_context(MockStorage::new()).await;
+        let caller = Caller::from(Permissions::from(vec![
+            RfdPermission::UpdateRfdsAll,
+            RfdPermission::CommitRfds,
+        ]));
+
+        assert!(ctx
+            .check_state_transition(
+                &caller,
+                Some(RfdState::Ideation),
+                Some(RfdState::Discussion)
+            )
+            .is_ok());
+        assert!(matches!(
+            ctx.check_state_transition(
+                &caller,
+                Some(RfdState::Ideation),
+                Some(RfdState::Committed)
+            ),
+            Err(ResourceError::InternalError(
+                UpdateRfdContentError::InvalidStateTransition {
+                    from: RfdState::Ideation,
+                    to: RfdState::Committed,
+                }
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn state_transition_without_state_is_allowed() {
+        let ctx = mock_context(MockStorage::new()).await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::UpdateRfdsAll]));
+
+        assert!(ctx
+            .check_state_transition(&caller, Some(RfdState::Discussion), None)
+            .is_ok());
+        assert!(ctx
+            .check_state_transition(&caller, None, Some(RfdState::Ideation))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn state_transition_requires_permission() {
+        let ctx = mock_context(MockStorage::new()).await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::UpdateRfdsAll]));
+        let publisher = Caller::from(Permissions::from(vec![
+            RfdPermission::UpdateRfdsAll,
+            RfdPermission::PublishRfds,
+        ]));
+
+        assert!(matches!(
+            ctx.check_state_transition(
+                &caller,
+                Some(RfdState::Discussion),
+                Some(RfdState::Published)
+            ),
+            Err(ResourceError::Restricted)
+        ));
+        assert!(ctx
+            .check_state_transition(
+                &publisher,
+                Some(RfdState::Discussion),
+                Some(RfdState::Published)
+            )
+            .is_ok());
+        assert!(matches!(
+            ctx.check_state_transition(
+                &publisher,
+                Some(RfdState::Published),
+                Some(RfdState::Abandoned)
+            ),
+            Err(ResourceError::Restricted)
+        ));
+
+        // Remaining in a restricted state does not require permission
+        assert!(ctx
+            .check_state_transition(
+                &caller,
+                Some(RfdState::Published),
+                Some(RfdState::Published)
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,204 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use rfd_github::{
+    GitHubDiscussionComment, GitHubPullRequestDiscussion, GitHubReviewAnchor, GitHubReviewThread,
+};
+use rfd_model::CommitSha;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdDiscussion {
+    /// Number of the pull request that hosts the discussion
+    pub pull_request: i64,
+    /// Link to the pull request on GitHub
+    pub url: String,
+    /// Commit of the RFD revision that the discussion was retrieved for
+    pub commit: CommitSha,
+    /// Review comments left on the RFD, grouped in to threads
+    pub review_threads: Vec<RfdDiscussionThread>,
+    /// General comments left on the pull request
+    pub comments: Vec<RfdDiscussionComment>,
+}
+
+impl RfdDiscussion {
+    pub fn new(url: String, commit: CommitSha, discussion: GitHubPullRequestDiscussion) -> Self {
+        Self {
+            pull_request: discussion.number,
+            url,
+            commit,
+            review_threads: discussion
+                .review_threads
+                .into_iter()
+                .map(RfdDiscussionThread::from)
+                .collect(),
+            comments: discussion
+                .comments
+                .into_iter()
+                .map(RfdDiscussionComment::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdDiscussionThread {
+    /// Id of the comment that started the thread
+    pub id: i64,
+    pub anchor: RfdDiscussionAnchor,
+    pub resolved: bool,
+    pub resolved_by: Option<String>,
+    /// Whether the lines the thread is attached to have since changed
+    pub outdated: bool,
+    pub comments: Vec<RfdDiscussionComment>,
+}
+
+impl From<GitHubReviewThread> for RfdDiscussionThread {
+    fn from(value: GitHubReviewThread) -> Self {
+        Self {
+            id: value.id,
+            anchor: value.anchor.into(),
+            resolved: value.resolved,
+            resolved_by: value.resolved_by,
+            outdated: value.outdated,
+            comments: value
+                .comments
+                .into_iter()
+                .map(RfdDiscussionComment::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdDiscussionAnchor {
+    /// Path of the file within the repository that the thread is attached to
+    pub path: String,
+    pub commit: CommitSha,
+    pub line: Option<i64>,
+    /// First line of a multi-line comment
+    pub start_line: Option<i64>,
+    /// Line that the thread was originally attached to
+    pub original_line: Option<i64>,
+    pub diff_hunk: String,
+}
+
+impl From<GitHubReviewAnchor> for RfdDiscussionAnchor {
+    fn from(value: GitHubReviewAnchor) -> Self {
+        Self {
+            path: value.path,
+            commit: value.commit.into(),
+            line: value.line,
+            start_line: value.start_line,
+            original_line: value.original_line,
+            diff_hunk: value.diff_hunk,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdDiscussionComment {
+    pub id: i64,
+    /// GitHub login of the comment author
+    pub author: Option<String>,
+    pub body: String,
+    pub url: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<GitHubDiscussionComment> for RfdDiscussionComment {
+    fn from(value: GitHubDiscussionComment) -> Self {
+        Self {
+            id: value.id,
+            author: value.author,
+            body: value.body,
+            url: value.url,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// Discussions are cached per commit of an RFD. Comments may still be added while a commit is the
+/// head of the RFD, so entries are only retained for a short period of time
+pub struct DiscussionCache {
+    ttl: Duration,
+    entries: RwLock<HashMap<(String, i64), (Instant, RfdDiscussion)>>,
+}
+
+impl DiscussionCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn get(&self, commit: &CommitSha, pull_request: i64) -> Option<RfdDiscussion> {
+        self.entries
+            .read()
+            .await
+            .get(&(commit.0.clone(), pull_request))
+            .filter(|(cached_at, _)| cached_at.elapsed() < self.ttl)
+            .map(|(_, discussion)| discussion.clone())
+    }
+
+    pub async fn insert(&self, discussion: RfdDiscussion) {
+        let mut entries = self.entries.write().await;
+
+        // Drop any stale entries while we hold the lock so that the cache does not grow without
+        // bound as new commits are made
+        entries.retain(|_, (cached_at, _)| cached_at.elapsed() < self.ttl);
+        entries.insert(
+            (discussion.commit.0.clone(), discussion.pull_request),
+            (Instant::now(), discussion),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rfd_model::CommitSha;
+
+    use super::{DiscussionCache, RfdDiscussion};
+
+    fn discussion(commit: &str) -> RfdDiscussion {
+        RfdDiscussion {
+            pull_request: 1,
+            url: String::new(),
+            commit: CommitSha(commit.to_string()),
+            review_threads: vec![],
+            comments: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn cache_is_keyed_by_commit() {
+        let cache = DiscussionCache::new(Duration::from_secs(60));
+        cache.insert(discussion("abc")).await;
+
+        assert!(cache.get(&CommitSha("abc".to_string()), 1).await.is_some());
+        assert!(cache.get(&CommitSha("def".to_string()), 1).await.is_none());
+        assert!(cache.get(&CommitSha("abc".to_string()), 2).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn cache_entries_expire() {
+        let cache = DiscussionCache::new(Duration::ZERO);
+        cache.insert(discussion("abc")).await;
+
+        assert!(cache.get(&CommitSha("abc".to_string()), 1).await.is_none());
+    }
+}
@@ -0,0 +1,193 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, Utc};
+use dropshot::{endpoint, ClientErrorStatusCode, HttpError, HttpResponseOk, Query, RequestContext};
+use rfd_model::{storage::AuditEventFilter, AuditEvent};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use trace_request::trace_request;
+use tracing::instrument;
+use uuid::Uuid;
+use v_api::{response::client_error, ApiContext};
+use v_model::{permissions::Caller, storage::ListPagination};
+
+use crate::{context::RfdContext, permissions::RfdPermission};
+
+use super::UNLIMITED;
+
+// Read Endpoints
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct ListAuditEventsQuery {
+    /// Only include events for the given RFD
+    rfd: Option<String>,
+    /// Only include events performed by the given user
+    caller: Option<Uuid>,
+    /// Only include events recorded at or after this time
+    since: Option<DateTime<Utc>>,
+    /// Only include events recorded before this time
+    until: Option<DateTime<Utc>>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+/// List recorded write operations, optionally limited to a single RFD, caller or time range
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/audit",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_audit_events(
+    rqctx: RequestContext<RfdContext>,
+    query: Query<ListAuditEventsQuery>,
+) -> Result<HttpResponseOk<Vec<AuditEvent>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let query = query.into_inner();
+    list_audit_events_op(ctx, &caller, query).await
+}
+
+// Read operation
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn list_audit_events_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    query: ListAuditEventsQuery,
+) -> Result<HttpResponseOk<Vec<AuditEvent>>, HttpError> {
+    if let Ok(rfd_number) = query.rfd.map(|rfd| rfd.parse::<i32>()).transpose() {
+        let events = ctx
+            .list_audit_events(
+                caller,
+                AuditEventFilter::default()
+                    .rfd_number(rfd_number.map(|rfd_number| vec![rfd_number]))
+                    .caller_id(query.caller.map(|caller| vec![caller]))
+                    .created_after(query.since)
+                    .created_before(query.until),
+                &ListPagination::default()
+                    .limit(query.limit.unwrap_or(UNLIMITED))
+                    .offset(query.offset.unwrap_or(0)),
+            )
+            .await?;
+        Ok(HttpResponseOk(events))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use dropshot::HttpResponseOk;
+    use http::StatusCode;
+    use newtype_uuid::TypedUuid;
+    use rfd_model::{
+        storage::{mock::MockStorage, MockAuditEventStore},
+        AuditEvent,
+    };
+    use uuid::Uuid;
+    use v_model::{permissions::Caller, Permissions};
+
+    use crate::{
+        context::{test_mocks::mock_context, RfdContext},
+        permissions::RfdPermission,
+    };
+
+    use super::{list_audit_events_op, ListAuditEventsQuery};
+
+    async fn ctx() -> RfdContext {
+        let events = vec![
+            AuditEvent {
+                id: TypedUuid::new_v4(),
+                caller_id: Uuid::new_v4(),
+                operation: "update_rfd_visibility".to_string(),
+                rfd_number: Some(123),
+                before: Some(serde_json::json!({ "visibility": "private" })),
+                after: Some(serde_json::json!({ "visibility": "public" })),
+                request_id: "request-1".to_string(),
+                created_at: Utc::now(),
+            },
+            AuditEvent {
+                id: TypedUuid::new_v4(),
+                caller_id: Uuid::new_v4(),
+                operation: "set_rfd_attr".to_string(),
+                rfd_number: Some(456),
+                before: None,
+                after: None,
+                request_id: "request-2".to_string(),
+                created_at: Utc::now(),
+            },
+        ];
+
+        let mut audit_event_store = MockAuditEventStore::new();
+        audit_event_store
+            .expect_list()
+            .returning(move |filters, _| {
+                Ok(events
+                    .iter()
+                    .filter(|event| {
+                        filters.iter().all(|filter| {
+                            filter
+                                .rfd_number
+                                .as_ref()
+                                .map(|numbers| {
+                                    event
+                                        .rfd_number
+                                        .map(|number| numbers.contains(&number))
+                                        .unwrap_or(false)
+                                })
+                                .unwrap_or(true)
+                        })
+                    })
+                    .cloned()
+                    .collect())
+            });
+
+        let mut storage = MockStorage::new();
+        storage.audit_event_store = Some(Arc::new(audit_event_store));
+
+        mock_context(storage).await
+    }
+
+    #[tokio::test]
+    async fn list_audit_events_with_permission() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetAuditEvents]));
+
+        let HttpResponseOk(events) = list_audit_events_op(
+            &ctx,
+            &caller,
+            ListAuditEventsQuery {
+                rfd: Some("0123".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(1, events.len());
+        assert_eq!("update_rfd_visibility", events[0].operation);
+    }
+
+    #[tokio::test]
+    async fn list_audit_events_without_permission() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let result = list_audit_events_op(&ctx, &caller, ListAuditEventsQuery::default()).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::FORBIDDEN, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 403 error, but instead found events {:?}",
+                response.0
+            ),
+        }
+    }
+}
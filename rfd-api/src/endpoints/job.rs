@@ -3,7 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use dropshot::{endpoint, ClientErrorStatusCode, HttpError, HttpResponseOk, Query, RequestContext};
-use rfd_model::{storage::JobFilter, Job};
+use rfd_model::{schema_ext::JobStatus, storage::JobFilter, Job};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use trace_request::trace_request;
@@ -19,12 +19,13 @@ use super::UNLIMITED;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 struct ListJobsQuery {
-    rfd: String,
+    rfd: Option<String>,
+    status: Option<JobStatus>,
     limit: Option<i64>,
     offset: Option<i64>,
 }
 
-/// List all jobs for a RFD
+/// List jobs, optionally limited to a single RFD or status
 #[trace_request]
 #[endpoint {
     method = GET,
@@ -49,11 +50,15 @@ async fn list_jobs_op(
     caller: &Caller<RfdPermission>,
     query: ListJobsQuery,
 ) -> Result<HttpResponseOk<Vec<Job>>, HttpError> {
-    if let Ok(rfd_number) = query.rfd.parse::<i32>() {
+    if let Ok(rfd_number) = query.rfd.map(|rfd| rfd.parse::<i32>()).transpose() {
         let jobs = ctx
             .list_jobs(
                 caller,
-                Some(JobFilter::default().rfd(Some(vec![rfd_number]))),
+                Some(
+                    JobFilter::default()
+                        .rfd(rfd_number.map(|rfd_number| vec![rfd_number]))
+                        .status(query.status.map(|status| vec![status])),
+                ),
                 &ListPagination::default()
                     .limit(query.limit.unwrap_or(UNLIMITED))
                     .offset(query.offset.unwrap_or(0)),
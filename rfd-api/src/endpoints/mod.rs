@@ -4,6 +4,13 @@
 
 pub static UNLIMITED: i64 = 9999999;
 
+pub mod audit;
 pub mod job;
 pub mod rfd;
+pub mod rfd_file;
+pub mod rfd_lint;
+pub mod rfd_preview;
+pub mod rfd_reservation;
+pub mod rfd_watch;
 pub mod webhook;
+pub mod webhook_subscription;
@@ -2,10 +2,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use base64::{prelude::BASE64_URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
 use dropshot::{
-    endpoint, ClientErrorStatusCode, HttpError, HttpResponseAccepted, HttpResponseOk, Path, Query,
-    RequestContext, TypedBody,
+    endpoint, Body, ClientErrorStatusCode, Header, HttpError, HttpResponseAccepted,
+    HttpResponseHeaders, HttpResponseOk, Path, Query, RequestContext, TypedBody,
 };
+use http::{header, Response, StatusCode};
 use newtype_uuid::TypedUuid;
 use rfd_data::{
     content::{RfdAsciidoc, RfdContent, RfdDocument, RfdMarkdown},
@@ -13,31 +16,44 @@ use rfd_data::{
 };
 use rfd_model::{
     schema_ext::{ContentFormat, Visibility},
-    Rfd, RfdRevisionId,
+    storage::{RfdFilter, RfdSort, RfdSortField, RfdSortKey, SortDirection},
+    CommitSha, Rfd, RfdRevisionId,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use trace_request::trace_request;
 use tracing::instrument;
-use v_api::{response::not_found, ApiContext};
+use v_api::{
+    response::{not_found, ResourceError},
+    ApiContext,
+};
 use v_model::{permissions::Caller, storage::ListPagination};
 
 use crate::{
     caller::CallerExt,
+    conditional::{rfd_etag, ConditionalHeaders, HttpResponseConditional},
     context::{
-        RfdContext, RfdRevisionIdentifier, RfdRevisionMeta, RfdRevisionMetadataChange, RfdWithPdf,
-        RfdWithRaw, RfdWithoutContent,
+        AuditRequest, RfdContext, RfdHtml, RfdRevisionDiff, RfdRevisionIdentifier, RfdRevisionMeta,
+        RfdRevisionMetadataChange, RfdTemplateSummary, RfdWithPdf, RfdWithRaw, RfdWithoutContent,
+        UpdateBase, UpdateRfdContentError,
     },
+    discussion::RfdDiscussion,
     endpoints::UNLIMITED,
+    export::{EXPORT_CONTENT_TYPE, EXPORT_FILENAME},
+    feed::{RfdFeed, ATOM_CONTENT_TYPE},
     permissions::RfdPermission,
     search::{MeiliSearchResult, SearchRequest},
     util::response::{client_error, internal_error, unauthorized},
 };
 
+// The number of entries included in an RFD activity feed
+static FEED_ENTRY_LIMIT: i64 = 50;
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct RfdPathParams {
     /// The RFD number (examples: 1 or 123)
-    number: String,
+    pub number: String,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -48,6 +64,16 @@ pub struct RfdRevisionPathParams {
     revision: TypedUuid<RfdRevisionId>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdRevisionDiffPathParams {
+    /// The RFD number (examples: 1 or 123)
+    number: String,
+    /// The revision id or commit sha of the older revision
+    a: String,
+    /// The revision id or commit sha of the newer revision
+    b: String,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct RfdAttrPathParams {
     /// The RFD number (examples: 1 or 123)
@@ -84,6 +110,73 @@ pub enum RfdAttr {
 
 // Read Endpoints
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ListRfdsQuery {
+    /// Comma separated list of states. RFDs in any of the given states are included
+    state: Option<String>,
+    /// Comma separated list of labels. Only RFDs that have all of the given labels are included
+    label: Option<String>,
+    /// Case insensitive substring to match against the RFD authors
+    author: Option<String>,
+    visibility: Option<Visibility>,
+    /// Only include RFDs whose latest revision was committed at or after this time
+    updated_since: Option<DateTime<Utc>>,
+    /// Only include RFDs that have had a major change at or after this time
+    major_change_since: Option<DateTime<Utc>>,
+    /// Case insensitive substring to match against the RFD title
+    title: Option<String>,
+    /// Field to sort by, defaults to the RFD number. Sorting by `updated_at` orders RFDs by the
+    /// commit time of their latest revision
+    sort: Option<RfdSortField>,
+    /// Direction to sort in, defaults to descending
+    direction: Option<SortDirection>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    /// Cursor returned by a previous request. Retrieves the page of RFDs that follows it when used
+    /// with the same filters and sort order
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ListRfdsHeaders {
+    /// Number of RFDs that match the filters, regardless of pagination
+    #[serde(rename = "x-total-count")]
+    pub total_count: String,
+    /// Cursor for retrieving the next page. Only present when a limit was requested and the page
+    /// was filled
+    #[serde(rename = "x-next-cursor", skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// A page of RFDs along with the details needed to fetch the pages that follow it
+#[derive(Debug)]
+struct ListRfdsPage {
+    rfds: Vec<RfdWithoutContent>,
+    total: i64,
+    next_cursor: Option<String>,
+}
+
+/// Position within a sorted RFD list, handed out to clients as an opaque string
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct ListRfdsCursor {
+    sort: RfdSortField,
+    direction: SortDirection,
+    key: RfdSortKey,
+}
+
+impl ListRfdsCursor {
+    fn encode(&self) -> String {
+        BASE64_URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    fn decode(cursor: &str) -> Option<Self> {
+        BASE64_URL_SAFE_NO_PAD
+            .decode(cursor)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+    }
+}
+
 /// List all available RFDs
 #[trace_request]
 #[endpoint {
@@ -93,10 +186,70 @@ pub enum RfdAttr {
 #[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
 pub async fn list_rfds(
     rqctx: RequestContext<RfdContext>,
-) -> Result<HttpResponseOk<Vec<RfdWithoutContent>>, HttpError> {
+    query: Query<ListRfdsQuery>,
+) -> Result<HttpResponseHeaders<HttpResponseOk<Vec<RfdWithoutContent>>, ListRfdsHeaders>, HttpError>
+{
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let page = list_rfds_op(ctx, &caller, query.into_inner()).await?;
+    Ok(HttpResponseHeaders::new(
+        HttpResponseOk(page.rfds),
+        ListRfdsHeaders {
+            total_count: page.total.to_string(),
+            next_cursor: page.next_cursor,
+        },
+    ))
+}
+
+/// Get an Atom feed of the activity of all RFDs
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/feeds/rfds.atom",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn view_rfds_feed(
+    rqctx: RequestContext<RfdContext>,
+) -> Result<Response<Body>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let feed = view_rfd_feed_op(ctx, &caller, None).await?;
+    atom_response(feed.to_atom(&ctx.public_url))
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct ExportRfdsQuery {
+    /// Include the content of every revision of each RFD in the archive
+    history: Option<bool>,
+}
+
+/// Download a gzipped tar archive of every RFD that the caller has access to. Each RFD is exported
+/// as the content of its latest revision along with a metadata.json file
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd-export",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn export_rfds(
+    rqctx: RequestContext<RfdContext>,
+    query: Query<ExportRfdsQuery>,
+) -> Result<Response<Body>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
-    list_rfds_op(ctx, &caller).await
+    let export = ctx
+        .export_rfds(&caller, query.into_inner().history.unwrap_or(false))
+        .await?;
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, EXPORT_CONTENT_TYPE)
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", EXPORT_FILENAME),
+        )
+        .body(export.into_body())
+        .map_err(|err| internal_error(format!("Failed to construct export response: {}", err)))
 }
 
 // Latest RFD revision endpoints
@@ -111,11 +264,17 @@ pub async fn list_rfds(
 pub async fn view_rfd_meta(
     rqctx: RequestContext<RfdContext>,
     path: Path<RfdPathParams>,
-) -> Result<HttpResponseOk<RfdWithoutContent>, HttpError> {
+    headers: Header<ConditionalHeaders>,
+) -> Result<HttpResponseConditional<RfdWithoutContent>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
     let path = path.into_inner();
-    view_rfd_meta_op(ctx, &caller, path.number, None).await
+    let HttpResponseOk(rfd) = view_rfd_meta_op(ctx, &caller, path.number, None).await?;
+    Ok(HttpResponseConditional::new(
+        rfd_etag(&caller, &rfd),
+        &headers.into_inner(),
+        rfd,
+    ))
 }
 
 /// Get the raw contents of the latest revision of a RFD
@@ -128,11 +287,17 @@ pub async fn view_rfd_meta(
 pub async fn view_rfd(
     rqctx: RequestContext<RfdContext>,
     path: Path<RfdPathParams>,
-) -> Result<HttpResponseOk<RfdWithRaw>, HttpError> {
+    headers: Header<ConditionalHeaders>,
+) -> Result<HttpResponseConditional<RfdWithRaw>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
     let path = path.into_inner();
-    view_rfd_op(ctx, &caller, path.number, None).await
+    let HttpResponseOk(rfd) = view_rfd_op(ctx, &caller, path.number, None).await?;
+    Ok(HttpResponseConditional::new(
+        rfd_etag(&caller, &rfd),
+        &headers.into_inner(),
+        rfd,
+    ))
 }
 
 /// Get the PDF locations of the latest revision of a RFD
@@ -145,11 +310,52 @@ pub async fn view_rfd(
 pub async fn view_rfd_pdf(
     rqctx: RequestContext<RfdContext>,
     path: Path<RfdPathParams>,
-) -> Result<HttpResponseOk<RfdWithPdf>, HttpError> {
+    headers: Header<ConditionalHeaders>,
+) -> Result<HttpResponseConditional<RfdWithPdf>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let path = path.into_inner();
+    let HttpResponseOk(rfd) = view_rfd_pdf_op(ctx, &caller, path.number, None).await?;
+    Ok(HttpResponseConditional::new(
+        rfd_etag(&caller, &rfd),
+        &headers.into_inner(),
+        rfd,
+    ))
+}
+
+/// Get the rendered HTML of the latest revision of a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/html",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn view_rfd_html(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+) -> Result<HttpResponseOk<RfdHtml>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let path = path.into_inner();
+    view_rfd_html_op(ctx, &caller, path.number, None).await
+}
+
+/// Get an Atom feed of the activity of a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/feed.atom",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn view_rfd_feed(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+) -> Result<Response<Body>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
     let path = path.into_inner();
-    view_rfd_pdf_op(ctx, &caller, path.number, None).await
+    let feed = view_rfd_feed_op(ctx, &caller, Some(path.number)).await?;
+    atom_response(feed.to_atom(&ctx.public_url))
 }
 
 /// Get the an attribute of the latest revision of a RFD
@@ -179,8 +385,43 @@ pub async fn view_rfd_attr(
 pub async fn view_rfd_discussion(
     rqctx: RequestContext<RfdContext>,
     path: Path<RfdPathParams>,
-) -> Result<HttpResponseOk<()>, HttpError> {
-    unimplemented!()
+) -> Result<HttpResponseOk<RfdDiscussion>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let path = path.into_inner();
+    view_rfd_discussion_op(ctx, &caller, path.number, None).await
+}
+
+/// List the RFDs referenced by the latest revision of a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/references",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_rfd_references(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+) -> Result<HttpResponseOk<Vec<RfdWithoutContent>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    list_rfd_references_op(ctx, &caller, path.into_inner().number).await
+}
+
+/// List the RFDs that reference a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/backlinks",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_rfd_backlinks(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+) -> Result<HttpResponseOk<Vec<RfdWithoutContent>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    list_rfd_backlinks_op(ctx, &caller, path.into_inner().number).await
 }
 
 // Specific RFD revision endpoints
@@ -219,11 +460,18 @@ pub async fn list_rfd_revisions(
 pub async fn view_rfd_revision_meta(
     rqctx: RequestContext<RfdContext>,
     path: Path<RfdRevisionPathParams>,
-) -> Result<HttpResponseOk<RfdWithoutContent>, HttpError> {
+    headers: Header<ConditionalHeaders>,
+) -> Result<HttpResponseConditional<RfdWithoutContent>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
     let path = path.into_inner();
-    view_rfd_meta_op(ctx, &caller, path.number, Some(path.revision.into())).await
+    let HttpResponseOk(rfd) =
+        view_rfd_meta_op(ctx, &caller, path.number, Some(path.revision.into())).await?;
+    Ok(HttpResponseConditional::new(
+        rfd_etag(&caller, &rfd),
+        &headers.into_inner(),
+        rfd,
+    ))
 }
 
 /// Get the raw contents of a revision of a RFD
@@ -236,11 +484,18 @@ pub async fn view_rfd_revision_meta(
 pub async fn view_rfd_revision(
     rqctx: RequestContext<RfdContext>,
     path: Path<RfdRevisionPathParams>,
-) -> Result<HttpResponseOk<RfdWithRaw>, HttpError> {
+    headers: Header<ConditionalHeaders>,
+) -> Result<HttpResponseConditional<RfdWithRaw>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
     let path = path.into_inner();
-    view_rfd_op(ctx, &caller, path.number, Some(path.revision.into())).await
+    let HttpResponseOk(rfd) =
+        view_rfd_op(ctx, &caller, path.number, Some(path.revision.into())).await?;
+    Ok(HttpResponseConditional::new(
+        rfd_etag(&caller, &rfd),
+        &headers.into_inner(),
+        rfd,
+    ))
 }
 
 /// Get the PDF locations of a revision of a RFD
@@ -253,11 +508,35 @@ pub async fn view_rfd_revision(
 pub async fn view_rfd_revision_pdf(
     rqctx: RequestContext<RfdContext>,
     path: Path<RfdRevisionPathParams>,
-) -> Result<HttpResponseOk<RfdWithPdf>, HttpError> {
+    headers: Header<ConditionalHeaders>,
+) -> Result<HttpResponseConditional<RfdWithPdf>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let path = path.into_inner();
+    let HttpResponseOk(rfd) =
+        view_rfd_pdf_op(ctx, &caller, path.number, Some(path.revision.into())).await?;
+    Ok(HttpResponseConditional::new(
+        rfd_etag(&caller, &rfd),
+        &headers.into_inner(),
+        rfd,
+    ))
+}
+
+/// Get the rendered HTML of a revision of a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/revision/{revision}/html",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn view_rfd_revision_html(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdRevisionPathParams>,
+) -> Result<HttpResponseOk<RfdHtml>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
     let path = path.into_inner();
-    view_rfd_pdf_op(ctx, &caller, path.number, Some(path.revision.into())).await
+    view_rfd_html_op(ctx, &caller, path.number, Some(path.revision.into())).await
 }
 
 /// Get the an attribute of a revision of a RFD
@@ -294,11 +573,31 @@ pub async fn view_rfd_revision_attr(
 pub async fn view_rfd_revision_discussion(
     rqctx: RequestContext<RfdContext>,
     path: Path<RfdRevisionPathParams>,
-) -> Result<HttpResponseOk<()>, HttpError> {
-    unimplemented!()
+) -> Result<HttpResponseOk<RfdDiscussion>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let path = path.into_inner();
+    view_rfd_discussion_op(ctx, &caller, path.number, Some(path.revision.into())).await
 }
 
-#[derive(Debug, Deserialize, JsonSchema)]
+/// Get the changes between two revisions of a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/revision/{a}/diff/{b}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn view_rfd_revision_diff(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdRevisionDiffPathParams>,
+) -> Result<HttpResponseOk<RfdRevisionDiff>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let path = path.into_inner();
+    view_rfd_revision_diff_op(ctx, &caller, path.number, path.a.into(), path.b.into()).await
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
 pub struct RfdSearchQuery {
     pub q: String,
     pub limit: Option<u32>,
@@ -306,6 +605,19 @@ pub struct RfdSearchQuery {
     pub highlight_pre_tag: Option<String>,
     pub highlight_post_tag: Option<String>,
     pub attributes_to_crop: Option<String>,
+    /// Comma separated list of states. Results from RFDs in any of the given states are included
+    pub state: Option<String>,
+    /// Comma separated list of labels. Only results from RFDs that have all of the given labels
+    /// are included
+    pub label: Option<String>,
+    /// Comma separated list of author names or emails. Results from RFDs written by any of the
+    /// given authors are included. Names and emails must match exactly as they are written in the
+    /// RFD
+    pub author: Option<String>,
+    /// Only include results from revisions committed at or after this time
+    pub committed_after: Option<DateTime<Utc>>,
+    /// Only include results from revisions committed before this time
+    pub committed_before: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
@@ -314,6 +626,10 @@ pub struct SearchResults {
     query: String,
     limit: Option<usize>,
     offset: Option<usize>,
+    /// Number of matching sections for each value of the state, label and author attributes. RFDs
+    /// are indexed as one document per section, so an RFD is counted once for each of its sections
+    /// that match the search rather than once per RFD
+    section_facets: BTreeMap<String, BTreeMap<String, usize>>,
 }
 
 // TODO: This should be a shared type across the api and processor, but it likely needs custom
@@ -363,9 +679,98 @@ pub async fn search_rfds(
 async fn list_rfds_op(
     ctx: &RfdContext,
     caller: &Caller<RfdPermission>,
-) -> Result<HttpResponseOk<Vec<RfdWithoutContent>>, HttpError> {
-    let rfds = ctx.list_rfds(caller, None).await?;
-    Ok(HttpResponseOk(rfds))
+    query: ListRfdsQuery,
+) -> Result<ListRfdsPage, HttpError> {
+    let field = query.sort.unwrap_or_default();
+    let direction = query.direction.unwrap_or_default();
+
+    let after = match query.cursor.as_deref().map(ListRfdsCursor::decode) {
+        Some(Some(cursor)) if cursor.sort == field && cursor.direction == direction => {
+            Some(cursor.key)
+        }
+        Some(Some(_)) => {
+            return Err(client_error(
+                ClientErrorStatusCode::BAD_REQUEST,
+                "Cursor does not match the requested sort order",
+            ))
+        }
+        Some(None) => {
+            return Err(client_error(
+                ClientErrorStatusCode::BAD_REQUEST,
+                "Malformed cursor",
+            ))
+        }
+        None => None,
+    };
+
+    let filter = RfdFilter::default()
+        .state(split_query_list(query.state.as_deref()))
+        .labels(split_query_list(query.label.as_deref()))
+        .author(query.author)
+        .title(query.title)
+        .public(
+            query
+                .visibility
+                .map(|visibility| visibility == Visibility::Public),
+        )
+        .updated_since(query.updated_since)
+        .major_change_since(query.major_change_since);
+
+    let rfds = ctx
+        .list_rfds(
+            caller,
+            Some(filter.clone()),
+            &RfdSort::default()
+                .field(field)
+                .direction(direction)
+                .after(after),
+            &ListPagination::default()
+                .limit(query.limit.unwrap_or(UNLIMITED))
+                .offset(query.offset.unwrap_or(0)),
+        )
+        .await?;
+    let total = ctx.count_rfds(caller, Some(filter)).await?;
+
+    let next_cursor = query
+        .limit
+        .filter(|limit| *limit > 0 && rfds.len() as i64 >= *limit)
+        .and_then(|_| rfds.last())
+        .map(|last| {
+            ListRfdsCursor {
+                sort: field,
+                direction,
+                key: RfdSortKey {
+                    rfd_number: last.rfd_number,
+                    value: match field {
+                        RfdSortField::Number => None,
+                        RfdSortField::UpdatedAt => last.committed_at,
+                        RfdSortField::LatestMajorChangeAt => last.latest_major_change_at,
+                    },
+                },
+            }
+            .encode()
+        });
+
+    Ok(ListRfdsPage {
+        rfds,
+        total,
+        next_cursor,
+    })
+}
+
+/// Split a comma separated query parameter in to its values. A parameter without any values is
+/// treated as if it was not provided
+pub(crate) fn split_query_list(value: Option<&str>) -> Option<Vec<String>> {
+    value
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .filter(|values| !values.is_empty())
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
@@ -451,6 +856,68 @@ async fn view_rfd_pdf_op(
     }
 }
 
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn view_rfd_html_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    number: String,
+    revision: Option<RfdRevisionIdentifier>,
+) -> Result<HttpResponseOk<RfdHtml>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseOk(
+            ctx.view_rfd_html(caller, rfd_number, revision).await?,
+        ))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn view_rfd_feed_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    number: Option<String>,
+) -> Result<RfdFeed, HttpError> {
+    let (rfd_number, title, url) = match number {
+        Some(number) => {
+            let rfd_number = number.parse::<i32>().map_err(|_| {
+                client_error(ClientErrorStatusCode::BAD_REQUEST, "Malformed RFD number")
+            })?;
+            (
+                Some(rfd_number),
+                format!("RFD {} activity", rfd_number),
+                format!("{}/rfd/{}/feed.atom", ctx.public_url, number),
+            )
+        }
+        None => (
+            None,
+            "RFD activity".to_string(),
+            format!("{}/feeds/rfds.atom", ctx.public_url),
+        ),
+    };
+
+    let entries = ctx
+        .list_rfd_feed_entries(caller, rfd_number, FEED_ENTRY_LIMIT)
+        .await?;
+
+    Ok(RfdFeed {
+        title,
+        url,
+        entries,
+    })
+}
+
+fn atom_response(feed: String) -> Result<Response<Body>, HttpError> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, ATOM_CONTENT_TYPE)
+        .body(Body::from(feed))
+        .map_err(|err| internal_error(format!("Failed to construct feed response: {}", err)))
+}
+
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
 async fn view_rfd_attr_op(
     ctx: &RfdContext,
@@ -486,15 +953,81 @@ async fn view_rfd_attr_op(
     }
 }
 
-#[allow(dead_code)]
-#[instrument(skip(_ctx, caller), fields(caller = ?caller.id), err(Debug))]
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
 async fn view_rfd_discussion_op(
-    _ctx: &RfdContext,
+    ctx: &RfdContext,
     caller: &Caller<RfdPermission>,
     number: String,
     revision: Option<RfdRevisionIdentifier>,
-) -> Result<HttpResponseOk<RfdWithRaw>, HttpError> {
-    unimplemented!()
+) -> Result<HttpResponseOk<RfdDiscussion>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseOk(
+            ctx.view_rfd_discussion(caller, rfd_number, revision)
+                .await?,
+        ))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn list_rfd_references_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    number: String,
+) -> Result<HttpResponseOk<Vec<RfdWithoutContent>>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseOk(
+            ctx.list_rfd_references(caller, rfd_number).await?,
+        ))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn list_rfd_backlinks_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    number: String,
+) -> Result<HttpResponseOk<Vec<RfdWithoutContent>>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseOk(
+            ctx.list_rfd_backlinks(caller, rfd_number).await?,
+        ))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn view_rfd_revision_diff_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    number: String,
+    from: RfdRevisionIdentifier,
+    to: RfdRevisionIdentifier,
+) -> Result<HttpResponseOk<RfdRevisionDiff>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseOk(
+            ctx.view_rfd_revision_diff(caller, rfd_number, from, to)
+                .await?,
+        ))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
@@ -516,7 +1049,7 @@ async fn search_rfds_op(
         // all RFDs or they access to some smaller set. If we need to filter down the RFD list we
         // construct a filter that will search across the RFDs the caller has direct access to as
         // well as any RFDs that are marked as publicly accessible.
-        search_request.filter = if caller.can(&RfdPermission::GetRfdsAll) {
+        let access_filter = if caller.can(&RfdPermission::GetRfdsAll) {
             None
         } else {
             let mut filter = "public = true".to_string();
@@ -528,12 +1061,21 @@ async fn search_rfds_op(
                 .collect::<Vec<_>>()
                 .join(", ");
             if !allowed_rfds.is_empty() {
-                filter = filter + &format!("OR rfd_number in [{}]", allowed_rfds);
+                filter = filter + &format!(" OR rfd_number in [{}]", allowed_rfds);
             }
 
             Some(filter)
         };
 
+        // The access filter is grouped so that the filters requested by the caller can only ever
+        // narrow the set of RFDs that are searched
+        search_request.filter = match (access_filter, search_request.filter.take()) {
+            (Some(access_filter), Some(filter)) => {
+                Some(format!("({}) AND {}", access_filter, filter))
+            }
+            (access_filter, filter) => access_filter.or(filter),
+        };
+
         tracing::debug!(?search_request, "Submitting search request to backend");
 
         // Pass the search request off to the meilisearch backend
@@ -556,6 +1098,12 @@ async fn search_rfds_op(
                     query: results.query,
                     limit: results.limit,
                     offset: results.offset,
+                    section_facets: results
+                        .facet_distribution
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|(facet, counts)| (facet, counts.into_iter().collect()))
+                        .collect(),
                 };
 
                 tracing::debug!(count = ?results.hits.len(), "Transformed search results");
@@ -580,6 +1128,14 @@ pub struct ReserveRfdBody {
     pub title: String,
     /// Optional contents of the RFD
     pub content: Option<String>,
+    /// Optional name of the template to construct the RFD from. Defaults to the standard new RFD
+    /// template
+    pub template: Option<String>,
+    /// Values for the fields of the selected template
+    pub fields: Option<HashMap<String, String>>,
+    /// Optional number to reserve. The number must either be held for a group that the caller is
+    /// a member of, or the caller must be permitted to manage RFD reservations
+    pub number: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
@@ -587,7 +1143,22 @@ pub struct ReserveRfdResponse {
     number: i32,
 }
 
-/// Create a new RFD
+/// List the templates that can be used when reserving a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd-templates",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_rfd_templates(
+    rqctx: RequestContext<RfdContext>,
+) -> Result<HttpResponseOk<Vec<RfdTemplateSummary>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    Ok(HttpResponseOk(ctx.list_rfd_templates(&caller)?))
+}
+
+/// Create a new RFD
 #[trace_request]
 #[endpoint {
     method = POST,
@@ -600,16 +1171,44 @@ pub async fn reserve_rfd(
 ) -> Result<HttpResponseAccepted<ReserveRfdResponse>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
-    reserve_rfd_op(ctx, &caller, body.into_inner()).await
+    reserve_rfd_op(ctx, &caller, &(&rqctx).into(), body.into_inner()).await
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
 async fn reserve_rfd_op(
     ctx: &RfdContext,
     caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
     body: ReserveRfdBody,
 ) -> Result<HttpResponseAccepted<ReserveRfdResponse>, HttpError> {
-    let number = ctx.create_rfd(caller, body.title, body.content).await?;
+    let Some(template) = ctx.content.new_rfd_template(
+        body.template.as_deref(),
+        body.title.clone(),
+        body.content,
+        body.fields.unwrap_or_default(),
+    ) else {
+        return Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Unknown RFD template",
+        ));
+    };
+
+    // The number is only known once it has been allocated, all other fields must be supplied
+    let missing_fields = template
+        .missing_fields()
+        .into_iter()
+        .filter(|field| field != "number")
+        .collect::<Vec<_>>();
+    if !missing_fields.is_empty() {
+        return Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            format!("Missing template fields: {}", missing_fields.join(", ")),
+        ));
+    }
+
+    let number = ctx
+        .create_rfd(caller, request, body.title, template, body.number)
+        .await?;
     Ok(HttpResponseAccepted(ReserveRfdResponse {
         number: number.into(),
     }))
@@ -621,6 +1220,12 @@ pub struct RfdUpdateBody {
     document: String,
     /// Optional Git commit message to send with this update (recommended)
     message: Option<String>,
+    /// The commit that this update was written against. If the RFD has been updated since this
+    /// commit then the update is rejected with a conflict
+    base_commit: Option<CommitSha>,
+    /// Attempt to merge this update with any changes made since the base commit. The update is
+    /// still rejected if the changes overlap
+    merge: Option<bool>,
 }
 
 /// Replace the full document of a RFD
@@ -636,24 +1241,34 @@ pub async fn set_rfd_document(
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
-    set_rfd_document_op(ctx, &caller, path.into_inner().number, body.into_inner()).await
+    set_rfd_document_op(
+        ctx,
+        &caller,
+        &(&rqctx).into(),
+        path.into_inner().number,
+        body.into_inner(),
+    )
+    .await
 }
 
 async fn set_rfd_document_op(
     ctx: &RfdContext,
     caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
     number: String,
     body: RfdUpdateBody,
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     if let Ok(rfd_number) = number.parse::<i32>() {
         ctx.update_rfd_document(
             caller,
+            request,
             rfd_number,
             &body.document,
             body.message.as_deref(),
-            None,
+            update_base(body.base_commit, body.merge),
         )
-        .await?;
+        .await
+        .map_err(update_rfd_error)?;
         Ok(HttpResponseAccepted(()))
     } else {
         Err(client_error(
@@ -669,6 +1284,12 @@ pub struct RfdUpdateContentBody {
     content: String,
     /// Optional Git commit message to send with this update (recommended)
     message: Option<String>,
+    /// The commit that this update was written against. If the RFD has been updated since this
+    /// commit then the update is rejected with a conflict
+    base_commit: Option<CommitSha>,
+    /// Attempt to merge this update with any changes made since the base commit. The update is
+    /// still rejected if the changes overlap
+    merge: Option<bool>,
 }
 
 /// Replace the contents of a RFD
@@ -684,24 +1305,34 @@ pub async fn set_rfd_content(
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
-    set_rfd_content_op(ctx, &caller, path.into_inner().number, body.into_inner()).await
+    set_rfd_content_op(
+        ctx,
+        &caller,
+        &(&rqctx).into(),
+        path.into_inner().number,
+        body.into_inner(),
+    )
+    .await
 }
 
 async fn set_rfd_content_op(
     ctx: &RfdContext,
     caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
     number: String,
     body: RfdUpdateContentBody,
 ) -> Result<HttpResponseAccepted<()>, HttpError> {
     if let Ok(rfd_number) = number.parse::<i32>() {
         ctx.update_rfd_document(
             caller,
+            request,
             rfd_number,
             &body.content,
             body.message.as_deref(),
-            None,
+            update_base(body.base_commit, body.merge),
         )
-        .await?;
+        .await
+        .map_err(update_rfd_error)?;
         Ok(HttpResponseAccepted(()))
     } else {
         Err(client_error(
@@ -717,6 +1348,12 @@ pub struct RfdAttrValue {
     value: String,
     /// Optional Git commit message to send with this update (recommended)
     message: Option<String>,
+    /// The commit that this update was written against. If the RFD has been updated since this
+    /// commit then the update is rejected with a conflict
+    base_commit: Option<CommitSha>,
+    /// Attempt to merge this update with any changes made since the base commit. The update is
+    /// still rejected if the changes overlap
+    merge: Option<bool>,
 }
 
 /// Set an attribute of a RFD
@@ -734,20 +1371,34 @@ pub async fn set_rfd_attr(
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
     let path = path.into_inner();
-    set_rfd_attr_op(ctx, &caller, path.number, path.attr, &body.into_inner()).await
+    set_rfd_attr_op(
+        ctx,
+        &caller,
+        &(&rqctx).into(),
+        path.number,
+        path.attr,
+        &body.into_inner(),
+    )
+    .await
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
 async fn set_rfd_attr_op(
     ctx: &RfdContext,
     caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
     number: String,
     attr: RfdAttrName,
     body: &RfdAttrValue,
 ) -> Result<HttpResponseAccepted<RfdAttr>, HttpError> {
     if let Ok(rfd_number) = number.parse::<i32>() {
-        // Get the latest revision
-        let revision = ctx.view_rfd_revision(caller, rfd_number, None).await?;
+        // Get the revision that the update was written against. Without a base commit this is
+        // the latest revision
+        let base = update_base(body.base_commit.clone(), body.merge);
+        let revision = ctx
+            .view_rfd_update_base(caller, rfd_number, base.as_ref())
+            .await
+            .map_err(update_rfd_error)?;
 
         // TODO: Get rid of these clones
         let mut content = match revision.content_format {
@@ -790,12 +1441,14 @@ async fn set_rfd_attr_op(
         // commits
         ctx.update_rfd_document(
             caller,
+            request,
             rfd_number,
             content.raw(),
             body.message.as_deref(),
-            None,
+            base,
         )
-        .await?;
+        .await
+        .map_err(update_rfd_error)?;
 
         extract_attr(&attr, &content).map(HttpResponseAccepted)
     } else {
@@ -823,11 +1476,14 @@ pub async fn discuss_rfd(
     set_rfd_attr_op(
         ctx,
         &caller,
+        &(&rqctx).into(),
         path.number,
         RfdAttrName::State,
         &RfdAttrValue {
             value: RfdState::Discussion.to_string(),
             message: Some("Move to discussion".to_string()),
+            base_commit: None,
+            merge: None,
         },
     )
     .await
@@ -850,11 +1506,14 @@ pub async fn publish_rfd(
     set_rfd_attr_op(
         ctx,
         &caller,
+        &(&rqctx).into(),
         path.number,
         RfdAttrName::State,
         &RfdAttrValue {
             value: RfdState::Published.to_string(),
             message: Some("Publish".to_string()),
+            base_commit: None,
+            merge: None,
         },
     )
     .await
@@ -880,12 +1539,21 @@ pub async fn update_rfd_revision(
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
     let path = path.into_inner();
-    update_rfd_revision_op(ctx, &caller, path.number, path.revision, body.into_inner()).await
+    update_rfd_revision_op(
+        ctx,
+        &caller,
+        &(&rqctx).into(),
+        path.number,
+        path.revision,
+        body.into_inner(),
+    )
+    .await
 }
 
 async fn update_rfd_revision_op(
     ctx: &RfdContext,
     caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
     rfd_number: String,
     revision: TypedUuid<RfdRevisionId>,
     raw_changes: UpdateRfdAttrBody,
@@ -907,12 +1575,32 @@ async fn update_rfd_revision_op(
     }
 
     Ok(HttpResponseOk(
-        ctx.update_rfd_revision_metadata(caller, rfd_number, revision, &changes)
+        ctx.update_rfd_revision_metadata(caller, request, rfd_number, revision, &changes)
             .await?
             .into(),
     ))
 }
 
+fn update_base(base_commit: Option<CommitSha>, merge: Option<bool>) -> Option<UpdateBase> {
+    base_commit.map(|commit| UpdateBase {
+        commit,
+        merge: merge.unwrap_or(false),
+    })
+}
+
+pub(crate) fn update_rfd_error(err: ResourceError<UpdateRfdContentError>) -> HttpError {
+    match err {
+        ResourceError::InternalError(err @ UpdateRfdContentError::Conflict { .. }) => {
+            client_error(ClientErrorStatusCode::CONFLICT, err)
+        }
+        ResourceError::InternalError(
+            err @ (UpdateRfdContentError::InvalidStateTransition { .. }
+            | UpdateRfdContentError::InvalidDocument(_)),
+        ) => client_error(ClientErrorStatusCode::BAD_REQUEST, err),
+        err => err.into(),
+    }
+}
+
 fn extract_attr(attr: &RfdAttrName, content: &RfdContent) -> Result<RfdAttr, HttpError> {
     match attr {
         RfdAttrName::Discussion => content
@@ -972,19 +1660,27 @@ pub async fn update_rfd_visibility(
 ) -> Result<HttpResponseOk<Rfd>, HttpError> {
     let ctx = rqctx.context();
     let caller = ctx.v_ctx().get_caller(&rqctx).await?;
-    update_rfd_visibility_op(ctx, &caller, path.into_inner().number, body.into_inner()).await
+    update_rfd_visibility_op(
+        ctx,
+        &caller,
+        &(&rqctx).into(),
+        path.into_inner().number,
+        body.into_inner(),
+    )
+    .await
 }
 
 #[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
 async fn update_rfd_visibility_op(
     ctx: &RfdContext,
     caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
     number: String,
     body: RfdVisibility,
 ) -> Result<HttpResponseOk<Rfd>, HttpError> {
     if let Ok(rfd_number) = number.parse::<i32>() {
         Ok(HttpResponseOk(
-            ctx.update_rfd_visibility(caller, rfd_number, body.visibility)
+            ctx.update_rfd_visibility(caller, request, rfd_number, body.visibility)
                 .await?,
         ))
     } else {
@@ -997,31 +1693,87 @@ async fn update_rfd_visibility_op(
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{cmp::Reverse, sync::Arc};
 
     use chrono::Utc;
     use dropshot::HttpResponseOk;
     use http::StatusCode;
     use newtype_uuid::{GenericUuid, TypedUuid};
     use rfd_model::{
-        schema_ext::ContentFormat,
+        schema_ext::{ContentFormat, Visibility, WebhookEvent},
         storage::{
-            mock::MockStorage, MockRfdMetaStore, MockRfdPdfStore, MockRfdRevisionMetaStore,
-            MockRfdRevisionStore, MockRfdStore,
+            mock::MockStorage, MockAuditEventStore, MockRfdMetaStore, MockRfdPdfStore,
+            MockRfdReferenceStore, MockRfdRevisionHtmlStore, MockRfdRevisionMetaStore,
+            MockRfdRevisionStore, MockRfdStore, MockWebhookSubscriptionDeliveryStore, RfdFilter,
         },
-        CommitSha, FileSha, Rfd, RfdMeta, RfdRevision, RfdRevisionMeta,
+        AuditEvent, CommitSha, FileSha, Rfd, RfdFeedRevision, RfdMeta, RfdReference, RfdRevision,
+        RfdRevisionHtml, RfdRevisionMeta,
     };
+    use serde_json::json;
     use uuid::Uuid;
     use v_api::ApiContext;
     use v_model::{permissions::Caller, Permissions};
 
     use crate::{
-        context::{test_mocks::mock_context, RfdContext},
-        endpoints::rfd::view_rfd_op,
+        context::{
+            test_mocks::{mock_context, mock_request},
+            RfdContext, RfdRevisionIdentifier,
+        },
+        endpoints::rfd::{
+            list_rfd_backlinks_op, list_rfd_references_op, update_rfd_visibility_op,
+            view_rfd_discussion_op, view_rfd_feed_op, view_rfd_html_op, view_rfd_op,
+            view_rfd_revision_diff_op, RfdVisibility,
+        },
         permissions::RfdPermission,
     };
 
-    use super::list_rfds_op;
+    use super::{
+        list_rfds_op, reserve_rfd_op, set_rfd_attr_op, set_rfd_document_op, ListRfdsPage,
+        ListRfdsQuery, ReserveRfdBody, RfdAttrName, RfdAttrValue, RfdUpdateBody,
+    };
+
+    // The RFDs referenced by each of the test RFDs
+    fn references(rfd_number: i32) -> Vec<i32> {
+        match rfd_number {
+            456 => vec![123, 789],
+            789 => vec![456],
+            _ => vec![],
+        }
+    }
+
+    // Evaluate the subset of filters used by the RFD listing against an RFD, combining nested
+    // filters the same way that the storage layer does
+    fn matches(filter: &RfdFilter, rfd_number: i32, visibility: &Visibility) -> bool {
+        filter
+            .rfd_number
+            .as_ref()
+            .map(|numbers| numbers.contains(&rfd_number))
+            .unwrap_or(true)
+            && filter
+                .references
+                .map(|target| references(rfd_number).contains(&target))
+                .unwrap_or(true)
+            && filter
+                .public
+                .map(|public| public == (*visibility == Visibility::Public))
+                .unwrap_or(true)
+            && filter
+                .any
+                .as_ref()
+                .map(|any| {
+                    any.iter()
+                        .any(|filter| matches(filter, rfd_number, visibility))
+                })
+                .unwrap_or(true)
+            && filter
+                .all
+                .as_ref()
+                .map(|all| {
+                    all.iter()
+                        .all(|filter| matches(filter, rfd_number, visibility))
+                })
+                .unwrap_or(true)
+    }
 
     async fn ctx() -> RfdContext {
         let private_rfd_id_1 = Uuid::new_v4();
@@ -1130,8 +1882,7 @@ mod tests {
             Ok(results)
         });
 
-        let mut rfd_meta_store = MockRfdMetaStore::new();
-        rfd_meta_store.expect_list().returning(move |filter, _| {
+        let rfd_metas = move |filter: Vec<RfdFilter>| {
             let mut results = vec![
                 RfdMeta {
                     id: TypedUuid::from_untyped_uuid(private_rfd_id_1),
@@ -1218,16 +1969,35 @@ mod tests {
 
             results.retain(|rfd| {
                 filter.is_empty()
-                    || filter[0].rfd_number.is_none()
-                    || filter[0]
-                        .rfd_number
-                        .as_ref()
-                        .unwrap()
-                        .contains(&rfd.rfd_number)
+                    || filter
+                        .iter()
+                        .any(|filter| matches(filter, rfd.rfd_number, &rfd.visibility))
             });
+            results.sort_by_key(|rfd| Reverse(rfd.rfd_number));
 
-            Ok(results)
-        });
+            results
+        };
+
+        let mut rfd_meta_store = MockRfdMetaStore::new();
+        rfd_meta_store
+            .expect_list()
+            .returning(move |filter, sort, pagination| {
+                let mut results = rfd_metas(filter);
+
+                // The mock only supports the default ordering by descending RFD number
+                if let Some(after) = &sort.after {
+                    results.retain(|rfd| rfd.rfd_number < after.rfd_number);
+                }
+
+                Ok(results
+                    .into_iter()
+                    .skip(pagination.offset as usize)
+                    .take(pagination.limit as usize)
+                    .collect())
+            });
+        rfd_meta_store
+            .expect_count()
+            .returning(move |filter| Ok(rfd_metas(filter).len() as i64));
 
         let private_rfd_revision_id_1 = TypedUuid::new_v4();
         let public_rfd_revision_id = TypedUuid::new_v4();
@@ -1367,15 +2137,86 @@ mod tests {
 
                 Ok(results)
             });
+        rfd_revision_meta_store
+            .expect_list_feed()
+            .returning(move |rfds, _| {
+                Ok(vec![
+                    (
+                        private_rfd_revision_id_1,
+                        private_rfd_id_1,
+                        "Private Test RFD 1",
+                    ),
+                    (public_rfd_revision_id, public_rfd_id, "Public Test RFD"),
+                    (
+                        private_rfd_revision_id_2,
+                        private_rfd_id_2,
+                        "Private Test RFD 2",
+                    ),
+                ]
+                .into_iter()
+                .filter(|(_, rfd_id, _)| rfds.contains(&TypedUuid::from_untyped_uuid(*rfd_id)))
+                .map(|(id, rfd_id, title)| RfdFeedRevision {
+                    revision: RfdRevisionMeta {
+                        id,
+                        rfd_id: TypedUuid::from_untyped_uuid(rfd_id),
+                        title: title.to_string(),
+                        state: None,
+                        discussion: None,
+                        authors: None,
+                        labels: None,
+                        content_format: rfd_model::schema_ext::ContentFormat::Asciidoc,
+                        sha: String::new().into(),
+                        commit: String::new().into(),
+                        committed_at: Utc::now(),
+                        major_change: false,
+                        created_at: Utc::now(),
+                        updated_at: Utc::now(),
+                        deleted_at: None,
+                    },
+                    first: true,
+                    previous_state: None,
+                })
+                .collect())
+            });
 
         let mut rfd_pdf_store = MockRfdPdfStore::new();
         rfd_pdf_store
             .expect_list()
             .returning(move |_, _| Ok(vec![]));
 
+        // Revision ids are generated per lookup, so the references of RFD 456 are returned for
+        // every revision
+        let mut rfd_reference_store = MockRfdReferenceStore::new();
+        rfd_reference_store
+            .expect_list()
+            .returning(move |filter, _| {
+                Ok(references(456)
+                    .into_iter()
+                    .map(|target_rfd_number| RfdReference {
+                        rfd_revision_id: filter[0].rfd_revision.as_ref().unwrap()[0],
+                        target_rfd_number,
+                        created_at: Utc::now(),
+                    })
+                    .collect())
+            });
+
+        let mut rfd_revision_html_store = MockRfdRevisionHtmlStore::new();
+        rfd_revision_html_store
+            .expect_get()
+            .returning(move |revision| {
+                Ok(Some(RfdRevisionHtml {
+                    rfd_revision_id: *revision,
+                    html: "<p>Rendered</p>".to_string(),
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                }))
+            });
+
         let mut storage = MockStorage::new();
         storage.rfd_store = Some(Arc::new(rfd_store));
         storage.rfd_meta_store = Some(Arc::new(rfd_meta_store));
+        storage.rfd_reference_store = Some(Arc::new(rfd_reference_store));
+        storage.rfd_revision_html_store = Some(Arc::new(rfd_revision_html_store));
         storage.rfd_revision_store = Some(Arc::new(rfd_revision_store));
         storage.rfd_revision_meta_store = Some(Arc::new(rfd_revision_meta_store));
         storage.rfd_pdf_store = Some(Arc::new(rfd_pdf_store));
@@ -1390,7 +2231,9 @@ mod tests {
         let ctx = ctx().await;
         let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
 
-        let HttpResponseOk(rfds) = list_rfds_op(&ctx, &caller).await.unwrap();
+        let ListRfdsPage { rfds, .. } = list_rfds_op(&ctx, &caller, ListRfdsQuery::default())
+            .await
+            .unwrap();
         assert_eq!(3, rfds.len());
         assert_eq!(789, rfds[0].rfd_number);
         assert_eq!(456, rfds[1].rfd_number);
@@ -1420,7 +2263,9 @@ mod tests {
         let ctx = ctx().await;
         let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfd(123)]));
 
-        let HttpResponseOk(rfds) = list_rfds_op(&ctx, &caller).await.unwrap();
+        let ListRfdsPage { rfds, .. } = list_rfds_op(&ctx, &caller, ListRfdsQuery::default())
+            .await
+            .unwrap();
         assert_eq!(2, rfds.len());
         assert_eq!(456, rfds[0].rfd_number);
         assert_eq!(123, rfds[1].rfd_number);
@@ -1449,11 +2294,27 @@ mod tests {
         let ctx = ctx().await;
         let caller = Caller::from(Permissions::<RfdPermission>::new());
 
-        let HttpResponseOk(rfds) = list_rfds_op(&ctx, &caller).await.unwrap();
+        let ListRfdsPage { rfds, .. } = list_rfds_op(&ctx, &caller, ListRfdsQuery::default())
+            .await
+            .unwrap();
         assert_eq!(1, rfds.len());
         assert_eq!(456, rfds[0].rfd_number);
     }
 
+    #[tokio::test]
+    async fn count_rfds_is_limited_to_accessible_rfds() {
+        let ctx = ctx().await;
+
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+        assert_eq!(3, ctx.count_rfds(&caller, None).await.unwrap());
+
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfd(123)]));
+        assert_eq!(2, ctx.count_rfds(&caller, None).await.unwrap());
+
+        let caller = Caller::from(Permissions::<RfdPermission>::new());
+        assert_eq!(1, ctx.count_rfds(&caller, None).await.unwrap());
+    }
+
     #[tokio::test]
     async fn view_rfd_without_permission() {
         let ctx = ctx().await;
@@ -1475,14 +2336,105 @@ mod tests {
     async fn list_rfds_as_unauthenticated() {
         let ctx = ctx().await;
 
-        let HttpResponseOk(rfds) =
-            list_rfds_op(&ctx, &ctx.v_ctx().builtin_unauthenticated_caller())
-                .await
-                .unwrap();
+        let ListRfdsPage { rfds, .. } = list_rfds_op(
+            &ctx,
+            &ctx.v_ctx().builtin_unauthenticated_caller(),
+            ListRfdsQuery::default(),
+        )
+        .await
+        .unwrap();
         assert_eq!(1, rfds.len());
         assert_eq!(456, rfds[0].rfd_number);
     }
 
+    // Test RFD list filtering and pagination
+
+    #[tokio::test]
+    async fn list_rfds_by_visibility() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let response = list_rfds_op(
+            &ctx,
+            &caller,
+            ListRfdsQuery {
+                visibility: Some(Visibility::Private),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(2, response.total);
+        assert_eq!(
+            vec![789, 123],
+            response
+                .rfds
+                .iter()
+                .map(|rfd| rfd.rfd_number)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn list_rfds_by_cursor() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let first = list_rfds_op(
+            &ctx,
+            &caller,
+            ListRfdsQuery {
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(3, first.total);
+        assert_eq!(2, first.rfds.len());
+        assert_eq!(456, first.rfds[1].rfd_number);
+        assert!(first.next_cursor.is_some());
+
+        let second = list_rfds_op(
+            &ctx,
+            &caller,
+            ListRfdsQuery {
+                limit: Some(2),
+                cursor: first.next_cursor,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(3, second.total);
+        assert_eq!(1, second.rfds.len());
+        assert_eq!(123, second.rfds[0].rfd_number);
+        assert!(second.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn list_rfds_with_invalid_cursor() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let result = list_rfds_op(
+            &ctx,
+            &caller,
+            ListRfdsQuery {
+                cursor: Some("not-a-cursor".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::BAD_REQUEST, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 400 error, but instead found RFDs {:?}",
+                response.rfds
+            ),
+        }
+    }
+
     #[tokio::test]
     async fn view_rfd_as_unauthenticated() {
         let ctx = ctx().await;
@@ -1497,4 +2449,446 @@ mod tests {
             ),
         }
     }
+
+    // Test RFD discussion access
+
+    #[tokio::test]
+    async fn view_rfd_discussion_without_permission() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let result = view_rfd_discussion_op(&ctx, &caller, "0123".to_string(), None).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::FORBIDDEN, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 403 error, but instead found a discussion {:?}",
+                response.0
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn view_rfd_discussion_of_inaccessible_rfd() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetDiscussionsAll]));
+
+        let result = view_rfd_discussion_op(&ctx, &caller, "0123".to_string(), None).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::NOT_FOUND, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 404 error, but instead found a discussion {:?}",
+                response.0
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn view_rfd_discussion_without_discussion_link() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![
+            RfdPermission::GetRfdsAll,
+            RfdPermission::GetDiscussionsAll,
+        ]));
+
+        let result = view_rfd_discussion_op(&ctx, &caller, "0123".to_string(), None).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::NOT_FOUND, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 404 error, but instead found a discussion {:?}",
+                response.0
+            ),
+        }
+    }
+
+    // Test RFD revision diffs
+
+    #[tokio::test]
+    async fn view_rfd_revision_diff_via_all_permission() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let HttpResponseOk(diff) = view_rfd_revision_diff_op(
+            &ctx,
+            &caller,
+            "0123".to_string(),
+            RfdRevisionIdentifier::Id(TypedUuid::new_v4()),
+            RfdRevisionIdentifier::Commit(CommitSha("abc".to_string())),
+        )
+        .await
+        .unwrap();
+        assert!(diff.attributes.is_empty());
+        assert!(diff.hunks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn view_rfd_revision_diff_without_permission() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::<RfdPermission>::new());
+
+        let result = view_rfd_revision_diff_op(
+            &ctx,
+            &caller,
+            "0123".to_string(),
+            RfdRevisionIdentifier::Id(TypedUuid::new_v4()),
+            RfdRevisionIdentifier::Commit(CommitSha("abc".to_string())),
+        )
+        .await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::NOT_FOUND, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 404 error, but instead found a diff {:?}",
+                response.0
+            ),
+        }
+    }
+
+    // Test that writes are recorded in the audit log
+
+    // Test rendered RFD access
+
+    #[tokio::test]
+    async fn view_rfd_html_as_unauthenticated() {
+        let ctx = ctx().await;
+        let caller = ctx.v_ctx().builtin_unauthenticated_caller();
+
+        let HttpResponseOk(html) = view_rfd_html_op(&ctx, &caller, "0456".to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(456, html.rfd_number);
+        assert_eq!("<p>Rendered</p>", html.html);
+
+        let result = view_rfd_html_op(&ctx, &caller, "0123".to_string(), None).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::NOT_FOUND, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 404 error, but instead found HTML {:?}",
+                response.0
+            ),
+        }
+    }
+
+    // Test RFD activity feeds
+
+    #[tokio::test]
+    async fn view_rfds_feed_as_unauthenticated() {
+        let ctx = ctx().await;
+        let caller = ctx.v_ctx().builtin_unauthenticated_caller();
+
+        let feed = view_rfd_feed_op(&ctx, &caller, None).await.unwrap();
+        assert_eq!(1, feed.entries.len());
+        assert_eq!(456, feed.entries[0].rfd_number);
+    }
+
+    #[tokio::test]
+    async fn view_rfds_feed_via_all_permission() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let feed = view_rfd_feed_op(&ctx, &caller, None).await.unwrap();
+        let mut numbers = feed
+            .entries
+            .iter()
+            .map(|entry| entry.rfd_number)
+            .collect::<Vec<_>>();
+        numbers.sort();
+        assert_eq!(vec![123, 456, 789], numbers);
+    }
+
+    #[tokio::test]
+    async fn view_rfd_feed_as_unauthenticated() {
+        let ctx = ctx().await;
+        let caller = ctx.v_ctx().builtin_unauthenticated_caller();
+
+        let feed = view_rfd_feed_op(&ctx, &caller, Some("0456".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(1, feed.entries.len());
+        assert!(feed.to_atom(&ctx.public_url).contains("Public Test RFD"));
+
+        let result = view_rfd_feed_op(&ctx, &caller, Some("0123".to_string())).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::NOT_FOUND, err.status_code),
+            Ok(feed) => panic!(
+                "Expected a 404 error, but instead found {} feed entries",
+                feed.entries.len()
+            ),
+        }
+    }
+
+    // Test RFD references and backlinks
+
+    #[tokio::test]
+    async fn list_rfd_references_via_all_permission() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let HttpResponseOk(rfds) = list_rfd_references_op(&ctx, &caller, "0456".to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![789, 123],
+            rfds.iter().map(|rfd| rfd.rfd_number).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn list_rfd_references_omits_inaccessible_rfds() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfd(123)]));
+
+        let HttpResponseOk(rfds) = list_rfd_references_op(&ctx, &caller, "0456".to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![123],
+            rfds.iter().map(|rfd| rfd.rfd_number).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn list_rfd_backlinks_omits_inaccessible_rfds() {
+        let ctx = ctx().await;
+
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+        let HttpResponseOk(rfds) = list_rfd_backlinks_op(&ctx, &caller, "0456".to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![789],
+            rfds.iter().map(|rfd| rfd.rfd_number).collect::<Vec<_>>()
+        );
+
+        let caller = Caller::from(Permissions::<RfdPermission>::new());
+        let HttpResponseOk(rfds) = list_rfd_backlinks_op(&ctx, &caller, "0456".to_string())
+            .await
+            .unwrap();
+        assert!(rfds.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_rfd_backlinks_of_inaccessible_rfd() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::<RfdPermission>::new());
+
+        let result = list_rfd_backlinks_op(&ctx, &caller, "0123".to_string()).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::NOT_FOUND, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 404 error, but instead found RFDs {:?}",
+                response.0
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn update_rfd_visibility_records_audit_event() {
+        let rfd_id = TypedUuid::new_v4();
+
+        let mut rfd_meta_store = MockRfdMetaStore::new();
+        rfd_meta_store.expect_list().returning(move |_, _, _| {
+            Ok(vec![RfdMeta {
+                id: rfd_id,
+                rfd_number: 123,
+                link: None,
+                content: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                deleted_at: None,
+                latest_major_change_at: None,
+                visibility: Visibility::Private,
+            }])
+        });
+
+        let mut rfd_store = MockRfdStore::new();
+        rfd_store.expect_upsert().returning(|new_rfd| {
+            Ok(Rfd {
+                id: new_rfd.id,
+                rfd_number: new_rfd.rfd_number,
+                link: new_rfd.link,
+                content: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                deleted_at: None,
+                latest_major_change_at: None,
+                visibility: new_rfd.visibility,
+            })
+        });
+
+        let mut audit_event_store = MockAuditEventStore::new();
+        audit_event_store
+            .expect_create()
+            .withf(|event| {
+                event.operation == "update_rfd_visibility"
+                    && event.request_id == "request-id"
+                    && event.rfd_number == Some(123)
+                    && event.before == Some(json!({ "visibility": "private" }))
+                    && event.after == Some(json!({ "visibility": "public" }))
+            })
+            .times(1)
+            .returning(|event| {
+                Ok(AuditEvent {
+                    id: event.id,
+                    caller_id: event.caller_id,
+                    operation: event.operation,
+                    rfd_number: event.rfd_number,
+                    before: event.before,
+                    after: event.after,
+                    request_id: event.request_id,
+                    created_at: Utc::now(),
+                })
+            });
+
+        let mut webhook_subscription_delivery_store = MockWebhookSubscriptionDeliveryStore::new();
+        webhook_subscription_delivery_store
+            .expect_enqueue()
+            .withf(|event, rfd_number, payload| {
+                *event == WebhookEvent::VisibilityChanged
+                    && *rfd_number == 123
+                    && payload["data"] == json!({ "from": "private", "to": "public" })
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(vec![]));
+
+        let mut storage = MockStorage::new();
+        storage.rfd_store = Some(Arc::new(rfd_store));
+        storage.rfd_meta_store = Some(Arc::new(rfd_meta_store));
+        storage.audit_event_store = Some(Arc::new(audit_event_store));
+        storage.webhook_subscription_delivery_store =
+            Some(Arc::new(webhook_subscription_delivery_store));
+        let ctx = mock_context(storage).await;
+
+        let caller = Caller::from(Permissions::from(vec![
+            RfdPermission::GetRfdsAll,
+            RfdPermission::ManageRfdsVisibilityAll,
+        ]));
+
+        let HttpResponseOk(rfd) = update_rfd_visibility_op(
+            &ctx,
+            &caller,
+            &mock_request("update_rfd_visibility"),
+            "123".to_string(),
+            RfdVisibility {
+                visibility: Visibility::Public,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(Visibility::Public, rfd.visibility);
+    }
+
+    async fn template_ctx() -> RfdContext {
+        let mut ctx = mock_context(MockStorage::new()).await;
+        ctx.content.templates.insert(
+            "postmortem".to_string(),
+            serde_json::from_value(json!({
+                "description": "Incident postmortem",
+                "template": ":title: {title}\n:incident: {incident}\n",
+                "required_fields": ["number", "title", "incident"],
+            }))
+            .unwrap(),
+        );
+        ctx
+    }
+
+    #[tokio::test]
+    async fn list_templates_hides_generated_fields() {
+        let ctx = template_ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::CreateRfd]));
+
+        let templates = ctx.list_rfd_templates(&caller).unwrap();
+        let names = templates
+            .iter()
+            .map(|template| template.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["new", "placeholder", "postmortem"], names);
+        assert_eq!(
+            Some("Incident postmortem".to_string()),
+            templates[2].description
+        );
+        assert_eq!(vec!["incident".to_string()], templates[2].fields);
+    }
+
+    #[tokio::test]
+    async fn reserve_with_unknown_template() {
+        let ctx = template_ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::CreateRfd]));
+
+        let result = reserve_rfd_op(
+            &ctx,
+            &caller,
+            &mock_request("reserve_rfd"),
+            ReserveRfdBody {
+                title: "Outage".to_string(),
+                content: None,
+                template: Some("hardware".to_string()),
+                fields: None,
+                number: None,
+            },
+        )
+        .await;
+        assert_eq!(StatusCode::BAD_REQUEST, result.unwrap_err().status_code);
+    }
+
+    #[tokio::test]
+    async fn reserve_with_missing_template_fields() {
+        let ctx = template_ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::CreateRfd]));
+
+        let result = reserve_rfd_op(
+            &ctx,
+            &caller,
+            &mock_request("reserve_rfd"),
+            ReserveRfdBody {
+                title: "Outage".to_string(),
+                content: None,
+                template: Some("postmortem".to_string()),
+                fields: None,
+                number: None,
+            },
+        )
+        .await;
+        let err = result.unwrap_err();
+        assert_eq!(StatusCode::BAD_REQUEST, err.status_code);
+        assert!(err.external_message.contains("incident"));
+    }
+
+    #[tokio::test]
+    async fn update_with_stale_base_conflicts() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![
+            RfdPermission::GetRfdsAll,
+            RfdPermission::UpdateRfdsAll,
+        ]));
+
+        let result = set_rfd_document_op(
+            &ctx,
+            &caller,
+            &mock_request("update_rfd"),
+            "123".to_string(),
+            RfdUpdateBody {
+                document: "= RFD 123 Title\n".to_string(),
+                message: None,
+                base_commit: Some(CommitSha("stale".to_string())),
+                merge: None,
+            },
+        )
+        .await;
+        assert_eq!(StatusCode::CONFLICT, result.unwrap_err().status_code);
+
+        let result = set_rfd_attr_op(
+            &ctx,
+            &caller,
+            &mock_request("update_rfd"),
+            "123".to_string(),
+            RfdAttrName::Discussion,
+            &RfdAttrValue {
+                value: "https://github.com/org/repo/pull/1".to_string(),
+                message: None,
+                base_commit: Some(CommitSha("stale".to_string())),
+                merge: Some(false),
+            },
+        )
+        .await;
+        assert_eq!(StatusCode::CONFLICT, result.unwrap_err().status_code);
+    }
 }
@@ -0,0 +1,314 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use dropshot::{
+    endpoint, ClientErrorStatusCode, HttpError, HttpResponseAccepted, HttpResponseOk, Path, Query,
+    RequestContext, UntypedBody,
+};
+use rfd_model::{CommitSha, FileSha};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use trace_request::trace_request;
+use tracing::instrument;
+use v_api::ApiContext;
+use v_model::permissions::Caller;
+
+use crate::{
+    context::{AuditRequest, RfdContext, RfdFileChange},
+    endpoints::rfd::{update_rfd_error, RfdPathParams},
+    permissions::RfdPermission,
+    util::response::client_error,
+};
+
+/// Largest supporting file that can be uploaded. Files are far more likely than RFD documents to
+/// exceed the default request body limit of the server
+const RFD_FILE_MAX_BYTES: usize = 32 * 1024 * 1024;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdFileQuery {
+    /// Path of the file relative to the RFD directory (examples: diagram.svg or images/flow.png)
+    path: String,
+    /// Optional Git commit message to send with this change (recommended)
+    message: Option<String>,
+}
+
+/// A file stored in the directory of an RFD alongside its README
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RfdFile {
+    /// Path of the file relative to the RFD directory
+    pub path: String,
+    pub sha: FileSha,
+    /// Size of the file in bytes
+    pub size: i64,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RfdFileCommit {
+    /// The commit that the change was pushed as
+    pub commit: CommitSha,
+}
+
+/// List the supporting files (images, includes, etc.) stored alongside a RFD
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd/{number}/files",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_rfd_files(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+) -> Result<HttpResponseOk<Vec<RfdFile>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    list_rfd_files_op(ctx, &caller, path.into_inner().number).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn list_rfd_files_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    number: String,
+) -> Result<HttpResponseOk<Vec<RfdFile>>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        let files = ctx
+            .list_rfd_files(caller, rfd_number)
+            .await?
+            .into_iter()
+            .map(|file| RfdFile {
+                path: file.path,
+                sha: file.sha,
+                size: file.size,
+            })
+            .collect();
+        Ok(HttpResponseOk(files))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+/// Upload a new supporting file to the branch of a RFD. The request body is the raw content of
+/// the file, which may be up to 32 MiB
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd/{number}/files",
+    request_body_max_bytes = RFD_FILE_MAX_BYTES,
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn create_rfd_file(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+    query: Query<RfdFileQuery>,
+    body: UntypedBody,
+) -> Result<HttpResponseAccepted<RfdFileCommit>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    update_rfd_file_op(
+        ctx,
+        &caller,
+        &(&rqctx).into(),
+        path.into_inner().number,
+        query.into_inner(),
+        RfdFileChange::Create(body.as_bytes()),
+    )
+    .await
+}
+
+/// Replace the contents of an existing supporting file on the branch of a RFD. The request body
+/// is the raw content of the file, which may be up to 32 MiB
+#[trace_request]
+#[endpoint {
+    method = PUT,
+    path = "/rfd/{number}/files",
+    request_body_max_bytes = RFD_FILE_MAX_BYTES,
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn replace_rfd_file(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+    query: Query<RfdFileQuery>,
+    body: UntypedBody,
+) -> Result<HttpResponseAccepted<RfdFileCommit>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    update_rfd_file_op(
+        ctx,
+        &caller,
+        &(&rqctx).into(),
+        path.into_inner().number,
+        query.into_inner(),
+        RfdFileChange::Replace(body.as_bytes()),
+    )
+    .await
+}
+
+/// Delete a supporting file from the branch of a RFD
+#[trace_request]
+#[endpoint {
+    method = DELETE,
+    path = "/rfd/{number}/files",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn delete_rfd_file(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+    query: Query<RfdFileQuery>,
+) -> Result<HttpResponseAccepted<RfdFileCommit>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    update_rfd_file_op(
+        ctx,
+        &caller,
+        &(&rqctx).into(),
+        path.into_inner().number,
+        query.into_inner(),
+        RfdFileChange::Delete,
+    )
+    .await
+}
+
+#[instrument(skip(ctx, caller, change), fields(caller = ?caller.id), err(Debug))]
+async fn update_rfd_file_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
+    number: String,
+    query: RfdFileQuery,
+    change: RfdFileChange<'_>,
+) -> Result<HttpResponseAccepted<RfdFileCommit>, HttpError> {
+    let Ok(rfd_number) = number.parse::<i32>() else {
+        return Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ));
+    };
+
+    if !is_valid_file_path(&query.path) {
+        return Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Invalid file path",
+        ));
+    }
+
+    let commit = ctx
+        .update_rfd_file(
+            caller,
+            request,
+            rfd_number,
+            &query.path,
+            change,
+            query.message.as_deref(),
+        )
+        .await
+        .map_err(update_rfd_error)?;
+
+    Ok(HttpResponseAccepted(RfdFileCommit { commit }))
+}
+
+/// Supporting files must be stored within the RFD directory and may not replace the RFD document
+/// itself, which is managed through the document and content endpoints
+fn is_valid_file_path(path: &str) -> bool {
+    !path.is_empty()
+        && !path.starts_with('/')
+        && !path.contains('\\')
+        && path
+            .split('/')
+            .all(|segment| !segment.is_empty() && segment != "." && segment != "..")
+        && path != "README.adoc"
+        && path != "README.md"
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use rfd_model::storage::mock::MockStorage;
+    use v_model::{permissions::Caller, Permissions};
+
+    use crate::{
+        context::{
+            test_mocks::{mock_context, mock_request},
+            RfdFileChange,
+        },
+        permissions::RfdPermission,
+    };
+
+    use super::{is_valid_file_path, update_rfd_file_op, RfdFileQuery};
+
+    #[test]
+    fn validates_file_paths() {
+        assert!(is_valid_file_path("diagram.svg"));
+        assert!(is_valid_file_path("images/flow.png"));
+        assert!(is_valid_file_path("images/README.md"));
+
+        assert!(!is_valid_file_path(""));
+        assert!(!is_valid_file_path("/diagram.svg"));
+        assert!(!is_valid_file_path("../0124/README.adoc"));
+        assert!(!is_valid_file_path("images/../../secret"));
+        assert!(!is_valid_file_path("images//flow.png"));
+        assert!(!is_valid_file_path("images\\flow.png"));
+        assert!(!is_valid_file_path("README.adoc"));
+        assert!(!is_valid_file_path("README.md"));
+    }
+
+    #[tokio::test]
+    async fn update_file_with_invalid_path() {
+        let ctx = mock_context(MockStorage::new()).await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::UpdateRfdsAll]));
+
+        let result = update_rfd_file_op(
+            &ctx,
+            &caller,
+            &mock_request("create_rfd_file"),
+            "123".to_string(),
+            RfdFileQuery {
+                path: "../0124/README.adoc".to_string(),
+                message: None,
+            },
+            RfdFileChange::Create(b"content"),
+        )
+        .await;
+
+        match result {
+            Err(err) => assert_eq!(StatusCode::BAD_REQUEST, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 400 error, but instead found a commit {:?}",
+                response.0
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn update_file_without_permission() {
+        let ctx = mock_context(MockStorage::new()).await;
+        let caller = Caller::from(Permissions::from(vec![
+            RfdPermission::GetRfdsAll,
+            RfdPermission::UpdateRfd(124),
+        ]));
+
+        let result = update_rfd_file_op(
+            &ctx,
+            &caller,
+            &mock_request("create_rfd_file"),
+            "123".to_string(),
+            RfdFileQuery {
+                path: "images/flow.png".to_string(),
+                message: None,
+            },
+            RfdFileChange::Delete,
+        )
+        .await;
+
+        match result {
+            Err(err) => assert_eq!(StatusCode::FORBIDDEN, err.status_code),
+            Ok(response) => panic!(
+                "Expected a 403 error, but instead found a commit {:?}",
+                response.0
+            ),
+        }
+    }
+}
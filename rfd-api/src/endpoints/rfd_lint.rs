@@ -0,0 +1,63 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use dropshot::{endpoint, HttpError, HttpResponseOk, RequestContext, TypedBody};
+use rfd_data::lint::{RfdLintDiagnostic, RfdLinter};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use trace_request::trace_request;
+use tracing::instrument;
+use v_api::ApiContext;
+use v_model::permissions::Caller;
+
+use crate::{context::RfdContext, permissions::RfdPermission};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct LintRfdBody {
+    /// Full Asciidoc document to check
+    content: String,
+    /// The number of the RFD that the document is stored as. When set, the number in the title of
+    /// the document must match it
+    number: Option<i32>,
+    /// Paths of the supporting files stored alongside the RFD, relative to its directory. Include
+    /// and image references are only checked when this is set
+    files: Option<Vec<String>>,
+}
+
+/// Check a RFD document for problems before it is committed
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd-lint",
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn lint_rfd(
+    rqctx: RequestContext<RfdContext>,
+    body: TypedBody<LintRfdBody>,
+) -> Result<HttpResponseOk<Vec<RfdLintDiagnostic>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    lint_rfd_op(ctx, &caller, body.into_inner()).await
+}
+
+#[instrument(skip(ctx, caller, body), fields(caller = ?caller.id), err(Debug))]
+async fn lint_rfd_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    body: LintRfdBody,
+) -> Result<HttpResponseOk<Vec<RfdLintDiagnostic>>, HttpError> {
+    let linter = linter(ctx, body.number, body.files);
+    Ok(HttpResponseOk(linter.lint(&body.content)))
+}
+
+pub(crate) fn linter(
+    ctx: &RfdContext,
+    rfd_number: Option<i32>,
+    files: Option<Vec<String>>,
+) -> RfdLinter {
+    RfdLinter::default()
+        .number(rfd_number.map(|number| number.into()))
+        .repository(&ctx.github.owner, &ctx.github.repo)
+        .files(files)
+}
@@ -0,0 +1,189 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use dropshot::{
+    endpoint, ClientErrorStatusCode, HttpError, HttpResponseOk, Path, RequestContext, TypedBody,
+};
+use rfd_data::{
+    content::{RfdAsciidoc, RfdContent, RfdDocument, RfdMarkdown, RfdSection},
+    lint::RfdLintDiagnostic,
+};
+use rfd_model::schema_ext::ContentFormat;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use trace_request::trace_request;
+use tracing::instrument;
+use v_api::ApiContext;
+use v_model::permissions::Caller;
+
+use crate::{
+    context::RfdContext,
+    endpoints::{rfd::RfdPathParams, rfd_lint::linter},
+    permissions::RfdPermission,
+    util::response::client_error,
+};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RfdPreviewBody {
+    /// Full document to parse
+    content: String,
+    /// The format that the document is written in
+    format: ContentFormat,
+    /// Paths of the supporting files stored alongside the RFD, relative to its directory. Include
+    /// and image references are only checked when this is set
+    files: Option<Vec<String>>,
+}
+
+/// The data that would be extracted from a document if it were committed
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct RfdPreview {
+    pub rfd_number: Option<i32>,
+    pub discussion: Option<String>,
+    pub title: Option<String>,
+    pub state: Option<String>,
+    pub authors: Option<String>,
+    pub labels: Option<String>,
+    pub content: String,
+    pub format: ContentFormat,
+    /// The section headings of the document in the order that they appear
+    pub outline: Vec<RfdSection>,
+    /// Problems found while linting the document. Only Asciidoc documents are linted
+    pub diagnostics: Vec<RfdLintDiagnostic>,
+}
+
+/// Parse a document without committing it, returning the data that would be extracted from it
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd-preview",
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn preview_rfd_content(
+    rqctx: RequestContext<RfdContext>,
+    body: TypedBody<RfdPreviewBody>,
+) -> Result<HttpResponseOk<RfdPreview>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    preview_rfd_op(ctx, &caller, None, body.into_inner()).await
+}
+
+/// Parse a document for a RFD without committing it, returning the data that would be extracted
+/// from it
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd/{number}/preview",
+}]
+#[instrument(skip(rqctx, body), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn preview_rfd(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdPathParams>,
+    body: TypedBody<RfdPreviewBody>,
+) -> Result<HttpResponseOk<RfdPreview>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+
+    if let Ok(rfd_number) = path.into_inner().number.parse::<i32>() {
+        preview_rfd_op(ctx, &caller, Some(rfd_number), body.into_inner()).await
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[instrument(skip(ctx, caller, body), fields(caller = ?caller.id), err(Debug))]
+async fn preview_rfd_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    rfd_number: Option<i32>,
+    body: RfdPreviewBody,
+) -> Result<HttpResponseOk<RfdPreview>, HttpError> {
+    let content = match body.format {
+        ContentFormat::Asciidoc => {
+            RfdContent::Asciidoc(RfdAsciidoc::new(body.content).map_err(|err| {
+                tracing::info!(?err, "Failed to parse RFD content");
+                client_error(
+                    ClientErrorStatusCode::BAD_REQUEST,
+                    "Unable to parse RFD content",
+                )
+            })?)
+        }
+        ContentFormat::Markdown => RfdContent::Markdown(RfdMarkdown::new(body.content)),
+    };
+
+    let diagnostics = match &content {
+        RfdContent::Asciidoc(_) => linter(ctx, rfd_number, body.files).lint(content.raw()),
+        RfdContent::Markdown(_) => vec![],
+    };
+
+    Ok(HttpResponseOk(RfdPreview {
+        rfd_number,
+        discussion: content.get_discussion().map(|value| value.to_string()),
+        title: content.get_title().map(|value| value.to_string()),
+        state: content.get_state().map(|value| value.to_string()),
+        authors: content.get_authors().map(|value| value.to_string()),
+        labels: content.get_labels().map(|value| value.to_string()),
+        outline: content.outline(),
+        format: content.format(),
+        content: content.raw().to_string(),
+        diagnostics,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use rfd_data::lint::RfdLintRule;
+    use rfd_model::{schema_ext::ContentFormat, storage::mock::MockStorage};
+    use v_model::{permissions::Caller, Permissions};
+
+    use crate::{context::test_mocks::mock_context, permissions::RfdPermission};
+
+    use super::{preview_rfd_op, RfdPreviewBody};
+
+    #[tokio::test]
+    async fn preview_extracts_attributes_and_outline() {
+        let ctx = mock_context(MockStorage::new()).await;
+        let caller = Caller::from(Permissions::<RfdPermission>::new());
+
+        let preview = preview_rfd_op(
+            &ctx,
+            &caller,
+            Some(124),
+            RfdPreviewBody {
+                content: ":state: ideation\n:labels: api, cli\n\n= RFD 123 Previews\nJane Doe <jane@example.com>\n\n== Background\n\n=== History\n".to_string(),
+                format: ContentFormat::Asciidoc,
+                files: None,
+            },
+        )
+        .await
+        .unwrap()
+        .0;
+
+        assert_eq!(Some("Previews"), preview.title.as_deref());
+        assert_eq!(Some("ideation"), preview.state.as_deref());
+        assert_eq!(Some("api, cli"), preview.labels.as_deref());
+        assert_eq!(
+            Some("Jane Doe <jane@example.com>"),
+            preview.authors.as_deref()
+        );
+        assert_eq!(
+            vec![("Background", 1), ("History", 2)],
+            preview
+                .outline
+                .iter()
+                .map(|section| (section.title.as_str(), section.level))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![RfdLintRule::MissingAttribute, RfdLintRule::MismatchedNumber],
+            preview
+                .diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.rule)
+                .collect::<Vec<_>>()
+        );
+    }
+}
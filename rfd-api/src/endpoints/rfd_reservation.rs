@@ -0,0 +1,367 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use dropshot::{
+    endpoint, ClientErrorStatusCode, HttpError, HttpResponseCreated, HttpResponseOk, Path, Query,
+    RequestContext, TypedBody,
+};
+use rfd_model::{storage::RfdNumberReservationFilter, RfdNumberReservation};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use trace_request::trace_request;
+use tracing::instrument;
+use uuid::Uuid;
+use v_api::{response::client_error, ApiContext};
+use v_model::{permissions::Caller, storage::ListPagination};
+
+use crate::{
+    context::{AuditRequest, RfdContext},
+    permissions::RfdPermission,
+};
+
+use super::UNLIMITED;
+
+/// The largest number of RFD numbers that can be held by a single request
+static MAX_HOLD_RANGE: i32 = 1000;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct RfdReservationPath {
+    /// The RFD number (examples: 1 or 123)
+    number: String,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct ListRfdReservationsQuery {
+    /// Only list numbers held for this group
+    group_id: Option<Uuid>,
+    /// Filter to numbers that have (true) or have not (false) been claimed by an RFD
+    claimed: Option<bool>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct HoldRfdNumbersBody {
+    /// The first number to hold
+    start: i32,
+    /// The last number to hold (inclusive). Defaults to holding only the first number
+    end: Option<i32>,
+    /// The group whose members are allowed to claim the held numbers
+    group_id: Uuid,
+}
+
+// Read Endpoints
+
+/// List reserved and held RFD numbers
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd-reservation",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_rfd_reservations(
+    rqctx: RequestContext<RfdContext>,
+    query: Query<ListRfdReservationsQuery>,
+) -> Result<HttpResponseOk<Vec<RfdNumberReservation>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    list_rfd_reservations_op(ctx, &caller, query.into_inner()).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn list_rfd_reservations_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    query: ListRfdReservationsQuery,
+) -> Result<HttpResponseOk<Vec<RfdNumberReservation>>, HttpError> {
+    let filter = RfdNumberReservationFilter::default()
+        .group_id(query.group_id.map(|id| vec![id]))
+        .claimed(query.claimed);
+
+    Ok(HttpResponseOk(
+        ctx.list_rfd_number_reservations(
+            caller,
+            filter,
+            &ListPagination::default()
+                .limit(query.limit.unwrap_or(UNLIMITED))
+                .offset(query.offset.unwrap_or(0)),
+        )
+        .await?,
+    ))
+}
+
+// Write Endpoints
+
+/// Hold a number or a range of numbers for a group. Held numbers are skipped when allocating new
+/// RFD numbers and can only be claimed by members of the group
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd-reservation",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn hold_rfd_numbers(
+    rqctx: RequestContext<RfdContext>,
+    body: TypedBody<HoldRfdNumbersBody>,
+) -> Result<HttpResponseCreated<Vec<RfdNumberReservation>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    hold_rfd_numbers_op(ctx, &caller, &(&rqctx).into(), body.into_inner()).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn hold_rfd_numbers_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
+    body: HoldRfdNumbersBody,
+) -> Result<HttpResponseCreated<Vec<RfdNumberReservation>>, HttpError> {
+    let end = body.end.unwrap_or(body.start);
+
+    if body.start < 1 {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "RFD numbers must be positive",
+        ))
+    } else if end < body.start {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "The end of the range must not be before the start",
+        ))
+    } else if end - body.start >= MAX_HOLD_RANGE {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            &format!("At most {} numbers can be held at once", MAX_HOLD_RANGE),
+        ))
+    } else {
+        Ok(HttpResponseCreated(
+            ctx.hold_rfd_numbers(caller, request, body.start, end, body.group_id)
+                .await?,
+        ))
+    }
+}
+
+/// Release a held RFD number that has not been claimed
+#[trace_request]
+#[endpoint {
+    method = DELETE,
+    path = "/rfd-reservation/{number}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn release_rfd_reservation(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdReservationPath>,
+) -> Result<HttpResponseOk<RfdNumberReservation>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    release_rfd_reservation_op(ctx, &caller, &(&rqctx).into(), path.into_inner().number).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn release_rfd_reservation_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
+    number: String,
+) -> Result<HttpResponseOk<RfdNumberReservation>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseOk(
+            ctx.release_held_rfd_number(caller, request, rfd_number)
+                .await?,
+        ))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use http::StatusCode;
+    use newtype_uuid::TypedUuid;
+    use rfd_model::{
+        storage::{mock::MockStorage, MockAuditEventStore, MockRfdNumberReservationStore},
+        AuditEvent, RfdNumberReservation,
+    };
+    use uuid::Uuid;
+    use v_model::{permissions::Caller, Permissions};
+
+    use crate::{
+        context::{
+            test_mocks::{mock_context, mock_request},
+            RfdContext,
+        },
+        permissions::RfdPermission,
+    };
+
+    use super::{
+        hold_rfd_numbers_op, list_rfd_reservations_op, release_rfd_reservation_op,
+        HoldRfdNumbersBody, ListRfdReservationsQuery,
+    };
+
+    async fn ctx() -> RfdContext {
+        let mut store = MockRfdNumberReservationStore::new();
+        store.expect_list().returning(|filters, _| {
+            let numbers = filters[0].rfd_number.clone().unwrap_or(vec![200, 201]);
+            Ok(numbers
+                .into_iter()
+                .filter(|number| *number == 200 || *number == 201)
+                .map(|number| RfdNumberReservation {
+                    id: TypedUuid::new_v4(),
+                    rfd_number: number,
+                    group_id: Some(Uuid::new_v4()),
+                    created_by: Uuid::new_v4(),
+                    claimed_by: (number == 201).then(Uuid::new_v4),
+                    claimed_at: (number == 201).then(Utc::now),
+                    created_at: Utc::now(),
+                })
+                .collect())
+        });
+        store.expect_delete().returning(|number| {
+            Ok(Some(RfdNumberReservation {
+                id: TypedUuid::new_v4(),
+                rfd_number: number,
+                group_id: Some(Uuid::new_v4()),
+                created_by: Uuid::new_v4(),
+                claimed_by: None,
+                claimed_at: None,
+                created_at: Utc::now(),
+            }))
+        });
+
+        let mut audit_event_store = MockAuditEventStore::new();
+        audit_event_store.expect_create().returning(|event| {
+            Ok(AuditEvent {
+                id: event.id,
+                caller_id: event.caller_id,
+                operation: event.operation,
+                rfd_number: event.rfd_number,
+                before: event.before,
+                after: event.after,
+                request_id: event.request_id,
+                created_at: Utc::now(),
+            })
+        });
+
+        let mut storage = MockStorage::new();
+        storage.rfd_number_reservation_store = Some(Arc::new(store));
+        storage.audit_event_store = Some(Arc::new(audit_event_store));
+
+        mock_context(storage).await
+    }
+
+    fn caller(permissions: Vec<RfdPermission>) -> Caller<RfdPermission> {
+        let mut caller = Caller::from(Permissions::from(permissions));
+        caller.id = TypedUuid::new_v4();
+        caller
+    }
+
+    #[tokio::test]
+    async fn list_reservations_requires_permission() {
+        let ctx = ctx().await;
+
+        let result = list_rfd_reservations_op(
+            &ctx,
+            &caller(vec![RfdPermission::CreateRfd]),
+            ListRfdReservationsQuery::default(),
+        )
+        .await;
+        assert_eq!(StatusCode::FORBIDDEN, result.unwrap_err().status_code);
+
+        let reservations = list_rfd_reservations_op(
+            &ctx,
+            &caller(vec![RfdPermission::ManageRfdReservations]),
+            ListRfdReservationsQuery::default(),
+        )
+        .await
+        .unwrap()
+        .0;
+        assert_eq!(2, reservations.len());
+    }
+
+    #[tokio::test]
+    async fn hold_rejects_invalid_ranges() {
+        let ctx = ctx().await;
+        let caller = caller(vec![RfdPermission::ManageRfdReservations]);
+
+        for (start, end) in [(0, None), (10, Some(9)), (10, Some(1010))] {
+            let result = hold_rfd_numbers_op(
+                &ctx,
+                &caller,
+                &mock_request("hold_rfd_numbers"),
+                HoldRfdNumbersBody {
+                    start,
+                    end,
+                    group_id: Uuid::new_v4(),
+                },
+            )
+            .await;
+            assert_eq!(StatusCode::BAD_REQUEST, result.unwrap_err().status_code);
+        }
+    }
+
+    #[tokio::test]
+    async fn hold_requires_permission() {
+        let ctx = ctx().await;
+
+        let result = hold_rfd_numbers_op(
+            &ctx,
+            &caller(vec![RfdPermission::CreateRfd]),
+            &mock_request("hold_rfd_numbers"),
+            HoldRfdNumbersBody {
+                start: 300,
+                end: Some(310),
+                group_id: Uuid::new_v4(),
+            },
+        )
+        .await;
+        assert_eq!(StatusCode::FORBIDDEN, result.unwrap_err().status_code);
+    }
+
+    #[tokio::test]
+    async fn release_unclaimed_reservation() {
+        let ctx = ctx().await;
+        let caller = caller(vec![RfdPermission::ManageRfdReservations]);
+
+        let reservation = release_rfd_reservation_op(
+            &ctx,
+            &caller,
+            &mock_request("release_rfd_reservation"),
+            "200".to_string(),
+        )
+        .await
+        .unwrap()
+        .0;
+        assert_eq!(200, reservation.rfd_number);
+    }
+
+    #[tokio::test]
+    async fn release_claimed_reservation_conflicts() {
+        let ctx = ctx().await;
+        let caller = caller(vec![RfdPermission::ManageRfdReservations]);
+
+        let result = release_rfd_reservation_op(
+            &ctx,
+            &caller,
+            &mock_request("release_rfd_reservation"),
+            "201".to_string(),
+        )
+        .await;
+        assert_eq!(StatusCode::CONFLICT, result.unwrap_err().status_code);
+
+        let result = release_rfd_reservation_op(
+            &ctx,
+            &caller,
+            &mock_request("release_rfd_reservation"),
+            "202".to_string(),
+        )
+        .await;
+        assert_eq!(StatusCode::NOT_FOUND, result.unwrap_err().status_code);
+    }
+}
@@ -0,0 +1,275 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use dropshot::{
+    endpoint, ClientErrorStatusCode, HttpError, HttpResponseCreated, HttpResponseOk, Path, Query,
+    RequestContext,
+};
+use newtype_uuid::TypedUuid;
+use rfd_model::{RfdWatch, RfdWatchId};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use trace_request::trace_request;
+use tracing::instrument;
+use v_api::{response::client_error, ApiContext};
+use v_model::{permissions::Caller, storage::ListPagination};
+
+use crate::{context::RfdContext, permissions::RfdPermission};
+
+use super::UNLIMITED;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct RfdWatchPath {
+    id: TypedUuid<RfdWatchId>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct WatchRfdPath {
+    /// The RFD number (examples: 1 or 123)
+    number: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct WatchRfdLabelPath {
+    /// The label to watch. Labels are matched ignoring case
+    label: String,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct ListRfdWatchesQuery {
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+// Read Endpoints
+
+/// List the RFDs and labels that the caller is watching
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/rfd-watch",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_rfd_watches(
+    rqctx: RequestContext<RfdContext>,
+    query: Query<ListRfdWatchesQuery>,
+) -> Result<HttpResponseOk<Vec<RfdWatch>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let query = query.into_inner();
+    Ok(HttpResponseOk(
+        ctx.list_rfd_watches(
+            &caller,
+            &ListPagination::default()
+                .limit(query.limit.unwrap_or(UNLIMITED))
+                .offset(query.offset.unwrap_or(0)),
+        )
+        .await?,
+    ))
+}
+
+// Write Endpoints
+
+/// Watch an RFD. An email is sent to the caller when the RFD changes state or receives a major
+/// change, for as long as the caller is able to read the RFD
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd/{number}/watch",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn watch_rfd(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<WatchRfdPath>,
+) -> Result<HttpResponseCreated<RfdWatch>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    watch_rfd_op(ctx, &caller, path.into_inner().number).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn watch_rfd_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    number: String,
+) -> Result<HttpResponseCreated<RfdWatch>, HttpError> {
+    if let Ok(rfd_number) = number.parse::<i32>() {
+        Ok(HttpResponseCreated(
+            ctx.watch_rfd(caller, rfd_number).await?,
+        ))
+    } else {
+        Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Malformed RFD number",
+        ))
+    }
+}
+
+/// Watch every RFD with a label. An email is sent to the caller when a labeled RFD that they are
+/// able to read changes state or receives a major change
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/rfd-label/{label}/watch",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn watch_rfd_label(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<WatchRfdLabelPath>,
+) -> Result<HttpResponseCreated<RfdWatch>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    watch_rfd_label_op(ctx, &caller, path.into_inner().label).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn watch_rfd_label_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    label: String,
+) -> Result<HttpResponseCreated<RfdWatch>, HttpError> {
+    let label = label.trim();
+    if label.is_empty() {
+        return Err(client_error(
+            ClientErrorStatusCode::BAD_REQUEST,
+            "Label must not be empty",
+        ));
+    }
+
+    Ok(HttpResponseCreated(
+        ctx.watch_rfd_label(caller, label.to_string()).await?,
+    ))
+}
+
+/// Stop watching an RFD or label
+#[trace_request]
+#[endpoint {
+    method = DELETE,
+    path = "/rfd-watch/{id}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn delete_rfd_watch(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<RfdWatchPath>,
+) -> Result<HttpResponseOk<RfdWatch>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    Ok(HttpResponseOk(
+        ctx.delete_rfd_watch(&caller, &path.into_inner().id).await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use dropshot::HttpResponseCreated;
+    use http::StatusCode;
+    use newtype_uuid::{GenericUuid, TypedUuid};
+    use rfd_model::{
+        schema_ext::Visibility,
+        storage::{mock::MockStorage, MockRfdMetaStore, MockRfdWatchStore},
+        RfdMeta, RfdWatch,
+    };
+    use v_api::ApiContext;
+    use v_model::{permissions::Caller, Permissions};
+
+    use crate::{
+        context::{test_mocks::mock_context, RfdContext},
+        permissions::RfdPermission,
+    };
+
+    use super::{watch_rfd_label_op, watch_rfd_op};
+
+    async fn ctx() -> RfdContext {
+        let mut rfd_meta_store = MockRfdMetaStore::new();
+        rfd_meta_store.expect_list().returning(|filters, _, _| {
+            let numbers = filters[0].rfd_number.clone().unwrap_or_default();
+            Ok(numbers
+                .into_iter()
+                .filter(|number| *number == 123)
+                .map(|number| RfdMeta {
+                    id: TypedUuid::new_v4(),
+                    rfd_number: number,
+                    link: None,
+                    content: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                    deleted_at: None,
+                    latest_major_change_at: None,
+                    visibility: Visibility::Private,
+                })
+                .collect())
+        });
+
+        let mut rfd_watch_store = MockRfdWatchStore::new();
+        rfd_watch_store.expect_list().returning(|_, _| Ok(vec![]));
+        rfd_watch_store.expect_create().returning(|new_watch| {
+            Ok(RfdWatch {
+                id: new_watch.id,
+                api_user_id: new_watch.api_user_id,
+                rfd_number: new_watch.rfd_number,
+                label: new_watch.label,
+                created_at: Utc::now(),
+                deleted_at: None,
+            })
+        });
+
+        let mut storage = MockStorage::new();
+        storage.rfd_meta_store = Some(Arc::new(rfd_meta_store));
+        storage.rfd_watch_store = Some(Arc::new(rfd_watch_store));
+
+        mock_context(storage).await
+    }
+
+    fn caller(permissions: Vec<RfdPermission>) -> Caller<RfdPermission> {
+        let mut caller = Caller::from(Permissions::from(permissions));
+        caller.id = TypedUuid::new_v4();
+        caller
+    }
+
+    #[tokio::test]
+    async fn watch_rfd_with_access() {
+        let ctx = ctx().await;
+        let caller = caller(vec![RfdPermission::GetRfd(123)]);
+
+        let HttpResponseCreated(watch) = watch_rfd_op(&ctx, &caller, "123".to_string())
+            .await
+            .unwrap();
+        assert_eq!(Some(123), watch.rfd_number);
+        assert_eq!(None, watch.label);
+        assert_eq!(caller.id.into_untyped_uuid(), watch.api_user_id);
+    }
+
+    #[tokio::test]
+    async fn watch_private_rfd_without_access() {
+        let ctx = ctx().await;
+        let caller = caller(vec![RfdPermission::GetRfd(456)]);
+
+        let result = watch_rfd_op(&ctx, &caller, "123".to_string()).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::NOT_FOUND, err.status_code),
+            Ok(HttpResponseCreated(watch)) => panic!(
+                "Expected a 404 error, but instead created a watch {:?}",
+                watch
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn watch_label_as_unauthenticated_caller() {
+        let ctx = ctx().await;
+        let caller = ctx.v_ctx().builtin_unauthenticated_caller();
+
+        let result = watch_rfd_label_op(&ctx, &caller, "api".to_string()).await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::FORBIDDEN, err.status_code),
+            Ok(HttpResponseCreated(watch)) => panic!(
+                "Expected a 403 error, but instead created a watch {:?}",
+                watch
+            ),
+        }
+    }
+}
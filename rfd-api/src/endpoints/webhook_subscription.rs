@@ -0,0 +1,366 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use dropshot::{
+    endpoint, ClientErrorStatusCode, HttpError, HttpResponseCreated, HttpResponseOk, Path, Query,
+    RequestContext, TypedBody,
+};
+use newtype_uuid::TypedUuid;
+use reqwest::Url;
+use rfd_model::{
+    schema_ext::WebhookEvent, WebhookSubscription, WebhookSubscriptionDelivery,
+    WebhookSubscriptionId,
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use trace_request::trace_request;
+use tracing::instrument;
+use v_api::{response::client_error, ApiContext};
+use v_model::{permissions::Caller, storage::ListPagination};
+
+use crate::{
+    context::{AuditRequest, CreatedWebhookSubscription, RfdContext},
+    permissions::RfdPermission,
+};
+
+use super::UNLIMITED;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct WebhookSubscriptionPath {
+    id: TypedUuid<WebhookSubscriptionId>,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct ListWebhookSubscriptionsQuery {
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+// Read Endpoints
+
+/// List webhook subscriptions
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/webhook-subscription",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_webhook_subscriptions(
+    rqctx: RequestContext<RfdContext>,
+    query: Query<ListWebhookSubscriptionsQuery>,
+) -> Result<HttpResponseOk<Vec<WebhookSubscription>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    list_webhook_subscriptions_op(ctx, &caller, query.into_inner()).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn list_webhook_subscriptions_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    query: ListWebhookSubscriptionsQuery,
+) -> Result<HttpResponseOk<Vec<WebhookSubscription>>, HttpError> {
+    let subscriptions = ctx
+        .list_webhook_subscriptions(
+            caller,
+            &ListPagination::default()
+                .limit(query.limit.unwrap_or(UNLIMITED))
+                .offset(query.offset.unwrap_or(0)),
+        )
+        .await?;
+    Ok(HttpResponseOk(subscriptions))
+}
+
+/// Get a webhook subscription
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/webhook-subscription/{id}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn view_webhook_subscription(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<WebhookSubscriptionPath>,
+) -> Result<HttpResponseOk<WebhookSubscription>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    Ok(HttpResponseOk(
+        ctx.get_webhook_subscription(&caller, &path.into_inner().id)
+            .await?,
+    ))
+}
+
+/// List the delivery attempts of a webhook subscription, newest first
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/webhook-subscription/{id}/delivery",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn list_webhook_subscription_deliveries(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<WebhookSubscriptionPath>,
+    query: Query<ListWebhookSubscriptionsQuery>,
+) -> Result<HttpResponseOk<Vec<WebhookSubscriptionDelivery>>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let query = query.into_inner();
+    Ok(HttpResponseOk(
+        ctx.list_webhook_subscription_deliveries(
+            &caller,
+            &path.into_inner().id,
+            &ListPagination::default()
+                .limit(query.limit.unwrap_or(UNLIMITED))
+                .offset(query.offset.unwrap_or(0)),
+        )
+        .await?,
+    ))
+}
+
+// Write Endpoints
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WebhookSubscriptionBody {
+    /// The http(s) url that event payloads are POSTed to
+    pub url: String,
+    /// The events to deliver to the subscription
+    pub events: Vec<WebhookEvent>,
+    /// Limit deliveries to events for the given RFDs
+    pub rfd_numbers: Option<Vec<i32>>,
+}
+
+impl WebhookSubscriptionBody {
+    fn validate(&self) -> Result<(), HttpError> {
+        match Url::parse(&self.url) {
+            Ok(url) if url.scheme() == "https" || url.scheme() == "http" => (),
+            _ => {
+                return Err(client_error(
+                    ClientErrorStatusCode::BAD_REQUEST,
+                    "Malformed webhook url",
+                ))
+            }
+        }
+
+        if self.events.is_empty() {
+            return Err(client_error(
+                ClientErrorStatusCode::BAD_REQUEST,
+                "At least one event must be subscribed to",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Create a webhook subscription. The returned secret is used to sign deliveries and can not be
+/// retrieved again
+#[trace_request]
+#[endpoint {
+    method = POST,
+    path = "/webhook-subscription",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn create_webhook_subscription(
+    rqctx: RequestContext<RfdContext>,
+    body: TypedBody<WebhookSubscriptionBody>,
+) -> Result<HttpResponseCreated<CreatedWebhookSubscription>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    create_webhook_subscription_op(ctx, &caller, &(&rqctx).into(), body.into_inner()).await
+}
+
+#[instrument(skip(ctx, caller), fields(caller = ?caller.id), err(Debug))]
+async fn create_webhook_subscription_op(
+    ctx: &RfdContext,
+    caller: &Caller<RfdPermission>,
+    request: &AuditRequest,
+    body: WebhookSubscriptionBody,
+) -> Result<HttpResponseCreated<CreatedWebhookSubscription>, HttpError> {
+    body.validate()?;
+    Ok(HttpResponseCreated(
+        ctx.create_webhook_subscription(caller, request, body.url, body.events, body.rfd_numbers)
+            .await?,
+    ))
+}
+
+/// Update the url and event filters of a webhook subscription
+#[trace_request]
+#[endpoint {
+    method = PUT,
+    path = "/webhook-subscription/{id}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn update_webhook_subscription(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<WebhookSubscriptionPath>,
+    body: TypedBody<WebhookSubscriptionBody>,
+) -> Result<HttpResponseOk<WebhookSubscription>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    let body = body.into_inner();
+    body.validate()?;
+    Ok(HttpResponseOk(
+        ctx.update_webhook_subscription(
+            &caller,
+            &(&rqctx).into(),
+            &path.into_inner().id,
+            body.url,
+            body.events,
+            body.rfd_numbers,
+        )
+        .await?,
+    ))
+}
+
+/// Delete a webhook subscription. Deliveries that have not yet been sent are abandoned
+#[trace_request]
+#[endpoint {
+    method = DELETE,
+    path = "/webhook-subscription/{id}",
+}]
+#[instrument(skip(rqctx), fields(request_id = rqctx.request_id), err(Debug))]
+pub async fn delete_webhook_subscription(
+    rqctx: RequestContext<RfdContext>,
+    path: Path<WebhookSubscriptionPath>,
+) -> Result<HttpResponseOk<WebhookSubscription>, HttpError> {
+    let ctx = rqctx.context();
+    let caller = ctx.v_ctx().get_caller(&rqctx).await?;
+    Ok(HttpResponseOk(
+        ctx.delete_webhook_subscription(&caller, &(&rqctx).into(), &path.into_inner().id)
+            .await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+    use dropshot::{HttpResponseCreated, HttpResponseOk};
+    use http::StatusCode;
+    use rfd_model::{
+        schema_ext::WebhookEvent,
+        storage::{mock::MockStorage, MockAuditEventStore, MockWebhookSubscriptionStore},
+        AuditEvent, WebhookSubscription,
+    };
+    use v_model::{permissions::Caller, Permissions};
+
+    use crate::{
+        context::{
+            test_mocks::{mock_context, mock_request},
+            RfdContext,
+        },
+        permissions::RfdPermission,
+    };
+
+    use super::{
+        create_webhook_subscription_op, list_webhook_subscriptions_op,
+        ListWebhookSubscriptionsQuery, WebhookSubscriptionBody,
+    };
+
+    async fn ctx() -> RfdContext {
+        let mut webhook_subscription_store = MockWebhookSubscriptionStore::new();
+        webhook_subscription_store
+            .expect_list()
+            .returning(|_, _| Ok(vec![]));
+        webhook_subscription_store
+            .expect_upsert()
+            .returning(|new_subscription| {
+                Ok(WebhookSubscription {
+                    id: new_subscription.id,
+                    url: new_subscription.url,
+                    events: new_subscription.events,
+                    rfd_numbers: new_subscription.rfd_numbers,
+                    secret: new_subscription.secret,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                    deleted_at: None,
+                })
+            });
+
+        let mut audit_event_store = MockAuditEventStore::new();
+        audit_event_store.expect_create().returning(|event| {
+            Ok(AuditEvent {
+                id: event.id,
+                caller_id: event.caller_id,
+                operation: event.operation,
+                rfd_number: event.rfd_number,
+                before: event.before,
+                after: event.after,
+                request_id: event.request_id,
+                created_at: Utc::now(),
+            })
+        });
+
+        let mut storage = MockStorage::new();
+        storage.webhook_subscription_store = Some(Arc::new(webhook_subscription_store));
+        storage.audit_event_store = Some(Arc::new(audit_event_store));
+
+        mock_context(storage).await
+    }
+
+    #[tokio::test]
+    async fn create_webhook_subscription_returns_secret() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::ManageWebhooks]));
+
+        let HttpResponseCreated(created) = create_webhook_subscription_op(
+            &ctx,
+            &caller,
+            &mock_request("create_webhook_subscription"),
+            WebhookSubscriptionBody {
+                url: "https://hooks.example.com/rfd".to_string(),
+                events: vec![WebhookEvent::StateChanged],
+                rfd_numbers: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(64, created.secret.len());
+        assert_eq!(created.secret, created.subscription.secret);
+
+        // The secret must never be serialized as part of the subscription itself
+        let serialized = serde_json::to_value(&created.subscription).unwrap();
+        assert!(serialized.get("secret").is_none());
+    }
+
+    #[tokio::test]
+    async fn create_webhook_subscription_rejects_invalid_url() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::ManageWebhooks]));
+
+        let result = create_webhook_subscription_op(
+            &ctx,
+            &caller,
+            &mock_request("create_webhook_subscription"),
+            WebhookSubscriptionBody {
+                url: "ftp://hooks.example.com/rfd".to_string(),
+                events: vec![WebhookEvent::StateChanged],
+                rfd_numbers: None,
+            },
+        )
+        .await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::BAD_REQUEST, err.status_code),
+            Ok(_) => panic!("Expected a 400 error, but instead created a subscription"),
+        }
+    }
+
+    #[tokio::test]
+    async fn list_webhook_subscriptions_without_permission() {
+        let ctx = ctx().await;
+        let caller = Caller::from(Permissions::from(vec![RfdPermission::GetRfdsAll]));
+
+        let result =
+            list_webhook_subscriptions_op(&ctx, &caller, ListWebhookSubscriptionsQuery::default())
+                .await;
+        match result {
+            Err(err) => assert_eq!(StatusCode::FORBIDDEN, err.status_code),
+            Ok(HttpResponseOk(subscriptions)) => panic!(
+                "Expected a 403 error, but instead found subscriptions {:?}",
+                subscriptions
+            ),
+        }
+    }
+}
@@ -5,6 +5,7 @@
 use dropshot::HttpError;
 use octorust::ClientError as OctorustError;
 use reqwest::Error as ReqwestError;
+use rfd_data::content::TemplateError;
 use rfd_github::GitHubError;
 use thiserror::Error;
 use v_api::response::{conflict, forbidden, internal_error, not_found, ResourceError};
@@ -18,6 +19,12 @@ pub enum AppError {
     GitHub(#[from] GitHubError),
     #[error("Invalid GitHub private key")]
     InvalidGitHubPrivateKey(#[from] rsa::pkcs1::Error),
+    #[error("Invalid RFD template {name}")]
+    InvalidRfdTemplate {
+        name: String,
+        #[source]
+        err: TemplateError,
+    },
     #[error("A template for new RFDs must be defined")]
     MissingNewRfdTemplate,
     #[error("At least one JWT signing key must be configured")]
@@ -0,0 +1,485 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use dropshot::Body;
+use flate2::{write::GzEncoder, Compression};
+use http_body::Frame;
+use newtype_uuid::TypedUuid;
+use rfd_model::{
+    schema_ext::{ContentFormat, Visibility},
+    storage::{RfdRevisionFilter, RfdRevisionMetaStore, RfdRevisionStore, RfdStorage},
+    CommitSha, RfdRevision, RfdRevisionId, RfdRevisionMeta,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tar::{Builder, Header};
+use thiserror::Error;
+use tokio::sync::mpsc;
+use v_model::storage::{ListPagination, StoreError};
+
+use crate::context::RfdWithoutContent;
+
+pub static EXPORT_CONTENT_TYPE: &str = "application/gzip";
+pub static EXPORT_FILENAME: &str = "rfds.tar.gz";
+
+// The number of archive chunks that may be buffered while waiting on the client to read them
+static EXPORT_CHANNEL_SIZE: usize = 4;
+
+#[derive(Debug, Error)]
+pub enum RfdExportError {
+    #[error("Failed to write RFD archive")]
+    Archive(#[from] std::io::Error),
+    #[error("Failed to serialize RFD metadata")]
+    Metadata(#[from] serde_json::Error),
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+}
+
+/// The metadata.json file written alongside the content of each exported RFD
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RfdExportMetadata {
+    pub rfd_number: i32,
+    pub title: Option<String>,
+    pub state: Option<String>,
+    pub authors: Option<String>,
+    pub labels: Vec<String>,
+    pub discussion: Option<String>,
+    pub link: Option<String>,
+    pub visibility: Visibility,
+    pub format: Option<ContentFormat>,
+    pub latest_major_change_at: Option<DateTime<Utc>>,
+    /// Revisions of the RFD ordered from oldest to newest
+    pub revisions: Vec<RfdExportRevision>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RfdExportRevision {
+    pub id: TypedUuid<RfdRevisionId>,
+    pub commit: CommitSha,
+    pub committed_at: DateTime<Utc>,
+    pub title: String,
+    pub state: Option<String>,
+    pub major_change: bool,
+    /// Location of the revision content within the archive. Only present when the revision history
+    /// was exported
+    pub path: Option<String>,
+}
+
+/// An archive of RFDs that the caller has already been authorized to read. Content is loaded one
+/// RFD at a time as the archive is written so that large exports are never held in memory
+pub struct RfdExport {
+    storage: Arc<dyn RfdStorage>,
+    rfds: Vec<RfdWithoutContent>,
+    include_history: bool,
+}
+
+impl RfdExport {
+    pub fn new(
+        storage: Arc<dyn RfdStorage>,
+        rfds: Vec<RfdWithoutContent>,
+        include_history: bool,
+    ) -> Self {
+        Self {
+            storage,
+            rfds,
+            include_history,
+        }
+    }
+
+    /// Write the archive in the background, streaming gzipped chunks to the returned body as each
+    /// RFD is appended
+    pub fn into_body(self) -> Body {
+        let (tx, rx) = mpsc::channel(EXPORT_CHANNEL_SIZE);
+
+        tokio::spawn(async move {
+            if let Err(err) = self.write(&tx).await {
+                tracing::error!(?err, "Failed to export RFDs");
+                let _ = tx.send(Err(err)).await;
+            }
+        });
+
+        Body::wrap(RfdExportBody { rx })
+    }
+
+    async fn write(
+        self,
+        tx: &mpsc::Sender<Result<Bytes, RfdExportError>>,
+    ) -> Result<(), RfdExportError> {
+        let mut archive = Builder::new(GzEncoder::new(vec![], Compression::default()));
+
+        for rfd in &self.rfds {
+            let (revisions, contents) = self.load(rfd).await?;
+            append_rfd(
+                &mut archive,
+                rfd,
+                &revisions,
+                &contents,
+                self.include_history,
+            )?;
+
+            let chunk = std::mem::take(archive.get_mut().get_mut());
+            if !chunk.is_empty() && tx.send(Ok(chunk.into())).await.is_err() {
+                tracing::info!("Client disconnected before the RFD export completed");
+                return Ok(());
+            }
+        }
+
+        let chunk = archive.into_inner()?.finish()?;
+        let _ = tx.send(Ok(chunk.into())).await;
+
+        Ok(())
+    }
+
+    /// Load the revisions of an RFD ordered from oldest to newest along with the revisions whose
+    /// content should be written to the archive
+    async fn load(
+        &self,
+        rfd: &RfdWithoutContent,
+    ) -> Result<(Vec<RfdRevisionMeta>, Vec<RfdRevision>), RfdExportError> {
+        let filter = || RfdRevisionFilter::default().rfd(Some(vec![rfd.id]));
+
+        let mut revisions = RfdRevisionMetaStore::list(
+            &*self.storage,
+            vec![filter()],
+            &ListPagination::unlimited(),
+        )
+        .await?;
+        revisions.sort_by_key(|revision| (revision.committed_at, revision.created_at));
+
+        let mut contents = if self.include_history {
+            RfdRevisionStore::list(&*self.storage, vec![filter()], &ListPagination::unlimited())
+                .await?
+        } else {
+            match revisions.last() {
+                Some(latest) => RfdRevisionStore::get(&*self.storage, &latest.id, false)
+                    .await?
+                    .into_iter()
+                    .collect(),
+                None => vec![],
+            }
+        };
+        contents.sort_by_key(|revision| (revision.committed_at, revision.created_at));
+
+        Ok((revisions, contents))
+    }
+}
+
+fn rfd_dir(rfd_number: i32) -> String {
+    format!("rfd/{:04}", rfd_number)
+}
+
+fn extension(format: &ContentFormat) -> &'static str {
+    match format {
+        ContentFormat::Asciidoc => "adoc",
+        ContentFormat::Markdown => "md",
+    }
+}
+
+fn revision_path(rfd_number: i32, revision: &RfdRevision) -> String {
+    format!(
+        "{}/revisions/{}-{}.{}",
+        rfd_dir(rfd_number),
+        revision.committed_at.format("%Y%m%dT%H%M%SZ"),
+        revision.commit,
+        extension(&revision.content_format)
+    )
+}
+
+fn append_rfd<W>(
+    archive: &mut Builder<W>,
+    rfd: &RfdWithoutContent,
+    revisions: &[RfdRevisionMeta],
+    contents: &[RfdRevision],
+    include_history: bool,
+) -> Result<(), RfdExportError>
+where
+    W: std::io::Write,
+{
+    // An RFD without any stored revision content has nothing to export. It is left out rather
+    // than failing the remainder of the export
+    let Some(latest) = contents.last() else {
+        tracing::warn!(
+            rfd_number = rfd.rfd_number,
+            "Skipping export of RFD without revision content"
+        );
+        return Ok(());
+    };
+
+    let dir = rfd_dir(rfd.rfd_number);
+
+    append_file(
+        archive,
+        &format!("{}/README.{}", dir, extension(&latest.content_format)),
+        latest.committed_at,
+        latest.content.as_bytes(),
+    )?;
+
+    if include_history {
+        for revision in contents {
+            append_file(
+                archive,
+                &revision_path(rfd.rfd_number, revision),
+                revision.committed_at,
+                revision.content.as_bytes(),
+            )?;
+        }
+    }
+
+    let metadata = RfdExportMetadata {
+        rfd_number: rfd.rfd_number,
+        title: rfd.title.clone(),
+        state: rfd.state.clone(),
+        authors: rfd.authors.clone(),
+        labels: rfd
+            .labels
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(str::to_string)
+            .collect(),
+        discussion: rfd.discussion.clone(),
+        link: rfd.link.clone(),
+        visibility: rfd.visibility.clone(),
+        format: rfd.format.clone(),
+        latest_major_change_at: rfd.latest_major_change_at,
+        revisions: revisions
+            .iter()
+            .map(|revision| RfdExportRevision {
+                id: revision.id,
+                commit: revision.commit.clone(),
+                committed_at: revision.committed_at,
+                title: revision.title.clone(),
+                state: revision.state.clone(),
+                major_change: revision.major_change,
+                path: include_history
+                    .then(|| contents.iter().find(|content| content.id == revision.id))
+                    .flatten()
+                    .map(|content| revision_path(rfd.rfd_number, content)),
+            })
+            .collect(),
+    };
+
+    append_file(
+        archive,
+        &format!("{}/metadata.json", dir),
+        latest.committed_at,
+        &serde_json::to_vec_pretty(&metadata)?,
+    )?;
+
+    Ok(())
+}
+
+fn append_file<W>(
+    archive: &mut Builder<W>,
+    path: &str,
+    modified_at: DateTime<Utc>,
+    data: &[u8],
+) -> Result<(), RfdExportError>
+where
+    W: std::io::Write,
+{
+    let mut header = Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(modified_at.timestamp().max(0) as u64);
+    archive.append_data(&mut header, path, data)?;
+    Ok(())
+}
+
+struct RfdExportBody {
+    rx: mpsc::Receiver<Result<Bytes, RfdExportError>>,
+}
+
+impl http_body::Body for RfdExportBody {
+    type Data = Bytes;
+    type Error = RfdExportError;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        self.rx
+            .poll_recv(cx)
+            .map(|chunk| chunk.map(|chunk| chunk.map(Frame::data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Read, sync::Arc};
+
+    use chrono::Utc;
+    use flate2::read::GzDecoder;
+    use newtype_uuid::TypedUuid;
+    use rfd_model::{
+        schema_ext::{ContentFormat, Visibility},
+        storage::{mock::MockStorage, MockRfdRevisionMetaStore, MockRfdRevisionStore},
+        CommitSha, FileSha, RfdRevision, RfdRevisionMeta,
+    };
+    use tar::{Archive, Builder};
+    use tokio::sync::mpsc;
+
+    use crate::context::RfdWithoutContent;
+
+    use super::{append_rfd, RfdExport, RfdExportMetadata};
+
+    fn revision(rfd: &RfdWithoutContent, commit: &str, content: &str) -> RfdRevision {
+        RfdRevision {
+            id: TypedUuid::new_v4(),
+            rfd_id: rfd.id,
+            title: "Export".to_string(),
+            state: Some("discussion".to_string()),
+            discussion: None,
+            authors: None,
+            labels: None,
+            content: content.to_string(),
+            content_format: ContentFormat::Asciidoc,
+            sha: FileSha(String::new()),
+            commit: CommitSha(commit.to_string()),
+            major_change: false,
+            committed_at: Utc::now(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    fn meta(revision: &RfdRevision) -> RfdRevisionMeta {
+        RfdRevisionMeta {
+            id: revision.id,
+            rfd_id: revision.rfd_id,
+            title: revision.title.clone(),
+            state: revision.state.clone(),
+            discussion: revision.discussion.clone(),
+            authors: revision.authors.clone(),
+            labels: revision.labels.clone(),
+            content_format: revision.content_format.clone(),
+            sha: revision.sha.clone(),
+            commit: revision.commit.clone(),
+            major_change: revision.major_change,
+            committed_at: revision.committed_at,
+            created_at: revision.created_at,
+            updated_at: revision.updated_at,
+            deleted_at: revision.deleted_at,
+        }
+    }
+
+    fn rfd() -> RfdWithoutContent {
+        RfdWithoutContent {
+            id: TypedUuid::new_v4(),
+            rfd_number: 5,
+            link: None,
+            discussion: None,
+            title: Some("Export".to_string()),
+            state: Some("discussion".to_string()),
+            authors: None,
+            labels: Some("api, storage".to_string()),
+            format: Some(ContentFormat::Asciidoc),
+            sha: None,
+            commit: None,
+            committed_at: None,
+            latest_major_change_at: None,
+            visibility: Visibility::Private,
+        }
+    }
+
+    async fn export(include_history: bool) -> HashMap<String, String> {
+        let rfd = rfd();
+        let first = revision(&rfd, "aaa", "= First");
+        let second = revision(&rfd, "bbb", "= Second");
+
+        let metas = vec![meta(&first), meta(&second)];
+        let mut revision_meta_store = MockRfdRevisionMetaStore::new();
+        revision_meta_store
+            .expect_list()
+            .returning(move |_, _| Ok(metas.clone()));
+
+        let latest = second.clone();
+        let all = vec![first, second];
+        let mut revision_store = MockRfdRevisionStore::new();
+        revision_store
+            .expect_get()
+            .returning(move |_, _| Ok(Some(latest.clone())));
+        revision_store
+            .expect_list()
+            .returning(move |_, _| Ok(all.clone()));
+
+        let mut storage = MockStorage::new();
+        storage.rfd_revision_meta_store = Some(Arc::new(revision_meta_store));
+        storage.rfd_revision_store = Some(Arc::new(revision_store));
+
+        let (tx, mut rx) = mpsc::channel(16);
+        RfdExport::new(Arc::new(storage), vec![rfd], include_history)
+            .write(&tx)
+            .await
+            .unwrap();
+        drop(tx);
+
+        let mut bytes = vec![];
+        while let Some(chunk) = rx.recv().await {
+            bytes.extend_from_slice(&chunk.unwrap());
+        }
+
+        let mut archive = Archive::new(GzDecoder::new(bytes.as_slice()));
+        archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let mut entry = entry.unwrap();
+                let path = entry.path().unwrap().to_string_lossy().to_string();
+                let mut contents = String::new();
+                entry.read_to_string(&mut contents).unwrap();
+                (path, contents)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn export_latest_content_and_metadata() {
+        let files = export(false).await;
+
+        assert_eq!(2, files.len());
+        assert_eq!("= Second", files["rfd/0005/README.adoc"]);
+
+        let metadata: RfdExportMetadata =
+            serde_json::from_str(&files["rfd/0005/metadata.json"]).unwrap();
+        assert_eq!(vec!["api", "storage"], metadata.labels);
+        assert_eq!(2, metadata.revisions.len());
+        assert!(metadata.revisions.iter().all(|rev| rev.path.is_none()));
+    }
+
+    #[tokio::test]
+    async fn export_revision_history() {
+        let files = export(true).await;
+
+        assert_eq!(4, files.len());
+
+        let metadata: RfdExportMetadata =
+            serde_json::from_str(&files["rfd/0005/metadata.json"]).unwrap();
+        let paths = metadata
+            .revisions
+            .iter()
+            .map(|rev| rev.path.clone().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!("= First", files[&paths[0]]);
+        assert_eq!("= Second", files[&paths[1]]);
+    }
+
+    #[test]
+    fn skip_rfds_without_content() {
+        let mut archive = Builder::new(vec![]);
+        append_rfd(&mut archive, &rfd(), &[], &[], true).unwrap();
+
+        let bytes = archive.into_inner().unwrap();
+        assert_eq!(0, Archive::new(bytes.as_slice()).entries().unwrap().count());
+    }
+}
@@ -0,0 +1,260 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use newtype_uuid::TypedUuid;
+use rfd_model::{RfdFeedRevision, RfdRevisionId};
+use std::fmt::Write;
+
+pub static ATOM_CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RfdFeedEvent {
+    Created,
+    StateChanged {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    MajorChange,
+}
+
+#[derive(Debug, Clone)]
+pub struct RfdFeedEntry {
+    pub rfd_number: i32,
+    pub revision: TypedUuid<RfdRevisionId>,
+    pub title: String,
+    pub authors: Option<String>,
+    pub event: RfdFeedEvent,
+    pub updated: DateTime<Utc>,
+}
+
+impl RfdFeedEntry {
+    fn title(&self) -> String {
+        let action = match &self.event {
+            RfdFeedEvent::Created => "created".to_string(),
+            RfdFeedEvent::StateChanged { to, .. } => {
+                format!("moved to {}", to.as_deref().unwrap_or("no state"))
+            }
+            RfdFeedEvent::MajorChange => "updated".to_string(),
+        };
+
+        format!("RFD {} {}: {}", self.rfd_number, action, self.title)
+    }
+
+    fn summary(&self) -> String {
+        match &self.event {
+            RfdFeedEvent::Created => format!("RFD {} was created", self.rfd_number),
+            RfdFeedEvent::StateChanged { from, to } => format!(
+                "RFD {} moved from {} to {}",
+                self.rfd_number,
+                from.as_deref().unwrap_or("no state"),
+                to.as_deref().unwrap_or("no state"),
+            ),
+            RfdFeedEvent::MajorChange => {
+                format!("RFD {} received a major change", self.rfd_number)
+            }
+        }
+    }
+}
+
+impl RfdFeedEntry {
+    /// Describe a revision that is reported in the feed of an RFD. The first revision of an RFD is
+    /// reported as its creation, and later revisions as either a change of state or, when the
+    /// state is unchanged, a major change
+    pub fn new(rfd_number: i32, feed_revision: RfdFeedRevision) -> Self {
+        let RfdFeedRevision {
+            revision,
+            first,
+            previous_state,
+        } = feed_revision;
+
+        let event = if first {
+            RfdFeedEvent::Created
+        } else if previous_state != revision.state {
+            RfdFeedEvent::StateChanged {
+                from: previous_state,
+                to: revision.state.clone(),
+            }
+        } else {
+            RfdFeedEvent::MajorChange
+        };
+
+        Self {
+            rfd_number,
+            revision: revision.id,
+            title: revision.title,
+            authors: revision.authors,
+            event,
+            updated: revision.committed_at,
+        }
+    }
+}
+
+pub struct RfdFeed {
+    pub title: String,
+    /// The url that the feed is served from
+    pub url: String,
+    /// Entries ordered from newest to oldest
+    pub entries: Vec<RfdFeedEntry>,
+}
+
+impl RfdFeed {
+    /// Render the feed as an Atom document. Entries link back to the RFDs served from the given
+    /// public url
+    pub fn to_atom(&self, public_url: &str) -> String {
+        let updated = self
+            .entries
+            .iter()
+            .map(|entry| entry.updated)
+            .max()
+            .unwrap_or_else(Utc::now);
+
+        let mut feed = String::new();
+        feed.push_str(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+        feed.push('\n');
+        feed.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+        feed.push('\n');
+
+        // Writing to a String can not fail
+        let _ = writeln!(feed, "  <id>{}</id>", escape(&self.url));
+        let _ = writeln!(feed, "  <title>{}</title>", escape(&self.title));
+        let _ = writeln!(feed, "  <updated>{}</updated>", timestamp(&updated));
+        let _ = writeln!(
+            feed,
+            r#"  <link rel="self" type="application/atom+xml" href="{}"/>"#,
+            escape(&self.url)
+        );
+        let _ = writeln!(feed, "  <author><name>RFD API</name></author>");
+
+        for entry in &self.entries {
+            let link = format!("{}/rfd/{:04}", public_url, entry.rfd_number);
+
+            let _ = writeln!(feed, "  <entry>");
+            let _ = writeln!(feed, "    <id>urn:uuid:{}</id>", entry.revision);
+            let _ = writeln!(feed, "    <title>{}</title>", escape(&entry.title()));
+            let _ = writeln!(feed, "    <updated>{}</updated>", timestamp(&entry.updated));
+            let _ = writeln!(feed, r#"    <link href="{}"/>"#, escape(&link));
+            if let Some(authors) = &entry.authors {
+                let _ = writeln!(
+                    feed,
+                    "    <author><name>{}</name></author>",
+                    escape(authors)
+                );
+            }
+            let _ = writeln!(feed, "    <summary>{}</summary>", escape(&entry.summary()));
+            let _ = writeln!(feed, "  </entry>");
+        }
+
+        feed.push_str("</feed>\n");
+
+        feed
+    }
+}
+
+fn timestamp(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use newtype_uuid::TypedUuid;
+    use rfd_model::{schema_ext::ContentFormat, RfdFeedRevision, RfdRevisionMeta};
+
+    use super::{RfdFeed, RfdFeedEntry, RfdFeedEvent};
+
+    fn revision(
+        previous_state: Option<&str>,
+        state: &str,
+        first: bool,
+        day: u32,
+    ) -> RfdFeedRevision {
+        RfdFeedRevision {
+            revision: RfdRevisionMeta {
+                id: TypedUuid::new_v4(),
+                rfd_id: TypedUuid::new_v4(),
+                title: "Feeds & <Things>".to_string(),
+                state: Some(state.to_string()),
+                discussion: None,
+                authors: Some("Firstname Lastname <first@example.com>".to_string()),
+                labels: None,
+                content_format: ContentFormat::Asciidoc,
+                sha: String::new().into(),
+                commit: String::new().into(),
+                committed_at: Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap(),
+                major_change: !first,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                deleted_at: None,
+            },
+            first,
+            previous_state: previous_state.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn feed_entries_from_revisions() {
+        let revisions = vec![
+            revision(None, "prediscussion", true, 1),
+            revision(Some("prediscussion"), "discussion", false, 3),
+            revision(Some("discussion"), "discussion", false, 4),
+            revision(Some("discussion"), "published", false, 5),
+        ];
+
+        let events = revisions
+            .into_iter()
+            .map(|revision| RfdFeedEntry::new(123, revision).event)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                RfdFeedEvent::Created,
+                RfdFeedEvent::StateChanged {
+                    from: Some("prediscussion".to_string()),
+                    to: Some("discussion".to_string()),
+                },
+                RfdFeedEvent::MajorChange,
+                RfdFeedEvent::StateChanged {
+                    from: Some("discussion".to_string()),
+                    to: Some("published".to_string()),
+                },
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn atom_feed_escapes_content() {
+        let feed = RfdFeed {
+            title: "RFD activity".to_string(),
+            url: "https://rfd.example.com/feeds/rfds.atom".to_string(),
+            entries: vec![RfdFeedEntry::new(123, revision(None, "ideation", true, 1))],
+        };
+
+        let atom = feed.to_atom("https://rfd.example.com");
+
+        assert!(atom.contains("<title>RFD 123 created: Feeds &amp; &lt;Things&gt;</title>"));
+        assert!(atom.contains(
+            "<author><name>Firstname Lastname &lt;first@example.com&gt;</name></author>"
+        ));
+        assert!(atom.contains(r#"<link href="https://rfd.example.com/rfd/0123"/>"#));
+        assert!(atom.contains("<updated>2024-01-01T00:00:00Z</updated>"));
+    }
+}
@@ -10,6 +10,7 @@ use std::{
     net::{SocketAddr, SocketAddrV4},
     path::Path,
     sync::Arc,
+    time::Duration,
 };
 use strum::IntoEnumIterator;
 use tap::TapFallible;
@@ -31,12 +32,17 @@ use crate::{
 };
 
 mod caller;
+mod conditional;
 mod config;
 mod context;
+mod discussion;
 mod endpoints;
 mod error;
+mod export;
+mod feed;
 mod initial_data;
 mod magic_link;
+mod notification;
 mod permissions;
 use permissions::RfdPermission;
 mod search;
@@ -310,6 +316,22 @@ async fn run_server(config_path: Option<String>) -> anyhow::Result<()> {
 
     tracing::info!("Configured server context");
 
+    // Email notifications for watched RFDs are sent from a background task that shares storage
+    // with the server
+    if let Some(notification) = config.notification {
+        let messenger = match notification.email_service {
+            EmailService::Resend { key } => ResendMagicLink::new(key, notification.from),
+        };
+        let notifier = context.watch_notifier(
+            Box::new(messenger),
+            Duration::from_secs(notification.interval),
+            notification.batch_size,
+        );
+        tokio::spawn(notifier.run());
+
+        tracing::info!("Started watch notifier");
+    }
+
     let init_data = InitialData::new(config.initial_mappers.map(|p| vec![p])).tap_err(|err| {
         tracing::error!(?err, "Failed to load initial data from configuration");
     })?;
@@ -0,0 +1,411 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use newtype_uuid::{GenericUuid, TypedUuid};
+use rfd_data::diff::{RfdDiff, RfdDiffAttribute, RfdDiffLineTag};
+use rfd_model::{
+    schema_ext::{Visibility, WebhookEvent},
+    storage::{
+        RfdRevisionFilter, RfdRevisionLabelFilter, RfdRevisionLabelStore, RfdRevisionMetaStore,
+        RfdRevisionStore, RfdStorage, RfdStore, RfdWatchFilter, RfdWatchNotificationFilter,
+        RfdWatchNotificationStore, RfdWatchStore,
+    },
+    RfdRevision, RfdWatchNotification,
+};
+use std::{collections::BTreeSet, fmt::Write, sync::Arc, time::Duration};
+use tracing::instrument;
+use uuid::Uuid;
+use v_api::{
+    messenger::{Message, Messenger},
+    VContext,
+};
+use v_model::{
+    permissions::{Caller, PermissionStorage},
+    storage::{AccessGroupFilter, ApiUserProviderFilter, ListPagination, StoreError},
+    Permissions,
+};
+
+use crate::permissions::RfdPermission;
+
+/// Sends emails to the watchers of an RFD when a notification is queued for one of its revisions
+pub struct RfdWatchNotifier {
+    pub(crate) public_url: String,
+    pub(crate) storage: Arc<dyn RfdStorage>,
+    pub(crate) v_context: Arc<VContext<RfdPermission>>,
+    pub(crate) messenger: Box<dyn Messenger>,
+    pub(crate) interval: Duration,
+    pub(crate) batch_size: i64,
+}
+
+impl RfdWatchNotifier {
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+        let pagination = ListPagination::default().limit(self.batch_size);
+
+        tracing::info!(?interval, ?pagination, "Starting watch notifier");
+
+        loop {
+            interval.tick().await;
+
+            let notifications = match RfdWatchNotificationStore::list(
+                &*self.storage,
+                vec![RfdWatchNotificationFilter::default().processed(Some(false))],
+                &pagination,
+            )
+            .await
+            {
+                Ok(notifications) => notifications,
+                Err(err) => {
+                    tracing::error!(?err, "Failed to list pending watch notifications");
+                    continue;
+                }
+            };
+
+            for notification in notifications {
+                match RfdWatchNotificationStore::claim(&*self.storage, &notification.id).await {
+                    Ok(Some(notification)) => self.notify(notification).await,
+                    Ok(None) => {
+                        tracing::info!(id = ?notification.id, "Notification was claimed by a different worker");
+                    }
+                    Err(err) => {
+                        tracing::warn!(id = ?notification.id, ?err, "Failed to claim notification");
+                    }
+                }
+            }
+        }
+    }
+
+    #[instrument(skip(self, notification), fields(id = ?notification.id, rfd_number = notification.rfd_number, event = ?notification.event))]
+    async fn notify(&self, notification: RfdWatchNotification) {
+        let (visibility, from, to) = match self.revisions(&notification).await {
+            Ok(Some(revisions)) => revisions,
+            Ok(None) => {
+                tracing::info!("RFD or revision no longer exists. Dropping notification");
+                return;
+            }
+            Err(err) => {
+                tracing::error!(?err, "Failed to lookup revisions for notification");
+                return;
+            }
+        };
+
+        let watchers = match self.watchers(&notification).await {
+            Ok(watchers) => watchers,
+            Err(err) => {
+                tracing::error!(?err, "Failed to lookup watchers for notification");
+                return;
+            }
+        };
+
+        let diff = RfdDiff::new(&from, &to);
+
+        for api_user_id in watchers {
+            let (caller, email) = match self.watcher(api_user_id).await {
+                Some(watcher) => watcher,
+                None => continue,
+            };
+
+            // Access is checked at send time against the current visibility of the RFD and the
+            // current permissions of the watcher, as either may have changed since the watch was
+            // created
+            if !(caller.can(&RfdPermission::GetRfdsAll)
+                || caller.can(&RfdPermission::GetRfd(notification.rfd_number))
+                || visibility == Visibility::Public)
+            {
+                tracing::info!(?api_user_id, "Watcher no longer has access to the RFD");
+                continue;
+            }
+
+            let message = watch_notification_message(
+                &email,
+                &self.public_url,
+                notification.event,
+                notification.rfd_number,
+                &to,
+                &diff,
+            );
+
+            if let Err(err) = self.messenger.send(message).await {
+                tracing::error!(?err, ?api_user_id, "Failed to send watch notification");
+            }
+        }
+    }
+
+    /// Load the visibility of the RFD along with the revision that triggered the notification and
+    /// the revision that preceded it
+    async fn revisions(
+        &self,
+        notification: &RfdWatchNotification,
+    ) -> Result<Option<(Visibility, RfdRevision, RfdRevision)>, StoreError> {
+        let Some(to) =
+            RfdRevisionStore::get(&*self.storage, &notification.rfd_revision_id, false).await?
+        else {
+            return Ok(None);
+        };
+        let Some(rfd) = RfdStore::get(&*self.storage, &to.rfd_id, None, false).await? else {
+            return Ok(None);
+        };
+
+        let mut revisions = RfdRevisionMetaStore::list(
+            &*self.storage,
+            vec![RfdRevisionFilter::default().rfd(Some(vec![rfd.id]))],
+            &ListPagination::unlimited(),
+        )
+        .await?;
+        revisions.sort_by_key(|revision| (revision.committed_at, revision.created_at));
+
+        let previous = revisions
+            .iter()
+            .take_while(|revision| revision.id != to.id)
+            .last()
+            .map(|revision| revision.id);
+        let from = match previous {
+            Some(previous) => RfdRevisionStore::get(&*self.storage, &previous, false).await?,
+            None => None,
+        };
+
+        // The first revision of an RFD has no predecessor, and is compared against itself so that
+        // no differences are reported
+        Ok(Some((
+            rfd.visibility,
+            from.unwrap_or_else(|| to.clone()),
+            to,
+        )))
+    }
+
+    /// Find the users watching the RFD directly or through one of the labels of the revision
+    async fn watchers(
+        &self,
+        notification: &RfdWatchNotification,
+    ) -> Result<BTreeSet<Uuid>, StoreError> {
+        let labels = RfdRevisionLabelStore::list(
+            &*self.storage,
+            vec![RfdRevisionLabelFilter::default()
+                .rfd_revision(Some(vec![notification.rfd_revision_id]))],
+            &ListPagination::unlimited(),
+        )
+        .await?
+        .into_iter()
+        .map(|label| label.label)
+        .collect::<Vec<_>>();
+
+        let mut filters =
+            vec![RfdWatchFilter::default().rfd_number(Some(vec![notification.rfd_number]))];
+        if !labels.is_empty() {
+            filters.push(RfdWatchFilter::default().label(Some(labels)));
+        }
+
+        Ok(
+            RfdWatchStore::list(&*self.storage, filters, &ListPagination::unlimited())
+                .await?
+                .into_iter()
+                .map(|watch| watch.api_user_id)
+                .collect(),
+        )
+    }
+
+    /// Resolve the current permissions and email address of a watcher. Permissions are combined
+    /// from the user and the groups they are a member of, and expanded as they would be for a
+    /// request made by the watcher. Watchers that no longer exist or do not have an email address
+    /// are skipped
+    async fn watcher(&self, api_user_id: Uuid) -> Option<(Caller<RfdPermission>, String)> {
+        let id = TypedUuid::from_untyped_uuid(api_user_id);
+        let registration = self.v_context.builtin_registration_user();
+
+        let info = self
+            .v_context
+            .user
+            .get_api_user(&registration, &id)
+            .await
+            .map_err(|err| tracing::warn!(?err, ?api_user_id, "Failed to lookup watcher"))
+            .ok()?;
+
+        let groups = if info.user.groups.is_empty() {
+            vec![]
+        } else {
+            self.v_context
+                .group
+                .get_groups(
+                    &registration,
+                    &AccessGroupFilter {
+                        id: Some(info.user.groups.iter().copied().collect()),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .map_err(|err| {
+                    tracing::warn!(?err, ?api_user_id, "Failed to lookup watcher groups")
+                })
+                .ok()?
+        };
+
+        let mut permissions = info.user.permissions.iter().cloned().collect::<Vec<_>>();
+        for group in groups {
+            permissions.extend(group.permissions.iter().cloned());
+        }
+
+        // Expand the combined permissions in the same way that v-api does when it resolves the
+        // caller of a request, so that aliased permissions are resolved for the watcher
+        let permissions = RfdPermission::expand(
+            &Permissions::from(permissions),
+            &info.user.id,
+            Some(&info.user.permissions),
+        );
+
+        let email = self
+            .v_context
+            .user
+            .list_api_user_provider(
+                &registration,
+                ApiUserProviderFilter {
+                    api_user_id: Some(vec![id]),
+                    ..Default::default()
+                },
+                &ListPagination::default(),
+            )
+            .await
+            .map_err(|err| tracing::warn!(?err, ?api_user_id, "Failed to lookup watcher email"))
+            .ok()?
+            .into_iter()
+            .flat_map(|provider| provider.emails)
+            .next();
+
+        let Some(email) = email else {
+            tracing::info!(?api_user_id, "Watcher does not have an email address");
+            return None;
+        };
+
+        let mut caller = Caller::from(permissions);
+        caller.id = id;
+
+        Some((caller, email))
+    }
+}
+
+/// Build the email sent to a watcher, including a short summary of the differences between the
+/// revision that triggered the notification and the revision before it
+pub fn watch_notification_message(
+    recipient: &str,
+    public_url: &str,
+    event: WebhookEvent,
+    rfd_number: i32,
+    revision: &RfdRevision,
+    diff: &RfdDiff,
+) -> Message {
+    let action = match event {
+        WebhookEvent::StateChanged => format!(
+            "moved to {}",
+            revision.state.as_deref().unwrap_or("no state")
+        ),
+        _ => "received a major change".to_string(),
+    };
+    let subject = format!("RFD {} {}: {}", rfd_number, action, revision.title);
+
+    let mut text = String::new();
+
+    // Writing to a String can not fail
+    let _ = writeln!(
+        text,
+        "RFD {} \"{}\" {}.",
+        rfd_number, revision.title, action
+    );
+    let _ = writeln!(text);
+
+    if !diff.attributes.is_empty() {
+        let _ = writeln!(text, "Changed attributes:");
+        for change in &diff.attributes {
+            let attribute = match change.attribute {
+                RfdDiffAttribute::Authors => "authors",
+                RfdDiffAttribute::Discussion => "discussion",
+                RfdDiffAttribute::Labels => "labels",
+                RfdDiffAttribute::State => "state",
+                RfdDiffAttribute::Title => "title",
+            };
+            let _ = writeln!(
+                text,
+                "  {}: {} -> {}",
+                attribute,
+                change.from.as_deref().unwrap_or("(none)"),
+                change.to.as_deref().unwrap_or("(none)"),
+            );
+        }
+        let _ = writeln!(text);
+    }
+
+    let lines = diff.hunks.iter().flat_map(|hunk| hunk.lines.iter());
+    let (added, removed) = lines.fold((0, 0), |(added, removed), line| match line.tag {
+        RfdDiffLineTag::Insert => (added + 1, removed),
+        RfdDiffLineTag::Delete => (added, removed + 1),
+        RfdDiffLineTag::Equal => (added, removed),
+    });
+    let _ = writeln!(text, "Lines added: {}, lines removed: {}", added, removed);
+    let _ = writeln!(text);
+    let _ = writeln!(text, "View the RFD: {}/rfd/{:04}", public_url, rfd_number);
+
+    Message {
+        recipient: recipient.to_string(),
+        subject: Some(subject),
+        text,
+        html: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use newtype_uuid::TypedUuid;
+    use rfd_data::diff::RfdDiff;
+    use rfd_model::{
+        schema_ext::{ContentFormat, WebhookEvent},
+        RfdRevision,
+    };
+
+    use super::watch_notification_message;
+
+    fn revision(state: &str, content: &str) -> RfdRevision {
+        RfdRevision {
+            id: TypedUuid::new_v4(),
+            rfd_id: TypedUuid::new_v4(),
+            title: "Watching RFDs".to_string(),
+            state: Some(state.to_string()),
+            discussion: None,
+            authors: None,
+            labels: None,
+            content: content.to_string(),
+            content_format: ContentFormat::Asciidoc,
+            sha: String::new().into(),
+            commit: String::new().into(),
+            committed_at: Utc::now(),
+            major_change: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn notification_message_summarizes_diff() {
+        let from = revision("ideation", "one\ntwo\nthree\n");
+        let to = revision("discussion", "one\n2\nthree\nfour\n");
+        let diff = RfdDiff::new(&from, &to);
+
+        let message = watch_notification_message(
+            "watcher@example.com",
+            "https://rfd.example.com",
+            WebhookEvent::StateChanged,
+            123,
+            &to,
+            &diff,
+        );
+
+        assert_eq!("watcher@example.com", message.recipient);
+        assert_eq!(
+            Some("RFD 123 moved to discussion: Watching RFDs".to_string()),
+            message.subject
+        );
+        assert!(message.text.contains("  state: ideation -> discussion"));
+        assert!(message.text.contains("Lines added: 2, lines removed: 1"));
+        assert!(message.text.contains("https://rfd.example.com/rfd/0123"));
+    }
+}
@@ -108,6 +108,18 @@ pub enum RfdPermission {
     GetDiscussionsAll,
     #[v_api(scope(to = "search", from = "search"))]
     SearchRfds,
+    #[v_api(scope(to = "rfd:audit:r", from = "rfd:audit:r"))]
+    GetAuditEvents,
+    #[v_api(scope(to = "rfd:webhook:w", from = "rfd:webhook:w"))]
+    ManageWebhooks,
+    #[v_api(scope(to = "rfd:reservation:w", from = "rfd:reservation:w"))]
+    ManageRfdReservations,
+    #[v_api(scope(to = "rfd:state:w", from = "rfd:state:w"))]
+    PublishRfds,
+    #[v_api(scope(to = "rfd:state:w", from = "rfd:state:w"))]
+    CommitRfds,
+    #[v_api(scope(to = "rfd:state:w", from = "rfd:state:w"))]
+    AbandonRfds,
 }
 
 #[cfg(test)]
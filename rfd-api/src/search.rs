@@ -9,7 +9,13 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::fmt::Display;
 use thiserror::Error;
 
-use crate::endpoints::rfd::{FormattedSearchResultHit, RfdSearchQuery, SearchResultHit};
+use crate::endpoints::rfd::{
+    split_query_list, FormattedSearchResultHit, RfdSearchQuery, SearchResultHit,
+};
+
+// Attributes that facet counts are returned for on every search. Counts are of matching sections,
+// not of RFDs, as each section of an RFD is indexed as its own document
+static SEARCH_FACETS: [&str; 3] = ["state", "label", "author"];
 
 #[derive(Debug, Error)]
 pub enum SearchError {
@@ -73,13 +79,66 @@ pub struct SearchRequest {
     pub limit: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub facets: Vec<String>,
+}
+
+/// Quote a value for use in a meilisearch filter expression
+fn quote_filter_value(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Construct a meilisearch filter from the attribute filters of a search query. Every condition
+/// must match for a result to be included
+fn search_filter(query: &RfdSearchQuery) -> Option<String> {
+    let mut conditions = vec![];
+
+    if let Some(states) = split_query_list(query.state.as_deref()) {
+        conditions.push(format!(
+            "state IN [{}]",
+            states
+                .iter()
+                .map(|state| quote_filter_value(state))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    if let Some(labels) = split_query_list(query.label.as_deref()) {
+        for label in labels {
+            conditions.push(format!("label = {}", quote_filter_value(&label)));
+        }
+    }
+
+    // Authors are indexed by both their name and email, either of which may be used to filter
+    if let Some(authors) = split_query_list(query.author.as_deref()) {
+        let authors = authors
+            .iter()
+            .map(|author| quote_filter_value(author))
+            .collect::<Vec<_>>()
+            .join(", ");
+        conditions.push(format!(
+            "(author IN [{}] OR author_email IN [{}])",
+            authors, authors
+        ));
+    }
+
+    if let Some(committed_after) = query.committed_after {
+        conditions.push(format!("committed_at >= {}", committed_after.timestamp()));
+    }
+
+    if let Some(committed_before) = query.committed_before {
+        conditions.push(format!("committed_at < {}", committed_before.timestamp()));
+    }
+
+    (!conditions.is_empty()).then(|| conditions.join(" AND "))
 }
 
 impl From<RfdSearchQuery> for SearchRequest {
     fn from(query: RfdSearchQuery) -> Self {
         SearchRequest {
+            filter: search_filter(&query),
             q: query.q,
-            filter: None,
             // TODO: Make this configurable
             attributes_to_highlight: vec!["*".to_string()],
             highlight_pre_tag: query.highlight_pre_tag,
@@ -92,6 +151,10 @@ impl From<RfdSearchQuery> for SearchRequest {
                 .collect::<Vec<_>>(),
             limit: query.limit,
             offset: query.offset,
+            facets: SEARCH_FACETS
+                .iter()
+                .map(|facet| facet.to_string())
+                .collect(),
         }
     }
 }
@@ -201,3 +264,46 @@ impl From<SearchResult<MeiliSearchResult>> for SearchResultHit {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::endpoints::rfd::RfdSearchQuery;
+
+    use super::SearchRequest;
+
+    #[test]
+    fn search_request_without_filters() {
+        let request = SearchRequest::from(RfdSearchQuery {
+            q: "storage".to_string(),
+            ..Default::default()
+        });
+
+        assert_eq!(None, request.filter);
+        assert_eq!(vec!["state", "label", "author"], request.facets);
+    }
+
+    #[test]
+    fn search_request_with_filters() {
+        let request = SearchRequest::from(RfdSearchQuery {
+            q: "storage".to_string(),
+            state: Some("published, committed".to_string()),
+            label: Some("storage,api".to_string()),
+            author: Some("Jane \"JD\" Doe".to_string()),
+            committed_after: Some(Utc.timestamp_opt(1700000000, 0).unwrap()),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            vec![
+                r#"state IN ["published", "committed"]"#,
+                r#"label = "storage""#,
+                r#"label = "api""#,
+                r#"(author IN ["Jane \"JD\" Doe"] OR author_email IN ["Jane \"JD\" Doe"])"#,
+                "committed_at >= 1700000000",
+            ],
+            request.filter.unwrap().split(" AND ").collect::<Vec<_>>()
+        );
+    }
+}
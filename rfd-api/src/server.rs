@@ -17,15 +17,28 @@ use v_api::{inject_endpoints, v_system_endpoints};
 use crate::{
     context::RfdContext,
     endpoints::{
+        audit::list_audit_events,
         job::list_jobs,
         rfd::{
-            discuss_rfd, list_rfd_revisions, list_rfds, publish_rfd, reserve_rfd, search_rfds,
-            set_rfd_attr, set_rfd_content, set_rfd_document, update_rfd_revision,
-            update_rfd_visibility, view_rfd, view_rfd_attr, view_rfd_discussion, view_rfd_meta,
-            view_rfd_pdf, view_rfd_revision, view_rfd_revision_attr, view_rfd_revision_discussion,
-            view_rfd_revision_meta, view_rfd_revision_pdf,
+            discuss_rfd, export_rfds, list_rfd_backlinks, list_rfd_references, list_rfd_revisions,
+            list_rfd_templates, list_rfds, publish_rfd, reserve_rfd, search_rfds, set_rfd_attr,
+            set_rfd_content, set_rfd_document, update_rfd_revision, update_rfd_visibility,
+            view_rfd, view_rfd_attr, view_rfd_discussion, view_rfd_feed, view_rfd_html,
+            view_rfd_meta, view_rfd_pdf, view_rfd_revision, view_rfd_revision_attr,
+            view_rfd_revision_diff, view_rfd_revision_discussion, view_rfd_revision_html,
+            view_rfd_revision_meta, view_rfd_revision_pdf, view_rfds_feed,
         },
+        rfd_file::{create_rfd_file, delete_rfd_file, list_rfd_files, replace_rfd_file},
+        rfd_lint::lint_rfd,
+        rfd_preview::{preview_rfd, preview_rfd_content},
+        rfd_reservation::{hold_rfd_numbers, list_rfd_reservations, release_rfd_reservation},
+        rfd_watch::{delete_rfd_watch, list_rfd_watches, watch_rfd, watch_rfd_label},
         webhook::github_webhook,
+        webhook_subscription::{
+            create_webhook_subscription, delete_webhook_subscription,
+            list_webhook_subscription_deliveries, list_webhook_subscriptions,
+            update_webhook_subscription, view_webhook_subscription,
+        },
     },
     permissions::RfdPermission,
 };
@@ -75,16 +88,28 @@ fn api_description() -> ApiDescription<RfdContext> {
     // RFDs
     api.register(list_rfds)
         .expect("Failed to register endpoint");
+    api.register(view_rfds_feed)
+        .expect("Failed to register endpoint");
+    api.register(export_rfds)
+        .expect("Failed to register endpoint");
 
     api.register(view_rfd_meta)
         .expect("Failed to register endpoint");
     api.register(view_rfd).expect("Failed to register endpoint");
     api.register(view_rfd_pdf)
         .expect("Failed to register endpoint");
+    api.register(view_rfd_html)
+        .expect("Failed to register endpoint");
     api.register(view_rfd_attr)
         .expect("Failed to register endpoint");
     api.register(view_rfd_discussion)
         .expect("Failed to register endpoint");
+    api.register(list_rfd_references)
+        .expect("Failed to register endpoint");
+    api.register(list_rfd_backlinks)
+        .expect("Failed to register endpoint");
+    api.register(view_rfd_feed)
+        .expect("Failed to register endpoint");
 
     api.register(list_rfd_revisions)
         .expect("Failed to register endpoint");
@@ -94,14 +119,20 @@ fn api_description() -> ApiDescription<RfdContext> {
         .expect("Failed to register endpoint");
     api.register(view_rfd_revision_pdf)
         .expect("Failed to register endpoint");
+    api.register(view_rfd_revision_html)
+        .expect("Failed to register endpoint");
     api.register(view_rfd_revision_attr)
         .expect("Failed to register endpoint");
     api.register(view_rfd_revision_discussion)
         .expect("Failed to register endpoint");
+    api.register(view_rfd_revision_diff)
+        .expect("Failed to register endpoint");
 
     api.register(search_rfds)
         .expect("Failed to register endpoint");
 
+    api.register(list_rfd_templates)
+        .expect("Failed to register endpoint");
     api.register(reserve_rfd)
         .expect("Failed to register endpoint");
     api.register(set_rfd_document)
@@ -119,13 +150,66 @@ fn api_description() -> ApiDescription<RfdContext> {
     api.register(update_rfd_revision)
         .expect("Failed to register endpoint");
 
+    // Supporting files
+    api.register(list_rfd_files)
+        .expect("Failed to register endpoint");
+    api.register(create_rfd_file)
+        .expect("Failed to register endpoint");
+    api.register(replace_rfd_file)
+        .expect("Failed to register endpoint");
+    api.register(delete_rfd_file)
+        .expect("Failed to register endpoint");
+
     api.register(list_jobs)
         .expect("Failed to register endpoint");
 
+    // Linting and previews
+    api.register(lint_rfd).expect("Failed to register endpoint");
+    api.register(preview_rfd_content)
+        .expect("Failed to register endpoint");
+    api.register(preview_rfd)
+        .expect("Failed to register endpoint");
+
+    // Reservations
+    api.register(list_rfd_reservations)
+        .expect("Failed to register endpoint");
+    api.register(hold_rfd_numbers)
+        .expect("Failed to register endpoint");
+    api.register(release_rfd_reservation)
+        .expect("Failed to register endpoint");
+
+    // Watches
+    api.register(list_rfd_watches)
+        .expect("Failed to register endpoint");
+    api.register(watch_rfd)
+        .expect("Failed to register endpoint");
+    api.register(watch_rfd_label)
+        .expect("Failed to register endpoint");
+    api.register(delete_rfd_watch)
+        .expect("Failed to register endpoint");
+
+    // Audit
+    api.register(list_audit_events)
+        .expect("Failed to register endpoint");
+
     // Webhooks
     api.register(github_webhook)
         .expect("Failed to register endpoint");
 
+    // Webhook Subscriptions
+    api.register(list_webhook_subscriptions)
+        .expect("Failed to register endpoint");
+    api.register(view_webhook_subscription)
+        .expect("Failed to register endpoint");
+    api.register(list_webhook_subscription_deliveries)
+        .expect("Failed to register endpoint");
+    api.register(create_webhook_subscription)
+        .expect("Failed to register endpoint");
+    api.register(update_webhook_subscription)
+        .expect("Failed to register endpoint");
+    api.register(delete_webhook_subscription)
+        .expect("Failed to register endpoint");
+
     api
 }
 
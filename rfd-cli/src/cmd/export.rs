@@ -0,0 +1,48 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use anyhow::Result;
+use clap::Parser;
+use futures::StreamExt;
+use std::{fs::File, io::Write, path::PathBuf};
+
+use crate::context::Context;
+
+/// Download an archive of every RFD that you have access to
+#[derive(Debug, Parser)]
+#[clap(name = "export")]
+pub struct ExportCmd {
+    /// File to write the gzipped tar archive to
+    #[clap(short, long, default_value = "rfds.tar.gz")]
+    output: PathBuf,
+    /// Include the content of every revision of each RFD
+    #[clap(long)]
+    history: bool,
+}
+
+impl ExportCmd {
+    pub async fn run(&self, ctx: &mut Context) -> Result<()> {
+        let client = ctx.require_client()?;
+        let mut stream = client
+            .export_rfds()
+            .history(self.history)
+            .send()
+            .await?
+            .into_inner()
+            .into_inner();
+
+        let mut file = File::create(&self.output)?;
+        let mut written = 0;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk)?;
+            written += chunk.len();
+        }
+        file.flush()?;
+
+        println!("Wrote {} bytes to {}", written, self.output.display());
+
+        Ok(())
+    }
+}
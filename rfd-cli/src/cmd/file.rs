@@ -0,0 +1,134 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand};
+use std::{fs, path::PathBuf};
+
+use crate::context::Context;
+
+/// Manage the supporting files (images, includes, etc.) stored alongside an RFD
+#[derive(Debug, Parser)]
+#[clap(name = "file")]
+pub struct FileCmd {
+    #[clap(subcommand)]
+    file: FileCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum FileCommands {
+    /// List the supporting files of an RFD
+    List(ListFiles),
+    /// Upload a new supporting file to the branch of an RFD
+    Upload(WriteFile),
+    /// Replace an existing supporting file on the branch of an RFD
+    Replace(WriteFile),
+    /// Delete a supporting file from the branch of an RFD
+    Delete(DeleteFile),
+}
+
+#[derive(Debug, Parser)]
+pub struct ListFiles {
+    /// The RFD number (examples: 1 or 123)
+    number: String,
+}
+
+#[derive(Debug, Parser)]
+pub struct WriteFile {
+    /// The RFD number (examples: 1 or 123)
+    number: String,
+    /// The local file to upload
+    file: PathBuf,
+    /// Path to store the file at relative to the RFD directory. Defaults to the name of the local
+    /// file
+    #[clap(short, long)]
+    path: Option<String>,
+    /// Git commit message to send with this change
+    #[clap(short, long)]
+    message: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+pub struct DeleteFile {
+    /// The RFD number (examples: 1 or 123)
+    number: String,
+    /// Path of the file relative to the RFD directory
+    path: String,
+    /// Git commit message to send with this change
+    #[clap(short, long)]
+    message: Option<String>,
+}
+
+impl FileCmd {
+    pub async fn run(&self, ctx: &mut Context) -> Result<()> {
+        let client = ctx.require_client()?;
+
+        match &self.file {
+            FileCommands::List(cmd) => {
+                let files = client
+                    .list_rfd_files()
+                    .number(&cmd.number)
+                    .send()
+                    .await?
+                    .into_inner();
+
+                for file in files {
+                    println!("{}\t{}", file.path, file.size);
+                }
+            }
+            FileCommands::Upload(cmd) => {
+                let path = cmd.path()?;
+                let mut request = client
+                    .create_rfd_file()
+                    .number(&cmd.number)
+                    .path(&path)
+                    .body(fs::read(&cmd.file)?);
+                if let Some(message) = &cmd.message {
+                    request = request.message(message);
+                }
+                let commit = request.send().await?.into_inner().commit;
+
+                println!("Uploaded {} in commit {}", path, commit.0);
+            }
+            FileCommands::Replace(cmd) => {
+                let path = cmd.path()?;
+                let mut request = client
+                    .replace_rfd_file()
+                    .number(&cmd.number)
+                    .path(&path)
+                    .body(fs::read(&cmd.file)?);
+                if let Some(message) = &cmd.message {
+                    request = request.message(message);
+                }
+                let commit = request.send().await?.into_inner().commit;
+
+                println!("Replaced {} in commit {}", path, commit.0);
+            }
+            FileCommands::Delete(cmd) => {
+                let mut request = client.delete_rfd_file().number(&cmd.number).path(&cmd.path);
+                if let Some(message) = &cmd.message {
+                    request = request.message(message);
+                }
+                let commit = request.send().await?.into_inner().commit;
+
+                println!("Deleted {} in commit {}", cmd.path, commit.0);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl WriteFile {
+    fn path(&self) -> Result<String> {
+        match &self.path {
+            Some(path) => Ok(path.clone()),
+            None => self
+                .file
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .ok_or_else(|| anyhow!("Unable to determine a path for {}", self.file.display())),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use rfd_sdk::types::RfdLintSeverity;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::context::Context;
+
+/// Check a local RFD document for problems before committing it
+#[derive(Debug, Parser)]
+#[clap(name = "lint")]
+pub struct LintCmd {
+    /// The RFD document to check. When the document is stored in an RFD directory (for instance
+    /// rfd/0123/README.adoc) the includes and images that it references are checked as well
+    file: PathBuf,
+    /// The number of the RFD. Defaults to the name of the directory containing the document
+    #[clap(short, long)]
+    number: Option<i32>,
+}
+
+impl LintCmd {
+    pub async fn run(&self, ctx: &mut Context) -> Result<()> {
+        let client = ctx.require_client()?;
+        let content = fs::read_to_string(&self.file)?;
+        let document = fs::canonicalize(&self.file)?;
+
+        let directory = document.parent().filter(|directory| {
+            directory
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.parse::<i32>().is_ok())
+                .unwrap_or(false)
+        });
+        let number = self.number.or_else(|| {
+            directory
+                .and_then(|directory| directory.file_name())
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.parse::<i32>().ok())
+        });
+        let files = directory
+            .map(|directory| supporting_files(directory, directory, &document))
+            .transpose()?;
+
+        let diagnostics = client
+            .lint_rfd()
+            .body_map(|body| body.content(content).number(number).files(files))
+            .send()
+            .await?
+            .into_inner();
+
+        for diagnostic in &diagnostics {
+            let location = diagnostic
+                .line
+                .map(|line| format!("{}:{}", self.file.display(), line))
+                .unwrap_or_else(|| self.file.display().to_string());
+            println!(
+                "{}: {}: {} [{}]",
+                location, diagnostic.severity, diagnostic.message, diagnostic.rule
+            );
+        }
+
+        let errors = diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == RfdLintSeverity::Error)
+            .count();
+
+        if errors > 0 {
+            Err(anyhow!(
+                "Found {} errors in {}",
+                errors,
+                self.file.display()
+            ))
+        } else {
+            println!(
+                "No errors found in {} ({} warnings)",
+                self.file.display(),
+                diagnostics.len()
+            );
+            Ok(())
+        }
+    }
+}
+
+/// List the files below `directory` as paths relative to `root`, excluding the document itself
+fn supporting_files(root: &Path, directory: &Path, document: &Path) -> Result<Vec<String>> {
+    let mut files = vec![];
+
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            files.extend(supporting_files(root, &path, document)?);
+        } else if path != document
+            && let Ok(relative) = path.strip_prefix(root)
+        {
+            files.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+    }
+
+    Ok(files)
+}
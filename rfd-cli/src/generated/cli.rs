@@ -33,6 +33,8 @@ impl<T: CliConfig> Cli<T> {
             CliCommand::GetApiUserToken => Self::cli_get_api_user_token(),
             CliCommand::DeleteApiUserToken => Self::cli_delete_api_user_token(),
             CliCommand::CreateLinkToken => Self::cli_create_link_token(),
+            CliCommand::ListAuditEvents => Self::cli_list_audit_events(),
+            CliCommand::ViewRfdsFeed => Self::cli_view_rfds_feed(),
             CliCommand::GithubWebhook => Self::cli_github_webhook(),
             CliCommand::GetGroups => Self::cli_get_groups(),
             CliCommand::CreateGroup => Self::cli_create_group(),
@@ -75,23 +77,53 @@ impl<T: CliConfig> Cli<T> {
             CliCommand::ViewRfdMeta => Self::cli_view_rfd_meta(),
             CliCommand::ViewRfdAttr => Self::cli_view_rfd_attr(),
             CliCommand::SetRfdAttr => Self::cli_set_rfd_attr(),
+            CliCommand::ListRfdBacklinks => Self::cli_list_rfd_backlinks(),
             CliCommand::SetRfdContent => Self::cli_set_rfd_content(),
             CliCommand::ViewRfdDiscussion => Self::cli_view_rfd_discussion(),
+            CliCommand::ViewRfdFeed => Self::cli_view_rfd_feed(),
+            CliCommand::ListRfdFiles => Self::cli_list_rfd_files(),
+            CliCommand::ReplaceRfdFile => Self::cli_replace_rfd_file(),
+            CliCommand::CreateRfdFile => Self::cli_create_rfd_file(),
+            CliCommand::DeleteRfdFile => Self::cli_delete_rfd_file(),
+            CliCommand::ViewRfdHtml => Self::cli_view_rfd_html(),
             CliCommand::ViewRfdPdf => Self::cli_view_rfd_pdf(),
+            CliCommand::PreviewRfd => Self::cli_preview_rfd(),
             CliCommand::ViewRfd => Self::cli_view_rfd(),
             CliCommand::SetRfdDocument => Self::cli_set_rfd_document(),
+            CliCommand::ListRfdReferences => Self::cli_list_rfd_references(),
             CliCommand::ListRfdRevisions => Self::cli_list_rfd_revisions(),
+            CliCommand::ViewRfdRevisionDiff => Self::cli_view_rfd_revision_diff(),
             CliCommand::ViewRfdRevisionMeta => Self::cli_view_rfd_revision_meta(),
             CliCommand::UpdateRfdRevision => Self::cli_update_rfd_revision(),
             CliCommand::ViewRfdRevisionAttr => Self::cli_view_rfd_revision_attr(),
             CliCommand::ViewRfdRevisionDiscussion => Self::cli_view_rfd_revision_discussion(),
+            CliCommand::ViewRfdRevisionHtml => Self::cli_view_rfd_revision_html(),
             CliCommand::ViewRfdRevisionPdf => Self::cli_view_rfd_revision_pdf(),
             CliCommand::ViewRfdRevision => Self::cli_view_rfd_revision(),
             CliCommand::DiscussRfd => Self::cli_discuss_rfd(),
             CliCommand::PublishRfd => Self::cli_publish_rfd(),
             CliCommand::UpdateRfdVisibility => Self::cli_update_rfd_visibility(),
+            CliCommand::WatchRfd => Self::cli_watch_rfd(),
+            CliCommand::ExportRfds => Self::cli_export_rfds(),
+            CliCommand::WatchRfdLabel => Self::cli_watch_rfd_label(),
+            CliCommand::LintRfd => Self::cli_lint_rfd(),
+            CliCommand::PreviewRfdContent => Self::cli_preview_rfd_content(),
+            CliCommand::ListRfdReservations => Self::cli_list_rfd_reservations(),
+            CliCommand::HoldRfdNumbers => Self::cli_hold_rfd_numbers(),
+            CliCommand::ReleaseRfdReservation => Self::cli_release_rfd_reservation(),
             CliCommand::SearchRfds => Self::cli_search_rfds(),
+            CliCommand::ListRfdTemplates => Self::cli_list_rfd_templates(),
+            CliCommand::ListRfdWatches => Self::cli_list_rfd_watches(),
+            CliCommand::DeleteRfdWatch => Self::cli_delete_rfd_watch(),
             CliCommand::GetSelf => Self::cli_get_self(),
+            CliCommand::ListWebhookSubscriptions => Self::cli_list_webhook_subscriptions(),
+            CliCommand::CreateWebhookSubscription => Self::cli_create_webhook_subscription(),
+            CliCommand::ViewWebhookSubscription => Self::cli_view_webhook_subscription(),
+            CliCommand::UpdateWebhookSubscription => Self::cli_update_webhook_subscription(),
+            CliCommand::DeleteWebhookSubscription => Self::cli_delete_webhook_subscription(),
+            CliCommand::ListWebhookSubscriptionDeliveries => {
+                Self::cli_list_webhook_subscription_deliveries()
+            }
         }
     }
 
@@ -432,6 +464,62 @@ impl<T: CliConfig> Cli<T> {
             .about("Create a new link token for linking this provider to a different api user")
     }
 
+    pub fn cli_list_audit_events() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("caller")
+                    .long("caller")
+                    .value_parser(::clap::value_parser!(::uuid::Uuid))
+                    .required(false)
+                    .help("Only include events performed by the given user"),
+            )
+            .arg(
+                ::clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("rfd")
+                    .long("rfd")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help("Only include events for the given RFD"),
+            )
+            .arg(
+                ::clap::Arg::new("since")
+                    .long("since")
+                    .value_parser(::clap::value_parser!(
+                        ::chrono::DateTime<::chrono::offset::Utc>
+                    ))
+                    .required(false)
+                    .help("Only include events recorded at or after this time"),
+            )
+            .arg(
+                ::clap::Arg::new("until")
+                    .long("until")
+                    .value_parser(::clap::value_parser!(
+                        ::chrono::DateTime<::chrono::offset::Utc>
+                    ))
+                    .required(false)
+                    .help("Only include events recorded before this time"),
+            )
+            .about(
+                "List recorded write operations, optionally limited to a single RFD, caller or \
+                 time range",
+            )
+    }
+
+    pub fn cli_view_rfds_feed() -> ::clap::Command {
+        ::clap::Command::new("").about("Get an Atom feed of the activity of all RFDs")
+    }
+
     pub fn cli_github_webhook() -> ::clap::Command {
         ::clap::Command::new("")
             .arg(
@@ -556,9 +644,25 @@ impl<T: CliConfig> Cli<T> {
                 ::clap::Arg::new("rfd")
                     .long("rfd")
                     .value_parser(::clap::value_parser!(::std::string::String))
-                    .required(true),
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("status")
+                    .long("status")
+                    .value_parser(::clap::builder::TypedValueParser::map(
+                        ::clap::builder::PossibleValuesParser::new([
+                            types::JobStatus::Queued.to_string(),
+                            types::JobStatus::Running.to_string(),
+                            types::JobStatus::Succeeded.to_string(),
+                            types::JobStatus::Failed.to_string(),
+                            types::JobStatus::Dead.to_string(),
+                            types::JobStatus::Superseded.to_string(),
+                        ]),
+                        |s| types::JobStatus::try_from(s).unwrap(),
+                    ))
+                    .required(false),
             )
-            .about("List all jobs for a RFD")
+            .about("List jobs, optionally limited to a single RFD or status")
     }
 
     pub fn cli_magic_link_exchange() -> ::clap::Command {
@@ -1234,7 +1338,127 @@ impl<T: CliConfig> Cli<T> {
     }
 
     pub fn cli_list_rfds() -> ::clap::Command {
-        ::clap::Command::new("").about("List all available RFDs")
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("author")
+                    .long("author")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help("Case insensitive substring to match against the RFD authors"),
+            )
+            .arg(
+                ::clap::Arg::new("cursor")
+                    .long("cursor")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "Cursor returned by a previous request. Retrieves the page of RFDs that \
+                         follows it when used with the same filters and sort order",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("direction")
+                    .long("direction")
+                    .value_parser(::clap::builder::TypedValueParser::map(
+                        ::clap::builder::PossibleValuesParser::new([
+                            types::SortDirection::Asc.to_string(),
+                            types::SortDirection::Desc.to_string(),
+                        ]),
+                        |s| types::SortDirection::try_from(s).unwrap(),
+                    ))
+                    .required(false)
+                    .help("Direction to sort in, defaults to descending"),
+            )
+            .arg(
+                ::clap::Arg::new("label")
+                    .long("label")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "Comma separated list of labels. Only RFDs that have all of the given \
+                         labels are included",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("major-change-since")
+                    .long("major-change-since")
+                    .value_parser(::clap::value_parser!(
+                        ::chrono::DateTime<::chrono::offset::Utc>
+                    ))
+                    .required(false)
+                    .help("Only include RFDs that have had a major change at or after this time"),
+            )
+            .arg(
+                ::clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("sort")
+                    .long("sort")
+                    .value_parser(::clap::builder::TypedValueParser::map(
+                        ::clap::builder::PossibleValuesParser::new([
+                            types::RfdSortField::Number.to_string(),
+                            types::RfdSortField::UpdatedAt.to_string(),
+                            types::RfdSortField::LatestMajorChangeAt.to_string(),
+                        ]),
+                        |s| types::RfdSortField::try_from(s).unwrap(),
+                    ))
+                    .required(false)
+                    .help(
+                        "Field to sort by, defaults to the RFD number. Sorting by `updated_at` \
+                         orders RFDs by the commit time of their latest revision",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("state")
+                    .long("state")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "Comma separated list of states. RFDs in any of the given states are \
+                         included",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("title")
+                    .long("title")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help("Case insensitive substring to match against the RFD title"),
+            )
+            .arg(
+                ::clap::Arg::new("updated-since")
+                    .long("updated-since")
+                    .value_parser(::clap::value_parser!(
+                        ::chrono::DateTime<::chrono::offset::Utc>
+                    ))
+                    .required(false)
+                    .help(
+                        "Only include RFDs whose latest revision was committed at or after this \
+                         time",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("visibility")
+                    .long("visibility")
+                    .value_parser(::clap::builder::TypedValueParser::map(
+                        ::clap::builder::PossibleValuesParser::new([
+                            types::Visibility::Public.to_string(),
+                            types::Visibility::Private.to_string(),
+                        ]),
+                        |s| types::Visibility::try_from(s).unwrap(),
+                    ))
+                    .required(false),
+            )
+            .about("List all available RFDs")
     }
 
     pub fn cli_reserve_rfd() -> ::clap::Command {
@@ -1246,6 +1470,27 @@ impl<T: CliConfig> Cli<T> {
                     .required(false)
                     .help("Optional contents of the RFD"),
             )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(i32))
+                    .required(false)
+                    .help(
+                        "Optional number to reserve. The number must either be held for a group \
+                         that the caller is a member of, or the caller must be permitted to \
+                         manage RFD reservations",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("template")
+                    .long("template")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "Optional name of the template to construct the RFD from. Defaults to the \
+                         standard new RFD template",
+                    ),
+            )
             .arg(
                 ::clap::Arg::new("title")
                     .long("title")
@@ -1272,6 +1517,16 @@ impl<T: CliConfig> Cli<T> {
 
     pub fn cli_view_rfd_meta() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("if-none-match")
+                    .long("if-none-match")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "An ETag previously returned for this resource. If it still matches the \
+                         resource then a 304 Not Modified response is returned without a body",
+                    ),
+            )
             .arg(
                 ::clap::Arg::new("number")
                     .long("number")
@@ -1324,6 +1579,26 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("An attribute that can be defined in an RFD document"),
             )
+            .arg(
+                ::clap::Arg::new("base-commit")
+                    .long("base-commit")
+                    .value_parser(::clap::value_parser!(types::CommitSha))
+                    .required(false)
+                    .help(
+                        "The commit that this update was written against. If the RFD has been \
+                         updated since this commit then the update is rejected with a conflict",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("merge")
+                    .long("merge")
+                    .value_parser(::clap::value_parser!(bool))
+                    .required(false)
+                    .help(
+                        "Attempt to merge this update with any changes made since the base \
+                         commit. The update is still rejected if the changes overlap",
+                    ),
+            )
             .arg(
                 ::clap::Arg::new("message")
                     .long("message")
@@ -1362,8 +1637,30 @@ impl<T: CliConfig> Cli<T> {
             .about("Set an attribute of a RFD")
     }
 
+    pub fn cli_list_rfd_backlinks() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("List the RFDs that reference a RFD")
+    }
+
     pub fn cli_set_rfd_content() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("base-commit")
+                    .long("base-commit")
+                    .value_parser(::clap::value_parser!(types::CommitSha))
+                    .required(false)
+                    .help(
+                        "The commit that this update was written against. If the RFD has been \
+                         updated since this commit then the update is rejected with a conflict",
+                    ),
+            )
             .arg(
                 ::clap::Arg::new("content")
                     .long("content")
@@ -1371,6 +1668,16 @@ impl<T: CliConfig> Cli<T> {
                     .required_unless_present("json-body")
                     .help("Asciidoc content to store for this RFD"),
             )
+            .arg(
+                ::clap::Arg::new("merge")
+                    .long("merge")
+                    .value_parser(::clap::value_parser!(bool))
+                    .required(false)
+                    .help(
+                        "Attempt to merge this update with any changes made since the base \
+                         commit. The update is still rejected if the changes overlap",
+                    ),
+            )
             .arg(
                 ::clap::Arg::new("message")
                     .long("message")
@@ -1414,7 +1721,7 @@ impl<T: CliConfig> Cli<T> {
             .about("Get the comments related to the latest revision of a RFD")
     }
 
-    pub fn cli_view_rfd_pdf() -> ::clap::Command {
+    pub fn cli_view_rfd_feed() -> ::clap::Command {
         ::clap::Command::new("")
             .arg(
                 ::clap::Arg::new("number")
@@ -1423,10 +1730,10 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The RFD number (examples: 1 or 123)"),
             )
-            .about("Get the PDF locations of the latest revision of a RFD")
+            .about("Get an Atom feed of the activity of a RFD")
     }
 
-    pub fn cli_view_rfd() -> ::clap::Command {
+    pub fn cli_list_rfd_files() -> ::clap::Command {
         ::clap::Command::new("")
             .arg(
                 ::clap::Arg::new("number")
@@ -1435,24 +1742,17 @@ impl<T: CliConfig> Cli<T> {
                     .required(true)
                     .help("The RFD number (examples: 1 or 123)"),
             )
-            .about("Get the raw contents of the latest revision of a RFD")
+            .about("List the supporting files (images, includes, etc.) stored alongside a RFD")
     }
 
-    pub fn cli_set_rfd_document() -> ::clap::Command {
+    pub fn cli_replace_rfd_file() -> ::clap::Command {
         ::clap::Command::new("")
-            .arg(
-                ::clap::Arg::new("document")
-                    .long("document")
-                    .value_parser(::clap::value_parser!(::std::string::String))
-                    .required_unless_present("json-body")
-                    .help("Full Asciidoc document to store for this RFD"),
-            )
             .arg(
                 ::clap::Arg::new("message")
                     .long("message")
                     .value_parser(::clap::value_parser!(::std::string::String))
                     .required(false)
-                    .help("Optional Git commit message to send with this update (recommended)"),
+                    .help("Optional Git commit message to send with this change (recommended)"),
             )
             .arg(
                 ::clap::Arg::new("number")
@@ -1462,29 +1762,29 @@ impl<T: CliConfig> Cli<T> {
                     .help("The RFD number (examples: 1 or 123)"),
             )
             .arg(
-                ::clap::Arg::new("json-body")
-                    .long("json-body")
-                    .value_name("JSON-FILE")
-                    .required(false)
-                    .value_parser(::clap::value_parser!(std::path::PathBuf))
-                    .help("Path to a file that contains the full json body."),
+                ::clap::Arg::new("path")
+                    .long("path")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help(
+                        "Path of the file relative to the RFD directory (examples: diagram.svg or \
+                         images/flow.png)",
+                    ),
             )
-            .arg(
-                ::clap::Arg::new("json-body-template")
-                    .long("json-body-template")
-                    .action(::clap::ArgAction::SetTrue)
-                    .help("XXX"),
+            .about(
+                "Replace the contents of an existing supporting file on the branch of a RFD. The \
+                 request body is the raw content of the file, which may be up to 32 MiB",
             )
-            .about("Replace the full document of a RFD")
     }
 
-    pub fn cli_list_rfd_revisions() -> ::clap::Command {
+    pub fn cli_create_rfd_file() -> ::clap::Command {
         ::clap::Command::new("")
             .arg(
-                ::clap::Arg::new("limit")
-                    .long("limit")
-                    .value_parser(::clap::value_parser!(i64))
-                    .required(false),
+                ::clap::Arg::new("message")
+                    .long("message")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help("Optional Git commit message to send with this change (recommended)"),
             )
             .arg(
                 ::clap::Arg::new("number")
@@ -1494,16 +1794,30 @@ impl<T: CliConfig> Cli<T> {
                     .help("The RFD number (examples: 1 or 123)"),
             )
             .arg(
-                ::clap::Arg::new("offset")
-                    .long("offset")
-                    .value_parser(::clap::value_parser!(i64))
-                    .required(false),
+                ::clap::Arg::new("path")
+                    .long("path")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help(
+                        "Path of the file relative to the RFD directory (examples: diagram.svg or \
+                         images/flow.png)",
+                    ),
+            )
+            .about(
+                "Upload a new supporting file to the branch of a RFD. The request body is the raw \
+                 content of the file, which may be up to 32 MiB",
             )
-            .about("List all revisions of an RFD")
     }
 
-    pub fn cli_view_rfd_revision_meta() -> ::clap::Command {
+    pub fn cli_delete_rfd_file() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("message")
+                    .long("message")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help("Optional Git commit message to send with this change (recommended)"),
+            )
             .arg(
                 ::clap::Arg::new("number")
                     .long("number")
@@ -1512,21 +1826,280 @@ impl<T: CliConfig> Cli<T> {
                     .help("The RFD number (examples: 1 or 123)"),
             )
             .arg(
-                ::clap::Arg::new("revision")
-                    .long("revision")
-                    .value_parser(::clap::value_parser!(types::TypedUuidForRfdRevisionId))
+                ::clap::Arg::new("path")
+                    .long("path")
+                    .value_parser(::clap::value_parser!(::std::string::String))
                     .required(true)
-                    .help("The revision id of the RFD"),
+                    .help(
+                        "Path of the file relative to the RFD directory (examples: diagram.svg or \
+                         images/flow.png)",
+                    ),
             )
-            .about("Get an RFD revision's metadata")
+            .about("Delete a supporting file from the branch of a RFD")
     }
 
-    pub fn cli_update_rfd_revision() -> ::clap::Command {
+    pub fn cli_view_rfd_html() -> ::clap::Command {
         ::clap::Command::new("")
             .arg(
-                ::clap::Arg::new("major-change")
-                    .long("major-change")
-                    .value_parser(::clap::value_parser!(bool))
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("Get the rendered HTML of the latest revision of a RFD")
+    }
+
+    pub fn cli_view_rfd_pdf() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("if-none-match")
+                    .long("if-none-match")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "An ETag previously returned for this resource. If it still matches the \
+                         resource then a 304 Not Modified response is returned without a body",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("Get the PDF locations of the latest revision of a RFD")
+    }
+
+    pub fn cli_preview_rfd() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("content")
+                    .long("content")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required_unless_present("json-body")
+                    .help("Full document to parse"),
+            )
+            .arg(
+                ::clap::Arg::new("format")
+                    .long("format")
+                    .value_parser(::clap::builder::TypedValueParser::map(
+                        ::clap::builder::PossibleValuesParser::new([
+                            types::ContentFormat::Asciidoc.to_string(),
+                            types::ContentFormat::Markdown.to_string(),
+                        ]),
+                        |s| types::ContentFormat::try_from(s).unwrap(),
+                    ))
+                    .required_unless_present("json-body")
+                    .help("The format that the document is written in"),
+            )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .arg(
+                ::clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(::clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                ::clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about(
+                "Parse a document for a RFD without committing it, returning the data that would \
+                 be extracted from it",
+            )
+    }
+
+    pub fn cli_view_rfd() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("if-none-match")
+                    .long("if-none-match")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "An ETag previously returned for this resource. If it still matches the \
+                         resource then a 304 Not Modified response is returned without a body",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("Get the raw contents of the latest revision of a RFD")
+    }
+
+    pub fn cli_set_rfd_document() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("base-commit")
+                    .long("base-commit")
+                    .value_parser(::clap::value_parser!(types::CommitSha))
+                    .required(false)
+                    .help(
+                        "The commit that this update was written against. If the RFD has been \
+                         updated since this commit then the update is rejected with a conflict",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("document")
+                    .long("document")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required_unless_present("json-body")
+                    .help("Full Asciidoc document to store for this RFD"),
+            )
+            .arg(
+                ::clap::Arg::new("merge")
+                    .long("merge")
+                    .value_parser(::clap::value_parser!(bool))
+                    .required(false)
+                    .help(
+                        "Attempt to merge this update with any changes made since the base \
+                         commit. The update is still rejected if the changes overlap",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("message")
+                    .long("message")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help("Optional Git commit message to send with this update (recommended)"),
+            )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .arg(
+                ::clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(::clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                ::clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about("Replace the full document of a RFD")
+    }
+
+    pub fn cli_list_rfd_references() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("List the RFDs referenced by the latest revision of a RFD")
+    }
+
+    pub fn cli_list_rfd_revisions() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .arg(
+                ::clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .about("List all revisions of an RFD")
+    }
+
+    pub fn cli_view_rfd_revision_diff() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("a")
+                    .long("a")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The revision id or commit sha of the older revision"),
+            )
+            .arg(
+                ::clap::Arg::new("b")
+                    .long("b")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The revision id or commit sha of the newer revision"),
+            )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("Get the changes between two revisions of a RFD")
+    }
+
+    pub fn cli_view_rfd_revision_meta() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("if-none-match")
+                    .long("if-none-match")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "An ETag previously returned for this resource. If it still matches the \
+                         resource then a 304 Not Modified response is returned without a body",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .arg(
+                ::clap::Arg::new("revision")
+                    .long("revision")
+                    .value_parser(::clap::value_parser!(types::TypedUuidForRfdRevisionId))
+                    .required(true)
+                    .help("The revision id of the RFD"),
+            )
+            .about("Get an RFD revision's metadata")
+    }
+
+    pub fn cli_update_rfd_revision() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("major-change")
+                    .long("major-change")
+                    .value_parser(::clap::value_parser!(bool))
                     .required(false),
             )
             .arg(
@@ -1612,8 +2185,37 @@ impl<T: CliConfig> Cli<T> {
             .about("Get the comments related to a revision of a RFD")
     }
 
+    pub fn cli_view_rfd_revision_html() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .arg(
+                ::clap::Arg::new("revision")
+                    .long("revision")
+                    .value_parser(::clap::value_parser!(types::TypedUuidForRfdRevisionId))
+                    .required(true)
+                    .help("The revision id of the RFD"),
+            )
+            .about("Get the rendered HTML of a revision of a RFD")
+    }
+
     pub fn cli_view_rfd_revision_pdf() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("if-none-match")
+                    .long("if-none-match")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "An ETag previously returned for this resource. If it still matches the \
+                         resource then a 304 Not Modified response is returned without a body",
+                    ),
+            )
             .arg(
                 ::clap::Arg::new("number")
                     .long("number")
@@ -1633,6 +2235,16 @@ impl<T: CliConfig> Cli<T> {
 
     pub fn cli_view_rfd_revision() -> ::clap::Command {
         ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("if-none-match")
+                    .long("if-none-match")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "An ETag previously returned for this resource. If it still matches the \
+                         resource then a 304 Not Modified response is returned without a body",
+                    ),
+            )
             .arg(
                 ::clap::Arg::new("number")
                     .long("number")
@@ -1712,16 +2324,261 @@ impl<T: CliConfig> Cli<T> {
             .about("Modify the visibility of a RFD")
     }
 
-    pub fn cli_search_rfds() -> ::clap::Command {
+    pub fn cli_watch_rfd() -> ::clap::Command {
         ::clap::Command::new("")
             .arg(
-                ::clap::Arg::new("attributes-to-crop")
-                    .long("attributes-to-crop")
+                ::clap::Arg::new("number")
+                    .long("number")
                     .value_parser(::clap::value_parser!(::std::string::String))
-                    .required(false),
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
             )
-            .arg(
-                ::clap::Arg::new("highlight-post-tag")
+            .about(
+                "Watch an RFD. An email is sent to the caller when the RFD changes state or \
+                 receives a major change, for as long as the caller is able to read the RFD",
+            )
+    }
+
+    pub fn cli_export_rfds() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("history")
+                    .long("history")
+                    .value_parser(::clap::value_parser!(bool))
+                    .required(false)
+                    .help("Include the content of every revision of each RFD in the archive"),
+            )
+            .about(
+                "Download a gzipped tar archive of every RFD that the caller has access to. Each \
+                 RFD is exported as the content of its latest revision along with a metadata.json \
+                 file",
+            )
+    }
+
+    pub fn cli_watch_rfd_label() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("label")
+                    .long("label")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The label to watch. Labels are matched ignoring case"),
+            )
+            .about(
+                "Watch every RFD with a label. An email is sent to the caller when a labeled RFD \
+                 that they are able to read changes state or receives a major change",
+            )
+    }
+
+    pub fn cli_lint_rfd() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("content")
+                    .long("content")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required_unless_present("json-body")
+                    .help("Full Asciidoc document to check"),
+            )
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(i32))
+                    .required(false)
+                    .help(
+                        "The number of the RFD that the document is stored as. When set, the \
+                         number in the title of the document must match it",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(::clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                ::clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about("Check a RFD document for problems before it is committed")
+    }
+
+    pub fn cli_preview_rfd_content() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("content")
+                    .long("content")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required_unless_present("json-body")
+                    .help("Full document to parse"),
+            )
+            .arg(
+                ::clap::Arg::new("format")
+                    .long("format")
+                    .value_parser(::clap::builder::TypedValueParser::map(
+                        ::clap::builder::PossibleValuesParser::new([
+                            types::ContentFormat::Asciidoc.to_string(),
+                            types::ContentFormat::Markdown.to_string(),
+                        ]),
+                        |s| types::ContentFormat::try_from(s).unwrap(),
+                    ))
+                    .required_unless_present("json-body")
+                    .help("The format that the document is written in"),
+            )
+            .arg(
+                ::clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(::clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                ::clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about(
+                "Parse a document without committing it, returning the data that would be \
+                 extracted from it",
+            )
+    }
+
+    pub fn cli_list_rfd_reservations() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("claimed")
+                    .long("claimed")
+                    .value_parser(::clap::value_parser!(bool))
+                    .required(false)
+                    .help(
+                        "Filter to numbers that have (true) or have not (false) been claimed by \
+                         an RFD",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("group-id")
+                    .long("group-id")
+                    .value_parser(::clap::value_parser!(::uuid::Uuid))
+                    .required(false)
+                    .help("Only list numbers held for this group"),
+            )
+            .arg(
+                ::clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .about("List reserved and held RFD numbers")
+    }
+
+    pub fn cli_hold_rfd_numbers() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("end")
+                    .long("end")
+                    .value_parser(::clap::value_parser!(i32))
+                    .required(false)
+                    .help(
+                        "The last number to hold (inclusive). Defaults to holding only the first \
+                         number",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("group-id")
+                    .long("group-id")
+                    .value_parser(::clap::value_parser!(::uuid::Uuid))
+                    .required_unless_present("json-body")
+                    .help("The group whose members are allowed to claim the held numbers"),
+            )
+            .arg(
+                ::clap::Arg::new("start")
+                    .long("start")
+                    .value_parser(::clap::value_parser!(i32))
+                    .required_unless_present("json-body")
+                    .help("The first number to hold"),
+            )
+            .arg(
+                ::clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(false)
+                    .value_parser(::clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                ::clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about(
+                "Hold a number or a range of numbers for a group. Held numbers are skipped when \
+                 allocating new RFD numbers and can only be claimed by members of the group",
+            )
+    }
+
+    pub fn cli_release_rfd_reservation() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("number")
+                    .long("number")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(true)
+                    .help("The RFD number (examples: 1 or 123)"),
+            )
+            .about("Release a held RFD number that has not been claimed")
+    }
+
+    pub fn cli_search_rfds() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("attributes-to-crop")
+                    .long("attributes-to-crop")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("author")
+                    .long("author")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "Comma separated list of author names or emails. Results from RFDs \
+                         written by any of the given authors are included. Names and emails must \
+                         match exactly as they are written in the RFD",
+                    ),
+            )
+            .arg(
+                ::clap::Arg::new("committed-after")
+                    .long("committed-after")
+                    .value_parser(::clap::value_parser!(
+                        ::chrono::DateTime<::chrono::offset::Utc>
+                    ))
+                    .required(false)
+                    .help("Only include results from revisions committed at or after this time"),
+            )
+            .arg(
+                ::clap::Arg::new("committed-before")
+                    .long("committed-before")
+                    .value_parser(::clap::value_parser!(
+                        ::chrono::DateTime<::chrono::offset::Utc>
+                    ))
+                    .required(false)
+                    .help("Only include results from revisions committed before this time"),
+            )
+            .arg(
+                ::clap::Arg::new("highlight-post-tag")
                     .long("highlight-post-tag")
                     .value_parser(::clap::value_parser!(::std::string::String))
                     .required(false),
@@ -1732,6 +2589,16 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::std::string::String))
                     .required(false),
             )
+            .arg(
+                ::clap::Arg::new("label")
+                    .long("label")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "Comma separated list of labels. Only results from RFDs that have all of \
+                         the given labels are included",
+                    ),
+            )
             .arg(
                 ::clap::Arg::new("limit")
                     .long("limit")
@@ -1750,49 +2617,227 @@ impl<T: CliConfig> Cli<T> {
                     .value_parser(::clap::value_parser!(::std::string::String))
                     .required(true),
             )
+            .arg(
+                ::clap::Arg::new("state")
+                    .long("state")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required(false)
+                    .help(
+                        "Comma separated list of states. Results from RFDs in any of the given \
+                         states are included",
+                    ),
+            )
             .about("Search the RFD index and get a list of results")
     }
 
+    pub fn cli_list_rfd_templates() -> ::clap::Command {
+        ::clap::Command::new("").about("List the templates that can be used when reserving a RFD")
+    }
+
+    pub fn cli_list_rfd_watches() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .about("List the RFDs and labels that the caller is watching")
+    }
+
+    pub fn cli_delete_rfd_watch() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("id")
+                    .long("id")
+                    .value_parser(::clap::value_parser!(types::TypedUuidForRfdWatchId))
+                    .required(true),
+            )
+            .about("Stop watching an RFD or label")
+    }
+
     pub fn cli_get_self() -> ::clap::Command {
         ::clap::Command::new("").about("View details for the calling user")
     }
 
-    pub async fn execute(
-        &self,
-        cmd: CliCommand,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        match cmd {
-            CliCommand::JwksJson => self.execute_jwks_json(matches).await,
-            CliCommand::OpenidConfiguration => self.execute_openid_configuration(matches).await,
-            CliCommand::ListApiUsers => self.execute_list_api_users(matches).await,
-            CliCommand::CreateApiUser => self.execute_create_api_user(matches).await,
-            CliCommand::GetApiUser => self.execute_get_api_user(matches).await,
-            CliCommand::UpdateApiUser => self.execute_update_api_user(matches).await,
-            CliCommand::SetApiUserContactEmail => {
-                self.execute_set_api_user_contact_email(matches).await
-            }
-            CliCommand::AddApiUserToGroup => self.execute_add_api_user_to_group(matches).await,
-            CliCommand::RemoveApiUserFromGroup => {
-                self.execute_remove_api_user_from_group(matches).await
-            }
-            CliCommand::LinkProvider => self.execute_link_provider(matches).await,
-            CliCommand::AddApiUserPermission => self.execute_add_api_user_permission(matches).await,
-            CliCommand::RemoveApiUserPermission => {
-                self.execute_remove_api_user_permission(matches).await
-            }
-            CliCommand::ListApiUserTokens => self.execute_list_api_user_tokens(matches).await,
-            CliCommand::CreateApiUserToken => self.execute_create_api_user_token(matches).await,
-            CliCommand::GetApiUserToken => self.execute_get_api_user_token(matches).await,
-            CliCommand::DeleteApiUserToken => self.execute_delete_api_user_token(matches).await,
-            CliCommand::CreateLinkToken => self.execute_create_link_token(matches).await,
-            CliCommand::GithubWebhook => self.execute_github_webhook(matches).await,
-            CliCommand::GetGroups => self.execute_get_groups(matches).await,
-            CliCommand::CreateGroup => self.execute_create_group(matches).await,
-            CliCommand::UpdateGroup => self.execute_update_group(matches).await,
-            CliCommand::DeleteGroup => self.execute_delete_group(matches).await,
-            CliCommand::GetGroupMembers => self.execute_get_group_members(matches).await,
-            CliCommand::ListJobs => self.execute_list_jobs(matches).await,
+    pub fn cli_list_webhook_subscriptions() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .about("List webhook subscriptions")
+    }
+
+    pub fn cli_create_webhook_subscription() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("url")
+                    .long("url")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required_unless_present("json-body")
+                    .help("The http(s) url that event payloads are POSTed to"),
+            )
+            .arg(
+                ::clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(true)
+                    .value_parser(::clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                ::clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about(
+                "Create a webhook subscription. The returned secret is used to sign deliveries \
+                 and can not be retrieved again",
+            )
+    }
+
+    pub fn cli_view_webhook_subscription() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("id")
+                    .long("id")
+                    .value_parser(::clap::value_parser!(
+                        types::TypedUuidForWebhookSubscriptionId
+                    ))
+                    .required(true),
+            )
+            .about("Get a webhook subscription")
+    }
+
+    pub fn cli_update_webhook_subscription() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("id")
+                    .long("id")
+                    .value_parser(::clap::value_parser!(
+                        types::TypedUuidForWebhookSubscriptionId
+                    ))
+                    .required(true),
+            )
+            .arg(
+                ::clap::Arg::new("url")
+                    .long("url")
+                    .value_parser(::clap::value_parser!(::std::string::String))
+                    .required_unless_present("json-body")
+                    .help("The http(s) url that event payloads are POSTed to"),
+            )
+            .arg(
+                ::clap::Arg::new("json-body")
+                    .long("json-body")
+                    .value_name("JSON-FILE")
+                    .required(true)
+                    .value_parser(::clap::value_parser!(std::path::PathBuf))
+                    .help("Path to a file that contains the full json body."),
+            )
+            .arg(
+                ::clap::Arg::new("json-body-template")
+                    .long("json-body-template")
+                    .action(::clap::ArgAction::SetTrue)
+                    .help("XXX"),
+            )
+            .about("Update the url and event filters of a webhook subscription")
+    }
+
+    pub fn cli_delete_webhook_subscription() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("id")
+                    .long("id")
+                    .value_parser(::clap::value_parser!(
+                        types::TypedUuidForWebhookSubscriptionId
+                    ))
+                    .required(true),
+            )
+            .about(
+                "Delete a webhook subscription. Deliveries that have not yet been sent are \
+                 abandoned",
+            )
+    }
+
+    pub fn cli_list_webhook_subscription_deliveries() -> ::clap::Command {
+        ::clap::Command::new("")
+            .arg(
+                ::clap::Arg::new("id")
+                    .long("id")
+                    .value_parser(::clap::value_parser!(
+                        types::TypedUuidForWebhookSubscriptionId
+                    ))
+                    .required(true),
+            )
+            .arg(
+                ::clap::Arg::new("limit")
+                    .long("limit")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .arg(
+                ::clap::Arg::new("offset")
+                    .long("offset")
+                    .value_parser(::clap::value_parser!(i64))
+                    .required(false),
+            )
+            .about("List the delivery attempts of a webhook subscription, newest first")
+    }
+
+    pub async fn execute(
+        &self,
+        cmd: CliCommand,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        match cmd {
+            CliCommand::JwksJson => self.execute_jwks_json(matches).await,
+            CliCommand::OpenidConfiguration => self.execute_openid_configuration(matches).await,
+            CliCommand::ListApiUsers => self.execute_list_api_users(matches).await,
+            CliCommand::CreateApiUser => self.execute_create_api_user(matches).await,
+            CliCommand::GetApiUser => self.execute_get_api_user(matches).await,
+            CliCommand::UpdateApiUser => self.execute_update_api_user(matches).await,
+            CliCommand::SetApiUserContactEmail => {
+                self.execute_set_api_user_contact_email(matches).await
+            }
+            CliCommand::AddApiUserToGroup => self.execute_add_api_user_to_group(matches).await,
+            CliCommand::RemoveApiUserFromGroup => {
+                self.execute_remove_api_user_from_group(matches).await
+            }
+            CliCommand::LinkProvider => self.execute_link_provider(matches).await,
+            CliCommand::AddApiUserPermission => self.execute_add_api_user_permission(matches).await,
+            CliCommand::RemoveApiUserPermission => {
+                self.execute_remove_api_user_permission(matches).await
+            }
+            CliCommand::ListApiUserTokens => self.execute_list_api_user_tokens(matches).await,
+            CliCommand::CreateApiUserToken => self.execute_create_api_user_token(matches).await,
+            CliCommand::GetApiUserToken => self.execute_get_api_user_token(matches).await,
+            CliCommand::DeleteApiUserToken => self.execute_delete_api_user_token(matches).await,
+            CliCommand::CreateLinkToken => self.execute_create_link_token(matches).await,
+            CliCommand::ListAuditEvents => self.execute_list_audit_events(matches).await,
+            CliCommand::ViewRfdsFeed => self.execute_view_rfds_feed(matches).await,
+            CliCommand::GithubWebhook => self.execute_github_webhook(matches).await,
+            CliCommand::GetGroups => self.execute_get_groups(matches).await,
+            CliCommand::CreateGroup => self.execute_create_group(matches).await,
+            CliCommand::UpdateGroup => self.execute_update_group(matches).await,
+            CliCommand::DeleteGroup => self.execute_delete_group(matches).await,
+            CliCommand::GetGroupMembers => self.execute_get_group_members(matches).await,
+            CliCommand::ListJobs => self.execute_list_jobs(matches).await,
             CliCommand::MagicLinkExchange => self.execute_magic_link_exchange(matches).await,
             CliCommand::MagicLinkSend => self.execute_magic_link_send(matches).await,
             CliCommand::AuthzCodeRedirect => self.execute_authz_code_redirect(matches).await,
@@ -1840,31 +2885,883 @@ impl<T: CliConfig> Cli<T> {
             CliCommand::ViewRfdMeta => self.execute_view_rfd_meta(matches).await,
             CliCommand::ViewRfdAttr => self.execute_view_rfd_attr(matches).await,
             CliCommand::SetRfdAttr => self.execute_set_rfd_attr(matches).await,
+            CliCommand::ListRfdBacklinks => self.execute_list_rfd_backlinks(matches).await,
             CliCommand::SetRfdContent => self.execute_set_rfd_content(matches).await,
             CliCommand::ViewRfdDiscussion => self.execute_view_rfd_discussion(matches).await,
+            CliCommand::ViewRfdFeed => self.execute_view_rfd_feed(matches).await,
+            CliCommand::ListRfdFiles => self.execute_list_rfd_files(matches).await,
+            CliCommand::ReplaceRfdFile => self.execute_replace_rfd_file(matches).await,
+            CliCommand::CreateRfdFile => self.execute_create_rfd_file(matches).await,
+            CliCommand::DeleteRfdFile => self.execute_delete_rfd_file(matches).await,
+            CliCommand::ViewRfdHtml => self.execute_view_rfd_html(matches).await,
             CliCommand::ViewRfdPdf => self.execute_view_rfd_pdf(matches).await,
+            CliCommand::PreviewRfd => self.execute_preview_rfd(matches).await,
             CliCommand::ViewRfd => self.execute_view_rfd(matches).await,
             CliCommand::SetRfdDocument => self.execute_set_rfd_document(matches).await,
+            CliCommand::ListRfdReferences => self.execute_list_rfd_references(matches).await,
             CliCommand::ListRfdRevisions => self.execute_list_rfd_revisions(matches).await,
+            CliCommand::ViewRfdRevisionDiff => self.execute_view_rfd_revision_diff(matches).await,
             CliCommand::ViewRfdRevisionMeta => self.execute_view_rfd_revision_meta(matches).await,
             CliCommand::UpdateRfdRevision => self.execute_update_rfd_revision(matches).await,
             CliCommand::ViewRfdRevisionAttr => self.execute_view_rfd_revision_attr(matches).await,
             CliCommand::ViewRfdRevisionDiscussion => {
                 self.execute_view_rfd_revision_discussion(matches).await
             }
+            CliCommand::ViewRfdRevisionHtml => self.execute_view_rfd_revision_html(matches).await,
             CliCommand::ViewRfdRevisionPdf => self.execute_view_rfd_revision_pdf(matches).await,
             CliCommand::ViewRfdRevision => self.execute_view_rfd_revision(matches).await,
             CliCommand::DiscussRfd => self.execute_discuss_rfd(matches).await,
             CliCommand::PublishRfd => self.execute_publish_rfd(matches).await,
             CliCommand::UpdateRfdVisibility => self.execute_update_rfd_visibility(matches).await,
+            CliCommand::WatchRfd => self.execute_watch_rfd(matches).await,
+            CliCommand::ExportRfds => self.execute_export_rfds(matches).await,
+            CliCommand::WatchRfdLabel => self.execute_watch_rfd_label(matches).await,
+            CliCommand::LintRfd => self.execute_lint_rfd(matches).await,
+            CliCommand::PreviewRfdContent => self.execute_preview_rfd_content(matches).await,
+            CliCommand::ListRfdReservations => self.execute_list_rfd_reservations(matches).await,
+            CliCommand::HoldRfdNumbers => self.execute_hold_rfd_numbers(matches).await,
+            CliCommand::ReleaseRfdReservation => {
+                self.execute_release_rfd_reservation(matches).await
+            }
             CliCommand::SearchRfds => self.execute_search_rfds(matches).await,
+            CliCommand::ListRfdTemplates => self.execute_list_rfd_templates(matches).await,
+            CliCommand::ListRfdWatches => self.execute_list_rfd_watches(matches).await,
+            CliCommand::DeleteRfdWatch => self.execute_delete_rfd_watch(matches).await,
             CliCommand::GetSelf => self.execute_get_self(matches).await,
+            CliCommand::ListWebhookSubscriptions => {
+                self.execute_list_webhook_subscriptions(matches).await
+            }
+            CliCommand::CreateWebhookSubscription => {
+                self.execute_create_webhook_subscription(matches).await
+            }
+            CliCommand::ViewWebhookSubscription => {
+                self.execute_view_webhook_subscription(matches).await
+            }
+            CliCommand::UpdateWebhookSubscription => {
+                self.execute_update_webhook_subscription(matches).await
+            }
+            CliCommand::DeleteWebhookSubscription => {
+                self.execute_delete_webhook_subscription(matches).await
+            }
+            CliCommand::ListWebhookSubscriptionDeliveries => {
+                self.execute_list_webhook_subscription_deliveries(matches)
+                    .await
+            }
+        }
+    }
+
+    pub async fn execute_jwks_json(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.jwks_json();
+        self.config.execute_jwks_json(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_openid_configuration(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.openid_configuration();
+        self.config
+            .execute_openid_configuration(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_list_api_users(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.list_api_users();
+        self.config.execute_list_api_users(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_create_api_user(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_api_user();
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value =
+                serde_json::from_str::<types::ApiUserUpdateParamsForRfdPermission>(&body_txt)
+                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_create_api_user(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_get_api_user(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_api_user();
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        self.config.execute_get_api_user(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_update_api_user(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.update_api_user();
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value =
+                serde_json::from_str::<types::ApiUserUpdateParamsForRfdPermission>(&body_txt)
+                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_update_api_user(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_set_api_user_contact_email(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.set_api_user_contact_email();
+        if let Some(value) = matches.get_one::<::std::string::String>("email") {
+            request = request.body_map(|body| body.email(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::ApiUserEmailUpdateParams>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_set_api_user_contact_email(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_add_api_user_to_group(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.add_api_user_to_group();
+        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
+            request = request.body_map(|body| body.group_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::AddGroupBody>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_add_api_user_to_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_remove_api_user_from_group(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.remove_api_user_from_group();
+        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
+            request = request.group_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        self.config
+            .execute_remove_api_user_from_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_link_provider(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.link_provider();
+        if let Some(value) = matches.get_one::<::std::string::String>("token") {
+            request = request.body_map(|body| body.token(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::ApiUserProviderLinkPayload>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_link_provider(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_no_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_add_api_user_permission(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.add_api_user_permission();
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value =
+                serde_json::from_str::<types::ApiUserPermissionParamsForRfdPermission>(&body_txt)
+                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_add_api_user_permission(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_remove_api_user_permission(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.remove_api_user_permission();
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value =
+                serde_json::from_str::<types::ApiUserPermissionParamsForRfdPermission>(&body_txt)
+                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_remove_api_user_permission(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_list_api_user_tokens(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.list_api_user_tokens();
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        self.config
+            .execute_list_api_user_tokens(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_create_api_user_token(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_api_user_token();
+        if let Some(value) =
+            matches.get_one::<::chrono::DateTime<::chrono::offset::Utc>>("expires-at")
+        {
+            request = request.body_map(|body| body.expires_at(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value =
+                serde_json::from_str::<types::ApiKeyCreateParamsForRfdPermission>(&body_txt)
+                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_create_api_user_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_get_api_user_token(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_api_user_token();
+        if let Some(value) = matches.get_one::<types::TypedUuidForApiKeyId>("api-key-id") {
+            request = request.api_key_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        self.config
+            .execute_get_api_user_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_delete_api_user_token(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.delete_api_user_token();
+        if let Some(value) = matches.get_one::<types::TypedUuidForApiKeyId>("api-key-id") {
+            request = request.api_key_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.user_id(value.clone());
+        }
+
+        self.config
+            .execute_delete_api_user_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_create_link_token(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_link_token();
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserProviderId>("provider-id") {
+            request = request.provider_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
+            request = request.body_map(|body| body.user_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::ApiUserLinkRequestPayload>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_create_link_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_list_audit_events(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.list_audit_events();
+        if let Some(value) = matches.get_one::<::uuid::Uuid>("caller") {
+            request = request.caller(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<i64>("limit") {
+            request = request.limit(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<i64>("offset") {
+            request = request.offset(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("rfd") {
+            request = request.rfd(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::chrono::DateTime<::chrono::offset::Utc>>("since") {
+            request = request.since(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::chrono::DateTime<::chrono::offset::Utc>>("until") {
+            request = request.until(value.clone());
+        }
+
+        self.config
+            .execute_list_audit_events(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_view_rfds_feed(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfds_feed();
+        self.config.execute_view_rfds_feed(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                todo!()
+            }
+            Err(r) => {
+                todo!()
+            }
+        }
+    }
+
+    pub async fn execute_github_webhook(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.github_webhook();
+        if let Some(value) = matches.get_one::<::std::string::String>("ref") {
+            request = request.body_map(|body| body.ref_(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::GitHubCommitPayload>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_github_webhook(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_get_groups(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_groups();
+        self.config.execute_get_groups(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_create_group(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.create_group();
+        if let Some(value) = matches.get_one::<::std::string::String>("name") {
+            request = request.body_map(|body| body.name(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value =
+                serde_json::from_str::<types::AccessGroupUpdateParamsForRfdPermission>(&body_txt)
+                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_create_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_update_group(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.update_group();
+        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
+            request = request.group_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("name") {
+            request = request.body_map(|body| body.name(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value =
+                serde_json::from_str::<types::AccessGroupUpdateParamsForRfdPermission>(&body_txt)
+                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_update_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_delete_group(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.delete_group();
+        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
+            request = request.group_id(value.clone());
+        }
+
+        self.config.execute_delete_group(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_get_group_members(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_group_members();
+        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
+            request = request.group_id(value.clone());
+        }
+
+        self.config
+            .execute_get_group_members(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_list_jobs(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.list_jobs();
+        if let Some(value) = matches.get_one::<i64>("limit") {
+            request = request.limit(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<i64>("offset") {
+            request = request.offset(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("rfd") {
+            request = request.rfd(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::JobStatus>("status") {
+            request = request.status(value.clone());
+        }
+
+        self.config.execute_list_jobs(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_magic_link_exchange(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.magic_link_exchange();
+        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkAttemptId>("attempt-id")
+        {
+            request = request.body_map(|body| body.attempt_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("channel") {
+            request = request.channel(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("recipient") {
+            request = request.body_map(|body| body.recipient(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::SecretString>("secret") {
+            request = request.body_map(|body| body.secret(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::MagicLinkExchangeRequest>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config
+            .execute_magic_link_exchange(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
         }
     }
 
-    pub async fn execute_jwks_json(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.jwks_json();
-        self.config.execute_jwks_json(matches, &mut request)?;
+    pub async fn execute_magic_link_send(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.magic_link_send();
+        if let Some(value) = matches.get_one::<::std::string::String>("channel") {
+            request = request.channel(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<i64>("expires-in") {
+            request = request.body_map(|body| body.expires_in(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::MagicLinkMedium>("medium") {
+            request = request.body_map(|body| body.medium(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("recipient") {
+            request = request.body_map(|body| body.recipient(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
+            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("scope") {
+            request = request.body_map(|body| body.scope(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::SecretString>("secret") {
+            request = request.body_map(|body| body.secret(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::MagicLinkSendRequest>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_magic_link_send(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1878,18 +3775,49 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_openid_configuration(
+    pub async fn execute_authz_code_redirect(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.openid_configuration();
+        let mut request = self.client.authz_code_redirect();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.client_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("code-challenge") {
+            request = request.code_challenge(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("code-challenge-method") {
+            request = request.code_challenge_method(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
+            request = request.redirect_uri(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("response-type") {
+            request = request.response_type(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("scope") {
+            request = request.scope(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("state") {
+            request = request.state(value.clone());
+        }
+
         self.config
-            .execute_openid_configuration(matches, &mut request)?;
+            .execute_authz_code_redirect(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                self.config.success_item(&r);
-                Ok(())
+                todo!()
             }
             Err(r) => {
                 self.config.error(&r);
@@ -1898,14 +3826,33 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_list_api_users(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.list_api_users();
-        self.config.execute_list_api_users(matches, &mut request)?;
+    pub async fn execute_authz_code_callback(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.authz_code_callback();
+        if let Some(value) = matches.get_one::<::std::string::String>("code") {
+            request = request.code(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("error") {
+            request = request.error(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("state") {
+            request = request.state(value.clone());
+        }
+
+        self.config
+            .execute_authz_code_callback(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                self.config.success_item(&r);
-                Ok(())
+                todo!()
             }
             Err(r) => {
                 self.config.error(&r);
@@ -1914,21 +3861,53 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_api_user(
+    pub async fn execute_authz_code_exchange(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.create_api_user();
+        let mut request = self.client.authz_code_exchange();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.body_map(|body| body.client_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::SecretString>("client-secret") {
+            request = request.body_map(|body| body.client_secret(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("code") {
+            request = request.body_map(|body| body.code(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("code-verifier") {
+            request = request.body_map(|body| body.code_verifier(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("grant-type") {
+            request = request.body_map(|body| body.grant_type(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
+            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<bool>("request-idp-token") {
+            request = request.request_idp_token(value.clone());
+        }
+
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value =
-                serde_json::from_str::<types::ApiUserUpdateParamsForRfdPermission>(&body_txt)
-                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::OAuthAuthzCodeExchangeBody>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config.execute_create_api_user(matches, &mut request)?;
+        self.config
+            .execute_authz_code_exchange(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1942,13 +3921,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_api_user(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_api_user();
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
+    pub async fn execute_get_device_provider(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_device_provider();
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
         }
 
-        self.config.execute_get_api_user(matches, &mut request)?;
+        self.config
+            .execute_get_device_provider(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1962,25 +3945,93 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_update_api_user(
+    pub async fn execute_device_authz(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.device_authz();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.body_map(|body| body.client_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("scope") {
+            request = request.body_map(|body| body.scope(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::DeviceAuthorizationRequest>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_device_authz(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                todo!()
+            }
+            Err(r) => {
+                todo!()
+            }
+        }
+    }
+
+    pub async fn execute_exchange_device_token(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.update_api_user();
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
+        let mut request = self.client.exchange_device_token();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.body_map(|body| body.client_id(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("device-code") {
+            request = request.body_map(|body| body.device_code(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("grant-type") {
+            request = request.body_map(|body| body.grant_type(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value =
-                serde_json::from_str::<types::ApiUserUpdateParamsForRfdPermission>(&body_txt)
-                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::DeviceTokenExchangeRequest>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config.execute_update_api_user(matches, &mut request)?;
+        self.config
+            .execute_exchange_device_token(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                todo!()
+            }
+            Err(r) => {
+                todo!()
+            }
+        }
+    }
+
+    pub async fn execute_get_web_pkce_provider(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_web_pkce_provider();
+        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
+            request = request.provider(value.clone());
+        }
+
+        self.config
+            .execute_get_web_pkce_provider(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -1994,29 +4045,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_set_api_user_contact_email(
+    pub async fn execute_list_magic_links(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.set_api_user_contact_email();
-        if let Some(value) = matches.get_one::<::std::string::String>("email") {
-            request = request.body_map(|body| body.email(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::ApiUserEmailUpdateParams>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
-        }
-
+        let mut request = self.client.list_magic_links();
         self.config
-            .execute_set_api_user_contact_email(matches, &mut request)?;
+            .execute_list_magic_links(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2030,29 +4065,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_add_api_user_to_group(
+    pub async fn execute_create_magic_link(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.add_api_user_to_group();
-        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
-            request = request.body_map(|body| body.group_id(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::AddGroupBody>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
-        }
-
+        let mut request = self.client.create_magic_link();
         self.config
-            .execute_add_api_user_to_group(matches, &mut request)?;
+            .execute_create_magic_link(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2066,21 +4085,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_remove_api_user_from_group(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.remove_api_user_from_group();
-        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
-            request = request.group_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
+    pub async fn execute_get_magic_link(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_magic_link();
+        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
+            request = request.client_id(value.clone());
         }
 
-        self.config
-            .execute_remove_api_user_from_group(matches, &mut request)?;
+        self.config.execute_get_magic_link(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2094,29 +4105,33 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_link_provider(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.link_provider();
-        if let Some(value) = matches.get_one::<::std::string::String>("token") {
-            request = request.body_map(|body| body.token(value.clone()))
+    pub async fn execute_create_magic_link_redirect_uri(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_magic_link_redirect_uri();
+        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
+            request = request.client_id(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
+            request = request.body_map(|body| body.redirect_uri(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::ApiUserProviderLinkPayload>(&body_txt)
+            let body_value = serde_json::from_str::<types::AddMagicLinkRedirectBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config.execute_link_provider(matches, &mut request)?;
+        self.config
+            .execute_create_magic_link_redirect_uri(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                self.config.success_no_item(&r);
+                self.config.success_item(&r);
                 Ok(())
             }
             Err(r) => {
@@ -2126,26 +4141,23 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_add_api_user_permission(
+    pub async fn execute_delete_magic_link_redirect_uri(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.add_api_user_permission();
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
+        let mut request = self.client.delete_magic_link_redirect_uri();
+        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
+            request = request.client_id(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value =
-                serde_json::from_str::<types::ApiUserPermissionParamsForRfdPermission>(&body_txt)
-                    .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
+        if let Some(value) =
+            matches.get_one::<types::TypedUuidForMagicLinkRedirectUriId>("redirect-uri-id")
+        {
+            request = request.redirect_uri_id(value.clone());
         }
 
         self.config
-            .execute_add_api_user_permission(matches, &mut request)?;
+            .execute_delete_magic_link_redirect_uri(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2159,26 +4171,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_remove_api_user_permission(
+    pub async fn execute_create_magic_link_secret(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.remove_api_user_permission();
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value =
-                serde_json::from_str::<types::ApiUserPermissionParamsForRfdPermission>(&body_txt)
-                    .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
+        let mut request = self.client.create_magic_link_secret();
+        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
+            request = request.client_id(value.clone());
         }
 
         self.config
-            .execute_remove_api_user_permission(matches, &mut request)?;
+            .execute_create_magic_link_secret(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2192,17 +4195,21 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_list_api_user_tokens(
+    pub async fn execute_delete_magic_link_secret(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.list_api_user_tokens();
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
+        let mut request = self.client.delete_magic_link_secret();
+        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
+            request = request.client_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkSecretId>("secret-id") {
+            request = request.secret_id(value.clone());
         }
 
         self.config
-            .execute_list_api_user_tokens(matches, &mut request)?;
+            .execute_delete_magic_link_secret(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2216,32 +4223,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_api_user_token(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.create_api_user_token();
-        if let Some(value) =
-            matches.get_one::<::chrono::DateTime<::chrono::offset::Utc>>("expires-at")
-        {
-            request = request.body_map(|body| body.expires_at(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value =
-                serde_json::from_str::<types::ApiKeyCreateParamsForRfdPermission>(&body_txt)
-                    .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
+    pub async fn execute_get_mappers(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_mappers();
+        if let Some(value) = matches.get_one::<bool>("include-depleted") {
+            request = request.include_depleted(value.clone());
         }
 
-        self.config
-            .execute_create_api_user_token(matches, &mut request)?;
+        self.config.execute_get_mappers(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2255,21 +4243,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_api_user_token(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.get_api_user_token();
-        if let Some(value) = matches.get_one::<types::TypedUuidForApiKeyId>("api-key-id") {
-            request = request.api_key_id(value.clone());
+    pub async fn execute_create_mapper(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.create_mapper();
+        if let Some(value) = matches.get_one::<i32>("max-activations") {
+            request = request.body_map(|body| body.max_activations(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("name") {
+            request = request.body_map(|body| body.name(value.clone()))
         }
 
-        self.config
-            .execute_get_api_user_token(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::CreateMapper>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_create_mapper(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2283,21 +4275,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_api_user_token(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.delete_api_user_token();
-        if let Some(value) = matches.get_one::<types::TypedUuidForApiKeyId>("api-key-id") {
-            request = request.api_key_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.user_id(value.clone());
+    pub async fn execute_delete_mapper(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.delete_mapper();
+        if let Some(value) = matches.get_one::<types::TypedUuidForMapperId>("mapper-id") {
+            request = request.mapper_id(value.clone());
         }
 
-        self.config
-            .execute_delete_api_user_token(matches, &mut request)?;
+        self.config.execute_delete_mapper(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2311,29 +4295,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_link_token(
+    pub async fn execute_list_oauth_clients(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.create_link_token();
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserProviderId>("provider-id") {
-            request = request.provider_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForUserId>("user-id") {
-            request = request.body_map(|body| body.user_id(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::ApiUserLinkRequestPayload>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
-        }
-
+        let mut request = self.client.list_oauth_clients();
         self.config
-            .execute_create_link_token(matches, &mut request)?;
+            .execute_list_oauth_clients(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2347,21 +4315,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_github_webhook(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.github_webhook();
-        if let Some(value) = matches.get_one::<::std::string::String>("ref") {
-            request = request.body_map(|body| body.ref_(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::GitHubCommitPayload>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
-        }
-
-        self.config.execute_github_webhook(matches, &mut request)?;
+    pub async fn execute_create_oauth_client(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_oauth_client();
+        self.config
+            .execute_create_oauth_client(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2375,9 +4335,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_groups(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_groups();
-        self.config.execute_get_groups(matches, &mut request)?;
+    pub async fn execute_get_oauth_client(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.get_oauth_client();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.client_id(value.clone());
+        }
+
+        self.config
+            .execute_get_oauth_client(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2391,22 +4359,29 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_group(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.create_group();
-        if let Some(value) = matches.get_one::<::std::string::String>("name") {
-            request = request.body_map(|body| body.name(value.clone()))
+    pub async fn execute_create_oauth_client_redirect_uri(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_oauth_client_redirect_uri();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.client_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
+            request = request.body_map(|body| body.redirect_uri(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value =
-                serde_json::from_str::<types::AccessGroupUpdateParamsForRfdPermission>(&body_txt)
-                    .with_context(|| format!("failed to parse {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::AddOAuthClientRedirectBody>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config.execute_create_group(matches, &mut request)?;
+        self.config
+            .execute_create_oauth_client_redirect_uri(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2420,26 +4395,23 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_update_group(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.update_group();
-        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
-            request = request.group_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("name") {
-            request = request.body_map(|body| body.name(value.clone()))
+    pub async fn execute_delete_oauth_client_redirect_uri(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.delete_oauth_client_redirect_uri();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.client_id(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value =
-                serde_json::from_str::<types::AccessGroupUpdateParamsForRfdPermission>(&body_txt)
-                    .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
+        if let Some(value) =
+            matches.get_one::<types::TypedUuidForOAuthRedirectUriId>("redirect-uri-id")
+        {
+            request = request.redirect_uri_id(value.clone());
         }
 
-        self.config.execute_update_group(matches, &mut request)?;
+        self.config
+            .execute_delete_oauth_client_redirect_uri(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2453,13 +4425,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_group(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.delete_group();
-        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
-            request = request.group_id(value.clone());
+    pub async fn execute_create_oauth_client_secret(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_oauth_client_secret();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.client_id(value.clone());
         }
 
-        self.config.execute_delete_group(matches, &mut request)?;
+        self.config
+            .execute_create_oauth_client_secret(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2473,17 +4449,21 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_group_members(
+    pub async fn execute_delete_oauth_client_secret(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.get_group_members();
-        if let Some(value) = matches.get_one::<types::TypedUuidForAccessGroupId>("group-id") {
-            request = request.group_id(value.clone());
+        let mut request = self.client.delete_oauth_client_secret();
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
+            request = request.client_id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthSecretId>("secret-id") {
+            request = request.secret_id(value.clone());
         }
 
         self.config
-            .execute_get_group_members(matches, &mut request)?;
+            .execute_delete_oauth_client_secret(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2497,21 +4477,61 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_list_jobs(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.list_jobs();
+    pub async fn execute_list_rfds(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.list_rfds();
+        if let Some(value) = matches.get_one::<::std::string::String>("author") {
+            request = request.author(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("cursor") {
+            request = request.cursor(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::SortDirection>("direction") {
+            request = request.direction(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("label") {
+            request = request.label(value.clone());
+        }
+
         if let Some(value) = matches.get_one::<i64>("limit") {
             request = request.limit(value.clone());
         }
 
+        if let Some(value) =
+            matches.get_one::<::chrono::DateTime<::chrono::offset::Utc>>("major-change-since")
+        {
+            request = request.major_change_since(value.clone());
+        }
+
         if let Some(value) = matches.get_one::<i64>("offset") {
             request = request.offset(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("rfd") {
-            request = request.rfd(value.clone());
+        if let Some(value) = matches.get_one::<types::RfdSortField>("sort") {
+            request = request.sort(value.clone());
         }
 
-        self.config.execute_list_jobs(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<::std::string::String>("state") {
+            request = request.state(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("title") {
+            request = request.title(value.clone());
+        }
+
+        if let Some(value) =
+            matches.get_one::<::chrono::DateTime<::chrono::offset::Utc>>("updated-since")
+        {
+            request = request.updated_since(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::Visibility>("visibility") {
+            request = request.visibility(value.clone());
+        }
+
+        self.config.execute_list_rfds(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2525,38 +4545,33 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_magic_link_exchange(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.magic_link_exchange();
-        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkAttemptId>("attempt-id")
-        {
-            request = request.body_map(|body| body.attempt_id(value.clone()))
+    pub async fn execute_reserve_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.reserve_rfd();
+        if let Some(value) = matches.get_one::<::std::string::String>("content") {
+            request = request.body_map(|body| body.content(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("channel") {
-            request = request.channel(value.clone());
+        if let Some(value) = matches.get_one::<i32>("number") {
+            request = request.body_map(|body| body.number(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("recipient") {
-            request = request.body_map(|body| body.recipient(value.clone()))
+        if let Some(value) = matches.get_one::<::std::string::String>("template") {
+            request = request.body_map(|body| body.template(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<types::SecretString>("secret") {
-            request = request.body_map(|body| body.secret(value.clone()))
+        if let Some(value) = matches.get_one::<::std::string::String>("title") {
+            request = request.body_map(|body| body.title(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::MagicLinkExchangeRequest>(&body_txt)
+            let body_value = serde_json::from_str::<types::ReserveRfdBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config
-            .execute_magic_link_exchange(matches, &mut request)?;
+        self.config.execute_reserve_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2570,48 +4585,41 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_magic_link_send(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.magic_link_send();
-        if let Some(value) = matches.get_one::<::std::string::String>("channel") {
-            request = request.channel(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<i64>("expires-in") {
-            request = request.body_map(|body| body.expires_in(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<types::MagicLinkMedium>("medium") {
-            request = request.body_map(|body| body.medium(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("recipient") {
-            request = request.body_map(|body| body.recipient(value.clone()))
+    pub async fn execute_view_rfd_meta(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfd_meta();
+        if let Some(value) = matches.get_one::<::std::string::String>("if-none-match") {
+            request = request.if_none_match(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
-            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("scope") {
-            request = request.body_map(|body| body.scope(value.clone()))
+        self.config.execute_view_rfd_meta(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
         }
+    }
 
-        if let Some(value) = matches.get_one::<types::SecretString>("secret") {
-            request = request.body_map(|body| body.secret(value.clone()))
+    pub async fn execute_view_rfd_attr(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfd_attr();
+        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
+            request = request.attr(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::MagicLinkSendRequest>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config.execute_magic_link_send(matches, &mut request)?;
+        self.config.execute_view_rfd_attr(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2625,49 +4633,46 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_authz_code_redirect(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.authz_code_redirect();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.client_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("code-challenge") {
-            request = request.code_challenge(value.clone());
+    pub async fn execute_set_rfd_attr(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.set_rfd_attr();
+        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
+            request = request.attr(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("code-challenge-method") {
-            request = request.code_challenge_method(value.clone());
+        if let Some(value) = matches.get_one::<types::CommitSha>("base-commit") {
+            request = request.body_map(|body| body.base_commit(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+        if let Some(value) = matches.get_one::<bool>("merge") {
+            request = request.body_map(|body| body.merge(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
-            request = request.redirect_uri(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("message") {
+            request = request.body_map(|body| body.message(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("response-type") {
-            request = request.response_type(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("scope") {
-            request = request.scope(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("value") {
+            request = request.body_map(|body| body.value(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("state") {
-            request = request.state(value.clone());
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::RfdAttrValue>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
         }
 
-        self.config
-            .execute_authz_code_redirect(matches, &mut request)?;
+        self.config.execute_set_rfd_attr(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                todo!()
+                self.config.success_item(&r);
+                Ok(())
             }
             Err(r) => {
                 self.config.error(&r);
@@ -2676,33 +4681,22 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_authz_code_callback(
+    pub async fn execute_list_rfd_backlinks(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.authz_code_callback();
-        if let Some(value) = matches.get_one::<::std::string::String>("code") {
-            request = request.code(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("error") {
-            request = request.error(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("state") {
-            request = request.state(value.clone());
+        let mut request = self.client.list_rfd_backlinks();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
         self.config
-            .execute_authz_code_callback(matches, &mut request)?;
+            .execute_list_rfd_backlinks(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                todo!()
+                self.config.success_item(&r);
+                Ok(())
             }
             Err(r) => {
                 self.config.error(&r);
@@ -2711,53 +4705,40 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_authz_code_exchange(
+    pub async fn execute_set_rfd_content(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.authz_code_exchange();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.body_map(|body| body.client_id(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<types::SecretString>("client-secret") {
-            request = request.body_map(|body| body.client_secret(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("code") {
-            request = request.body_map(|body| body.code(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("code-verifier") {
-            request = request.body_map(|body| body.code_verifier(value.clone()))
+        let mut request = self.client.set_rfd_content();
+        if let Some(value) = matches.get_one::<types::CommitSha>("base-commit") {
+            request = request.body_map(|body| body.base_commit(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("grant-type") {
-            request = request.body_map(|body| body.grant_type(value.clone()))
+        if let Some(value) = matches.get_one::<::std::string::String>("content") {
+            request = request.body_map(|body| body.content(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+        if let Some(value) = matches.get_one::<bool>("merge") {
+            request = request.body_map(|body| body.merge(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
-            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        if let Some(value) = matches.get_one::<::std::string::String>("message") {
+            request = request.body_map(|body| body.message(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<bool>("request-idp-token") {
-            request = request.request_idp_token(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::OAuthAuthzCodeExchangeBody>(&body_txt)
+            let body_value = serde_json::from_str::<types::RfdUpdateContentBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config
-            .execute_authz_code_exchange(matches, &mut request)?;
+        self.config.execute_set_rfd_content(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2771,17 +4752,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_device_provider(
+    pub async fn execute_view_rfd_discussion(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.get_device_provider();
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+        let mut request = self.client.view_rfd_discussion();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
         self.config
-            .execute_get_device_provider(matches, &mut request)?;
+            .execute_view_rfd_discussion(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2795,93 +4776,94 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_device_authz(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.device_authz();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.body_map(|body| body.client_id(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+    pub async fn execute_view_rfd_feed(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfd_feed();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("scope") {
-            request = request.body_map(|body| body.scope(value.clone()))
+        self.config.execute_view_rfd_feed(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                todo!()
+            }
+            Err(r) => {
+                todo!()
+            }
         }
+    }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::DeviceAuthorizationRequest>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
+    pub async fn execute_list_rfd_files(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.list_rfd_files();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config.execute_device_authz(matches, &mut request)?;
+        self.config.execute_list_rfd_files(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                todo!()
+                self.config.success_item(&r);
+                Ok(())
             }
             Err(r) => {
-                todo!()
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
             }
         }
     }
 
-    pub async fn execute_exchange_device_token(
+    pub async fn execute_replace_rfd_file(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.exchange_device_token();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.body_map(|body| body.client_id(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("device-code") {
-            request = request.body_map(|body| body.device_code(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("grant-type") {
-            request = request.body_map(|body| body.grant_type(value.clone()))
+        let mut request = self.client.replace_rfd_file();
+        if let Some(value) = matches.get_one::<::std::string::String>("message") {
+            request = request.message(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::DeviceTokenExchangeRequest>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
+        if let Some(value) = matches.get_one::<::std::string::String>("path") {
+            request = request.path(value.clone());
         }
 
         self.config
-            .execute_exchange_device_token(matches, &mut request)?;
+            .execute_replace_rfd_file(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
-                todo!()
+                self.config.success_item(&r);
+                Ok(())
             }
             Err(r) => {
-                todo!()
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
             }
         }
     }
 
-    pub async fn execute_get_web_pkce_provider(
+    pub async fn execute_create_rfd_file(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.get_web_pkce_provider();
-        if let Some(value) = matches.get_one::<types::OAuthProviderName>("provider") {
-            request = request.provider(value.clone());
+        let mut request = self.client.create_rfd_file();
+        if let Some(value) = matches.get_one::<::std::string::String>("message") {
+            request = request.message(value.clone());
         }
 
-        self.config
-            .execute_get_web_pkce_provider(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("path") {
+            request = request.path(value.clone());
+        }
+
+        self.config.execute_create_rfd_file(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2895,13 +4877,24 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_list_magic_links(
+    pub async fn execute_delete_rfd_file(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.list_magic_links();
-        self.config
-            .execute_list_magic_links(matches, &mut request)?;
+        let mut request = self.client.delete_rfd_file();
+        if let Some(value) = matches.get_one::<::std::string::String>("message") {
+            request = request.message(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("path") {
+            request = request.path(value.clone());
+        }
+
+        self.config.execute_delete_rfd_file(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2915,13 +4908,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_magic_link(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.create_magic_link();
-        self.config
-            .execute_create_magic_link(matches, &mut request)?;
+    pub async fn execute_view_rfd_html(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfd_html();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        self.config.execute_view_rfd_html(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2935,13 +4928,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_magic_link(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_magic_link();
-        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
-            request = request.client_id(value.clone());
+    pub async fn execute_view_rfd_pdf(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfd_pdf();
+        if let Some(value) = matches.get_one::<::std::string::String>("if-none-match") {
+            request = request.if_none_match(value.clone());
         }
 
-        self.config.execute_get_magic_link(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        self.config.execute_view_rfd_pdf(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2955,29 +4952,101 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_magic_link_redirect_uri(
+    pub async fn execute_preview_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.preview_rfd();
+        if let Some(value) = matches.get_one::<::std::string::String>("content") {
+            request = request.body_map(|body| body.content(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<types::ContentFormat>("format") {
+            request = request.body_map(|body| body.format(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::RfdPreviewBody>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
+        }
+
+        self.config.execute_preview_rfd(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_view_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfd();
+        if let Some(value) = matches.get_one::<::std::string::String>("if-none-match") {
+            request = request.if_none_match(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        self.config.execute_view_rfd(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                self.config.success_item(&r);
+                Ok(())
+            }
+            Err(r) => {
+                self.config.error(&r);
+                Err(anyhow::Error::new(r))
+            }
+        }
+    }
+
+    pub async fn execute_set_rfd_document(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.create_magic_link_redirect_uri();
-        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
-            request = request.client_id(value.clone());
+        let mut request = self.client.set_rfd_document();
+        if let Some(value) = matches.get_one::<types::CommitSha>("base-commit") {
+            request = request.body_map(|body| body.base_commit(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
-            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        if let Some(value) = matches.get_one::<::std::string::String>("document") {
+            request = request.body_map(|body| body.document(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<bool>("merge") {
+            request = request.body_map(|body| body.merge(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("message") {
+            request = request.body_map(|body| body.message(value.clone()))
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::AddMagicLinkRedirectBody>(&body_txt)
+            let body_value = serde_json::from_str::<types::RfdUpdateBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
         self.config
-            .execute_create_magic_link_redirect_uri(matches, &mut request)?;
+            .execute_set_rfd_document(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -2991,23 +5060,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_magic_link_redirect_uri(
+    pub async fn execute_list_rfd_references(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.delete_magic_link_redirect_uri();
-        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
-            request = request.client_id(value.clone());
-        }
-
-        if let Some(value) =
-            matches.get_one::<types::TypedUuidForMagicLinkRedirectUriId>("redirect-uri-id")
-        {
-            request = request.redirect_uri_id(value.clone());
+        let mut request = self.client.list_rfd_references();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
         self.config
-            .execute_delete_magic_link_redirect_uri(matches, &mut request)?;
+            .execute_list_rfd_references(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3021,17 +5084,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_magic_link_secret(
+    pub async fn execute_list_rfd_revisions(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.create_magic_link_secret();
-        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
-            request = request.client_id(value.clone());
+        let mut request = self.client.list_rfd_revisions();
+        if let Some(value) = matches.get_one::<i64>("limit") {
+            request = request.limit(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<i64>("offset") {
+            request = request.offset(value.clone());
         }
 
         self.config
-            .execute_create_magic_link_secret(matches, &mut request)?;
+            .execute_list_rfd_revisions(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3045,21 +5116,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_magic_link_secret(
+    pub async fn execute_view_rfd_revision_diff(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.delete_magic_link_secret();
-        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkId>("client-id") {
-            request = request.client_id(value.clone());
+        let mut request = self.client.view_rfd_revision_diff();
+        if let Some(value) = matches.get_one::<::std::string::String>("a") {
+            request = request.a(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<types::TypedUuidForMagicLinkSecretId>("secret-id") {
-            request = request.secret_id(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("b") {
+            request = request.b(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
         self.config
-            .execute_delete_magic_link_secret(matches, &mut request)?;
+            .execute_view_rfd_revision_diff(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3073,13 +5148,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_mappers(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_mappers();
-        if let Some(value) = matches.get_one::<bool>("include-depleted") {
-            request = request.include_depleted(value.clone());
+    pub async fn execute_view_rfd_revision_meta(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfd_revision_meta();
+        if let Some(value) = matches.get_one::<::std::string::String>("if-none-match") {
+            request = request.if_none_match(value.clone());
         }
 
-        self.config.execute_get_mappers(matches, &mut request)?;
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
+            request = request.revision(value.clone());
+        }
+
+        self.config
+            .execute_view_rfd_revision_meta(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3093,25 +5180,33 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_mapper(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.create_mapper();
-        if let Some(value) = matches.get_one::<i32>("max-activations") {
-            request = request.body_map(|body| body.max_activations(value.clone()))
+    pub async fn execute_update_rfd_revision(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.update_rfd_revision();
+        if let Some(value) = matches.get_one::<bool>("major-change") {
+            request = request.body_map(|body| body.major_change(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("name") {
-            request = request.body_map(|body| body.name(value.clone()))
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
+            request = request.revision(value.clone());
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::CreateMapper>(&body_txt)
+            let body_value = serde_json::from_str::<types::UpdateRfdAttrBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config.execute_create_mapper(matches, &mut request)?;
+        self.config
+            .execute_update_rfd_revision(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3125,53 +5220,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_mapper(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.delete_mapper();
-        if let Some(value) = matches.get_one::<types::TypedUuidForMapperId>("mapper-id") {
-            request = request.mapper_id(value.clone());
+    pub async fn execute_view_rfd_revision_attr(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.view_rfd_revision_attr();
+        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
+            request = request.attr(value.clone());
         }
 
-        self.config.execute_delete_mapper(matches, &mut request)?;
-        let result = request.send().await;
-        match result {
-            Ok(r) => {
-                self.config.success_item(&r);
-                Ok(())
-            }
-            Err(r) => {
-                self.config.error(&r);
-                Err(anyhow::Error::new(r))
-            }
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
-    }
 
-    pub async fn execute_list_oauth_clients(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.list_oauth_clients();
-        self.config
-            .execute_list_oauth_clients(matches, &mut request)?;
-        let result = request.send().await;
-        match result {
-            Ok(r) => {
-                self.config.success_item(&r);
-                Ok(())
-            }
-            Err(r) => {
-                self.config.error(&r);
-                Err(anyhow::Error::new(r))
-            }
+        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
+            request = request.revision(value.clone());
         }
-    }
 
-    pub async fn execute_create_oauth_client(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.create_oauth_client();
         self.config
-            .execute_create_oauth_client(matches, &mut request)?;
+            .execute_view_rfd_revision_attr(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3185,17 +5252,21 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_oauth_client(
+    pub async fn execute_view_rfd_revision_discussion(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.get_oauth_client();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.client_id(value.clone());
+        let mut request = self.client.view_rfd_revision_discussion();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
+            request = request.revision(value.clone());
         }
 
         self.config
-            .execute_get_oauth_client(matches, &mut request)?;
+            .execute_view_rfd_revision_discussion(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3209,29 +5280,21 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_oauth_client_redirect_uri(
+    pub async fn execute_view_rfd_revision_html(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.create_oauth_client_redirect_uri();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.client_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("redirect-uri") {
-            request = request.body_map(|body| body.redirect_uri(value.clone()))
+        let mut request = self.client.view_rfd_revision_html();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::AddOAuthClientRedirectBody>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
+        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
+            request = request.revision(value.clone());
         }
 
         self.config
-            .execute_create_oauth_client_redirect_uri(matches, &mut request)?;
+            .execute_view_rfd_revision_html(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3245,23 +5308,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_oauth_client_redirect_uri(
+    pub async fn execute_view_rfd_revision_pdf(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.delete_oauth_client_redirect_uri();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.client_id(value.clone());
+        let mut request = self.client.view_rfd_revision_pdf();
+        if let Some(value) = matches.get_one::<::std::string::String>("if-none-match") {
+            request = request.if_none_match(value.clone());
         }
 
-        if let Some(value) =
-            matches.get_one::<types::TypedUuidForOAuthRedirectUriId>("redirect-uri-id")
-        {
-            request = request.redirect_uri_id(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
+            request = request.revision(value.clone());
         }
 
         self.config
-            .execute_delete_oauth_client_redirect_uri(matches, &mut request)?;
+            .execute_view_rfd_revision_pdf(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3275,17 +5340,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_create_oauth_client_secret(
+    pub async fn execute_view_rfd_revision(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.create_oauth_client_secret();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.client_id(value.clone());
+        let mut request = self.client.view_rfd_revision();
+        if let Some(value) = matches.get_one::<::std::string::String>("if-none-match") {
+            request = request.if_none_match(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
+            request = request.revision(value.clone());
         }
 
         self.config
-            .execute_create_oauth_client_secret(matches, &mut request)?;
+            .execute_view_rfd_revision(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3299,21 +5372,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_delete_oauth_client_secret(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.delete_oauth_client_secret();
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthClientId>("client-id") {
-            request = request.client_id(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForOAuthSecretId>("secret-id") {
-            request = request.secret_id(value.clone());
+    pub async fn execute_discuss_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.discuss_rfd();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        self.config
-            .execute_delete_oauth_client_secret(matches, &mut request)?;
+        self.config.execute_discuss_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3327,9 +5392,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_list_rfds(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.list_rfds();
-        self.config.execute_list_rfds(matches, &mut request)?;
+    pub async fn execute_publish_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.publish_rfd();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
+        }
+
+        self.config.execute_publish_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3343,25 +5412,29 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_reserve_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.reserve_rfd();
-        if let Some(value) = matches.get_one::<::std::string::String>("content") {
-            request = request.body_map(|body| body.content(value.clone()))
+    pub async fn execute_update_rfd_visibility(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.update_rfd_visibility();
+        if let Some(value) = matches.get_one::<::std::string::String>("number") {
+            request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("title") {
-            request = request.body_map(|body| body.title(value.clone()))
+        if let Some(value) = matches.get_one::<types::Visibility>("visibility") {
+            request = request.body_map(|body| body.visibility(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::ReserveRfdBody>(&body_txt)
+            let body_value = serde_json::from_str::<types::RfdVisibility>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config.execute_reserve_rfd(matches, &mut request)?;
+        self.config
+            .execute_update_rfd_visibility(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3375,13 +5448,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_view_rfd_meta(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_meta();
+    pub async fn execute_watch_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.watch_rfd();
         if let Some(value) = matches.get_one::<::std::string::String>("number") {
             request = request.number(value.clone());
         }
 
-        self.config.execute_view_rfd_meta(matches, &mut request)?;
+        self.config.execute_watch_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3395,17 +5468,34 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_view_rfd_attr(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_attr();
-        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
-            request = request.attr(value.clone());
+    pub async fn execute_export_rfds(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.export_rfds();
+        if let Some(value) = matches.get_one::<bool>("history") {
+            request = request.history(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        self.config.execute_export_rfds(matches, &mut request)?;
+        let result = request.send().await;
+        match result {
+            Ok(r) => {
+                todo!()
+            }
+            Err(r) => {
+                todo!()
+            }
         }
+    }
 
-        self.config.execute_view_rfd_attr(matches, &mut request)?;
+    pub async fn execute_watch_rfd_label(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.watch_rfd_label();
+        if let Some(value) = matches.get_one::<::std::string::String>("label") {
+            request = request.label(value.clone());
+        }
+
+        self.config.execute_watch_rfd_label(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3419,33 +5509,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_set_rfd_attr(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.set_rfd_attr();
-        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
-            request = request.attr(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("message") {
-            request = request.body_map(|body| body.message(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+    pub async fn execute_lint_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.lint_rfd();
+        if let Some(value) = matches.get_one::<::std::string::String>("content") {
+            request = request.body_map(|body| body.content(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("value") {
-            request = request.body_map(|body| body.value(value.clone()))
+        if let Some(value) = matches.get_one::<i32>("number") {
+            request = request.body_map(|body| body.number(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::RfdAttrValue>(&body_txt)
+            let body_value = serde_json::from_str::<types::LintRfdBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config.execute_set_rfd_attr(matches, &mut request)?;
+        self.config.execute_lint_rfd(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3459,32 +5541,29 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_set_rfd_content(
+    pub async fn execute_preview_rfd_content(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.set_rfd_content();
+        let mut request = self.client.preview_rfd_content();
         if let Some(value) = matches.get_one::<::std::string::String>("content") {
             request = request.body_map(|body| body.content(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("message") {
-            request = request.body_map(|body| body.message(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        if let Some(value) = matches.get_one::<types::ContentFormat>("format") {
+            request = request.body_map(|body| body.format(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::RfdUpdateContentBody>(&body_txt)
+            let body_value = serde_json::from_str::<types::RfdPreviewBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
-        self.config.execute_set_rfd_content(matches, &mut request)?;
+        self.config
+            .execute_preview_rfd_content(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3498,57 +5577,29 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_view_rfd_discussion(
+    pub async fn execute_list_rfd_reservations(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_discussion();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
-        }
-
-        self.config
-            .execute_view_rfd_discussion(matches, &mut request)?;
-        let result = request.send().await;
-        match result {
-            Ok(r) => {
-                self.config.success_item(&r);
-                Ok(())
-            }
-            Err(r) => {
-                self.config.error(&r);
-                Err(anyhow::Error::new(r))
-            }
+        let mut request = self.client.list_rfd_reservations();
+        if let Some(value) = matches.get_one::<bool>("claimed") {
+            request = request.claimed(value.clone());
         }
-    }
 
-    pub async fn execute_view_rfd_pdf(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_pdf();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        if let Some(value) = matches.get_one::<::uuid::Uuid>("group-id") {
+            request = request.group_id(value.clone());
         }
 
-        self.config.execute_view_rfd_pdf(matches, &mut request)?;
-        let result = request.send().await;
-        match result {
-            Ok(r) => {
-                self.config.success_item(&r);
-                Ok(())
-            }
-            Err(r) => {
-                self.config.error(&r);
-                Err(anyhow::Error::new(r))
-            }
+        if let Some(value) = matches.get_one::<i64>("limit") {
+            request = request.limit(value.clone());
         }
-    }
 
-    pub async fn execute_view_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        if let Some(value) = matches.get_one::<i64>("offset") {
+            request = request.offset(value.clone());
         }
 
-        self.config.execute_view_rfd(matches, &mut request)?;
+        self.config
+            .execute_list_rfd_reservations(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3562,33 +5613,33 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_set_rfd_document(
+    pub async fn execute_hold_rfd_numbers(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.set_rfd_document();
-        if let Some(value) = matches.get_one::<::std::string::String>("document") {
-            request = request.body_map(|body| body.document(value.clone()))
+        let mut request = self.client.hold_rfd_numbers();
+        if let Some(value) = matches.get_one::<i32>("end") {
+            request = request.body_map(|body| body.end(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("message") {
-            request = request.body_map(|body| body.message(value.clone()))
+        if let Some(value) = matches.get_one::<::uuid::Uuid>("group-id") {
+            request = request.body_map(|body| body.group_id(value.clone()))
         }
 
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        if let Some(value) = matches.get_one::<i32>("start") {
+            request = request.body_map(|body| body.start(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::RfdUpdateBody>(&body_txt)
+            let body_value = serde_json::from_str::<types::HoldRfdNumbersBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
         self.config
-            .execute_set_rfd_document(matches, &mut request)?;
+            .execute_hold_rfd_numbers(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3602,25 +5653,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_list_rfd_revisions(
+    pub async fn execute_release_rfd_reservation(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.list_rfd_revisions();
-        if let Some(value) = matches.get_one::<i64>("limit") {
-            request = request.limit(value.clone());
-        }
-
+        let mut request = self.client.release_rfd_reservation();
         if let Some(value) = matches.get_one::<::std::string::String>("number") {
             request = request.number(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<i64>("offset") {
-            request = request.offset(value.clone());
-        }
-
         self.config
-            .execute_list_rfd_revisions(matches, &mut request)?;
+            .execute_release_rfd_reservation(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3634,21 +5677,57 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_view_rfd_revision_meta(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_revision_meta();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+    pub async fn execute_search_rfds(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.search_rfds();
+        if let Some(value) = matches.get_one::<::std::string::String>("attributes-to-crop") {
+            request = request.attributes_to_crop(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
-            request = request.revision(value.clone());
+        if let Some(value) = matches.get_one::<::std::string::String>("author") {
+            request = request.author(value.clone());
         }
 
-        self.config
-            .execute_view_rfd_revision_meta(matches, &mut request)?;
+        if let Some(value) =
+            matches.get_one::<::chrono::DateTime<::chrono::offset::Utc>>("committed-after")
+        {
+            request = request.committed_after(value.clone());
+        }
+
+        if let Some(value) =
+            matches.get_one::<::chrono::DateTime<::chrono::offset::Utc>>("committed-before")
+        {
+            request = request.committed_before(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("highlight-post-tag") {
+            request = request.highlight_post_tag(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("highlight-pre-tag") {
+            request = request.highlight_pre_tag(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("label") {
+            request = request.label(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<u32>("limit") {
+            request = request.limit(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<u32>("offset") {
+            request = request.offset(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("q") {
+            request = request.q(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<::std::string::String>("state") {
+            request = request.state(value.clone());
+        }
+
+        self.config.execute_search_rfds(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3662,33 +5741,13 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_update_rfd_revision(
+    pub async fn execute_list_rfd_templates(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.update_rfd_revision();
-        if let Some(value) = matches.get_one::<bool>("major-change") {
-            request = request.body_map(|body| body.major_change(value.clone()))
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
-            request = request.revision(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
-            let body_txt = std::fs::read_to_string(value)
-                .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::UpdateRfdAttrBody>(&body_txt)
-                .with_context(|| format!("failed to parse {}", value.display()))?;
-            request = request.body(body_value);
-        }
-
+        let mut request = self.client.list_rfd_templates();
         self.config
-            .execute_update_rfd_revision(matches, &mut request)?;
+            .execute_list_rfd_templates(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3702,25 +5761,21 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_view_rfd_revision_attr(
+    pub async fn execute_list_rfd_watches(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_revision_attr();
-        if let Some(value) = matches.get_one::<types::RfdAttrName>("attr") {
-            request = request.attr(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        let mut request = self.client.list_rfd_watches();
+        if let Some(value) = matches.get_one::<i64>("limit") {
+            request = request.limit(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
-            request = request.revision(value.clone());
+        if let Some(value) = matches.get_one::<i64>("offset") {
+            request = request.offset(value.clone());
         }
 
         self.config
-            .execute_view_rfd_revision_attr(matches, &mut request)?;
+            .execute_list_rfd_watches(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3734,21 +5789,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_view_rfd_revision_discussion(
+    pub async fn execute_delete_rfd_watch(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_revision_discussion();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
-            request = request.revision(value.clone());
+        let mut request = self.client.delete_rfd_watch();
+        if let Some(value) = matches.get_one::<types::TypedUuidForRfdWatchId>("id") {
+            request = request.id(value.clone());
         }
 
         self.config
-            .execute_view_rfd_revision_discussion(matches, &mut request)?;
+            .execute_delete_rfd_watch(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3762,21 +5813,9 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_view_rfd_revision_pdf(
-        &self,
-        matches: &::clap::ArgMatches,
-    ) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_revision_pdf();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
-            request = request.revision(value.clone());
-        }
-
-        self.config
-            .execute_view_rfd_revision_pdf(matches, &mut request)?;
+    pub async fn execute_get_self(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
+        let mut request = self.client.get_self();
+        self.config.execute_get_self(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3790,21 +5829,21 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_view_rfd_revision(
+    pub async fn execute_list_webhook_subscriptions(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.view_rfd_revision();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        let mut request = self.client.list_webhook_subscriptions();
+        if let Some(value) = matches.get_one::<i64>("limit") {
+            request = request.limit(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<types::TypedUuidForRfdRevisionId>("revision") {
-            request = request.revision(value.clone());
+        if let Some(value) = matches.get_one::<i64>("offset") {
+            request = request.offset(value.clone());
         }
 
         self.config
-            .execute_view_rfd_revision(matches, &mut request)?;
+            .execute_list_webhook_subscriptions(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3816,15 +5855,27 @@ impl<T: CliConfig> Cli<T> {
                 Err(anyhow::Error::new(r))
             }
         }
-    }
+    }
+
+    pub async fn execute_create_webhook_subscription(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.create_webhook_subscription();
+        if let Some(value) = matches.get_one::<::std::string::String>("url") {
+            request = request.body_map(|body| body.url(value.clone()))
+        }
 
-    pub async fn execute_discuss_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.discuss_rfd();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
+            let body_txt = std::fs::read_to_string(value)
+                .with_context(|| format!("failed to read {}", value.display()))?;
+            let body_value = serde_json::from_str::<types::WebhookSubscriptionBody>(&body_txt)
+                .with_context(|| format!("failed to parse {}", value.display()))?;
+            request = request.body(body_value);
         }
 
-        self.config.execute_discuss_rfd(matches, &mut request)?;
+        self.config
+            .execute_create_webhook_subscription(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3838,13 +5889,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_publish_rfd(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.publish_rfd();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+    pub async fn execute_view_webhook_subscription(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.view_webhook_subscription();
+        if let Some(value) = matches.get_one::<types::TypedUuidForWebhookSubscriptionId>("id") {
+            request = request.id(value.clone());
         }
 
-        self.config.execute_publish_rfd(matches, &mut request)?;
+        self.config
+            .execute_view_webhook_subscription(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3858,29 +5913,29 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_update_rfd_visibility(
+    pub async fn execute_update_webhook_subscription(
         &self,
         matches: &::clap::ArgMatches,
     ) -> anyhow::Result<()> {
-        let mut request = self.client.update_rfd_visibility();
-        if let Some(value) = matches.get_one::<::std::string::String>("number") {
-            request = request.number(value.clone());
+        let mut request = self.client.update_webhook_subscription();
+        if let Some(value) = matches.get_one::<types::TypedUuidForWebhookSubscriptionId>("id") {
+            request = request.id(value.clone());
         }
 
-        if let Some(value) = matches.get_one::<types::Visibility>("visibility") {
-            request = request.body_map(|body| body.visibility(value.clone()))
+        if let Some(value) = matches.get_one::<::std::string::String>("url") {
+            request = request.body_map(|body| body.url(value.clone()))
         }
 
         if let Some(value) = matches.get_one::<std::path::PathBuf>("json-body") {
             let body_txt = std::fs::read_to_string(value)
                 .with_context(|| format!("failed to read {}", value.display()))?;
-            let body_value = serde_json::from_str::<types::RfdVisibility>(&body_txt)
+            let body_value = serde_json::from_str::<types::WebhookSubscriptionBody>(&body_txt)
                 .with_context(|| format!("failed to parse {}", value.display()))?;
             request = request.body(body_value);
         }
 
         self.config
-            .execute_update_rfd_visibility(matches, &mut request)?;
+            .execute_update_webhook_subscription(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3894,33 +5949,17 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_search_rfds(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.search_rfds();
-        if let Some(value) = matches.get_one::<::std::string::String>("attributes-to-crop") {
-            request = request.attributes_to_crop(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("highlight-post-tag") {
-            request = request.highlight_post_tag(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("highlight-pre-tag") {
-            request = request.highlight_pre_tag(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<u32>("limit") {
-            request = request.limit(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<u32>("offset") {
-            request = request.offset(value.clone());
-        }
-
-        if let Some(value) = matches.get_one::<::std::string::String>("q") {
-            request = request.q(value.clone());
+    pub async fn execute_delete_webhook_subscription(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.delete_webhook_subscription();
+        if let Some(value) = matches.get_one::<types::TypedUuidForWebhookSubscriptionId>("id") {
+            request = request.id(value.clone());
         }
 
-        self.config.execute_search_rfds(matches, &mut request)?;
+        self.config
+            .execute_delete_webhook_subscription(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -3934,9 +5973,25 @@ impl<T: CliConfig> Cli<T> {
         }
     }
 
-    pub async fn execute_get_self(&self, matches: &::clap::ArgMatches) -> anyhow::Result<()> {
-        let mut request = self.client.get_self();
-        self.config.execute_get_self(matches, &mut request)?;
+    pub async fn execute_list_webhook_subscription_deliveries(
+        &self,
+        matches: &::clap::ArgMatches,
+    ) -> anyhow::Result<()> {
+        let mut request = self.client.list_webhook_subscription_deliveries();
+        if let Some(value) = matches.get_one::<types::TypedUuidForWebhookSubscriptionId>("id") {
+            request = request.id(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<i64>("limit") {
+            request = request.limit(value.clone());
+        }
+
+        if let Some(value) = matches.get_one::<i64>("offset") {
+            request = request.offset(value.clone());
+        }
+
+        self.config
+            .execute_list_webhook_subscription_deliveries(matches, &mut request)?;
         let result = request.send().await;
         match result {
             Ok(r) => {
@@ -4107,6 +6162,22 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_list_audit_events(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListAuditEvents,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_view_rfds_feed(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ViewRfdsFeed,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_github_webhook(
         &self,
         matches: &::clap::ArgMatches,
@@ -4411,6 +6482,14 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_list_rfd_backlinks(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListRfdBacklinks,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_set_rfd_content(
         &self,
         matches: &::clap::ArgMatches,
@@ -4427,6 +6506,54 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_view_rfd_feed(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ViewRfdFeed,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_list_rfd_files(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListRfdFiles,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_replace_rfd_file(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ReplaceRfdFile,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_create_rfd_file(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::CreateRfdFile,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_delete_rfd_file(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::DeleteRfdFile,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_view_rfd_html(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ViewRfdHtml,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_view_rfd_pdf(
         &self,
         matches: &::clap::ArgMatches,
@@ -4435,6 +6562,14 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_preview_rfd(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::PreviewRfd,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_view_rfd(
         &self,
         matches: &::clap::ArgMatches,
@@ -4451,6 +6586,14 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_list_rfd_references(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListRfdReferences,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_list_rfd_revisions(
         &self,
         matches: &::clap::ArgMatches,
@@ -4459,6 +6602,14 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_view_rfd_revision_diff(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ViewRfdRevisionDiff,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_view_rfd_revision_meta(
         &self,
         matches: &::clap::ArgMatches,
@@ -4491,6 +6642,14 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_view_rfd_revision_html(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ViewRfdRevisionHtml,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_view_rfd_revision_pdf(
         &self,
         matches: &::clap::ArgMatches,
@@ -4531,6 +6690,70 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_watch_rfd(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::WatchRfd,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_export_rfds(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ExportRfds,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_watch_rfd_label(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::WatchRfdLabel,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_lint_rfd(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::LintRfd,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_preview_rfd_content(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::PreviewRfdContent,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_list_rfd_reservations(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListRfdReservations,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_hold_rfd_numbers(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::HoldRfdNumbers,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_release_rfd_reservation(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ReleaseRfdReservation,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_search_rfds(
         &self,
         matches: &::clap::ArgMatches,
@@ -4539,6 +6762,30 @@ pub trait CliConfig {
         Ok(())
     }
 
+    fn execute_list_rfd_templates(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListRfdTemplates,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_list_rfd_watches(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListRfdWatches,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_delete_rfd_watch(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::DeleteRfdWatch,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn execute_get_self(
         &self,
         matches: &::clap::ArgMatches,
@@ -4546,6 +6793,54 @@ pub trait CliConfig {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn execute_list_webhook_subscriptions(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListWebhookSubscriptions,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_create_webhook_subscription(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::CreateWebhookSubscription,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_view_webhook_subscription(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ViewWebhookSubscription,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_update_webhook_subscription(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::UpdateWebhookSubscription,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_delete_webhook_subscription(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::DeleteWebhookSubscription,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_list_webhook_subscription_deliveries(
+        &self,
+        matches: &::clap::ArgMatches,
+        request: &mut builder::ListWebhookSubscriptionDeliveries,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -4567,6 +6862,8 @@ pub enum CliCommand {
     GetApiUserToken,
     DeleteApiUserToken,
     CreateLinkToken,
+    ListAuditEvents,
+    ViewRfdsFeed,
     GithubWebhook,
     GetGroups,
     CreateGroup,
@@ -4605,23 +6902,51 @@ pub enum CliCommand {
     ViewRfdMeta,
     ViewRfdAttr,
     SetRfdAttr,
+    ListRfdBacklinks,
     SetRfdContent,
     ViewRfdDiscussion,
+    ViewRfdFeed,
+    ListRfdFiles,
+    ReplaceRfdFile,
+    CreateRfdFile,
+    DeleteRfdFile,
+    ViewRfdHtml,
     ViewRfdPdf,
+    PreviewRfd,
     ViewRfd,
     SetRfdDocument,
+    ListRfdReferences,
     ListRfdRevisions,
+    ViewRfdRevisionDiff,
     ViewRfdRevisionMeta,
     UpdateRfdRevision,
     ViewRfdRevisionAttr,
     ViewRfdRevisionDiscussion,
+    ViewRfdRevisionHtml,
     ViewRfdRevisionPdf,
     ViewRfdRevision,
     DiscussRfd,
     PublishRfd,
     UpdateRfdVisibility,
+    WatchRfd,
+    ExportRfds,
+    WatchRfdLabel,
+    LintRfd,
+    PreviewRfdContent,
+    ListRfdReservations,
+    HoldRfdNumbers,
+    ReleaseRfdReservation,
     SearchRfds,
+    ListRfdTemplates,
+    ListRfdWatches,
+    DeleteRfdWatch,
     GetSelf,
+    ListWebhookSubscriptions,
+    CreateWebhookSubscription,
+    ViewWebhookSubscription,
+    UpdateWebhookSubscription,
+    DeleteWebhookSubscription,
+    ListWebhookSubscriptionDeliveries,
 }
 
 impl CliCommand {
@@ -4644,6 +6969,8 @@ impl CliCommand {
             CliCommand::GetApiUserToken,
             CliCommand::DeleteApiUserToken,
             CliCommand::CreateLinkToken,
+            CliCommand::ListAuditEvents,
+            CliCommand::ViewRfdsFeed,
             CliCommand::GithubWebhook,
             CliCommand::GetGroups,
             CliCommand::CreateGroup,
@@ -4682,23 +7009,51 @@ impl CliCommand {
             CliCommand::ViewRfdMeta,
             CliCommand::ViewRfdAttr,
             CliCommand::SetRfdAttr,
+            CliCommand::ListRfdBacklinks,
             CliCommand::SetRfdContent,
             CliCommand::ViewRfdDiscussion,
+            CliCommand::ViewRfdFeed,
+            CliCommand::ListRfdFiles,
+            CliCommand::ReplaceRfdFile,
+            CliCommand::CreateRfdFile,
+            CliCommand::DeleteRfdFile,
+            CliCommand::ViewRfdHtml,
             CliCommand::ViewRfdPdf,
+            CliCommand::PreviewRfd,
             CliCommand::ViewRfd,
             CliCommand::SetRfdDocument,
+            CliCommand::ListRfdReferences,
             CliCommand::ListRfdRevisions,
+            CliCommand::ViewRfdRevisionDiff,
             CliCommand::ViewRfdRevisionMeta,
             CliCommand::UpdateRfdRevision,
             CliCommand::ViewRfdRevisionAttr,
             CliCommand::ViewRfdRevisionDiscussion,
+            CliCommand::ViewRfdRevisionHtml,
             CliCommand::ViewRfdRevisionPdf,
             CliCommand::ViewRfdRevision,
             CliCommand::DiscussRfd,
             CliCommand::PublishRfd,
             CliCommand::UpdateRfdVisibility,
+            CliCommand::WatchRfd,
+            CliCommand::ExportRfds,
+            CliCommand::WatchRfdLabel,
+            CliCommand::LintRfd,
+            CliCommand::PreviewRfdContent,
+            CliCommand::ListRfdReservations,
+            CliCommand::HoldRfdNumbers,
+            CliCommand::ReleaseRfdReservation,
             CliCommand::SearchRfds,
+            CliCommand::ListRfdTemplates,
+            CliCommand::ListRfdWatches,
+            CliCommand::DeleteRfdWatch,
             CliCommand::GetSelf,
+            CliCommand::ListWebhookSubscriptions,
+            CliCommand::CreateWebhookSubscription,
+            CliCommand::ViewWebhookSubscription,
+            CliCommand::UpdateWebhookSubscription,
+            CliCommand::DeleteWebhookSubscription,
+            CliCommand::ListWebhookSubscriptionDeliveries,
         ]
         .into_iter()
     }
@@ -4722,6 +7077,8 @@ impl CliCommand {
             CliCommand::GetApiUserToken => "get_api_user_token",
             CliCommand::DeleteApiUserToken => "delete_api_user_token",
             CliCommand::CreateLinkToken => "create_link_token",
+            CliCommand::ListAuditEvents => "list_audit_events",
+            CliCommand::ViewRfdsFeed => "view_rfds_feed",
             CliCommand::GithubWebhook => "github_webhook",
             CliCommand::GetGroups => "get_groups",
             CliCommand::CreateGroup => "create_group",
@@ -4760,23 +7117,51 @@ impl CliCommand {
             CliCommand::ViewRfdMeta => "view_rfd_meta",
             CliCommand::ViewRfdAttr => "view_rfd_attr",
             CliCommand::SetRfdAttr => "set_rfd_attr",
+            CliCommand::ListRfdBacklinks => "list_rfd_backlinks",
             CliCommand::SetRfdContent => "set_rfd_content",
             CliCommand::ViewRfdDiscussion => "view_rfd_discussion",
+            CliCommand::ViewRfdFeed => "view_rfd_feed",
+            CliCommand::ListRfdFiles => "list_rfd_files",
+            CliCommand::ReplaceRfdFile => "replace_rfd_file",
+            CliCommand::CreateRfdFile => "create_rfd_file",
+            CliCommand::DeleteRfdFile => "delete_rfd_file",
+            CliCommand::ViewRfdHtml => "view_rfd_html",
             CliCommand::ViewRfdPdf => "view_rfd_pdf",
+            CliCommand::PreviewRfd => "preview_rfd",
             CliCommand::ViewRfd => "view_rfd",
             CliCommand::SetRfdDocument => "set_rfd_document",
+            CliCommand::ListRfdReferences => "list_rfd_references",
             CliCommand::ListRfdRevisions => "list_rfd_revisions",
+            CliCommand::ViewRfdRevisionDiff => "view_rfd_revision_diff",
             CliCommand::ViewRfdRevisionMeta => "view_rfd_revision_meta",
             CliCommand::UpdateRfdRevision => "update_rfd_revision",
             CliCommand::ViewRfdRevisionAttr => "view_rfd_revision_attr",
             CliCommand::ViewRfdRevisionDiscussion => "view_rfd_revision_discussion",
+            CliCommand::ViewRfdRevisionHtml => "view_rfd_revision_html",
             CliCommand::ViewRfdRevisionPdf => "view_rfd_revision_pdf",
             CliCommand::ViewRfdRevision => "view_rfd_revision",
             CliCommand::DiscussRfd => "discuss_rfd",
             CliCommand::PublishRfd => "publish_rfd",
             CliCommand::UpdateRfdVisibility => "update_rfd_visibility",
+            CliCommand::WatchRfd => "watch_rfd",
+            CliCommand::ExportRfds => "export_rfds",
+            CliCommand::WatchRfdLabel => "watch_rfd_label",
+            CliCommand::LintRfd => "lint_rfd",
+            CliCommand::PreviewRfdContent => "preview_rfd_content",
+            CliCommand::ListRfdReservations => "list_rfd_reservations",
+            CliCommand::HoldRfdNumbers => "hold_rfd_numbers",
+            CliCommand::ReleaseRfdReservation => "release_rfd_reservation",
             CliCommand::SearchRfds => "search_rfds",
+            CliCommand::ListRfdTemplates => "list_rfd_templates",
+            CliCommand::ListRfdWatches => "list_rfd_watches",
+            CliCommand::DeleteRfdWatch => "delete_rfd_watch",
             CliCommand::GetSelf => "get_self",
+            CliCommand::ListWebhookSubscriptions => "list_webhook_subscriptions",
+            CliCommand::CreateWebhookSubscription => "create_webhook_subscription",
+            CliCommand::ViewWebhookSubscription => "view_webhook_subscription",
+            CliCommand::UpdateWebhookSubscription => "update_webhook_subscription",
+            CliCommand::DeleteWebhookSubscription => "delete_webhook_subscription",
+            CliCommand::ListWebhookSubscriptionDeliveries => "list_webhook_subscription_deliveries",
         }
     }
 }
@@ -57,6 +57,10 @@ fn cmd_path<'a>(cmd: &CliCommand) -> Option<&'a str> {
         CliCommand::ViewRfdAttr => Some("attr"),
         CliCommand::ViewRfdDiscussion => Some("discussion"),
         CliCommand::ViewRfdPdf => Some("pdf"),
+        CliCommand::ViewRfdHtml => Some("html"),
+        CliCommand::ViewRfdRevisionDiff => Some("diff"),
+        CliCommand::ListRfdReferences => Some("references"),
+        CliCommand::ListRfdBacklinks => Some("backlinks"),
         CliCommand::SearchRfds => Some("search"),
 
         CliCommand::ListRfdRevisions => Some("revision list"),
@@ -66,9 +70,14 @@ fn cmd_path<'a>(cmd: &CliCommand) -> Option<&'a str> {
         CliCommand::ViewRfdRevisionAttr => Some("revision attr"),
         CliCommand::ViewRfdRevisionDiscussion => Some("revision discussion"),
         CliCommand::ViewRfdRevisionPdf => Some("revision pdf"),
+        CliCommand::ViewRfdRevisionHtml => Some("revision html"),
         CliCommand::UpdateRfdRevision => Some("revision update"),
 
+        CliCommand::PreviewRfd => Some("preview rfd"),
+        CliCommand::PreviewRfdContent => Some("preview content"),
+
         CliCommand::ReserveRfd => Some("reserve"),
+        CliCommand::ListRfdTemplates => Some("templates"),
 
         CliCommand::SetRfdAttr => Some("edit attr"),
         CliCommand::SetRfdContent => Some("edit content"),
@@ -80,6 +89,28 @@ fn cmd_path<'a>(cmd: &CliCommand) -> Option<&'a str> {
         // Job commands
         CliCommand::ListJobs => Some("job list"),
 
+        // Audit commands
+        CliCommand::ListAuditEvents => Some("audit list"),
+
+        // Webhook subscription commands
+        CliCommand::ListWebhookSubscriptions => Some("webhook list"),
+        CliCommand::CreateWebhookSubscription => Some("webhook create"),
+        CliCommand::ViewWebhookSubscription => Some("webhook get"),
+        CliCommand::UpdateWebhookSubscription => Some("webhook update"),
+        CliCommand::DeleteWebhookSubscription => Some("webhook delete"),
+        CliCommand::ListWebhookSubscriptionDeliveries => Some("webhook deliveries"),
+
+        // Watch commands
+        CliCommand::ListRfdWatches => Some("watch list"),
+        CliCommand::WatchRfd => Some("watch rfd"),
+        CliCommand::WatchRfdLabel => Some("watch label"),
+        CliCommand::DeleteRfdWatch => Some("watch delete"),
+
+        // Reservation commands
+        CliCommand::ListRfdReservations => Some("reservation list"),
+        CliCommand::HoldRfdNumbers => Some("reservation hold"),
+        CliCommand::ReleaseRfdReservation => Some("reservation release"),
+
         // User commands
         CliCommand::CreateApiUser => Some("sys user create"),
         CliCommand::CreateApiUserToken => Some("sys user token create"),
@@ -134,6 +165,18 @@ fn cmd_path<'a>(cmd: &CliCommand) -> Option<&'a str> {
         CliCommand::CreateMagicLinkSecret => Some("sys mlink secret create"),
         CliCommand::DeleteMagicLinkSecret => Some("sys mlink secret delete"),
 
+        // Exports are handled separately
+        CliCommand::ExportRfds => None,
+
+        // Linting is handled separately
+        CliCommand::LintRfd => None,
+
+        // Supporting files are handled separately
+        CliCommand::ListRfdFiles => None,
+        CliCommand::CreateRfdFile => None,
+        CliCommand::ReplaceRfdFile => None,
+        CliCommand::DeleteRfdFile => None,
+
         // Authentication is handled separately
         CliCommand::ExchangeDeviceToken => None,
         CliCommand::DeviceAuthz => None,
@@ -147,6 +190,8 @@ fn cmd_path<'a>(cmd: &CliCommand) -> Option<&'a str> {
         CliCommand::AuthzCodeCallback => None,
         CliCommand::AuthzCodeExchange => None,
         CliCommand::GithubWebhook => None,
+        CliCommand::ViewRfdsFeed => None,
+        CliCommand::ViewRfdFeed => None,
         CliCommand::OpenidConfiguration => None,
         CliCommand::JwksJson => None,
     }
@@ -205,6 +250,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     cmd = cmd.subcommand(Auth::<LoginProvider>::command());
     cmd = cmd.subcommand(ConfigCmd::command());
+    cmd = cmd.subcommand(cmd::export::ExportCmd::command());
+    cmd = cmd.subcommand(cmd::file::FileCmd::command());
+    cmd = cmd.subcommand(cmd::lint::LintCmd::command());
     cmd = cmd.subcommand(cmd::shortcut::ShortcutCmd::command());
     cmd = cmd.subcommand(cmd::version::VersionCmd::command());
 
@@ -242,6 +290,24 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 .run(&mut ctx)
                 .await?;
         }
+        Some(("export", sub_matches)) => {
+            cmd::export::ExportCmd::from_arg_matches(sub_matches)
+                .unwrap()
+                .run(&mut ctx)
+                .await?;
+        }
+        Some(("file", sub_matches)) => {
+            cmd::file::FileCmd::from_arg_matches(sub_matches)
+                .unwrap()
+                .run(&mut ctx)
+                .await?;
+        }
+        Some(("lint", sub_matches)) => {
+            cmd::lint::LintCmd::from_arg_matches(sub_matches)
+                .unwrap()
+                .run(&mut ctx)
+                .await?;
+        }
         Some(("shortcut", sub_matches)) => {
             cmd::shortcut::ShortcutCmd::from_arg_matches(sub_matches)
                 .unwrap()
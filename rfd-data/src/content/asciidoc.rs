@@ -264,19 +264,22 @@ impl<'a> RfdDocument for RfdAsciidoc<'a> {
     }
 }
 
+/// The authors of an RFD as listed on its author line
 #[derive(Debug)]
-struct RfdAuthors(Vec<RfdAuthor>);
+pub struct RfdAuthors(Vec<RfdAuthor>);
 
 #[derive(Debug)]
-struct RfdAuthor {
-    first_name: String,
-    last_name: Option<String>,
-    middle_name: Option<String>,
-    email: Option<String>,
+pub struct RfdAuthor {
+    pub first_name: String,
+    pub last_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub email: Option<String>,
 }
 
 impl RfdAuthors {
-    fn parse(line: &str) -> Option<Self> {
+    /// Parse an author line in either the Asciidoc author line format or the resolved `authors`
+    /// attribute format. Returns `None` if the line does not describe any valid authors
+    pub fn parse(line: &str) -> Option<Self> {
         // Author parsing is defined by the Asciidoc documentation https://docs.asciidoctor.org/asciidoc/latest/document/author-information/#multiple-author-attributes
 
         // Ensure that the line has a single newline at its end. Otherwise it is considered invalid
@@ -324,6 +327,10 @@ impl RfdAuthors {
         Some(Self(authors))
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &RfdAuthor> {
+        self.0.iter()
+    }
+
     fn into_attr(self) -> String {
         self.0
             .into_iter()
@@ -334,6 +341,19 @@ impl RfdAuthors {
 }
 
 impl RfdAuthor {
+    /// The full name of the author without their email
+    pub fn name(&self) -> String {
+        [
+            Some(self.first_name.as_str()),
+            self.middle_name.as_deref(),
+            self.last_name.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
     fn parse(source: &str) -> Option<Self> {
         // Split on the first occurence of < which denotes the start of an email or url
         let parts = source.split('<').collect::<Vec<_>>();
@@ -543,6 +563,24 @@ mod tests {
         assert_eq!(expected, authors.0.first().unwrap().first_name);
     }
 
+    #[test]
+    fn test_author_name() {
+        let line = "First M Last <first@company.com>; Other <other@company.com>";
+
+        let authors = RfdAuthors::parse(line).unwrap();
+        let names = authors.iter().map(RfdAuthor::name).collect::<Vec<_>>();
+        let emails = authors
+            .iter()
+            .map(|author| author.email.as_deref())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["First M Last", "Other"], names);
+        assert_eq!(
+            vec![Some("first@company.com"), Some("other@company.com")],
+            emails
+        );
+    }
+
     // Reference resolution tests
 
     #[test]
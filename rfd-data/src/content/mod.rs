@@ -4,12 +4,15 @@
 
 mod asciidoc;
 mod markdown;
+mod outline;
 mod template;
 
 pub use asciidoc::RfdAsciidoc;
 pub use asciidoc::RfdAsciidocError;
+pub use asciidoc::{RfdAuthor, RfdAuthors};
 pub use markdown::RfdMarkdown;
-pub use template::{RenderableRfdTemplate, RfdTemplate, TemplateError};
+pub use outline::RfdSection;
+pub use template::{RenderableRfdTemplate, RfdTemplate, TemplateError, TemplateSyntax};
 
 use rfd_model::{schema_ext::ContentFormat, RfdRevision};
 use thiserror::Error;
@@ -77,6 +80,14 @@ impl<'a> RfdContent<'a> {
             Self::Markdown(_) => ContentFormat::Markdown,
         }
     }
+
+    /// The section headings of the document in the order that they appear
+    pub fn outline(&self) -> Vec<RfdSection> {
+        match self {
+            Self::Asciidoc(inner) => outline::asciidoc_outline(inner.raw()),
+            Self::Markdown(inner) => outline::markdown_outline(inner.raw()),
+        }
+    }
 }
 
 impl<'a> RfdDocument for RfdContent<'a> {
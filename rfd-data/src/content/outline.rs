@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use regex::{regex, Regex};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A section heading within the body of an RFD
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct RfdSection {
+    /// The depth of the section. Top level sections of the document have a level of 1
+    pub level: u8,
+    pub title: String,
+    /// The line (starting from 1) that the section heading is on
+    pub line: usize,
+}
+
+pub(super) fn asciidoc_outline(content: &str) -> Vec<RfdSection> {
+    outline(content, asciidoc_heading_pattern(), |line| {
+        // Headings are not recognized inside of listing, literal, passthrough, or comment blocks
+        ["----", "....", "++++", "////"]
+            .into_iter()
+            .find(|delimiter| line.trim_end() == *delimiter)
+    })
+}
+
+pub(super) fn markdown_outline(content: &str) -> Vec<RfdSection> {
+    outline(content, markdown_heading_pattern(), |line| {
+        ["```", "~~~"]
+            .into_iter()
+            .find(|fence| line.trim_start().starts_with(fence))
+    })
+}
+
+fn outline(
+    content: &str,
+    heading: &Regex,
+    block_delimiter: impl Fn(&str) -> Option<&'static str>,
+) -> Vec<RfdSection> {
+    let mut sections = vec![];
+    let mut block: Option<&str> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        match (block, block_delimiter(line)) {
+            (None, Some(delimiter)) => block = Some(delimiter),
+            (Some(open), Some(delimiter)) if open == delimiter => block = None,
+            (None, None) => {
+                if let Some(captures) = heading.captures(line) {
+                    let (_, [marker, title]) = captures.extract();
+                    sections.push(RfdSection {
+                        // The document title uses a single marker, so sections start at two
+                        level: (marker.len() - 1) as u8,
+                        title: title.trim().to_string(),
+                        line: index + 1,
+                    });
+                }
+            }
+            _ => (),
+        }
+    }
+
+    sections
+}
+
+fn asciidoc_heading_pattern() -> &'static Regex {
+    regex!(r"^(={2,6})[ \t]+(\S.*)$")
+}
+
+fn markdown_heading_pattern() -> &'static Regex {
+    regex!(r"^(#{2,6})[ \t]+(\S.*?)[ \t#]*$")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{asciidoc_outline, markdown_outline, RfdSection};
+
+    fn section(level: u8, title: &str, line: usize) -> RfdSection {
+        RfdSection {
+            level,
+            title: title.to_string(),
+            line,
+        }
+    }
+
+    #[test]
+    fn test_asciidoc_outline() {
+        let content = "= RFD 123 Title\n\n== Background\n\n=== History\n\n----\n== Not a heading\n----\n\n==Also not a heading\n\n== Proposal\n";
+
+        assert_eq!(
+            vec![
+                section(1, "Background", 3),
+                section(2, "History", 5),
+                section(1, "Proposal", 13),
+            ],
+            asciidoc_outline(content)
+        );
+    }
+
+    #[test]
+    fn test_markdown_outline() {
+        let content =
+            "# RFD 123 Title\n\n## Background ##\n\n```\n## Not a heading\n```\n\n### Details\n";
+
+        assert_eq!(
+            vec![section(1, "Background", 3), section(2, "Details", 9)],
+            markdown_outline(content)
+        );
+    }
+}
@@ -2,29 +2,55 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use chrono::{DateTime, NaiveDate, Utc};
+use minijinja::{Environment, Error as JinjaError, ErrorKind, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
 
+use crate::RfdNumber;
+
 #[derive(Debug, Error)]
 pub enum TemplateError {
+    #[error("Template could not be parsed")]
+    Invalid(#[source] JinjaError),
     #[error("Template is missing some of the required values")]
     MissingRequiredFields {
         template: RfdTemplate,
         values: Vec<String>,
     },
+    #[error("Failed to render template")]
+    Render(#[source] JinjaError),
+}
+
+/// The syntax that a template is written in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateSyntax {
+    /// Required fields are substituted wherever `{field}` appears. All other text is left as is
+    #[default]
+    Placeholder,
+    /// A Jinja template. Fields that are not required may be left unset, and the `rfd_number`,
+    /// `date`, and `split_list` filters along with a `now()` function are available
+    Jinja,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Default)]
 pub struct RfdTemplate {
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    syntax: TemplateSyntax,
     template: String,
+    /// Values that are set ahead of rendering. Configured values act as defaults that can be
+    /// overridden when the template is filled in
     #[serde(default)]
     values: HashMap<String, String>,
     required_fields: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
-pub struct RenderableRfdTemplate(RfdTemplate);
+pub struct RenderableRfdTemplate(String);
 
 impl RfdTemplate {
     pub fn field(mut self, field: String, value: String) -> Self {
@@ -32,17 +58,52 @@ impl RfdTemplate {
         self
     }
 
-    pub fn build(self) -> Result<RenderableRfdTemplate, TemplateError> {
-        let set_fields = self.values.keys().collect::<Vec<_>>();
-        let missing_fields = self
-            .required_fields
+    /// A short human readable explanation of what the template should be used for
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn required_fields(&self) -> &[String] {
+        &self.required_fields
+    }
+
+    /// The required fields that have not yet been assigned a value
+    pub fn missing_fields(&self) -> Vec<String> {
+        self.required_fields
             .iter()
-            .filter(|field| !set_fields.contains(field))
+            .filter(|field| !self.values.contains_key(*field))
             .cloned()
-            .collect::<Vec<_>>();
+            .collect()
+    }
+
+    /// Check that the template can be parsed. This does not require any values to be set
+    pub fn validate(&self) -> Result<(), TemplateError> {
+        let source = self.source();
+        environment()
+            .template_from_str(&source)
+            .map_err(TemplateError::Invalid)?;
+        Ok(())
+    }
+
+    pub fn build(self) -> Result<RenderableRfdTemplate, TemplateError> {
+        let missing_fields = self.missing_fields();
 
         if missing_fields.is_empty() {
-            Ok(RenderableRfdTemplate(self))
+            let source = self.source();
+            let env = environment();
+            let template = env
+                .template_from_str(&source)
+                .map_err(TemplateError::Invalid)?;
+
+            let rendered = match self.syntax {
+                TemplateSyntax::Placeholder => template.render(minijinja::context! {
+                    fields => &self.values,
+                }),
+                TemplateSyntax::Jinja => template.render(&self.values),
+            }
+            .map_err(TemplateError::Render)?;
+
+            Ok(RenderableRfdTemplate(rendered))
         } else {
             Err(TemplateError::MissingRequiredFields {
                 template: self,
@@ -50,16 +111,213 @@ impl RfdTemplate {
             })
         }
     }
+
+    /// The Jinja source for this template. Placeholder templates are translated so that each
+    /// `{field}` of a required field becomes a lookup of that field, and all other text is
+    /// emitted verbatim
+    fn source(&self) -> String {
+        match self.syntax {
+            TemplateSyntax::Placeholder => {
+                placeholder_source(&self.template, &self.required_fields)
+            }
+            TemplateSyntax::Jinja => self.template.clone(),
+        }
+    }
 }
 
 impl RenderableRfdTemplate {
     pub fn render(self) -> String {
-        let mut rendered = self.0.template;
+        self.0
+    }
+}
+
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+
+    // Templates are rendered in to files, and the final newline of a file should be preserved
+    env.set_keep_trailing_newline(true);
+    env.add_filter("rfd_number", rfd_number_filter);
+    env.add_filter("date", date_filter);
+    env.add_filter("split_list", split_list_filter);
+    env.add_function("now", || Utc::now().to_rfc3339());
+
+    env
+}
+
+/// Format an RFD number in its expanded form with leading 0s
+fn rfd_number_filter(value: Value) -> Result<String, JinjaError> {
+    let number = match value.as_i64() {
+        Some(number) => i32::try_from(number).ok(),
+        None => value
+            .as_str()
+            .and_then(|value| value.trim().parse::<i32>().ok()),
+    }
+    .ok_or_else(|| {
+        JinjaError::new(
+            ErrorKind::InvalidOperation,
+            format!("{} is not a valid RFD number", value),
+        )
+    })?;
+
+    Ok(RfdNumber::from(number).as_number_string())
+}
+
+/// Format an RFC 3339 timestamp or a YYYY-MM-DD date. Defaults to formatting as YYYY-MM-DD
+fn date_filter(value: String, format: Option<String>) -> Result<String, JinjaError> {
+    let format = format.as_deref().unwrap_or("%Y-%m-%d");
+
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(&value) {
+        Ok(timestamp.format(format).to_string())
+    } else if let Ok(date) = NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+        Ok(date.format(format).to_string())
+    } else {
+        Err(JinjaError::new(
+            ErrorKind::InvalidOperation,
+            format!("{} is not a valid date", value),
+        ))
+    }
+}
+
+/// Split a delimited list of values, dropping any empty entries. Defaults to splitting on commas
+fn split_list_filter(value: String, separator: Option<String>) -> Vec<String> {
+    value
+        .split(separator.as_deref().unwrap_or(","))
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| item.to_string())
+        .collect()
+}
+
+fn placeholder_source(template: &str, fields: &[String]) -> String {
+    let mut source = String::new();
+    let mut literal_start = 0;
+    let mut position = 0;
+
+    while let Some(c) = template[position..].chars().next() {
+        let field = (c == '{')
+            .then(|| {
+                fields.iter().find(|field| {
+                    template[position + 1..].starts_with(field.as_str())
+                        && template[position + 1 + field.len()..].starts_with('}')
+                })
+            })
+            .flatten();
+
+        if let Some(field) = field {
+            push_literal(&mut source, &template[literal_start..position]);
+            source.push_str(&format!(
+                "{{{{ fields[\"{}\"] }}}}",
+                field.replace('\\', "\\\\").replace('"', "\\\"")
+            ));
+            position += field.len() + 2;
+            literal_start = position;
+        } else {
+            position += c.len_utf8();
+        }
+    }
+
+    push_literal(&mut source, &template[literal_start..]);
+    source
+}
+
+fn push_literal(source: &mut String, literal: &str) {
+    if !literal.is_empty() {
+        // A raw block ends at the first endraw tag, so any that appear in the literal are split
+        // across two raw blocks
+        source.push_str("{% raw %}");
+        source.push_str(&literal.replace("endraw", "end{% endraw %}{% raw %}raw"));
+        source.push_str("{% endraw %}");
+    }
+}
 
-        for field in self.0.required_fields {
-            rendered = rendered.replace(&format!("{{{}}}", field), self.0.values.get(&field).expect("Renderable template is missing a required field. This is a bug, please report."));
+#[cfg(test)]
+mod tests {
+    use super::{RfdTemplate, TemplateError, TemplateSyntax};
+
+    fn template(syntax: TemplateSyntax, template: &str, required_fields: &[&str]) -> RfdTemplate {
+        RfdTemplate {
+            syntax,
+            template: template.to_string(),
+            required_fields: required_fields.iter().map(|f| f.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn renders_placeholder_templates_unchanged() {
+        let rendered = template(
+            TemplateSyntax::Placeholder,
+            ":showtitle:\n:authors: {{ not jinja }} {%\n:state: {unknown}\n\n= RFD {number} {title}\n\n{body}\n",
+            &["number", "title", "body"],
+        )
+        .field("number".to_string(), "123".to_string())
+        .field("title".to_string(), "{% raw %}A {title}".to_string())
+        .field("body".to_string(), "Contains endraw and {{ braces }}".to_string())
+        .build()
+        .unwrap()
+        .render();
+
+        assert_eq!(
+            ":showtitle:\n:authors: {{ not jinja }} {%\n:state: {unknown}\n\n= RFD 123 {% raw %}A {title}\n\nContains endraw and {{ braces }}\n",
+            rendered
+        );
+    }
+
+    #[test]
+    fn placeholder_literals_may_contain_endraw() {
+        let rendered = template(
+            TemplateSyntax::Placeholder,
+            "{% endraw %} {number}",
+            &["number"],
+        )
+        .field("number".to_string(), "1".to_string())
+        .build()
+        .unwrap()
+        .render();
+
+        assert_eq!("{% endraw %} 1", rendered);
+    }
+
+    #[test]
+    fn renders_jinja_templates_with_optional_fields_and_filters() {
+        let source = "= RFD {{ number | rfd_number }} {{ title }}\n:state: {{ state | default(\"prediscussion\") }}\n:date: {{ date | date(\"%d %B %Y\") }}\n{% if discussion %}:discussion: {{ discussion }}\n{% endif %}{% for author in authors | split_list %}* {{ author }}\n{% endfor %}";
+
+        let rendered = template(TemplateSyntax::Jinja, source, &["number", "title"])
+            .field("number".to_string(), "42".to_string())
+            .field("title".to_string(), "Templates".to_string())
+            .field("date".to_string(), "2026-10-18".to_string())
+            .field("authors".to_string(), "Alice, Bob,".to_string())
+            .build()
+            .unwrap()
+            .render();
+
+        assert_eq!(
+            "= RFD 0042 Templates\n:state: prediscussion\n:date: 18 October 2026\n* Alice\n* Bob\n",
+            rendered
+        );
+    }
+
+    #[test]
+    fn reports_missing_required_fields() {
+        let result = template(TemplateSyntax::Jinja, "{{ title }}", &["number", "title"])
+            .field("title".to_string(), "Templates".to_string())
+            .build();
+
+        match result {
+            Err(TemplateError::MissingRequiredFields { values, .. }) => {
+                assert_eq!(vec!["number".to_string()], values)
+            }
+            other => panic!("Expected missing fields, but found {:?}", other),
         }
+    }
 
-        rendered
+    #[test]
+    fn validates_template_syntax() {
+        assert!(template(TemplateSyntax::Jinja, "{% if title %}", &[])
+            .validate()
+            .is_err());
+        assert!(template(TemplateSyntax::Placeholder, "{% if title %}", &[])
+            .validate()
+            .is_ok());
     }
 }
@@ -0,0 +1,273 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use rfd_model::RfdRevision;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use similar::{Algorithm, ChangeTag, TextDiff};
+
+/// Number of unchanged lines to include around each change
+const CONTEXT_RADIUS: usize = 3;
+
+/// The differences between two revisions of an RFD. Attributes that are extracted from the RFD
+/// header are compared individually, while the document itself is compared line by line
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdDiff {
+    pub attributes: Vec<RfdAttributeChange>,
+    pub unified: String,
+    pub hunks: Vec<RfdDiffHunk>,
+}
+
+impl RfdDiff {
+    pub fn new(from: &RfdRevision, to: &RfdRevision) -> Self {
+        let attributes = [
+            (
+                RfdDiffAttribute::Title,
+                Some(from.title.as_str()),
+                Some(to.title.as_str()),
+            ),
+            (
+                RfdDiffAttribute::State,
+                from.state.as_deref(),
+                to.state.as_deref(),
+            ),
+            (
+                RfdDiffAttribute::Labels,
+                from.labels.as_deref(),
+                to.labels.as_deref(),
+            ),
+            (
+                RfdDiffAttribute::Authors,
+                from.authors.as_deref(),
+                to.authors.as_deref(),
+            ),
+            (
+                RfdDiffAttribute::Discussion,
+                from.discussion.as_deref(),
+                to.discussion.as_deref(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(attribute, from, to)| RfdAttributeChange::new(attribute, from, to))
+        .collect();
+
+        let (unified, hunks) = diff_lines(
+            &from.content,
+            &to.content,
+            &format!("a/{}", from.commit.0),
+            &format!("b/{}", to.commit.0),
+        );
+
+        Self {
+            attributes,
+            unified,
+            hunks,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.hunks.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RfdDiffAttribute {
+    Authors,
+    Discussion,
+    Labels,
+    State,
+    Title,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdAttributeChange {
+    pub attribute: RfdDiffAttribute,
+    /// Value of the attribute in the older revision
+    pub from: Option<String>,
+    /// Value of the attribute in the newer revision
+    pub to: Option<String>,
+}
+
+impl RfdAttributeChange {
+    /// Compare the values of an attribute, returning a change only if they differ. Surrounding
+    /// whitespace is not considered significant, and an empty value is treated as missing
+    pub fn new(attribute: RfdDiffAttribute, from: Option<&str>, to: Option<&str>) -> Option<Self> {
+        let from = from.map(str::trim).filter(|value| !value.is_empty());
+        let to = to.map(str::trim).filter(|value| !value.is_empty());
+
+        (from != to).then(|| Self {
+            attribute,
+            from: from.map(str::to_string),
+            to: to.map(str::to_string),
+        })
+    }
+}
+
+/// A contiguous region of changed lines along with their surrounding context. Line numbers are one
+/// based to match the unified diff representation
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdDiffHunk {
+    pub old_start: usize,
+    pub old_lines: usize,
+    pub new_start: usize,
+    pub new_lines: usize,
+    pub lines: Vec<RfdDiffLine>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RfdDiffLine {
+    pub tag: RfdDiffLineTag,
+    /// Line number of this line in the older revision, if it exists there
+    pub old_line: Option<usize>,
+    /// Line number of this line in the newer revision, if it exists there
+    pub new_line: Option<usize>,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RfdDiffLineTag {
+    Delete,
+    Equal,
+    Insert,
+}
+
+impl From<ChangeTag> for RfdDiffLineTag {
+    fn from(value: ChangeTag) -> Self {
+        match value {
+            ChangeTag::Delete => Self::Delete,
+            ChangeTag::Equal => Self::Equal,
+            ChangeTag::Insert => Self::Insert,
+        }
+    }
+}
+
+fn diff_lines(
+    from: &str,
+    to: &str,
+    from_label: &str,
+    to_label: &str,
+) -> (String, Vec<RfdDiffHunk>) {
+    let diff = TextDiff::configure()
+        .algorithm(Algorithm::Myers)
+        .diff_lines(from, to);
+
+    let unified = diff
+        .unified_diff()
+        .context_radius(CONTEXT_RADIUS)
+        .header(from_label, to_label)
+        .to_string();
+
+    let hunks = diff
+        .unified_diff()
+        .context_radius(CONTEXT_RADIUS)
+        .iter_hunks()
+        .map(|hunk| {
+            let lines = hunk
+                .iter_changes()
+                .map(|change| RfdDiffLine {
+                    tag: change.tag().into(),
+                    old_line: change.old_index().map(|index| index + 1),
+                    new_line: change.new_index().map(|index| index + 1),
+                    content: change.to_string().trim_end_matches('\n').to_string(),
+                })
+                .collect::<Vec<_>>();
+
+            let old_lines = lines.iter().filter(|line| line.old_line.is_some()).count();
+            let new_lines = lines.iter().filter(|line| line.new_line.is_some()).count();
+
+            RfdDiffHunk {
+                old_start: lines
+                    .iter()
+                    .find_map(|line| line.old_line)
+                    .unwrap_or_default(),
+                old_lines,
+                new_start: lines
+                    .iter()
+                    .find_map(|line| line.new_line)
+                    .unwrap_or_default(),
+                new_lines,
+                lines,
+            }
+        })
+        .collect();
+
+    (unified, hunks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff_lines, RfdAttributeChange, RfdDiffAttribute, RfdDiffLineTag};
+
+    #[test]
+    fn test_unchanged_attributes_are_skipped() {
+        assert!(RfdAttributeChange::new(
+            RfdDiffAttribute::State,
+            Some("discussion"),
+            Some(" discussion ")
+        )
+        .is_none());
+        assert!(RfdAttributeChange::new(RfdDiffAttribute::Discussion, None, Some("")).is_none());
+    }
+
+    #[test]
+    fn test_changed_attributes_are_reported() {
+        let change = RfdAttributeChange::new(
+            RfdDiffAttribute::State,
+            Some("discussion"),
+            Some("published"),
+        )
+        .unwrap();
+        assert_eq!(Some("discussion".to_string()), change.from);
+        assert_eq!(Some("published".to_string()), change.to);
+
+        let change =
+            RfdAttributeChange::new(RfdDiffAttribute::Labels, None, Some("api, storage")).unwrap();
+        assert_eq!(None, change.from);
+        assert_eq!(Some("api, storage".to_string()), change.to);
+    }
+
+    #[test]
+    fn test_identical_content_has_no_hunks() {
+        let content = "= RFD 123 Test\n\nBody\n";
+        let (unified, hunks) = diff_lines(content, content, "a", "b");
+        assert!(unified.is_empty());
+        assert!(hunks.is_empty());
+    }
+
+    #[test]
+    fn test_hunk_line_numbers() {
+        let from = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight\nnine\nten\n";
+        let to = "one\ntwo\nthree\nfour\nfive\nsix\nseven\nEIGHT\nnine\nten\neleven\n";
+        let (unified, hunks) = diff_lines(from, to, "a/from", "b/to");
+
+        assert!(unified.starts_with("--- a/from\n+++ b/to\n"));
+        assert!(unified.contains("-eight\n+EIGHT\n"));
+
+        assert_eq!(1, hunks.len());
+        let hunk = &hunks[0];
+        assert_eq!(5, hunk.old_start);
+        assert_eq!(6, hunk.old_lines);
+        assert_eq!(5, hunk.new_start);
+        assert_eq!(7, hunk.new_lines);
+
+        let deleted = hunk
+            .lines
+            .iter()
+            .find(|line| line.tag == RfdDiffLineTag::Delete)
+            .unwrap();
+        assert_eq!("eight", deleted.content);
+        assert_eq!(Some(8), deleted.old_line);
+        assert_eq!(None, deleted.new_line);
+
+        let inserted = hunk
+            .lines
+            .iter()
+            .filter(|line| line.tag == RfdDiffLineTag::Insert)
+            .map(|line| (line.new_line, line.content.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(Some(8), "EIGHT"), (Some(11), "eleven")], inserted);
+    }
+}
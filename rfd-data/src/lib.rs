@@ -8,6 +8,11 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 pub mod content;
+pub mod diff;
+pub mod lint;
+pub mod merge;
+pub mod references;
+pub mod state;
 
 #[derive(Debug, Copy, Clone)]
 pub struct RfdNumber(i32);
@@ -59,7 +64,7 @@ impl From<&RfdNumber> for i32 {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum RfdState {
     Abandoned,
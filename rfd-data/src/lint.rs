@@ -0,0 +1,489 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use regex::{regex, Regex};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{content::RfdAuthors, RfdNumber, RfdState};
+
+/// Header attributes that every RFD is expected to define
+static REQUIRED_ATTRIBUTES: [&str; 2] = ["state", "discussion"];
+
+/// Header attributes that are understood by the RFD tooling or by Asciidoctor when rendering
+static KNOWN_ATTRIBUTES: [&str; 19] = [
+    "authors",
+    "description",
+    "discussion",
+    "doctype",
+    "icons",
+    "imagesdir",
+    "labels",
+    "numbered",
+    "reproducible",
+    "revdate",
+    "revnumber",
+    "revremark",
+    "sectanchors",
+    "sectlinks",
+    "sectnums",
+    "showtitle",
+    "state",
+    "toc",
+    "toclevels",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RfdLintRule {
+    MissingAttribute,
+    UnknownAttribute,
+    InvalidState,
+    InvalidAuthors,
+    InvalidDiscussion,
+    MissingTitle,
+    MismatchedNumber,
+    MissingInclude,
+    MissingImage,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RfdLintSeverity {
+    Error,
+    Warning,
+}
+
+/// A single problem found in an RFD document
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct RfdLintDiagnostic {
+    pub rule: RfdLintRule,
+    pub severity: RfdLintSeverity,
+    /// The line (starting from 1) that the problem was found on. Problems that are caused by
+    /// something missing from the document do not have a line
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl RfdLintDiagnostic {
+    fn error(rule: RfdLintRule, line: Option<usize>, message: String) -> Self {
+        Self {
+            rule,
+            severity: RfdLintSeverity::Error,
+            line,
+            message,
+        }
+    }
+
+    fn warning(rule: RfdLintRule, line: Option<usize>, message: String) -> Self {
+        Self {
+            rule,
+            severity: RfdLintSeverity::Warning,
+            line,
+            message,
+        }
+    }
+}
+
+/// Checks an Asciidoc RFD document for common mistakes. Checks that depend on information from
+/// outside of the document are only performed when that information has been supplied
+#[derive(Debug, Clone, Default)]
+pub struct RfdLinter {
+    number: Option<RfdNumber>,
+    repository: Option<(String, String)>,
+    files: Option<Vec<String>>,
+    attributes: Vec<String>,
+}
+
+impl RfdLinter {
+    /// The number of the directory that the RFD is stored in. The number in the title of the RFD
+    /// must match it
+    pub fn number(mut self, number: Option<RfdNumber>) -> Self {
+        self.number = number;
+        self
+    }
+
+    /// The GitHub repository that discussion pull requests are expected to be opened against
+    pub fn repository(mut self, owner: &str, repo: &str) -> Self {
+        self.repository = Some((owner.to_string(), repo.to_string()));
+        self
+    }
+
+    /// Paths of the supporting files stored alongside the RFD, relative to its directory. Include
+    /// targets and images are only checked when the supporting files are known
+    pub fn files(mut self, files: Option<Vec<String>>) -> Self {
+        self.files = files;
+        self
+    }
+
+    /// Additional header attributes that should not be reported as unknown
+    pub fn attributes(mut self, attributes: Vec<String>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    pub fn lint(&self, content: &str) -> Vec<RfdLintDiagnostic> {
+        let lines = content.lines().collect::<Vec<_>>();
+        let title = lines.iter().position(|line| title_pattern().is_match(line));
+
+        let mut diagnostics = vec![];
+
+        self.lint_title(&lines, title, &mut diagnostics);
+        self.lint_attributes(&lines, title, &mut diagnostics);
+        self.lint_authors(&lines, title, &mut diagnostics);
+        self.lint_files(&lines, &mut diagnostics);
+
+        diagnostics.sort_by_key(|diagnostic| diagnostic.line);
+        diagnostics
+    }
+
+    fn lint_title(
+        &self,
+        lines: &[&str],
+        title: Option<usize>,
+        diagnostics: &mut Vec<RfdLintDiagnostic>,
+    ) {
+        match title {
+            Some(index) => {
+                let number = title_pattern()
+                    .captures(lines[index])
+                    .and_then(|captures| captures.get(1))
+                    .and_then(|number| number.as_str().parse::<i32>().ok());
+
+                if let (Some(expected), Some(number)) = (self.number, number)
+                    && number != i32::from(expected)
+                {
+                    diagnostics.push(RfdLintDiagnostic::error(
+                        RfdLintRule::MismatchedNumber,
+                        Some(index + 1),
+                        format!(
+                            "Title refers to RFD {}, but the document is stored as RFD {}",
+                            number, expected
+                        ),
+                    ));
+                }
+            }
+            None => diagnostics.push(RfdLintDiagnostic::error(
+                RfdLintRule::MissingTitle,
+                None,
+                "Document does not have a title".to_string(),
+            )),
+        }
+    }
+
+    fn lint_attributes(
+        &self,
+        lines: &[&str],
+        title: Option<usize>,
+        diagnostics: &mut Vec<RfdLintDiagnostic>,
+    ) {
+        let attributes = header_attributes(lines, title);
+
+        for required in REQUIRED_ATTRIBUTES {
+            if !attributes.iter().any(|(_, name, _)| *name == required) {
+                diagnostics.push(RfdLintDiagnostic::error(
+                    RfdLintRule::MissingAttribute,
+                    None,
+                    format!("Header is missing the :{}: attribute", required),
+                ));
+            }
+        }
+
+        for (index, name, value) in attributes {
+            let line = Some(index + 1);
+
+            match name {
+                "state" => {
+                    if RfdState::try_from(value).is_err() {
+                        diagnostics.push(RfdLintDiagnostic::error(
+                            RfdLintRule::InvalidState,
+                            line,
+                            format!("{:?} is not a valid RFD state", value),
+                        ));
+                    }
+                }
+                "discussion" => {
+                    if let Some(message) = self.check_discussion(value) {
+                        diagnostics.push(RfdLintDiagnostic::warning(
+                            RfdLintRule::InvalidDiscussion,
+                            line,
+                            message,
+                        ));
+                    }
+                }
+                "authors" => {
+                    if RfdAuthors::parse(value).is_none() {
+                        diagnostics.push(RfdLintDiagnostic::error(
+                            RfdLintRule::InvalidAuthors,
+                            line,
+                            format!("Unable to parse the authors {:?}", value),
+                        ));
+                    }
+                }
+                _ => {
+                    if !KNOWN_ATTRIBUTES.contains(&name)
+                        && !self.attributes.iter().any(|known| known == name)
+                    {
+                        diagnostics.push(RfdLintDiagnostic::warning(
+                            RfdLintRule::UnknownAttribute,
+                            line,
+                            format!("Unknown header attribute :{}:", name),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    fn lint_authors(
+        &self,
+        lines: &[&str],
+        title: Option<usize>,
+        diagnostics: &mut Vec<RfdLintDiagnostic>,
+    ) {
+        // The line directly following the title is the author line, unless it is empty or is the
+        // start of the attribute entries
+        if let Some(index) = title.map(|title| title + 1)
+            && let Some(line) = lines.get(index)
+            && !line.trim().is_empty()
+            && !attribute_pattern().is_match(line)
+            && RfdAuthors::parse(line).is_none()
+        {
+            diagnostics.push(RfdLintDiagnostic::error(
+                RfdLintRule::InvalidAuthors,
+                Some(index + 1),
+                format!("Unable to parse the author line {:?}", line.trim()),
+            ));
+        }
+    }
+
+    fn lint_files(&self, lines: &[&str], diagnostics: &mut Vec<RfdLintDiagnostic>) {
+        let Some(files) = &self.files else {
+            return;
+        };
+        let exists = |target: &str| {
+            let target = target.trim_start_matches("./");
+            files
+                .iter()
+                .any(|file| file.trim_start_matches("./") == target)
+        };
+
+        for (index, line) in lines.iter().enumerate() {
+            if let Some(captures) = include_pattern().captures(line) {
+                let target = &captures[1];
+                if !exists(target) {
+                    diagnostics.push(RfdLintDiagnostic::error(
+                        RfdLintRule::MissingInclude,
+                        Some(index + 1),
+                        format!("Included file {} does not exist", target),
+                    ));
+                }
+            }
+
+            for captures in image_pattern().captures_iter(line) {
+                let target = &captures[1];
+                if !target.contains("://") && !target.starts_with("data:") && !exists(target) {
+                    diagnostics.push(RfdLintDiagnostic::error(
+                        RfdLintRule::MissingImage,
+                        Some(index + 1),
+                        format!("Image {} does not exist", target),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn check_discussion(&self, value: &str) -> Option<String> {
+        // A discussion link is not expected until a pull request has been opened
+        if value.is_empty() {
+            return None;
+        }
+
+        match discussion_pattern().captures(value) {
+            Some(captures) => match &self.repository {
+                Some((owner, repo))
+                    if !captures[1].eq_ignore_ascii_case(owner)
+                        || !captures[2].eq_ignore_ascii_case(repo) =>
+                {
+                    Some(format!(
+                        "Discussion link {} is not a pull request on {}/{}",
+                        value, owner, repo
+                    ))
+                }
+                _ => None,
+            },
+            None => Some(format!(
+                "Discussion link {} is not a GitHub pull request",
+                value
+            )),
+        }
+    }
+}
+
+/// Collect the attribute entries of the document header as (line index, name, value). Entries may
+/// appear before the title, or in the block directly following the title and author line
+fn header_attributes<'a>(
+    lines: &[&'a str],
+    title: Option<usize>,
+) -> Vec<(usize, &'a str, &'a str)> {
+    let end = title
+        .map(|title| {
+            lines[title + 1..]
+                .iter()
+                .position(|line| line.trim().is_empty())
+                .map(|offset| title + 1 + offset)
+                .unwrap_or(lines.len())
+        })
+        .unwrap_or(lines.len());
+
+    lines[..end]
+        .iter()
+        .copied()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            attribute_pattern().captures(line).map(|captures| {
+                let (_, [name, value]) = captures.extract();
+                (index, name, value.trim())
+            })
+        })
+        .collect()
+}
+
+fn title_pattern() -> &'static Regex {
+    regex!(r"^=[ ]+(?:RFD ?(\d+):? )?(.*)$")
+}
+
+fn attribute_pattern() -> &'static Regex {
+    regex!(r"^:([\w\-]+)!?:(.*)$")
+}
+
+fn include_pattern() -> &'static Regex {
+    regex!(r"^include::(.*)\[.*\]$")
+}
+
+fn image_pattern() -> &'static Regex {
+    regex!(r"image::?([^\s\[]+)\[")
+}
+
+fn discussion_pattern() -> &'static Regex {
+    regex!(r"^https://github\.com/([^/]+)/([^/]+)/pull/\d+/?$")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RfdLintRule, RfdLinter};
+
+    static VALID: &str = ":showtitle:\n:numbered:\n:state: discussion\n:discussion: https://github.com/oxidecomputer/rfd/pull/12\n:labels: api\n\n= RFD 123 Linting\nJane Doe <jane@example.com>\n\n== Background\n\ninclude::diagrams.adoc[]\n\nimage::images/overview.svg[Overview]\n";
+
+    fn linter() -> RfdLinter {
+        RfdLinter::default()
+            .number(Some(123.into()))
+            .repository("oxidecomputer", "rfd")
+            .files(Some(vec![
+                "diagrams.adoc".to_string(),
+                "images/overview.svg".to_string(),
+            ]))
+    }
+
+    fn rules(content: &str) -> Vec<(RfdLintRule, Option<usize>)> {
+        linter()
+            .lint(content)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.rule, diagnostic.line))
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_document_has_no_diagnostics() {
+        assert!(linter().lint(VALID).is_empty());
+    }
+
+    #[test]
+    fn test_missing_and_unknown_attributes() {
+        let content = VALID
+            .replace(
+                ":discussion: https://github.com/oxidecomputer/rfd/pull/12\n",
+                "",
+            )
+            .replace(":labels: api", ":lables: api");
+
+        assert_eq!(
+            vec![
+                (RfdLintRule::MissingAttribute, None),
+                (RfdLintRule::UnknownAttribute, Some(4)),
+            ],
+            rules(&content)
+        );
+    }
+
+    #[test]
+    fn test_invalid_state() {
+        let content = VALID.replace(":state: discussion", ":state: discusion");
+        assert_eq!(vec![(RfdLintRule::InvalidState, Some(3))], rules(&content));
+    }
+
+    #[test]
+    fn test_invalid_author_line() {
+        let content = VALID.replace("Jane Doe <jane@example.com>", "Jane Doe <jane@example.com");
+        assert_eq!(
+            vec![(RfdLintRule::InvalidAuthors, Some(8))],
+            rules(&content)
+        );
+    }
+
+    #[test]
+    fn test_discussion_on_another_repository() {
+        let content = VALID.replace("oxidecomputer/rfd/pull/12", "someone/else/pull/12");
+        assert_eq!(
+            vec![(RfdLintRule::InvalidDiscussion, Some(4))],
+            rules(&content)
+        );
+
+        let content = VALID.replace("pull/12", "issues/12");
+        assert_eq!(
+            vec![(RfdLintRule::InvalidDiscussion, Some(4))],
+            rules(&content)
+        );
+    }
+
+    #[test]
+    fn test_empty_discussion_is_allowed() {
+        let content = VALID.replace(
+            ":discussion: https://github.com/oxidecomputer/rfd/pull/12",
+            ":discussion:",
+        );
+        assert!(linter().lint(&content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_supporting_files() {
+        let content = VALID
+            .replace("diagrams.adoc", "diagram.adoc")
+            .replace("images/overview.svg", "images/missing.png")
+            + "See image:https://example.com/remote.png[remote]\n";
+
+        assert_eq!(
+            vec![
+                (RfdLintRule::MissingInclude, Some(12)),
+                (RfdLintRule::MissingImage, Some(14)),
+            ],
+            rules(&content)
+        );
+
+        // Supporting files are not checked when they are unknown
+        assert!(linter().files(None).lint(&content).is_empty());
+    }
+
+    #[test]
+    fn test_title_number_must_match_directory() {
+        let content = VALID.replace("= RFD 123 Linting", "= RFD 124 Linting");
+        assert_eq!(
+            vec![(RfdLintRule::MismatchedNumber, Some(7))],
+            rules(&content)
+        );
+
+        let content = VALID.replace("= RFD 123 Linting", "Linting");
+        assert_eq!(vec![(RfdLintRule::MissingTitle, None)], rules(&content));
+    }
+}
@@ -0,0 +1,127 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use similar::{capture_diff_slices, Algorithm, DiffTag};
+use std::ops::Range;
+
+/// A contiguous change to a range of base lines
+#[derive(Debug, PartialEq)]
+struct Hunk<'a> {
+    base: Range<usize>,
+    lines: &'a [&'a str],
+}
+
+/// Perform a line based three-way merge of two documents that were both derived from `base`.
+/// Returns `None` if the documents change the same lines, or lines that are directly adjacent to
+/// each other, in different ways
+pub fn merge_lines(base: &str, ours: &str, theirs: &str) -> Option<String> {
+    let base_lines = base.split_inclusive('\n').collect::<Vec<_>>();
+    let ours_lines = ours.split_inclusive('\n').collect::<Vec<_>>();
+    let theirs_lines = theirs.split_inclusive('\n').collect::<Vec<_>>();
+
+    let mut hunks = changes(&base_lines, &ours_lines);
+    hunks.extend(changes(&base_lines, &theirs_lines));
+    hunks.sort_by_key(|hunk| (hunk.base.start, hunk.base.end));
+
+    let mut merged: Vec<Hunk> = vec![];
+    for hunk in hunks {
+        match merged.last() {
+            // Both sides made the exact same change
+            Some(last) if *last == hunk => (),
+            Some(last) if hunk.base.start <= last.base.end => return None,
+            _ => merged.push(hunk),
+        }
+    }
+
+    let mut output = String::with_capacity(theirs.len().max(ours.len()));
+    let mut position = 0;
+    for hunk in merged {
+        output.extend(base_lines[position..hunk.base.start].iter().copied());
+        output.extend(hunk.lines.iter().copied());
+        position = hunk.base.end;
+    }
+    output.extend(base_lines[position..].iter().copied());
+
+    Some(output)
+}
+
+/// Group the differences between the base and a derived document into hunks. Hunks from the same
+/// document are always separated by at least one unchanged line
+fn changes<'a>(base: &[&str], other: &'a [&'a str]) -> Vec<Hunk<'a>> {
+    let mut ranges: Vec<(Range<usize>, Range<usize>)> = vec![];
+
+    for op in capture_diff_slices(Algorithm::Myers, base, other) {
+        let (tag, base_range, other_range) = op.as_tag_tuple();
+        if tag == DiffTag::Equal {
+            continue;
+        }
+
+        match ranges.last_mut() {
+            Some((last_base, last_other))
+                if last_base.end == base_range.start && last_other.end == other_range.start =>
+            {
+                last_base.end = base_range.end;
+                last_other.end = other_range.end;
+            }
+            _ => ranges.push((base_range, other_range)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(base, other_range)| Hunk {
+            base,
+            lines: &other[other_range],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_lines;
+
+    static BASE: &str = "= RFD 123 Title\n:state: discussion\n\n== Background\n\nSome text\n\n== Proposal\n\nMore text\n";
+
+    #[test]
+    fn test_merges_changes_to_separate_regions() {
+        let ours = BASE.replace(":state: discussion", ":state: published");
+        let theirs = BASE.replace("More text\n", "More text\n\nAn additional paragraph\n");
+
+        assert_eq!(
+            Some("= RFD 123 Title\n:state: published\n\n== Background\n\nSome text\n\n== Proposal\n\nMore text\n\nAn additional paragraph\n".to_string()),
+            merge_lines(BASE, &ours, &theirs)
+        );
+    }
+
+    #[test]
+    fn test_identical_changes_are_merged() {
+        let ours = BASE.replace("Some text", "Updated text");
+
+        assert_eq!(Some(ours.clone()), merge_lines(BASE, &ours, &ours));
+    }
+
+    #[test]
+    fn test_overlapping_changes_conflict() {
+        let ours = BASE.replace("Some text", "Our text");
+        let theirs = BASE.replace("Some text", "Their text");
+
+        assert_eq!(None, merge_lines(BASE, &ours, &theirs));
+    }
+
+    #[test]
+    fn test_adjacent_changes_conflict() {
+        let ours = BASE.replace("== Proposal", "== Design");
+        let theirs = BASE.replace("\n\nMore text", "\nInserted\nMore text");
+
+        assert_eq!(None, merge_lines(BASE, &ours, &theirs));
+    }
+
+    #[test]
+    fn test_unchanged_sides_take_the_other_side() {
+        let theirs = BASE.replace("Title", "New Title");
+
+        assert_eq!(Some(theirs.clone()), merge_lines(BASE, BASE, &theirs));
+        assert_eq!(Some(theirs.clone()), merge_lines(BASE, &theirs, BASE));
+    }
+}
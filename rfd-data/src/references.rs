@@ -0,0 +1,53 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use regex::{regex, Regex};
+
+fn reference_pattern() -> &'static Regex {
+    // Matches prose references ("RFD 123", "RFD-0123"), asciidoc cross references (<<rfd-123>>)
+    // and links to RFDs (https://rfd.example.com/rfd/0123)
+    regex!(r"(?i)(?:\brfd[ \-]?|/rfd/)(\d{1,4})\b")
+}
+
+/// Extract the numbers of all of the RFDs referenced from the given content. The returned numbers
+/// are sorted and do not contain duplicates
+pub fn extract_references(content: &str) -> Vec<i32> {
+    let mut references = reference_pattern()
+        .captures_iter(content)
+        .filter_map(|capture| capture.extract::<1>().1[0].parse::<i32>().ok())
+        .collect::<Vec<_>>();
+    references.sort();
+    references.dedup();
+
+    references
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extract_references;
+
+    #[test]
+    fn test_extract_prose_references() {
+        let content = "As described in RFD 123 and rfd-0045, but not in RFD12345 or BRFD 9.";
+        assert_eq!(vec![45, 123], extract_references(content));
+    }
+
+    #[test]
+    fn test_extract_cross_references() {
+        let content = "See <<rfd-7>> and <<RFD-0007,the seventh RFD>> for details";
+        assert_eq!(vec![7], extract_references(content));
+    }
+
+    #[test]
+    fn test_extract_link_references() {
+        let content =
+            "https://rfd.example.com/rfd/0321[RFD 321 link] and https://example.com/rfd/18";
+        assert_eq!(vec![18, 321], extract_references(content));
+    }
+
+    #[test]
+    fn test_extract_no_references() {
+        assert!(extract_references("Nothing to see here: refund 12").is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::RfdState;
+
+/// The states that an RFD may move to from each state. An RFD may always remain in its current
+/// state, and a state that is missing from the table can not be moved out of. A deserialized table
+/// is merged with the default table, so that it only needs to list the states that it changes
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct RfdStateTransitions(HashMap<RfdState, Vec<RfdState>>);
+
+impl<'de> Deserialize<'de> for RfdStateTransitions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let configured = HashMap::<RfdState, Vec<RfdState>>::deserialize(deserializer)?;
+
+        let mut transitions = Self::default();
+        transitions.0.extend(configured);
+
+        Ok(transitions)
+    }
+}
+
+impl Default for RfdStateTransitions {
+    fn default() -> Self {
+        Self(HashMap::from([
+            (
+                RfdState::Prediscussion,
+                vec![
+                    RfdState::Ideation,
+                    RfdState::Discussion,
+                    RfdState::Abandoned,
+                ],
+            ),
+            (
+                RfdState::Ideation,
+                vec![
+                    RfdState::Prediscussion,
+                    RfdState::Discussion,
+                    RfdState::Abandoned,
+                ],
+            ),
+            (
+                RfdState::Discussion,
+                vec![RfdState::Ideation, RfdState::Published, RfdState::Abandoned],
+            ),
+            (
+                RfdState::Published,
+                vec![
+                    RfdState::Discussion,
+                    RfdState::Committed,
+                    RfdState::Abandoned,
+                ],
+            ),
+            (
+                RfdState::Committed,
+                vec![RfdState::Discussion, RfdState::Abandoned],
+            ),
+            (
+                RfdState::Abandoned,
+                vec![RfdState::Prediscussion, RfdState::Ideation],
+            ),
+        ]))
+    }
+}
+
+impl RfdStateTransitions {
+    pub fn new(transitions: HashMap<RfdState, Vec<RfdState>>) -> Self {
+        Self(transitions)
+    }
+
+    /// Check if an RFD is allowed to move from one state to another
+    pub fn allows(&self, from: RfdState, to: RfdState) -> bool {
+        from == to || self.next(from).contains(&to)
+    }
+
+    /// The states that an RFD in the given state is allowed to move to
+    pub fn next(&self, from: RfdState) -> &[RfdState] {
+        self.0
+            .get(&from)
+            .map(|states| states.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::RfdState;
+
+    use super::RfdStateTransitions;
+
+    #[test]
+    fn test_default_transitions() {
+        let transitions = RfdStateTransitions::default();
+
+        assert!(transitions.allows(RfdState::Ideation, RfdState::Discussion));
+        assert!(transitions.allows(RfdState::Discussion, RfdState::Published));
+        assert!(transitions.allows(RfdState::Published, RfdState::Committed));
+        assert!(transitions.allows(RfdState::Committed, RfdState::Abandoned));
+
+        assert!(!transitions.allows(RfdState::Ideation, RfdState::Committed));
+        assert!(!transitions.allows(RfdState::Prediscussion, RfdState::Published));
+        assert!(!transitions.allows(RfdState::Abandoned, RfdState::Published));
+    }
+
+    #[test]
+    fn test_remaining_in_state_is_allowed() {
+        let transitions = RfdStateTransitions::new(HashMap::new());
+
+        assert!(transitions.allows(RfdState::Committed, RfdState::Committed));
+        assert!(!transitions.allows(RfdState::Committed, RfdState::Discussion));
+    }
+
+    #[test]
+    fn test_deserialize_transitions() {
+        let transitions: RfdStateTransitions = toml::from_str(
+            r#"
+            ideation = ["discussion"]
+            discussion = ["published", "ideation"]
+            "#,
+        )
+        .unwrap();
+
+        assert!(transitions.allows(RfdState::Ideation, RfdState::Discussion));
+        assert!(transitions.allows(RfdState::Discussion, RfdState::Ideation));
+        assert!(!transitions.allows(RfdState::Ideation, RfdState::Abandoned));
+        assert_eq!(
+            &[RfdState::Published, RfdState::Ideation],
+            transitions.next(RfdState::Discussion)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_partial_transitions() {
+        let transitions: RfdStateTransitions = toml::from_str(
+            r#"
+            committed = []
+            "#,
+        )
+        .unwrap();
+
+        // Listed states replace their default transitions
+        assert!(!transitions.allows(RfdState::Committed, RfdState::Discussion));
+
+        // States that are not listed keep their default transitions
+        assert_eq!(
+            RfdStateTransitions::default().next(RfdState::Published),
+            transitions.next(RfdState::Published)
+        );
+        assert!(transitions.allows(RfdState::Ideation, RfdState::Discussion));
+    }
+}
@@ -0,0 +1,398 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use octorust::{
+    types::{IssueComment, Order, PullRequestReviewComment, Sort},
+    Client, Message,
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::GitHubError;
+
+/// Read access to the conversation that has taken place on a single pull request
+#[derive(Clone)]
+pub struct GitHubPullRequestComments {
+    pub client: Client,
+    pub owner: String,
+    pub repo: String,
+    pub number: i64,
+}
+
+impl std::fmt::Debug for GitHubPullRequestComments {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GitHubPullRequestComments")
+            .field("owner", &self.owner)
+            .field("repo", &self.repo)
+            .field("number", &self.number)
+            .finish()
+    }
+}
+
+impl GitHubPullRequestComments {
+    /// Fetch all of the review and issue comments of the pull request and assemble them in to
+    /// threads. Review comments are grouped by the comment that they reply to, while issue comments
+    /// are returned as a flat list in the order they were created
+    #[instrument(skip(self), fields(owner = self.owner, repo = self.repo, number = self.number))]
+    pub async fn discussion(&self) -> Result<GitHubPullRequestDiscussion, GitHubError> {
+        let review_comments = self.review_comments().await?;
+        let issue_comments = self.issue_comments().await?;
+        let thread_states = self.review_thread_states().await?;
+
+        tracing::debug!(
+            review_comments = review_comments.len(),
+            issue_comments = issue_comments.len(),
+            threads = thread_states.len(),
+            "Fetched pull request comments"
+        );
+
+        Ok(GitHubPullRequestDiscussion {
+            number: self.number,
+            review_threads: thread_review_comments(review_comments, &thread_states),
+            comments: issue_comments
+                .into_iter()
+                .map(GitHubDiscussionComment::from)
+                .collect(),
+        })
+    }
+
+    pub async fn review_comments(&self) -> Result<Vec<PullRequestReviewComment>, GitHubError> {
+        Ok(self
+            .client
+            .pulls()
+            .list_all_review_comments(
+                &self.owner,
+                &self.repo,
+                self.number,
+                Sort::Created,
+                Order::Asc,
+                None,
+            )
+            .await?
+            .body)
+    }
+
+    pub async fn issue_comments(&self) -> Result<Vec<IssueComment>, GitHubError> {
+        Ok(self
+            .client
+            .issues()
+            .list_all_comments(&self.owner, &self.repo, self.number, None)
+            .await?
+            .body)
+    }
+
+    /// The REST API does not expose whether a review thread has been resolved. This information is
+    /// only available via GraphQL, so we look up the state of every thread and key it by the id of
+    /// the comment that started the thread
+    pub async fn review_thread_states(
+        &self,
+    ) -> Result<HashMap<i64, GitHubReviewThreadState>, GitHubError> {
+        let mut states = HashMap::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let request = GraphQLRequest {
+                query: REVIEW_THREADS_QUERY,
+                variables: ReviewThreadsVariables {
+                    owner: &self.owner,
+                    repo: &self.repo,
+                    number: self.number,
+                    cursor: cursor.as_deref(),
+                },
+            };
+
+            let response: GraphQLResponse<ReviewThreadsData> = self
+                .client
+                .post(
+                    &self.client.url("/graphql", None),
+                    Message {
+                        body: Some(reqwest::Body::from(serde_json::to_vec(&request)?)),
+                        content_type: Some("application/json".to_string()),
+                    },
+                )
+                .await?
+                .body;
+
+            if let Some(errors) = response.errors.filter(|errors| !errors.is_empty()) {
+                tracing::warn!(?errors, "Review thread lookup returned errors");
+                return Err(GitHubError::GraphQL(
+                    errors
+                        .into_iter()
+                        .map(|err| err.message)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ));
+            }
+
+            let Some(threads) = response
+                .data
+                .and_then(|data| data.repository)
+                .and_then(|repository| repository.pull_request)
+                .map(|pull_request| pull_request.review_threads)
+            else {
+                break;
+            };
+
+            for thread in threads.nodes {
+                if let Some(id) = thread
+                    .comments
+                    .nodes
+                    .first()
+                    .and_then(|comment| comment.database_id)
+                {
+                    states.insert(
+                        id,
+                        GitHubReviewThreadState {
+                            resolved: thread.is_resolved,
+                            outdated: thread.is_outdated,
+                            resolved_by: thread.resolved_by.map(|actor| actor.login),
+                        },
+                    );
+                }
+            }
+
+            if threads.page_info.has_next_page {
+                cursor = threads.page_info.end_cursor;
+            } else {
+                break;
+            }
+        }
+
+        Ok(states)
+    }
+}
+
+fn thread_review_comments(
+    comments: Vec<PullRequestReviewComment>,
+    states: &HashMap<i64, GitHubReviewThreadState>,
+) -> Vec<GitHubReviewThread> {
+    let parents = comments
+        .iter()
+        .map(|comment| (comment.id, comment.in_reply_to_id))
+        .collect::<HashMap<_, _>>();
+
+    // Replies always point at the comment that started the thread, but walk the chain regardless
+    // in case a reply to a reply is ever returned
+    let root_of = |mut id: i64| {
+        while let Some(parent) = parents.get(&id).filter(|parent| **parent != 0) {
+            id = *parent;
+        }
+        id
+    };
+
+    let mut threads: Vec<GitHubReviewThread> = vec![];
+    let mut index: HashMap<i64, usize> = HashMap::new();
+
+    for comment in comments {
+        let root = root_of(comment.id);
+
+        match index.get(&root) {
+            Some(position) => threads[*position]
+                .comments
+                .push(GitHubDiscussionComment::from(&comment)),
+            None => {
+                let state = states.get(&root).cloned().unwrap_or_default();
+                index.insert(root, threads.len());
+                threads.push(GitHubReviewThread {
+                    id: root,
+                    anchor: GitHubReviewAnchor::from(&comment),
+                    resolved: state.resolved,
+                    resolved_by: state.resolved_by,
+                    outdated: state.outdated,
+                    comments: vec![GitHubDiscussionComment::from(&comment)],
+                });
+            }
+        }
+    }
+
+    threads
+}
+
+#[derive(Debug, Clone)]
+pub struct GitHubPullRequestDiscussion {
+    pub number: i64,
+    pub review_threads: Vec<GitHubReviewThread>,
+    pub comments: Vec<GitHubDiscussionComment>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GitHubReviewThread {
+    pub id: i64,
+    pub anchor: GitHubReviewAnchor,
+    pub resolved: bool,
+    pub resolved_by: Option<String>,
+    pub outdated: bool,
+    pub comments: Vec<GitHubDiscussionComment>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GitHubReviewThreadState {
+    pub resolved: bool,
+    pub outdated: bool,
+    pub resolved_by: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GitHubReviewAnchor {
+    pub path: String,
+    pub commit: String,
+    pub line: Option<i64>,
+    pub start_line: Option<i64>,
+    pub original_line: Option<i64>,
+    pub diff_hunk: String,
+}
+
+impl From<&PullRequestReviewComment> for GitHubReviewAnchor {
+    fn from(value: &PullRequestReviewComment) -> Self {
+        // The client represents missing line numbers as zero
+        let line = |line: i64| Some(line).filter(|line| *line > 0);
+
+        Self {
+            path: value.path.clone(),
+            commit: value.commit_id.clone(),
+            line: line(value.line),
+            start_line: line(value.start_line),
+            original_line: line(value.original_line),
+            diff_hunk: value.diff_hunk.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GitHubDiscussionComment {
+    pub id: i64,
+    pub author: Option<String>,
+    pub body: String,
+    pub url: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<&PullRequestReviewComment> for GitHubDiscussionComment {
+    fn from(value: &PullRequestReviewComment) -> Self {
+        Self {
+            id: value.id,
+            author: value.user.as_ref().map(|user| user.login.clone()),
+            body: value.body.clone(),
+            url: value.html_url.clone(),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<IssueComment> for GitHubDiscussionComment {
+    fn from(value: IssueComment) -> Self {
+        Self {
+            id: value.id,
+            author: value.user.map(|user| user.login),
+            body: value.body,
+            url: value.html_url,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+static REVIEW_THREADS_QUERY: &str = r#"
+query($owner: String!, $repo: String!, $number: Int!, $cursor: String) {
+  repository(owner: $owner, name: $repo) {
+    pullRequest(number: $number) {
+      reviewThreads(first: 100, after: $cursor) {
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          isResolved
+          isOutdated
+          resolvedBy { login }
+          comments(first: 1) { nodes { databaseId } }
+        }
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Debug, Serialize)]
+struct GraphQLRequest<'a, T> {
+    query: &'a str,
+    variables: T,
+}
+
+#[derive(Debug, Serialize)]
+struct ReviewThreadsVariables<'a> {
+    owner: &'a str,
+    repo: &'a str,
+    number: i64,
+    cursor: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLResponse<T> {
+    data: Option<T>,
+    errors: Option<Vec<GraphQLError>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewThreadsData {
+    repository: Option<ReviewThreadsRepository>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewThreadsRepository {
+    pull_request: Option<ReviewThreadsPullRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewThreadsPullRequest {
+    review_threads: ReviewThreadConnection,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewThreadConnection {
+    page_info: PageInfo,
+    nodes: Vec<ReviewThreadNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewThreadNode {
+    is_resolved: bool,
+    is_outdated: bool,
+    resolved_by: Option<Actor>,
+    comments: ReviewThreadComments,
+}
+
+#[derive(Debug, Deserialize)]
+struct Actor {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReviewThreadComments {
+    nodes: Vec<ReviewThreadComment>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReviewThreadComment {
+    database_id: Option<i64>,
+}
@@ -18,7 +18,10 @@ use base64::{prelude::BASE64_STANDARD, DecodeError, Engine};
 use chrono::{DateTime, ParseError, Utc};
 use http::StatusCode;
 use octorust::{
-    types::{GitCreateRefRequest, PullRequestSimple, ReposCreateUpdateFileContentsRequest},
+    types::{
+        GitCreateRefRequest, PullRequestSimple, ReposCreateUpdateFileContentsRequest,
+        ReposDeleteFileRequest,
+    },
     Client, ClientError, Response,
 };
 use regex::regex;
@@ -30,9 +33,14 @@ use rfd_model::{CommitSha, FileSha};
 use thiserror::Error;
 use tracing::{instrument, Instrument};
 
+pub mod discussion;
 pub mod ext;
 mod util;
 
+pub use discussion::{
+    GitHubDiscussionComment, GitHubPullRequestComments, GitHubPullRequestDiscussion,
+    GitHubReviewAnchor, GitHubReviewThread,
+};
 use ext::ReposExt;
 use util::decode_base64;
 
@@ -50,6 +58,10 @@ pub enum GitHubError {
     InvalidData(#[from] Utf8Error),
     #[error("Failed to parse date")]
     InvalidDate(#[from] ParseError),
+    #[error("GitHub GraphQL request failed: {0}")]
+    GraphQL(String),
+    #[error("Failed to serialize request")]
+    Serialization(#[from] serde_json::Error),
     #[error("Expected to find at least one commit")]
     NoCommitsFound,
     #[error("Expected to find at least one committer")]
@@ -218,6 +230,30 @@ impl GitHubRfdRepo {
         }
     }
 
+    /// Extracts the pull request number from a discussion url. Only pull requests that belong to
+    /// this repository are considered valid
+    pub fn pull_request_number(&self, url: &str) -> Option<i64> {
+        let pattern = regex!(r#"^https://github\.com/([^/]+)/([^/]+)/pull/(\d+)/?$"#);
+        pattern.captures(url.trim()).and_then(|captures| {
+            if captures[1].eq_ignore_ascii_case(&self.owner)
+                && captures[2].eq_ignore_ascii_case(&self.repo)
+            {
+                captures[3].parse::<i64>().ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn pull_request_comments(&self, number: i64) -> GitHubPullRequestComments {
+        GitHubPullRequestComments {
+            client: self.client.clone(),
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            number,
+        }
+    }
+
     pub async fn locations_for_commit(
         &self,
         commit: CommitSha,
@@ -684,6 +720,34 @@ impl GitHubRfdLocation {
             .parse()?)
     }
 
+    /// The most recent commit on the branch of this location that modified the RFD
+    #[instrument(skip(self))]
+    pub async fn latest_commit(&self, rfd_number: &RfdNumber) -> Result<CommitSha, GitHubError> {
+        let commits = self
+            .client
+            .repos()
+            .list_commits(
+                &self.owner,
+                &self.repo,
+                &self.branch,
+                &rfd_number.repo_path(),
+                "",
+                None,
+                None,
+                0,
+                0,
+            )
+            .await?
+            .body;
+
+        Ok(commits
+            .into_iter()
+            .next()
+            .ok_or_else(|| GitHubError::NoCommitsFound)?
+            .sha
+            .into())
+    }
+
     #[instrument(skip(self, content))]
     pub async fn upsert(
         &self,
@@ -739,30 +803,183 @@ impl GitHubRfdLocation {
 
         Ok(Some(response.body.commit.sha.into()))
     }
-}
 
-#[allow(dead_code)]
-#[derive(Clone)]
-struct GitHubPullRequestComments {
-    pub client: Client,
-}
+    /// List the files stored in the directory of an RFD alongside its README. Paths are relative
+    /// to the RFD directory
+    #[instrument(skip(self))]
+    pub async fn list_supporting_files(
+        &self,
+        rfd_number: &RfdNumber,
+    ) -> Result<Vec<GitHubRfdFile>, GitHubError> {
+        let root = rfd_number.repo_path();
+        let root = root.trim_start_matches('/');
 
-impl GitHubPullRequestComments {
-    #[allow(dead_code)]
-    async fn comments(&self) {
-        let pulls = self.client.pulls();
-        let _comments = pulls
-            .list_all_review_comments(
-                "owner",
-                "repo",
-                0,
-                octorust::types::Sort::Created,
-                octorust::types::Order::Desc,
-                None,
+        let files = Self::list_supporting_files_internal(
+            &self.client,
+            &self.owner,
+            &self.repo,
+            &self.commit,
+            root.to_string(),
+        )
+        .await?;
+
+        Ok(files
+            .into_iter()
+            .filter_map(|mut file| {
+                file.path = file
+                    .path
+                    .strip_prefix(root)?
+                    .trim_start_matches('/')
+                    .to_string();
+                Some(file)
+            })
+            .filter(|file| file.path != "README.adoc" && file.path != "README.md")
+            .collect())
+    }
+
+    #[instrument(skip(client, dir))]
+    fn list_supporting_files_internal<'a>(
+        client: &'a Client,
+        owner: &'a String,
+        repo: &'a String,
+        ref_: &'a CommitSha,
+        dir: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GitHubRfdFile>, GitHubError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut files = vec![];
+
+            let resp = client
+                .repos()
+                .get_content_vec_entries(owner, repo, &dir, ref_.0.as_str())
+                .await?;
+
+            for entry in resp.body {
+                if entry.type_ == "dir" {
+                    files.extend(
+                        Self::list_supporting_files_internal(client, owner, repo, ref_, entry.path)
+                            .await?,
+                    );
+                } else {
+                    files.push(GitHubRfdFile {
+                        path: entry.path,
+                        sha: entry.sha.into(),
+                        size: entry.size,
+                    });
+                }
+            }
+
+            Ok(files)
+        })
+    }
+
+    /// Look up the sha of a file in the directory of an RFD. Returns `None` if the file does not
+    /// exist at the commit of this location
+    #[instrument(skip(self))]
+    pub async fn supporting_file_sha(
+        &self,
+        rfd_number: &RfdNumber,
+        path: &str,
+    ) -> Result<Option<FileSha>, GitHubError> {
+        match self
+            .client
+            .repos()
+            .get_content_file(
+                &self.owner,
+                &self.repo,
+                &Self::supporting_file_path(rfd_number, path),
+                self.commit.0.as_str(),
             )
             .await
-            .unwrap();
+        {
+            Ok(response) => Ok(Some(response.body.sha.into())),
+            Err(ClientError::HttpError { status, .. }) if status == StatusCode::NOT_FOUND => {
+                Ok(None)
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Write a file to the directory of an RFD. The sha of the file being replaced must be
+    /// supplied when overwriting an existing file
+    #[instrument(skip(self, content))]
+    pub async fn write_supporting_file(
+        &self,
+        rfd_number: &RfdNumber,
+        path: &str,
+        content: &[u8],
+        existing: Option<&FileSha>,
+        message: &str,
+    ) -> Result<CommitSha, GitHubError> {
+        tracing::info!(size = content.len(), "Writing supporting file to GitHub");
+
+        let response = self
+            .client
+            .repos()
+            .create_or_update_file_contents(
+                &self.owner,
+                &self.repo,
+                &Self::supporting_file_path(rfd_number, path),
+                &ReposCreateUpdateFileContentsRequest {
+                    message: format!("{}\nCommitted via rfd-api", message),
+                    sha: existing.map(|sha| sha.0.clone()).unwrap_or_default(),
+                    branch: self.branch.clone(),
+                    content: BASE64_STANDARD.encode(content),
+                    committer: Default::default(),
+                    author: Default::default(),
+                },
+            )
+            .await?;
+
+        Ok(response.body.commit.sha.into())
     }
+
+    /// Remove a file from the directory of an RFD
+    #[instrument(skip(self))]
+    pub async fn delete_supporting_file(
+        &self,
+        rfd_number: &RfdNumber,
+        path: &str,
+        existing: &FileSha,
+        message: &str,
+    ) -> Result<CommitSha, GitHubError> {
+        tracing::info!("Deleting supporting file from GitHub");
+
+        let response = self
+            .client
+            .repos()
+            .delete_file(
+                &self.owner,
+                &self.repo,
+                &Self::supporting_file_path(rfd_number, path),
+                &ReposDeleteFileRequest {
+                    message: format!("{}\nCommitted via rfd-api", message),
+                    sha: existing.0.clone(),
+                    branch: self.branch.clone(),
+                    committer: Default::default(),
+                    author: Default::default(),
+                },
+            )
+            .await?;
+
+        Ok(response.body.commit.sha.into())
+    }
+
+    fn supporting_file_path(rfd_number: &RfdNumber, path: &str) -> String {
+        format!(
+            "{}/{}",
+            rfd_number.repo_path().trim_start_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+}
+
+/// A file stored in the directory of an RFD
+#[derive(Debug, Clone)]
+pub struct GitHubRfdFile {
+    /// Path of the file relative to the RFD directory
+    pub path: String,
+    pub sha: FileSha,
+    pub size: i64,
 }
 
 struct FetchedRfdContent {
@@ -0,0 +1,8 @@
+DROP INDEX jobs_status;
+
+ALTER TABLE job DROP COLUMN next_attempt_at;
+ALTER TABLE job DROP COLUMN last_error;
+ALTER TABLE job DROP COLUMN attempts;
+ALTER TABLE job DROP COLUMN status;
+
+DROP TYPE RFD_JOB_STATUS;
@@ -0,0 +1,12 @@
+CREATE TYPE RFD_JOB_STATUS as ENUM('queued', 'running', 'succeeded', 'failed', 'dead');
+
+ALTER TABLE job ADD COLUMN status RFD_JOB_STATUS NOT NULL DEFAULT 'queued';
+ALTER TABLE job ADD COLUMN attempts INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE job ADD COLUMN last_error VARCHAR;
+ALTER TABLE job ADD COLUMN next_attempt_at TIMESTAMPTZ;
+
+-- Jobs that were started but never completed may still be running, so they are left as running
+UPDATE job SET status = 'succeeded', attempts = 1 WHERE processed = TRUE;
+UPDATE job SET status = 'running', attempts = 1 WHERE processed = FALSE AND started_at IS NOT NULL;
+
+CREATE INDEX jobs_status ON job (status, next_attempt_at ASC, committed_at ASC, created_at ASC);
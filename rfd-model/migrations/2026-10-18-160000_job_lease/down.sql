@@ -0,0 +1,3 @@
+DROP INDEX jobs_lease;
+
+ALTER TABLE job DROP COLUMN lease_expires_at;
@@ -0,0 +1,7 @@
+ALTER TABLE job ADD COLUMN lease_expires_at TIMESTAMPTZ;
+
+-- Running jobs from before leases existed have no owner that can renew them, so they are expired
+-- immediately and will be reclaimed by the next processor that runs
+UPDATE job SET lease_expires_at = NOW() WHERE status = 'running';
+
+CREATE INDEX jobs_lease ON job (status, lease_expires_at ASC);
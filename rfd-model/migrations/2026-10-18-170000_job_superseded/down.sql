@@ -0,0 +1,11 @@
+-- Postgres does not support removing a value from an enum, so the type needs to be recreated
+UPDATE job SET status = 'succeeded' WHERE status = 'superseded';
+
+ALTER TYPE RFD_JOB_STATUS RENAME TO RFD_JOB_STATUS_OLD;
+CREATE TYPE RFD_JOB_STATUS as ENUM('queued', 'running', 'succeeded', 'failed', 'dead');
+
+ALTER TABLE job ALTER COLUMN status DROP DEFAULT;
+ALTER TABLE job ALTER COLUMN status TYPE RFD_JOB_STATUS USING status::text::RFD_JOB_STATUS;
+ALTER TABLE job ALTER COLUMN status SET DEFAULT 'queued';
+
+DROP TYPE RFD_JOB_STATUS_OLD;
@@ -0,0 +1 @@
+ALTER TYPE RFD_JOB_STATUS ADD VALUE 'superseded';
@@ -0,0 +1,43 @@
+CREATE TABLE rfd_revision_author (
+  rfd_revision_id UUID REFERENCES rfd_revision (id) NOT NULL,
+  position INTEGER NOT NULL,
+  name VARCHAR NOT NULL,
+  email VARCHAR,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  PRIMARY KEY (rfd_revision_id, position)
+);
+
+CREATE INDEX rfd_revision_author_email_idx ON rfd_revision_author (LOWER(email));
+
+CREATE TABLE rfd_revision_label (
+  rfd_revision_id UUID REFERENCES rfd_revision (id) NOT NULL,
+  label VARCHAR NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  PRIMARY KEY (rfd_revision_id, label)
+);
+
+CREATE INDEX rfd_revision_label_label_idx ON rfd_revision_label (LOWER(label));
+
+-- Backfill from the existing revisions. Authors are separated by either semicolons or commas, with
+-- semicolons taking precedence, and may carry an email wrapped in angle brackets
+INSERT INTO rfd_revision_author (rfd_revision_id, position, name, email)
+SELECT
+  rfd_revision.id,
+  (author.position - 1)::INTEGER,
+  TRIM(REGEXP_REPLACE(author.value, '<[^>]*>', '')),
+  NULLIF(TRIM(SUBSTRING(author.value FROM '<([^>]*)>')), '')
+FROM rfd_revision,
+  UNNEST(REGEXP_SPLIT_TO_ARRAY(
+    rfd_revision.authors,
+    CASE WHEN rfd_revision.authors LIKE '%;%' THEN ';' ELSE ',' END
+  )) WITH ORDINALITY AS author(value, position)
+WHERE rfd_revision.authors IS NOT NULL AND TRIM(author.value) <> '';
+
+INSERT INTO rfd_revision_label (rfd_revision_id, label)
+SELECT DISTINCT rfd_revision.id, TRIM(label)
+FROM rfd_revision, UNNEST(STRING_TO_ARRAY(rfd_revision.labels, ',')) AS label
+WHERE rfd_revision.labels IS NOT NULL AND TRIM(label) <> '';
@@ -0,0 +1,15 @@
+CREATE TABLE audit_event (
+  id UUID PRIMARY KEY,
+  caller_id UUID NOT NULL,
+  operation VARCHAR NOT NULL,
+  rfd_number INTEGER,
+  before JSONB,
+  after JSONB,
+  request_id VARCHAR NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX audit_event_rfd_idx ON audit_event (rfd_number, created_at DESC);
+CREATE INDEX audit_event_caller_idx ON audit_event (caller_id, created_at DESC);
+CREATE INDEX audit_event_created_at_idx ON audit_event (created_at DESC);
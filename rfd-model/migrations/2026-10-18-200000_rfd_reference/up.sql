@@ -0,0 +1,10 @@
+CREATE TABLE rfd_reference (
+  rfd_revision_id UUID REFERENCES rfd_revision (id) NOT NULL,
+  target_rfd_number INTEGER NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  PRIMARY KEY (rfd_revision_id, target_rfd_number)
+);
+
+CREATE INDEX rfd_reference_target_rfd_number_idx ON rfd_reference (target_rfd_number);
@@ -0,0 +1,7 @@
+CREATE TABLE rfd_revision_html (
+  rfd_revision_id UUID PRIMARY KEY REFERENCES rfd_revision (id),
+  html TEXT NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
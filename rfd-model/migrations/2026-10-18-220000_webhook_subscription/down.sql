@@ -0,0 +1,4 @@
+DROP TABLE webhook_subscription_delivery;
+DROP TABLE webhook_subscription;
+DROP TYPE RFD_WEBHOOK_DELIVERY_STATUS;
+DROP TYPE RFD_WEBHOOK_EVENT;
@@ -0,0 +1,34 @@
+CREATE TYPE RFD_WEBHOOK_EVENT as ENUM('reserved', 'state_changed', 'major_revision', 'visibility_changed');
+CREATE TYPE RFD_WEBHOOK_DELIVERY_STATUS as ENUM('pending', 'sending', 'delivered', 'failed', 'dead');
+
+CREATE TABLE webhook_subscription (
+  id UUID PRIMARY KEY,
+  url VARCHAR NOT NULL,
+  events RFD_WEBHOOK_EVENT[] NOT NULL,
+  rfd_numbers INTEGER[],
+  secret VARCHAR NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  deleted_at TIMESTAMPTZ
+);
+
+CREATE TABLE webhook_subscription_delivery (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  webhook_subscription_id UUID REFERENCES webhook_subscription (id) NOT NULL,
+  event RFD_WEBHOOK_EVENT NOT NULL,
+  rfd_number INTEGER NOT NULL,
+  payload JSONB NOT NULL,
+  status RFD_WEBHOOK_DELIVERY_STATUS NOT NULL DEFAULT 'pending',
+  attempts INTEGER NOT NULL DEFAULT 0,
+  response_status INTEGER,
+  last_error VARCHAR,
+  next_attempt_at TIMESTAMPTZ,
+  delivered_at TIMESTAMPTZ,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX webhook_subscription_delivery_subscription_idx ON webhook_subscription_delivery (webhook_subscription_id, created_at DESC);
+CREATE INDEX webhook_subscription_delivery_status_idx ON webhook_subscription_delivery (status, next_attempt_at ASC);
@@ -0,0 +1,2 @@
+DROP TABLE rfd_watch_notification;
+DROP TABLE rfd_watch;
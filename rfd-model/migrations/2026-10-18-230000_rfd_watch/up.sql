@@ -0,0 +1,27 @@
+CREATE TABLE rfd_watch (
+  id UUID PRIMARY KEY,
+  api_user_id UUID NOT NULL,
+  rfd_number INTEGER,
+  label VARCHAR,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  deleted_at TIMESTAMPTZ,
+
+  CHECK ((rfd_number IS NULL) <> (label IS NULL))
+);
+
+CREATE INDEX rfd_watch_api_user_idx ON rfd_watch (api_user_id);
+CREATE INDEX rfd_watch_rfd_number_idx ON rfd_watch (rfd_number) WHERE deleted_at IS NULL;
+CREATE INDEX rfd_watch_label_idx ON rfd_watch (label) WHERE deleted_at IS NULL;
+
+CREATE TABLE rfd_watch_notification (
+  id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+  rfd_number INTEGER NOT NULL,
+  rfd_revision_id UUID REFERENCES rfd_revision (id) NOT NULL,
+  event RFD_WEBHOOK_EVENT NOT NULL,
+  processed_at TIMESTAMPTZ,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX rfd_watch_notification_pending_idx ON rfd_watch_notification (created_at ASC) WHERE processed_at IS NULL;
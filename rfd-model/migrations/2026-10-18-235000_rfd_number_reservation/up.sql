@@ -0,0 +1,15 @@
+CREATE TABLE rfd_number_reservation (
+  id UUID PRIMARY KEY,
+  rfd_number INTEGER NOT NULL,
+  group_id UUID,
+  created_by UUID NOT NULL,
+  claimed_by UUID,
+  claimed_at TIMESTAMPTZ,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT rfd_number_reservation_unique UNIQUE (rfd_number),
+  CHECK ((claimed_by IS NULL) = (claimed_at IS NULL))
+);
+
+CREATE INDEX rfd_number_reservation_group_idx ON rfd_number_reservation (group_id) WHERE group_id IS NOT NULL;
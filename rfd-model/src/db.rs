@@ -9,8 +9,15 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    schema::{job, rfd, rfd_pdf, rfd_revision},
-    schema_ext::{rfd_meta_join, rfd_pdf_join, ContentFormat, PdfSource, Visibility},
+    schema::{
+        audit_event, job, rfd, rfd_number_reservation, rfd_pdf, rfd_reference, rfd_revision,
+        rfd_revision_author, rfd_revision_html, rfd_revision_label, rfd_watch,
+        rfd_watch_notification, webhook_subscription, webhook_subscription_delivery,
+    },
+    schema_ext::{
+        rfd_feed_join, rfd_meta_join, rfd_pdf_join, ContentFormat, JobStatus, PdfSource,
+        Visibility, WebhookDeliveryStatus, WebhookEvent,
+    },
 };
 
 #[derive(Debug, Deserialize, Serialize, Queryable, Insertable, Selectable)]
@@ -117,6 +124,28 @@ pub struct RfdPdfJoinRow {
     pub latest_major_change_at: Option<DateTime<Utc>>,
 }
 
+#[derive(QueryableByName)]
+#[diesel(table_name = rfd_feed_join)]
+pub struct RfdFeedRow {
+    pub id: Uuid,
+    pub rfd_id: Uuid,
+    pub title: String,
+    pub state: Option<String>,
+    pub discussion: Option<String>,
+    pub authors: Option<String>,
+    pub content_format: ContentFormat,
+    pub sha: String,
+    pub commit_sha: String,
+    pub committed_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub labels: Option<String>,
+    pub major_change: bool,
+    pub previous_state: Option<String>,
+    pub position: i64,
+}
+
 #[partial(RfdRevisionMetaModel)]
 #[partial(RfdRevisionPdfModel)]
 #[derive(Debug, Deserialize, Serialize, Queryable, Selectable)]
@@ -225,6 +254,32 @@ impl From<RfdPdfJoinRow> for (RfdModel, RfdLatestMajorChange, RfdRevisionPdfMode
     }
 }
 
+impl From<RfdFeedRow> for (RfdRevisionMetaModel, Option<String>, i64) {
+    fn from(value: RfdFeedRow) -> Self {
+        (
+            RfdRevisionMetaModel {
+                id: value.id,
+                rfd_id: value.rfd_id,
+                title: value.title,
+                state: value.state,
+                discussion: value.discussion,
+                authors: value.authors,
+                content_format: value.content_format,
+                sha: value.sha,
+                commit_sha: value.commit_sha,
+                committed_at: value.committed_at,
+                created_at: value.created_at,
+                updated_at: value.updated_at,
+                deleted_at: value.deleted_at,
+                labels: value.labels,
+                major_change: value.major_change,
+            },
+            value.previous_state,
+            value.position,
+        )
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
 #[diesel(table_name = rfd_pdf)]
 pub struct RfdPdfModel {
@@ -239,6 +294,41 @@ pub struct RfdPdfModel {
     pub external_id: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = rfd_revision_author)]
+pub struct RfdRevisionAuthorModel {
+    pub rfd_revision_id: Uuid,
+    pub position: i32,
+    pub name: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = rfd_revision_label)]
+pub struct RfdRevisionLabelModel {
+    pub rfd_revision_id: Uuid,
+    pub label: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable, Selectable)]
+#[diesel(table_name = rfd_revision_html)]
+pub struct RfdRevisionHtmlModel {
+    pub rfd_revision_id: Uuid,
+    pub html: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable, Selectable)]
+#[diesel(table_name = rfd_reference)]
+pub struct RfdReferenceModel {
+    pub rfd_revision_id: Uuid,
+    pub target_rfd_number: i32,
+    pub created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
 #[diesel(table_name = job)]
 pub struct JobModel {
@@ -253,9 +343,92 @@ pub struct JobModel {
     pub processed: bool,
     pub created_at: DateTime<Utc>,
     pub started_at: Option<DateTime<Utc>>,
+    pub status: JobStatus,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub lease_expires_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug)]
 pub(crate) struct RfdLatestMajorChange {
     pub(crate) committed_at: Option<DateTime<Utc>>,
 }
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable)]
+#[diesel(table_name = audit_event)]
+pub struct AuditEventModel {
+    pub id: Uuid,
+    pub caller_id: Uuid,
+    pub operation: String,
+    pub rfd_number: Option<i32>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub request_id: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable, Selectable)]
+#[diesel(table_name = webhook_subscription)]
+pub struct WebhookSubscriptionModel {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub rfd_numbers: Option<Vec<i32>>,
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, QueryableByName, Selectable)]
+#[diesel(table_name = webhook_subscription_delivery)]
+pub struct WebhookSubscriptionDeliveryModel {
+    pub id: Uuid,
+    pub webhook_subscription_id: Uuid,
+    pub event: WebhookEvent,
+    pub rfd_number: i32,
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable, Selectable)]
+#[diesel(table_name = rfd_watch)]
+pub struct RfdWatchModel {
+    pub id: Uuid,
+    pub api_user_id: Uuid,
+    pub rfd_number: Option<i32>,
+    pub label: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, Insertable, Selectable)]
+#[diesel(table_name = rfd_watch_notification)]
+pub struct RfdWatchNotificationModel {
+    pub id: Uuid,
+    pub rfd_number: i32,
+    pub rfd_revision_id: Uuid,
+    pub event: WebhookEvent,
+    pub processed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Queryable, QueryableByName, Insertable, Selectable)]
+#[diesel(table_name = rfd_number_reservation)]
+pub struct RfdNumberReservationModel {
+    pub id: Uuid,
+    pub rfd_number: i32,
+    pub group_id: Option<Uuid>,
+    pub created_by: Uuid,
+    pub claimed_by: Option<Uuid>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
@@ -4,16 +4,22 @@
 
 use chrono::{DateTime, Utc};
 use db::{
-    JobModel, RfdLatestMajorChange, RfdModel, RfdPdfModel, RfdRevisionMetaModel, RfdRevisionModel,
-    RfdRevisionPdfModel,
+    AuditEventModel, JobModel, RfdLatestMajorChange, RfdModel, RfdNumberReservationModel,
+    RfdPdfModel, RfdReferenceModel, RfdRevisionAuthorModel, RfdRevisionHtmlModel,
+    RfdRevisionLabelModel, RfdRevisionMetaModel, RfdRevisionModel, RfdRevisionPdfModel,
+    RfdWatchModel, RfdWatchNotificationModel, WebhookSubscriptionDeliveryModel,
+    WebhookSubscriptionModel,
 };
 use newtype_uuid::{GenericUuid, TypedUuid, TypedUuidKind, TypedUuidTag};
 use partial_struct::partial;
-use schema_ext::{ContentFormat, PdfSource, Visibility};
+use schema_ext::{
+    ContentFormat, JobStatus, PdfSource, Visibility, WebhookDeliveryStatus, WebhookEvent,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use thiserror::Error;
+use uuid::Uuid;
 
 pub mod db;
 #[cfg(feature = "installer")]
@@ -258,6 +264,28 @@ impl From<RfdRevisionMetaModel> for RfdRevisionMeta {
     }
 }
 
+/// A revision that is reported in the activity feed of its RFD
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdFeedRevision {
+    pub revision: RfdRevisionMeta,
+    /// Whether this is the first revision of the RFD
+    pub first: bool,
+    /// The state of the RFD prior to this revision
+    pub previous_state: Option<String>,
+}
+
+impl From<(RfdRevisionMetaModel, Option<String>, i64)> for RfdFeedRevision {
+    fn from(
+        (revision, previous_state, position): (RfdRevisionMetaModel, Option<String>, i64),
+    ) -> Self {
+        Self {
+            revision: revision.into(),
+            first: position == 1,
+            previous_state,
+        }
+    }
+}
+
 impl From<RfdRevisionPdfModel> for RfdRevisionPdf {
     fn from(value: RfdRevisionPdfModel) -> Self {
         Self {
@@ -323,6 +351,90 @@ impl From<RfdPdfModel> for RfdPdf {
     }
 }
 
+#[partial(NewRfdRevisionAuthor)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdRevisionAuthor {
+    pub rfd_revision_id: TypedUuid<RfdRevisionId>,
+    pub position: i32,
+    pub name: String,
+    pub email: Option<String>,
+    #[partial(NewRfdRevisionAuthor(skip))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RfdRevisionAuthorModel> for RfdRevisionAuthor {
+    fn from(value: RfdRevisionAuthorModel) -> Self {
+        Self {
+            rfd_revision_id: TypedUuid::from_untyped_uuid(value.rfd_revision_id),
+            position: value.position,
+            name: value.name,
+            email: value.email,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[partial(NewRfdRevisionLabel)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdRevisionLabel {
+    pub rfd_revision_id: TypedUuid<RfdRevisionId>,
+    pub label: String,
+    #[partial(NewRfdRevisionLabel(skip))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RfdRevisionLabelModel> for RfdRevisionLabel {
+    fn from(value: RfdRevisionLabelModel) -> Self {
+        Self {
+            rfd_revision_id: TypedUuid::from_untyped_uuid(value.rfd_revision_id),
+            label: value.label,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[partial(NewRfdRevisionHtml)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdRevisionHtml {
+    pub rfd_revision_id: TypedUuid<RfdRevisionId>,
+    pub html: String,
+    #[partial(NewRfdRevisionHtml(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewRfdRevisionHtml(skip))]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<RfdRevisionHtmlModel> for RfdRevisionHtml {
+    fn from(value: RfdRevisionHtmlModel) -> Self {
+        Self {
+            rfd_revision_id: TypedUuid::from_untyped_uuid(value.rfd_revision_id),
+            html: value.html,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[partial(NewRfdReference)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdReference {
+    pub rfd_revision_id: TypedUuid<RfdRevisionId>,
+    /// The number of the RFD that is referenced by the revision
+    pub target_rfd_number: i32,
+    #[partial(NewRfdReference(skip))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RfdReferenceModel> for RfdReference {
+    fn from(value: RfdReferenceModel) -> Self {
+        Self {
+            rfd_revision_id: TypedUuid::from_untyped_uuid(value.rfd_revision_id),
+            target_rfd_number: value.target_rfd_number,
+            created_at: value.created_at,
+        }
+    }
+}
+
 #[derive(JsonSchema)]
 pub enum WebhookDeliveryId {}
 impl TypedUuidKind for WebhookDeliveryId {
@@ -350,6 +462,16 @@ pub struct Job {
     pub created_at: DateTime<Utc>,
     #[partial(NewJob(skip))]
     pub started_at: Option<DateTime<Utc>>,
+    #[partial(NewJob(skip))]
+    pub status: JobStatus,
+    #[partial(NewJob(skip))]
+    pub attempts: i32,
+    #[partial(NewJob(skip))]
+    pub last_error: Option<String>,
+    #[partial(NewJob(skip))]
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    #[partial(NewJob(skip))]
+    pub lease_expires_at: Option<DateTime<Utc>>,
 }
 
 impl From<JobModel> for Job {
@@ -366,6 +488,270 @@ impl From<JobModel> for Job {
             processed: value.processed,
             created_at: value.created_at,
             started_at: value.started_at,
+            status: value.status,
+            attempts: value.attempts,
+            last_error: value.last_error,
+            next_attempt_at: value.next_attempt_at,
+            lease_expires_at: value.lease_expires_at,
+        }
+    }
+}
+
+#[derive(JsonSchema)]
+pub enum AuditEventId {}
+impl TypedUuidKind for AuditEventId {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("audit-event");
+        TAG
+    }
+}
+
+#[partial(NewAuditEvent)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AuditEvent {
+    pub id: TypedUuid<AuditEventId>,
+    /// The id of the user that performed the operation
+    pub caller_id: Uuid,
+    pub operation: String,
+    pub rfd_number: Option<i32>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub request_id: String,
+    #[partial(NewAuditEvent(skip))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditEventModel> for AuditEvent {
+    fn from(value: AuditEventModel) -> Self {
+        Self {
+            id: TypedUuid::from_untyped_uuid(value.id),
+            caller_id: value.caller_id,
+            operation: value.operation,
+            rfd_number: value.rfd_number,
+            before: value.before,
+            after: value.after,
+            request_id: value.request_id,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(JsonSchema)]
+pub enum WebhookSubscriptionId {}
+impl TypedUuidKind for WebhookSubscriptionId {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("webhook-subscription");
+        TAG
+    }
+}
+
+#[partial(NewWebhookSubscription)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WebhookSubscription {
+    pub id: TypedUuid<WebhookSubscriptionId>,
+    /// The url that event payloads are POSTed to
+    pub url: String,
+    /// The events that are delivered to this subscription
+    pub events: Vec<WebhookEvent>,
+    /// Limits deliveries to events for the given RFDs. All RFDs are included when unset
+    pub rfd_numbers: Option<Vec<i32>>,
+    /// The key used to sign delivered payloads
+    #[serde(skip)]
+    pub secret: String,
+    #[partial(NewWebhookSubscription(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewWebhookSubscription(skip))]
+    pub updated_at: DateTime<Utc>,
+    #[partial(NewWebhookSubscription(skip))]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebhookSubscriptionModel> for WebhookSubscription {
+    fn from(value: WebhookSubscriptionModel) -> Self {
+        Self {
+            id: TypedUuid::from_untyped_uuid(value.id),
+            url: value.url,
+            events: value.events,
+            rfd_numbers: value.rfd_numbers,
+            secret: value.secret,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+#[derive(JsonSchema)]
+pub enum WebhookSubscriptionDeliveryId {}
+impl TypedUuidKind for WebhookSubscriptionDeliveryId {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("webhook-subscription-delivery");
+        TAG
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WebhookSubscriptionDelivery {
+    pub id: TypedUuid<WebhookSubscriptionDeliveryId>,
+    pub webhook_subscription_id: TypedUuid<WebhookSubscriptionId>,
+    pub event: WebhookEvent,
+    pub rfd_number: i32,
+    /// The body that is POSTed to the subscription url
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    /// The status code returned by the subscriber on the latest attempt
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WebhookSubscriptionDeliveryModel> for WebhookSubscriptionDelivery {
+    fn from(value: WebhookSubscriptionDeliveryModel) -> Self {
+        Self {
+            id: TypedUuid::from_untyped_uuid(value.id),
+            webhook_subscription_id: TypedUuid::from_untyped_uuid(value.webhook_subscription_id),
+            event: value.event,
+            rfd_number: value.rfd_number,
+            payload: value.payload,
+            status: value.status,
+            attempts: value.attempts,
+            response_status: value.response_status,
+            last_error: value.last_error,
+            next_attempt_at: value.next_attempt_at,
+            delivered_at: value.delivered_at,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// The body that is delivered to webhook subscribers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WebhookEventPayload {
+    pub event: WebhookEvent,
+    pub rfd_number: i32,
+    pub occurred_at: DateTime<Utc>,
+    /// Event specific details
+    pub data: serde_json::Value,
+}
+
+#[derive(JsonSchema)]
+pub enum RfdWatchId {}
+impl TypedUuidKind for RfdWatchId {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("rfd-watch");
+        TAG
+    }
+}
+
+/// A request from a user to be notified of changes to either a single RFD or to every RFD with a
+/// given label. Exactly one of `rfd_number` and `label` is set
+#[partial(NewRfdWatch)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdWatch {
+    pub id: TypedUuid<RfdWatchId>,
+    /// The id of the user that is watching
+    pub api_user_id: Uuid,
+    pub rfd_number: Option<i32>,
+    pub label: Option<String>,
+    #[partial(NewRfdWatch(skip))]
+    pub created_at: DateTime<Utc>,
+    #[partial(NewRfdWatch(skip))]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<RfdWatchModel> for RfdWatch {
+    fn from(value: RfdWatchModel) -> Self {
+        Self {
+            id: TypedUuid::from_untyped_uuid(value.id),
+            api_user_id: value.api_user_id,
+            rfd_number: value.rfd_number,
+            label: value.label,
+            created_at: value.created_at,
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+#[derive(JsonSchema)]
+pub enum RfdWatchNotificationId {}
+impl TypedUuidKind for RfdWatchNotificationId {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("rfd-watch-notification");
+        TAG
+    }
+}
+
+/// A change to an RFD that watchers still need to be notified of
+#[partial(NewRfdWatchNotification)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdWatchNotification {
+    #[partial(NewRfdWatchNotification(skip))]
+    pub id: TypedUuid<RfdWatchNotificationId>,
+    pub rfd_number: i32,
+    /// The revision that introduced the change
+    pub rfd_revision_id: TypedUuid<RfdRevisionId>,
+    pub event: WebhookEvent,
+    #[partial(NewRfdWatchNotification(skip))]
+    pub processed_at: Option<DateTime<Utc>>,
+    #[partial(NewRfdWatchNotification(skip))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RfdWatchNotificationModel> for RfdWatchNotification {
+    fn from(value: RfdWatchNotificationModel) -> Self {
+        Self {
+            id: TypedUuid::from_untyped_uuid(value.id),
+            rfd_number: value.rfd_number,
+            rfd_revision_id: TypedUuid::from_untyped_uuid(value.rfd_revision_id),
+            event: value.event,
+            processed_at: value.processed_at,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(JsonSchema)]
+pub enum RfdNumberReservationId {}
+impl TypedUuidKind for RfdNumberReservationId {
+    fn tag() -> TypedUuidTag {
+        const TAG: TypedUuidTag = TypedUuidTag::new("rfd-number-reservation");
+        TAG
+    }
+}
+
+/// An RFD number that is unavailable for automatic allocation. Numbers are either allocated when
+/// an RFD is reserved, or held ahead of time for a group and claimed later by one of its members
+#[partial(NewRfdNumberReservation)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RfdNumberReservation {
+    pub id: TypedUuid<RfdNumberReservationId>,
+    pub rfd_number: i32,
+    /// The group that the number is held for
+    pub group_id: Option<Uuid>,
+    /// The id of the user that created the reservation
+    pub created_by: Uuid,
+    /// The id of the user that reserved an RFD with the number
+    pub claimed_by: Option<Uuid>,
+    pub claimed_at: Option<DateTime<Utc>>,
+    #[partial(NewRfdNumberReservation(skip))]
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RfdNumberReservationModel> for RfdNumberReservation {
+    fn from(value: RfdNumberReservationModel) -> Self {
+        Self {
+            id: TypedUuid::from_untyped_uuid(value.id),
+            rfd_number: value.rfd_number,
+            group_id: value.group_id,
+            created_by: value.created_by,
+            claimed_by: value.claimed_by,
+            claimed_at: value.claimed_at,
+            created_at: value.created_at,
         }
     }
 }
@@ -7,6 +7,10 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "rfd_content_format"))]
     pub struct RfdContentFormat;
 
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "rfd_job_status"))]
+    pub struct RfdJobStatus;
+
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "rfd_pdf_source"))]
     pub struct RfdPdfSource;
@@ -14,9 +18,33 @@ pub mod sql_types {
     #[derive(diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "rfd_visibility"))]
     pub struct RfdVisibility;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "rfd_webhook_delivery_status"))]
+    pub struct RfdWebhookDeliveryStatus;
+
+    #[derive(diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "rfd_webhook_event"))]
+    pub struct RfdWebhookEvent;
 }
 
 diesel::table! {
+    audit_event (id) {
+        id -> Uuid,
+        caller_id -> Uuid,
+        operation -> Varchar,
+        rfd_number -> Nullable<Int4>,
+        before -> Nullable<Jsonb>,
+        after -> Nullable<Jsonb>,
+        request_id -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RfdJobStatus;
+
     job (id) {
         id -> Int4,
         owner -> Varchar,
@@ -29,6 +57,11 @@ diesel::table! {
         processed -> Bool,
         created_at -> Timestamptz,
         started_at -> Nullable<Timestamptz>,
+        status -> RfdJobStatus,
+        attempts -> Int4,
+        last_error -> Nullable<Varchar>,
+        next_attempt_at -> Nullable<Timestamptz>,
+        lease_expires_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -47,6 +80,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rfd_number_reservation (id) {
+        id -> Uuid,
+        rfd_number -> Int4,
+        group_id -> Nullable<Uuid>,
+        created_by -> Uuid,
+        claimed_by -> Nullable<Uuid>,
+        claimed_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::RfdPdfSource;
@@ -64,6 +109,14 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rfd_reference (rfd_revision_id, target_rfd_number) {
+        rfd_revision_id -> Uuid,
+        target_rfd_number -> Int4,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::RfdContentFormat;
@@ -88,8 +141,104 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rfd_revision_author (rfd_revision_id, position) {
+        rfd_revision_id -> Uuid,
+        position -> Int4,
+        name -> Varchar,
+        email -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    rfd_revision_html (rfd_revision_id) {
+        rfd_revision_id -> Uuid,
+        html -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    rfd_revision_label (rfd_revision_id, label) {
+        rfd_revision_id -> Uuid,
+        label -> Varchar,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    rfd_watch (id) {
+        id -> Uuid,
+        api_user_id -> Uuid,
+        rfd_number -> Nullable<Int4>,
+        label -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RfdWebhookEvent;
+
+    rfd_watch_notification (id) {
+        id -> Uuid,
+        rfd_number -> Int4,
+        rfd_revision_id -> Uuid,
+        event -> RfdWebhookEvent,
+        processed_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RfdWebhookEvent;
+
+    webhook_subscription (id) {
+        id -> Uuid,
+        url -> Varchar,
+        events -> Array<RfdWebhookEvent>,
+        rfd_numbers -> Nullable<Array<Int4>>,
+        secret -> Varchar,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::RfdWebhookEvent;
+    use super::sql_types::RfdWebhookDeliveryStatus;
+
+    webhook_subscription_delivery (id) {
+        id -> Uuid,
+        webhook_subscription_id -> Uuid,
+        event -> RfdWebhookEvent,
+        rfd_number -> Int4,
+        payload -> Jsonb,
+        status -> RfdWebhookDeliveryStatus,
+        attempts -> Int4,
+        response_status -> Nullable<Int4>,
+        last_error -> Nullable<Varchar>,
+        next_attempt_at -> Nullable<Timestamptz>,
+        delivered_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::joinable!(rfd_pdf -> rfd (rfd_id));
 diesel::joinable!(rfd_pdf -> rfd_revision (rfd_revision_id));
+diesel::joinable!(rfd_reference -> rfd_revision (rfd_revision_id));
 diesel::joinable!(rfd_revision -> rfd (rfd_id));
+diesel::joinable!(rfd_revision_author -> rfd_revision (rfd_revision_id));
+diesel::joinable!(rfd_revision_html -> rfd_revision (rfd_revision_id));
+diesel::joinable!(rfd_revision_label -> rfd_revision (rfd_revision_id));
+diesel::joinable!(rfd_watch_notification -> rfd_revision (rfd_revision_id));
+diesel::joinable!(webhook_subscription_delivery -> webhook_subscription (webhook_subscription_id));
 
-diesel::allow_tables_to_appear_in_same_query!(job, rfd, rfd_pdf, rfd_revision,);
+diesel::allow_tables_to_appear_in_same_query!(audit_event, job, rfd, rfd_number_reservation, rfd_pdf, rfd_reference, rfd_revision, rfd_revision_author, rfd_revision_html, rfd_revision_label, rfd_watch, rfd_watch_notification, webhook_subscription, webhook_subscription_delivery,);
@@ -17,7 +17,10 @@ use std::{
     io::Write,
 };
 
-use crate::schema::sql_types::{RfdContentFormat, RfdPdfSource, RfdVisibility};
+use crate::schema::sql_types::{
+    RfdContentFormat, RfdJobStatus, RfdPdfSource, RfdVisibility, RfdWebhookDeliveryStatus,
+    RfdWebhookEvent,
+};
 
 macro_rules! sql_conversion {
     (
@@ -119,6 +122,108 @@ impl Display for Visibility {
     }
 }
 
+#[derive(
+    Debug, PartialEq, Eq, Clone, Copy, FromSqlRow, AsExpression, Serialize, Deserialize, JsonSchema,
+)]
+#[diesel(sql_type = RfdJobStatus)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Dead,
+    Superseded,
+}
+
+sql_conversion! {
+    RfdJobStatus => JobStatus,
+    Queued => b"queued",
+    Running => b"running",
+    Succeeded => b"succeeded",
+    Failed => b"failed",
+    Dead => b"dead",
+    Superseded => b"superseded",
+}
+
+impl Display for JobStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobStatus::Queued => write!(f, "queued"),
+            JobStatus::Running => write!(f, "running"),
+            JobStatus::Succeeded => write!(f, "succeeded"),
+            JobStatus::Failed => write!(f, "failed"),
+            JobStatus::Dead => write!(f, "dead"),
+            JobStatus::Superseded => write!(f, "superseded"),
+        }
+    }
+}
+
+#[derive(
+    Debug, PartialEq, Eq, Clone, Copy, FromSqlRow, AsExpression, Serialize, Deserialize, JsonSchema,
+)]
+#[diesel(sql_type = RfdWebhookEvent)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    Reserved,
+    StateChanged,
+    MajorRevision,
+    VisibilityChanged,
+}
+
+sql_conversion! {
+    RfdWebhookEvent => WebhookEvent,
+    Reserved => b"reserved",
+    StateChanged => b"state_changed",
+    MajorRevision => b"major_revision",
+    VisibilityChanged => b"visibility_changed",
+}
+
+impl Display for WebhookEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookEvent::Reserved => write!(f, "reserved"),
+            WebhookEvent::StateChanged => write!(f, "state_changed"),
+            WebhookEvent::MajorRevision => write!(f, "major_revision"),
+            WebhookEvent::VisibilityChanged => write!(f, "visibility_changed"),
+        }
+    }
+}
+
+#[derive(
+    Debug, PartialEq, Eq, Clone, Copy, FromSqlRow, AsExpression, Serialize, Deserialize, JsonSchema,
+)]
+#[diesel(sql_type = RfdWebhookDeliveryStatus)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Sending,
+    Delivered,
+    Failed,
+    Dead,
+}
+
+sql_conversion! {
+    RfdWebhookDeliveryStatus => WebhookDeliveryStatus,
+    Pending => b"pending",
+    Sending => b"sending",
+    Delivered => b"delivered",
+    Failed => b"failed",
+    Dead => b"dead",
+}
+
+impl Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookDeliveryStatus::Pending => write!(f, "pending"),
+            WebhookDeliveryStatus::Sending => write!(f, "sending"),
+            WebhookDeliveryStatus::Delivered => write!(f, "delivered"),
+            WebhookDeliveryStatus::Failed => write!(f, "failed"),
+            WebhookDeliveryStatus::Dead => write!(f, "dead"),
+        }
+    }
+}
+
 // diesel::table! {
 //     use diesel::sql_types::*;
 //     use crate::schema::sql_types::{RfdContentFormat, RfdVisibility};
@@ -180,6 +285,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::schema::sql_types::RfdContentFormat;
+
+    rfd_feed_join (id) {
+        id -> Uuid,
+        rfd_id -> Uuid,
+        title -> Varchar,
+        state -> Nullable<Varchar>,
+        discussion -> Nullable<Varchar>,
+        authors -> Nullable<Varchar>,
+        content_format -> RfdContentFormat,
+        sha -> Varchar,
+        commit_sha -> Varchar,
+        committed_at -> Timestamptz,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+        labels -> Nullable<Varchar>,
+        major_change -> Bool,
+        previous_state -> Nullable<Varchar>,
+        position -> BigInt,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::schema::sql_types::{RfdPdfSource, RfdContentFormat, RfdVisibility};
@@ -3,20 +3,37 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use newtype_uuid::TypedUuid;
 use std::sync::Arc;
+use uuid::Uuid;
 use v_model::storage::StoreError;
 
 use crate::{
-    Job, NewJob, NewRfd, NewRfdPdf, NewRfdRevision, Rfd, RfdId, RfdMeta, RfdPdf, RfdPdfId, RfdPdfs,
-    RfdRevision, RfdRevisionId, RfdRevisionMeta,
+    schema_ext::WebhookEvent, AuditEvent, Job, NewAuditEvent, NewJob, NewRfd,
+    NewRfdNumberReservation, NewRfdPdf, NewRfdReference, NewRfdRevision, NewRfdRevisionAuthor,
+    NewRfdRevisionHtml, NewRfdRevisionLabel, NewRfdWatch, NewRfdWatchNotification,
+    NewWebhookSubscription, Rfd, RfdFeedRevision, RfdId, RfdMeta, RfdNumberReservation, RfdPdf,
+    RfdPdfId, RfdPdfs, RfdReference, RfdRevision, RfdRevisionAuthor, RfdRevisionHtml,
+    RfdRevisionId, RfdRevisionLabel, RfdRevisionMeta, RfdWatch, RfdWatchId, RfdWatchNotification,
+    RfdWatchNotificationId, WebhookSubscription, WebhookSubscriptionDelivery,
+    WebhookSubscriptionDeliveryId, WebhookSubscriptionId,
 };
 
 use super::{
-    JobFilter, JobStore, ListPagination, MockJobStore, MockRfdMetaStore, MockRfdPdfStore,
-    MockRfdPdfsStore, MockRfdRevisionMetaStore, MockRfdRevisionPdfStore, MockRfdRevisionStore,
-    MockRfdStore, RfdFilter, RfdMetaStore, RfdPdfFilter, RfdPdfStore, RfdPdfsStore,
-    RfdRevisionFilter, RfdRevisionMetaStore, RfdRevisionStore, RfdStore,
+    AuditEventFilter, AuditEventStore, JobFilter, JobStore, ListPagination, MockAuditEventStore,
+    MockJobStore, MockRfdMetaStore, MockRfdNumberReservationStore, MockRfdPdfStore,
+    MockRfdPdfsStore, MockRfdReferenceStore, MockRfdRevisionAuthorStore, MockRfdRevisionHtmlStore,
+    MockRfdRevisionLabelStore, MockRfdRevisionMetaStore, MockRfdRevisionPdfStore,
+    MockRfdRevisionStore, MockRfdStore, MockRfdWatchNotificationStore, MockRfdWatchStore,
+    MockWebhookSubscriptionDeliveryStore, MockWebhookSubscriptionStore, RfdFilter, RfdMetaStore,
+    RfdNumberReservationFilter, RfdNumberReservationStore, RfdPdfFilter, RfdPdfStore, RfdPdfsStore,
+    RfdReferenceFilter, RfdReferenceStore, RfdRevisionAuthorFilter, RfdRevisionAuthorStore,
+    RfdRevisionFilter, RfdRevisionHtmlStore, RfdRevisionLabelFilter, RfdRevisionLabelStore,
+    RfdRevisionMetaStore, RfdRevisionStore, RfdSort, RfdStore, RfdWatchFilter,
+    RfdWatchNotificationFilter, RfdWatchNotificationStore, RfdWatchStore,
+    WebhookSubscriptionDeliveryFilter, WebhookSubscriptionDeliveryStore, WebhookSubscriptionFilter,
+    WebhookSubscriptionStore,
 };
 
 pub struct MockStorage {
@@ -26,8 +43,18 @@ pub struct MockStorage {
     pub rfd_revision_store: Option<Arc<MockRfdRevisionStore>>,
     pub rfd_revision_meta_store: Option<Arc<MockRfdRevisionMetaStore>>,
     pub rfd_revision_pdf_store: Option<Arc<MockRfdRevisionPdfStore>>,
+    pub rfd_revision_author_store: Option<Arc<MockRfdRevisionAuthorStore>>,
+    pub rfd_revision_label_store: Option<Arc<MockRfdRevisionLabelStore>>,
+    pub rfd_reference_store: Option<Arc<MockRfdReferenceStore>>,
+    pub rfd_revision_html_store: Option<Arc<MockRfdRevisionHtmlStore>>,
     pub rfd_pdf_store: Option<Arc<MockRfdPdfStore>>,
     pub job_store: Option<Arc<MockJobStore>>,
+    pub audit_event_store: Option<Arc<MockAuditEventStore>>,
+    pub webhook_subscription_store: Option<Arc<MockWebhookSubscriptionStore>>,
+    pub webhook_subscription_delivery_store: Option<Arc<MockWebhookSubscriptionDeliveryStore>>,
+    pub rfd_watch_store: Option<Arc<MockRfdWatchStore>>,
+    pub rfd_watch_notification_store: Option<Arc<MockRfdWatchNotificationStore>>,
+    pub rfd_number_reservation_store: Option<Arc<MockRfdNumberReservationStore>>,
 }
 
 impl Default for MockStorage {
@@ -45,8 +72,18 @@ impl MockStorage {
             rfd_revision_store: None,
             rfd_revision_meta_store: None,
             rfd_revision_pdf_store: None,
+            rfd_revision_author_store: None,
+            rfd_revision_label_store: None,
+            rfd_reference_store: None,
+            rfd_revision_html_store: None,
             rfd_pdf_store: None,
             job_store: None,
+            audit_event_store: None,
+            webhook_subscription_store: None,
+            webhook_subscription_delivery_store: None,
+            rfd_watch_store: None,
+            rfd_watch_notification_store: None,
+            rfd_number_reservation_store: None,
         }
     }
 }
@@ -105,14 +142,19 @@ impl RfdMetaStore for MockStorage {
     async fn list(
         &self,
         filters: Vec<RfdFilter>,
+        sort: &RfdSort,
         pagination: &ListPagination,
     ) -> Result<Vec<RfdMeta>, StoreError> {
         self.rfd_meta_store
             .as_ref()
             .unwrap()
-            .list(filters, pagination)
+            .list(filters, sort, pagination)
             .await
     }
+
+    async fn count(&self, filters: Vec<RfdFilter>) -> Result<i64, StoreError> {
+        self.rfd_meta_store.as_ref().unwrap().count(filters).await
+    }
 }
 
 #[async_trait]
@@ -210,6 +252,121 @@ impl RfdRevisionMetaStore for MockStorage {
             .list(filters, pagination)
             .await
     }
+
+    async fn list_feed(
+        &self,
+        rfds: Vec<TypedUuid<RfdId>>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdFeedRevision>, StoreError> {
+        self.rfd_revision_meta_store
+            .as_ref()
+            .unwrap()
+            .list_feed(rfds, pagination)
+            .await
+    }
+}
+
+#[async_trait]
+impl RfdRevisionAuthorStore for MockStorage {
+    async fn list(
+        &self,
+        filters: Vec<RfdRevisionAuthorFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionAuthor>, StoreError> {
+        self.rfd_revision_author_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        authors: Vec<NewRfdRevisionAuthor>,
+    ) -> Result<Vec<RfdRevisionAuthor>, StoreError> {
+        self.rfd_revision_author_store
+            .as_ref()
+            .unwrap()
+            .replace(rfd_revision_id, authors)
+            .await
+    }
+}
+
+#[async_trait]
+impl RfdRevisionLabelStore for MockStorage {
+    async fn list(
+        &self,
+        filters: Vec<RfdRevisionLabelFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionLabel>, StoreError> {
+        self.rfd_revision_label_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        labels: Vec<NewRfdRevisionLabel>,
+    ) -> Result<Vec<RfdRevisionLabel>, StoreError> {
+        self.rfd_revision_label_store
+            .as_ref()
+            .unwrap()
+            .replace(rfd_revision_id, labels)
+            .await
+    }
+}
+
+#[async_trait]
+impl RfdReferenceStore for MockStorage {
+    async fn list(
+        &self,
+        filters: Vec<RfdReferenceFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdReference>, StoreError> {
+        self.rfd_reference_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        references: Vec<NewRfdReference>,
+    ) -> Result<Vec<RfdReference>, StoreError> {
+        self.rfd_reference_store
+            .as_ref()
+            .unwrap()
+            .replace(rfd_revision_id, references)
+            .await
+    }
+}
+
+#[async_trait]
+impl RfdRevisionHtmlStore for MockStorage {
+    async fn get(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+    ) -> Result<Option<RfdRevisionHtml>, StoreError> {
+        self.rfd_revision_html_store
+            .as_ref()
+            .unwrap()
+            .get(rfd_revision_id)
+            .await
+    }
+
+    async fn upsert(&self, new_html: NewRfdRevisionHtml) -> Result<RfdRevisionHtml, StoreError> {
+        self.rfd_revision_html_store
+            .as_ref()
+            .unwrap()
+            .upsert(new_html)
+            .await
+    }
 }
 
 #[async_trait]
@@ -265,11 +422,345 @@ impl JobStore for MockStorage {
         self.job_store.as_ref().unwrap().upsert(new_job).await
     }
 
-    async fn start(&self, id: i32) -> Result<Option<Job>, StoreError> {
-        self.job_store.as_ref().unwrap().start(id).await
+    async fn start(
+        &self,
+        id: i32,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<Job>, StoreError> {
+        self.job_store
+            .as_ref()
+            .unwrap()
+            .start(id, lease_expires_at)
+            .await
+    }
+
+    async fn heartbeat(
+        &self,
+        id: i32,
+        attempt: i32,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<Job>, StoreError> {
+        self.job_store
+            .as_ref()
+            .unwrap()
+            .heartbeat(id, attempt, lease_expires_at)
+            .await
+    }
+
+    async fn complete(&self, id: i32, attempt: i32) -> Result<Option<Job>, StoreError> {
+        self.job_store.as_ref().unwrap().complete(id, attempt).await
+    }
+
+    async fn requeue(&self, id: i32, at: DateTime<Utc>) -> Result<Option<Job>, StoreError> {
+        self.job_store.as_ref().unwrap().requeue(id, at).await
+    }
+
+    async fn supersede(&self, id: i32) -> Result<Option<Job>, StoreError> {
+        self.job_store.as_ref().unwrap().supersede(id).await
+    }
+
+    async fn fail(
+        &self,
+        id: i32,
+        attempt: i32,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<Job>, StoreError> {
+        self.job_store
+            .as_ref()
+            .unwrap()
+            .fail(id, attempt, error, retry_at)
+            .await
+    }
+
+    async fn reap(
+        &self,
+        expired_before: DateTime<Utc>,
+        max_attempts: i32,
+    ) -> Result<Vec<Job>, StoreError> {
+        self.job_store
+            .as_ref()
+            .unwrap()
+            .reap(expired_before, max_attempts)
+            .await
+    }
+}
+
+#[async_trait]
+impl AuditEventStore for MockStorage {
+    async fn list(
+        &self,
+        filters: Vec<AuditEventFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<AuditEvent>, StoreError> {
+        self.audit_event_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn create(&self, new_event: NewAuditEvent) -> Result<AuditEvent, StoreError> {
+        self.audit_event_store
+            .as_ref()
+            .unwrap()
+            .create(new_event)
+            .await
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionStore for MockStorage {
+    async fn get(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionId>,
+        deleted: bool,
+    ) -> Result<Option<WebhookSubscription>, StoreError> {
+        self.webhook_subscription_store
+            .as_ref()
+            .unwrap()
+            .get(id, deleted)
+            .await
+    }
+
+    async fn list(
+        &self,
+        filters: Vec<WebhookSubscriptionFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookSubscription>, StoreError> {
+        self.webhook_subscription_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn upsert(
+        &self,
+        new_subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, StoreError> {
+        self.webhook_subscription_store
+            .as_ref()
+            .unwrap()
+            .upsert(new_subscription)
+            .await
+    }
+
+    async fn delete(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionId>,
+    ) -> Result<Option<WebhookSubscription>, StoreError> {
+        self.webhook_subscription_store
+            .as_ref()
+            .unwrap()
+            .delete(id)
+            .await
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionDeliveryStore for MockStorage {
+    async fn list(
+        &self,
+        filters: Vec<WebhookSubscriptionDeliveryFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookSubscriptionDelivery>, StoreError> {
+        self.webhook_subscription_delivery_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn enqueue(
+        &self,
+        event: WebhookEvent,
+        rfd_number: i32,
+        payload: serde_json::Value,
+    ) -> Result<Vec<WebhookSubscriptionDelivery>, StoreError> {
+        self.webhook_subscription_delivery_store
+            .as_ref()
+            .unwrap()
+            .enqueue(event, rfd_number, payload)
+            .await
+    }
+
+    async fn start(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError> {
+        self.webhook_subscription_delivery_store
+            .as_ref()
+            .unwrap()
+            .start(id, lease_expires_at)
+            .await
+    }
+
+    async fn complete(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        response_status: i32,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError> {
+        self.webhook_subscription_delivery_store
+            .as_ref()
+            .unwrap()
+            .complete(id, response_status)
+            .await
+    }
+
+    async fn fail(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        response_status: Option<i32>,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError> {
+        self.webhook_subscription_delivery_store
+            .as_ref()
+            .unwrap()
+            .fail(id, response_status, error, retry_at)
+            .await
+    }
+}
+
+#[async_trait]
+impl RfdWatchStore for MockStorage {
+    async fn get(
+        &self,
+        id: &TypedUuid<RfdWatchId>,
+        deleted: bool,
+    ) -> Result<Option<RfdWatch>, StoreError> {
+        self.rfd_watch_store
+            .as_ref()
+            .unwrap()
+            .get(id, deleted)
+            .await
+    }
+
+    async fn list(
+        &self,
+        filters: Vec<RfdWatchFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdWatch>, StoreError> {
+        self.rfd_watch_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn create(&self, new_watch: NewRfdWatch) -> Result<RfdWatch, StoreError> {
+        self.rfd_watch_store
+            .as_ref()
+            .unwrap()
+            .create(new_watch)
+            .await
+    }
+
+    async fn delete(&self, id: &TypedUuid<RfdWatchId>) -> Result<Option<RfdWatch>, StoreError> {
+        self.rfd_watch_store.as_ref().unwrap().delete(id).await
+    }
+}
+
+#[async_trait]
+impl RfdWatchNotificationStore for MockStorage {
+    async fn list(
+        &self,
+        filters: Vec<RfdWatchNotificationFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdWatchNotification>, StoreError> {
+        self.rfd_watch_notification_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn create(
+        &self,
+        new_notification: NewRfdWatchNotification,
+    ) -> Result<RfdWatchNotification, StoreError> {
+        self.rfd_watch_notification_store
+            .as_ref()
+            .unwrap()
+            .create(new_notification)
+            .await
+    }
+
+    async fn claim(
+        &self,
+        id: &TypedUuid<RfdWatchNotificationId>,
+    ) -> Result<Option<RfdWatchNotification>, StoreError> {
+        self.rfd_watch_notification_store
+            .as_ref()
+            .unwrap()
+            .claim(id)
+            .await
+    }
+}
+
+#[async_trait]
+impl RfdNumberReservationStore for MockStorage {
+    async fn list(
+        &self,
+        filters: Vec<RfdNumberReservationFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError> {
+        self.rfd_number_reservation_store
+            .as_ref()
+            .unwrap()
+            .list(filters, pagination)
+            .await
+    }
+
+    async fn create(
+        &self,
+        new_reservations: Vec<NewRfdNumberReservation>,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError> {
+        self.rfd_number_reservation_store
+            .as_ref()
+            .unwrap()
+            .create(new_reservations)
+            .await
     }
 
-    async fn complete(&self, id: i32) -> Result<Option<Job>, StoreError> {
-        self.job_store.as_ref().unwrap().complete(id).await
+    async fn create_all(
+        &self,
+        new_reservations: Vec<NewRfdNumberReservation>,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError> {
+        self.rfd_number_reservation_store
+            .as_ref()
+            .unwrap()
+            .create_all(new_reservations)
+            .await
+    }
+
+    async fn claim(
+        &self,
+        rfd_number: i32,
+        api_user_id: Uuid,
+    ) -> Result<Option<RfdNumberReservation>, StoreError> {
+        self.rfd_number_reservation_store
+            .as_ref()
+            .unwrap()
+            .claim(rfd_number, api_user_id)
+            .await
+    }
+
+    async fn unclaim(&self, rfd_number: i32) -> Result<Option<RfdNumberReservation>, StoreError> {
+        self.rfd_number_reservation_store
+            .as_ref()
+            .unwrap()
+            .unclaim(rfd_number)
+            .await
+    }
+
+    async fn delete(&self, rfd_number: i32) -> Result<Option<RfdNumberReservation>, StoreError> {
+        self.rfd_number_reservation_store
+            .as_ref()
+            .unwrap()
+            .delete(rfd_number)
+            .await
     }
 }
@@ -4,17 +4,27 @@
 
 pub use async_bb8_diesel::{ConnectionError, PoolError};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 pub use diesel::result::Error as DbError;
 #[cfg(feature = "mock")]
 use mockall::automock;
 use newtype_uuid::TypedUuid;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use uuid::Uuid;
 use v_model::storage::{ListPagination, StoreError};
 
 use crate::{
-    schema_ext::PdfSource, CommitSha, Job, NewJob, NewRfd, NewRfdPdf, NewRfdRevision, Rfd, RfdId,
-    RfdMeta, RfdPdf, RfdPdfId, RfdPdfs, RfdRevision, RfdRevisionId, RfdRevisionMeta,
-    RfdRevisionPdf,
+    schema_ext::{JobStatus, PdfSource, WebhookDeliveryStatus, WebhookEvent},
+    AuditEvent, CommitSha, Job, NewAuditEvent, NewJob, NewRfd, NewRfdNumberReservation, NewRfdPdf,
+    NewRfdReference, NewRfdRevision, NewRfdRevisionAuthor, NewRfdRevisionHtml, NewRfdRevisionLabel,
+    NewRfdWatch, NewRfdWatchNotification, NewWebhookSubscription, Rfd, RfdFeedRevision, RfdId,
+    RfdMeta, RfdNumberReservation, RfdPdf, RfdPdfId, RfdPdfs, RfdReference, RfdRevision,
+    RfdRevisionAuthor, RfdRevisionHtml, RfdRevisionId, RfdRevisionLabel, RfdRevisionMeta,
+    RfdRevisionPdf, RfdWatch, RfdWatchId, RfdWatchNotification, RfdWatchNotificationId,
+    WebhookSubscription, WebhookSubscriptionDelivery, WebhookSubscriptionDeliveryId,
+    WebhookSubscriptionId,
 };
 
 #[cfg(feature = "mock")]
@@ -26,9 +36,19 @@ pub trait RfdStorage:
     + RfdMetaStore
     + RfdRevisionStore
     + RfdRevisionMetaStore
+    + RfdRevisionAuthorStore
+    + RfdRevisionLabelStore
+    + RfdReferenceStore
+    + RfdRevisionHtmlStore
     + RfdPdfStore
     + RfdPdfsStore
     + JobStore
+    + AuditEventStore
+    + WebhookSubscriptionStore
+    + WebhookSubscriptionDeliveryStore
+    + RfdWatchStore
+    + RfdWatchNotificationStore
+    + RfdNumberReservationStore
     + Send
     + Sync
     + 'static
@@ -39,16 +59,26 @@ impl<T> RfdStorage for T where
         + RfdMetaStore
         + RfdRevisionStore
         + RfdRevisionMetaStore
+        + RfdRevisionAuthorStore
+        + RfdRevisionLabelStore
+        + RfdReferenceStore
+        + RfdRevisionHtmlStore
         + RfdPdfStore
         + RfdPdfsStore
         + JobStore
+        + AuditEventStore
+        + WebhookSubscriptionStore
+        + WebhookSubscriptionDeliveryStore
+        + RfdWatchStore
+        + RfdWatchNotificationStore
+        + RfdNumberReservationStore
         + Send
         + Sync
         + 'static
 {
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct RfdFilter {
     pub id: Option<Vec<TypedUuid<RfdId>>>,
     pub revision: Option<Vec<TypedUuid<RfdRevisionId>>>,
@@ -56,6 +86,27 @@ pub struct RfdFilter {
     pub commit: Option<Vec<CommitSha>>,
     pub public: Option<bool>,
     pub deleted: bool,
+    pub state: Option<Vec<String>>,
+    /// Labels that must all be present on the RFD
+    pub labels: Option<Vec<String>>,
+    /// Case insensitive substring to match against the RFD authors
+    pub author: Option<String>,
+    /// Emails that must all belong to an author of the RFD, ignoring case
+    pub author_email: Option<Vec<String>>,
+    /// Case insensitive substring to match against the RFD title
+    pub title: Option<String>,
+    /// Only match RFDs whose latest revision was committed at or after this time
+    pub updated_since: Option<DateTime<Utc>>,
+    /// Only match RFDs that have had a major change committed at or after this time
+    pub major_change_since: Option<DateTime<Utc>>,
+    /// Only match RFDs whose revision references the given RFD number
+    pub references: Option<i32>,
+    /// Nested filters of which at least one must match. The deleted flag of nested filters is
+    /// ignored, deleted RFDs are only included based on the outermost filter
+    pub any: Option<Vec<RfdFilter>>,
+    /// Nested filters that must all match. As with `any`, the deleted flag of nested filters is
+    /// ignored
+    pub all: Option<Vec<RfdFilter>>,
 }
 
 impl RfdFilter {
@@ -88,6 +139,107 @@ impl RfdFilter {
         self.deleted = deleted;
         self
     }
+
+    pub fn state(mut self, state: Option<Vec<String>>) -> Self {
+        self.state = state;
+        self
+    }
+
+    pub fn labels(mut self, labels: Option<Vec<String>>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    pub fn author(mut self, author: Option<String>) -> Self {
+        self.author = author;
+        self
+    }
+
+    pub fn author_email(mut self, author_email: Option<Vec<String>>) -> Self {
+        self.author_email = author_email;
+        self
+    }
+
+    pub fn title(mut self, title: Option<String>) -> Self {
+        self.title = title;
+        self
+    }
+
+    pub fn updated_since(mut self, updated_since: Option<DateTime<Utc>>) -> Self {
+        self.updated_since = updated_since;
+        self
+    }
+
+    pub fn major_change_since(mut self, major_change_since: Option<DateTime<Utc>>) -> Self {
+        self.major_change_since = major_change_since;
+        self
+    }
+
+    pub fn references(mut self, references: Option<i32>) -> Self {
+        self.references = references;
+        self
+    }
+
+    pub fn any(mut self, any: Option<Vec<RfdFilter>>) -> Self {
+        self.any = any;
+        self
+    }
+
+    pub fn all(mut self, all: Option<Vec<RfdFilter>>) -> Self {
+        self.all = all;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RfdSortField {
+    #[default]
+    Number,
+    UpdatedAt,
+    LatestMajorChangeAt,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// Position of an RFD within a sorted list. The value is the RFD's value for the sort field, and is
+/// unused when sorting by number
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RfdSortKey {
+    pub rfd_number: i32,
+    pub value: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RfdSort {
+    pub field: RfdSortField,
+    pub direction: SortDirection,
+    /// Only include RFDs that are positioned after the given key. Ties on the sort field are
+    /// broken by RFD number
+    pub after: Option<RfdSortKey>,
+}
+
+impl RfdSort {
+    pub fn field(mut self, field: RfdSortField) -> Self {
+        self.field = field;
+        self
+    }
+
+    pub fn direction(mut self, direction: SortDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn after(mut self, after: Option<RfdSortKey>) -> Self {
+        self.after = after;
+        self
+    }
 }
 
 #[cfg_attr(feature = "mock", automock)]
@@ -120,8 +272,10 @@ pub trait RfdMetaStore {
     async fn list(
         &self,
         filters: Vec<RfdFilter>,
+        sort: &RfdSort,
         pagination: &ListPagination,
     ) -> Result<Vec<RfdMeta>, StoreError>;
+    async fn count(&self, filters: Vec<RfdFilter>) -> Result<i64, StoreError>;
 }
 
 #[cfg_attr(feature = "mock", automock)]
@@ -213,6 +367,14 @@ pub trait RfdRevisionMetaStore {
         filters: Vec<RfdRevisionFilter>,
         pagination: &ListPagination,
     ) -> Result<Vec<RfdRevisionMeta>, StoreError>;
+    /// List the revisions of the given RFDs that are reported in their activity feeds, newest
+    /// first. These are the first revision of each RFD along with any later revision that either
+    /// changes the state of the RFD or is flagged as a major change
+    async fn list_feed(
+        &self,
+        rfds: Vec<TypedUuid<RfdId>>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdFeedRevision>, StoreError>;
 }
 
 #[cfg_attr(feature = "mock", automock)]
@@ -230,6 +392,121 @@ pub trait RfdRevisionPdfStore {
     ) -> Result<Vec<RfdRevisionPdf>, StoreError>;
 }
 
+#[derive(Debug, Default)]
+pub struct RfdRevisionAuthorFilter {
+    pub rfd_revision: Option<Vec<TypedUuid<RfdRevisionId>>>,
+    /// Emails to match against, ignoring case
+    pub email: Option<Vec<String>>,
+}
+
+impl RfdRevisionAuthorFilter {
+    pub fn rfd_revision(mut self, rfd_revision: Option<Vec<TypedUuid<RfdRevisionId>>>) -> Self {
+        self.rfd_revision = rfd_revision;
+        self
+    }
+
+    pub fn email(mut self, email: Option<Vec<String>>) -> Self {
+        self.email = email;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdRevisionAuthorStore {
+    async fn list(
+        &self,
+        filters: Vec<RfdRevisionAuthorFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionAuthor>, StoreError>;
+    /// Replace all of the authors recorded for a revision with the given authors
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        authors: Vec<NewRfdRevisionAuthor>,
+    ) -> Result<Vec<RfdRevisionAuthor>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RfdRevisionLabelFilter {
+    pub rfd_revision: Option<Vec<TypedUuid<RfdRevisionId>>>,
+    /// Labels to match against, ignoring case
+    pub label: Option<Vec<String>>,
+}
+
+impl RfdRevisionLabelFilter {
+    pub fn rfd_revision(mut self, rfd_revision: Option<Vec<TypedUuid<RfdRevisionId>>>) -> Self {
+        self.rfd_revision = rfd_revision;
+        self
+    }
+
+    pub fn label(mut self, label: Option<Vec<String>>) -> Self {
+        self.label = label;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdRevisionLabelStore {
+    async fn list(
+        &self,
+        filters: Vec<RfdRevisionLabelFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionLabel>, StoreError>;
+    /// Replace all of the labels recorded for a revision with the given labels
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        labels: Vec<NewRfdRevisionLabel>,
+    ) -> Result<Vec<RfdRevisionLabel>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RfdReferenceFilter {
+    pub rfd_revision: Option<Vec<TypedUuid<RfdRevisionId>>>,
+    pub target_rfd_number: Option<Vec<i32>>,
+}
+
+impl RfdReferenceFilter {
+    pub fn rfd_revision(mut self, rfd_revision: Option<Vec<TypedUuid<RfdRevisionId>>>) -> Self {
+        self.rfd_revision = rfd_revision;
+        self
+    }
+
+    pub fn target_rfd_number(mut self, target_rfd_number: Option<Vec<i32>>) -> Self {
+        self.target_rfd_number = target_rfd_number;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdReferenceStore {
+    async fn list(
+        &self,
+        filters: Vec<RfdReferenceFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdReference>, StoreError>;
+    /// Replace all of the references recorded for a revision with the given references
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        references: Vec<NewRfdReference>,
+    ) -> Result<Vec<RfdReference>, StoreError>;
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdRevisionHtmlStore {
+    async fn get(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+    ) -> Result<Option<RfdRevisionHtml>, StoreError>;
+    /// Store the rendered HTML of a revision, replacing any previously rendered HTML
+    async fn upsert(&self, new_html: NewRfdRevisionHtml) -> Result<RfdRevisionHtml, StoreError>;
+}
+
 #[derive(Debug, Default)]
 pub struct RfdPdfFilter {
     pub id: Option<Vec<TypedUuid<RfdPdfId>>>,
@@ -294,8 +571,11 @@ pub struct JobFilter {
     pub id: Option<Vec<i32>>,
     pub sha: Option<Vec<String>>,
     pub rfd: Option<Vec<i32>>,
+    pub branch: Option<Vec<String>>,
     pub processed: Option<bool>,
     pub started: Option<bool>,
+    pub status: Option<Vec<JobStatus>>,
+    pub next_attempt_before: Option<DateTime<Utc>>,
 }
 
 impl JobFilter {
@@ -314,6 +594,11 @@ impl JobFilter {
         self
     }
 
+    pub fn branch(mut self, branch: Option<Vec<String>>) -> Self {
+        self.branch = branch;
+        self
+    }
+
     pub fn processed(mut self, processed: Option<bool>) -> Self {
         self.processed = processed;
         self
@@ -323,6 +608,18 @@ impl JobFilter {
         self.started = started;
         self
     }
+
+    pub fn status(mut self, status: Option<Vec<JobStatus>>) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Limit results to jobs that are either not waiting on a retry, or whose next attempt is
+    /// scheduled for at or before the given time
+    pub fn next_attempt_before(mut self, next_attempt_before: Option<DateTime<Utc>>) -> Self {
+        self.next_attempt_before = next_attempt_before;
+        self
+    }
 }
 
 #[cfg_attr(feature = "mock", automock)]
@@ -335,6 +632,368 @@ pub trait JobStore {
         pagination: &ListPagination,
     ) -> Result<Vec<Job>, StoreError>;
     async fn upsert(&self, new_job: NewJob) -> Result<Job, StoreError>;
-    async fn start(&self, id: i32) -> Result<Option<Job>, StoreError>;
-    async fn complete(&self, id: i32) -> Result<Option<Job>, StoreError>;
+    /// Claim a queued or failed job for the caller. The job is held until the given lease expiry,
+    /// after which it may be reclaimed. Returns `None` if the job could not be claimed
+    async fn start(
+        &self,
+        id: i32,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<Job>, StoreError>;
+    /// Extend the lease of a running job. Returns `None` if the job is no longer running the given
+    /// attempt
+    async fn heartbeat(
+        &self,
+        id: i32,
+        attempt: i32,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<Job>, StoreError>;
+    /// Mark the given attempt of a running job as having succeeded. Returns `None` if the job is
+    /// no longer running the given attempt, i.e. its lease was lost to another processor
+    async fn complete(&self, id: i32, attempt: i32) -> Result<Option<Job>, StoreError>;
+    /// Return a running job to the queue without counting the current attempt against it. The job
+    /// will not be picked up again until the given time
+    async fn requeue(&self, id: i32, at: DateTime<Utc>) -> Result<Option<Job>, StoreError>;
+    /// Mark a job that has not yet finished as no longer needing to run, as a newer job for the
+    /// same RFD will produce its result
+    async fn supersede(&self, id: i32) -> Result<Option<Job>, StoreError>;
+    /// Record a failed attempt of a running job. The job will be retried at the given time, or if
+    /// no retry time is given, it is moved to the dead state and will not be run again. Returns
+    /// `None` if the job is no longer running the given attempt
+    async fn fail(
+        &self,
+        id: i32,
+        attempt: i32,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<Job>, StoreError>;
+    /// Release all running jobs whose lease expired before the given time. Jobs that have
+    /// exhausted their attempts are moved to the dead state, all others are made available to be
+    /// retried immediately
+    async fn reap(
+        &self,
+        expired_before: DateTime<Utc>,
+        max_attempts: i32,
+    ) -> Result<Vec<Job>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct AuditEventFilter {
+    pub rfd_number: Option<Vec<i32>>,
+    pub caller_id: Option<Vec<Uuid>>,
+    pub operation: Option<Vec<String>>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl AuditEventFilter {
+    pub fn rfd_number(mut self, rfd_number: Option<Vec<i32>>) -> Self {
+        self.rfd_number = rfd_number;
+        self
+    }
+
+    pub fn caller_id(mut self, caller_id: Option<Vec<Uuid>>) -> Self {
+        self.caller_id = caller_id;
+        self
+    }
+
+    pub fn operation(mut self, operation: Option<Vec<String>>) -> Self {
+        self.operation = operation;
+        self
+    }
+
+    /// Limit results to events that were recorded at or after the given time
+    pub fn created_after(mut self, created_after: Option<DateTime<Utc>>) -> Self {
+        self.created_after = created_after;
+        self
+    }
+
+    /// Limit results to events that were recorded before the given time
+    pub fn created_before(mut self, created_before: Option<DateTime<Utc>>) -> Self {
+        self.created_before = created_before;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait AuditEventStore {
+    async fn list(
+        &self,
+        filters: Vec<AuditEventFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<AuditEvent>, StoreError>;
+    async fn create(&self, new_event: NewAuditEvent) -> Result<AuditEvent, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct WebhookSubscriptionFilter {
+    pub id: Option<Vec<TypedUuid<WebhookSubscriptionId>>>,
+    pub deleted: bool,
+}
+
+impl WebhookSubscriptionFilter {
+    pub fn id(mut self, id: Option<Vec<TypedUuid<WebhookSubscriptionId>>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait WebhookSubscriptionStore {
+    async fn get(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionId>,
+        deleted: bool,
+    ) -> Result<Option<WebhookSubscription>, StoreError>;
+    async fn list(
+        &self,
+        filters: Vec<WebhookSubscriptionFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookSubscription>, StoreError>;
+    async fn upsert(
+        &self,
+        new_subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, StoreError>;
+    async fn delete(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionId>,
+    ) -> Result<Option<WebhookSubscription>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct WebhookSubscriptionDeliveryFilter {
+    pub id: Option<Vec<TypedUuid<WebhookSubscriptionDeliveryId>>>,
+    pub webhook_subscription: Option<Vec<TypedUuid<WebhookSubscriptionId>>>,
+    pub status: Option<Vec<WebhookDeliveryStatus>>,
+    pub next_attempt_before: Option<DateTime<Utc>>,
+}
+
+impl WebhookSubscriptionDeliveryFilter {
+    pub fn id(mut self, id: Option<Vec<TypedUuid<WebhookSubscriptionDeliveryId>>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn webhook_subscription(
+        mut self,
+        webhook_subscription: Option<Vec<TypedUuid<WebhookSubscriptionId>>>,
+    ) -> Self {
+        self.webhook_subscription = webhook_subscription;
+        self
+    }
+
+    pub fn status(mut self, status: Option<Vec<WebhookDeliveryStatus>>) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Limit results to deliveries that are either not waiting on a retry, or whose next attempt
+    /// is scheduled for at or before the given time
+    pub fn next_attempt_before(mut self, next_attempt_before: Option<DateTime<Utc>>) -> Self {
+        self.next_attempt_before = next_attempt_before;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait WebhookSubscriptionDeliveryStore {
+    async fn list(
+        &self,
+        filters: Vec<WebhookSubscriptionDeliveryFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookSubscriptionDelivery>, StoreError>;
+    /// Create a pending delivery of an event for every subscription that is interested in it
+    async fn enqueue(
+        &self,
+        event: WebhookEvent,
+        rfd_number: i32,
+        payload: serde_json::Value,
+    ) -> Result<Vec<WebhookSubscriptionDelivery>, StoreError>;
+    /// Claim a pending or failed delivery for the caller. The delivery is held until the given
+    /// lease expiry, after which it may be reclaimed. Returns `None` if the delivery could not be
+    /// claimed
+    async fn start(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError>;
+    async fn complete(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        response_status: i32,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError>;
+    /// Record a failed attempt of a delivery. The delivery will be retried at the given time, or
+    /// if no retry time is given, it is moved to the dead state and will not be attempted again
+    async fn fail(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        response_status: Option<i32>,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RfdWatchFilter {
+    pub id: Option<Vec<TypedUuid<RfdWatchId>>>,
+    pub api_user_id: Option<Vec<Uuid>>,
+    pub rfd_number: Option<Vec<i32>>,
+    /// Labels to match against, ignoring case
+    pub label: Option<Vec<String>>,
+    pub deleted: bool,
+}
+
+impl RfdWatchFilter {
+    pub fn id(mut self, id: Option<Vec<TypedUuid<RfdWatchId>>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn api_user_id(mut self, api_user_id: Option<Vec<Uuid>>) -> Self {
+        self.api_user_id = api_user_id;
+        self
+    }
+
+    pub fn rfd_number(mut self, rfd_number: Option<Vec<i32>>) -> Self {
+        self.rfd_number = rfd_number;
+        self
+    }
+
+    pub fn label(mut self, label: Option<Vec<String>>) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn deleted(mut self, deleted: bool) -> Self {
+        self.deleted = deleted;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdWatchStore {
+    async fn get(
+        &self,
+        id: &TypedUuid<RfdWatchId>,
+        deleted: bool,
+    ) -> Result<Option<RfdWatch>, StoreError>;
+    async fn list(
+        &self,
+        filters: Vec<RfdWatchFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdWatch>, StoreError>;
+    async fn create(&self, new_watch: NewRfdWatch) -> Result<RfdWatch, StoreError>;
+    async fn delete(&self, id: &TypedUuid<RfdWatchId>) -> Result<Option<RfdWatch>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RfdWatchNotificationFilter {
+    pub id: Option<Vec<TypedUuid<RfdWatchNotificationId>>>,
+    pub processed: Option<bool>,
+}
+
+impl RfdWatchNotificationFilter {
+    pub fn id(mut self, id: Option<Vec<TypedUuid<RfdWatchNotificationId>>>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn processed(mut self, processed: Option<bool>) -> Self {
+        self.processed = processed;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdWatchNotificationStore {
+    /// List notifications ordered from oldest to newest
+    async fn list(
+        &self,
+        filters: Vec<RfdWatchNotificationFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdWatchNotification>, StoreError>;
+    async fn create(
+        &self,
+        new_notification: NewRfdWatchNotification,
+    ) -> Result<RfdWatchNotification, StoreError>;
+    /// Mark a notification as processed on behalf of the caller. Returns `None` if the
+    /// notification has already been processed, in which case it must not be sent again
+    async fn claim(
+        &self,
+        id: &TypedUuid<RfdWatchNotificationId>,
+    ) -> Result<Option<RfdWatchNotification>, StoreError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RfdNumberReservationFilter {
+    pub rfd_number: Option<Vec<i32>>,
+    /// Only include reservations of numbers greater than or equal to this number
+    pub min_rfd_number: Option<i32>,
+    pub group_id: Option<Vec<Uuid>>,
+    pub claimed: Option<bool>,
+}
+
+impl RfdNumberReservationFilter {
+    pub fn rfd_number(mut self, rfd_number: Option<Vec<i32>>) -> Self {
+        self.rfd_number = rfd_number;
+        self
+    }
+
+    pub fn min_rfd_number(mut self, min_rfd_number: Option<i32>) -> Self {
+        self.min_rfd_number = min_rfd_number;
+        self
+    }
+
+    pub fn group_id(mut self, group_id: Option<Vec<Uuid>>) -> Self {
+        self.group_id = group_id;
+        self
+    }
+
+    pub fn claimed(mut self, claimed: Option<bool>) -> Self {
+        self.claimed = claimed;
+        self
+    }
+}
+
+#[cfg_attr(feature = "mock", automock)]
+#[async_trait]
+pub trait RfdNumberReservationStore {
+    /// List reservations ordered by RFD number
+    async fn list(
+        &self,
+        filters: Vec<RfdNumberReservationFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError>;
+    /// Reserve each of the given numbers. Numbers that are already reserved are left untouched and
+    /// are not included in the result
+    async fn create(
+        &self,
+        new_reservations: Vec<NewRfdNumberReservation>,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError>;
+    /// Reserve all of the given numbers together. If any of the numbers is already reserved then
+    /// none of them are reserved and an empty list is returned
+    async fn create_all(
+        &self,
+        new_reservations: Vec<NewRfdNumberReservation>,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError>;
+    /// Claim a held number on behalf of a user. Returns `None` if the number is not reserved or
+    /// has already been claimed
+    async fn claim(
+        &self,
+        rfd_number: i32,
+        api_user_id: Uuid,
+    ) -> Result<Option<RfdNumberReservation>, StoreError>;
+    /// Return a claimed number to being held
+    async fn unclaim(&self, rfd_number: i32) -> Result<Option<RfdNumberReservation>, StoreError>;
+    /// Remove the reservation of a number so that it can be allocated again
+    async fn delete(&self, rfd_number: i32) -> Result<Option<RfdNumberReservation>, StoreError>;
 }
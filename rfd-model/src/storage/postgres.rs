@@ -2,23 +2,29 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use async_bb8_diesel::AsyncRunQueryDsl;
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use diesel::{
-    debug_query,
-    dsl::max,
+    debug_query, delete,
+    dsl::{max, sql},
+    helper_types::LeftJoinQuerySource,
     insert_into,
     pg::Pg,
+    query_builder::{BoxedSqlQuery, SqlQuery},
     query_dsl::QueryDsl,
     sql_query,
-    sql_types::Bool,
+    sql_types::{Array, BigInt, Bool, Integer, Jsonb, Nullable, Text, Timestamptz},
     update,
     upsert::{excluded, on_constraint},
     BoolExpressionMethods, BoxableExpression, ExpressionMethods, NullableExpressionMethods,
+    PgTextExpressionMethods, QueryableByName,
 };
 use newtype_uuid::{GenericUuid, TypedUuid};
-use std::collections::{btree_map::Entry, BTreeMap, HashMap};
+use std::{
+    collections::{btree_map::Entry, BTreeMap, HashMap},
+    future::Future,
+};
 use tap::TapFallible;
 use tracing::instrument;
 use uuid::Uuid;
@@ -26,22 +32,45 @@ use v_model::storage::postgres::PostgresStore;
 
 use crate::{
     db::{
-        JobModel, RfdLatestMajorChange, RfdMetaJoinRow, RfdModel, RfdPdfJoinRow, RfdPdfModel,
-        RfdRevisionMetaModel, RfdRevisionModel, RfdRevisionPdfModel,
+        AuditEventModel, JobModel, RfdFeedRow, RfdLatestMajorChange, RfdMetaJoinRow, RfdModel,
+        RfdNumberReservationModel, RfdPdfJoinRow, RfdPdfModel, RfdReferenceModel,
+        RfdRevisionAuthorModel, RfdRevisionHtmlModel, RfdRevisionLabelModel, RfdRevisionMetaModel,
+        RfdRevisionModel, RfdRevisionPdfModel, RfdWatchModel, RfdWatchNotificationModel,
+        WebhookSubscriptionDeliveryModel, WebhookSubscriptionModel,
+    },
+    schema::{
+        audit_event, job, rfd, rfd_number_reservation, rfd_pdf, rfd_reference, rfd_revision,
+        rfd_revision_author, rfd_revision_label, rfd_watch, rfd_watch_notification,
+        sql_types::{RfdVisibility, RfdWebhookEvent},
+        webhook_subscription, webhook_subscription_delivery,
     },
-    schema::{job, rfd, rfd_pdf, rfd_revision},
-    schema_ext::Visibility,
+    schema_ext::{JobStatus, Visibility, WebhookDeliveryStatus, WebhookEvent},
     storage::StoreError,
-    Job, NewJob, NewRfd, NewRfdPdf, NewRfdRevision, Rfd, RfdId, RfdMeta, RfdPdf, RfdPdfId, RfdPdfs,
-    RfdRevision, RfdRevisionId, RfdRevisionMeta, RfdRevisionPdf,
+    AuditEvent, Job, NewAuditEvent, NewJob, NewRfd, NewRfdNumberReservation, NewRfdPdf,
+    NewRfdReference, NewRfdRevision, NewRfdRevisionAuthor, NewRfdRevisionHtml, NewRfdRevisionLabel,
+    NewRfdWatch, NewRfdWatchNotification, NewWebhookSubscription, Rfd, RfdFeedRevision, RfdId,
+    RfdMeta, RfdNumberReservation, RfdPdf, RfdPdfId, RfdPdfs, RfdReference, RfdRevision,
+    RfdRevisionAuthor, RfdRevisionHtml, RfdRevisionId, RfdRevisionLabel, RfdRevisionMeta,
+    RfdRevisionPdf, RfdWatch, RfdWatchId, RfdWatchNotification, RfdWatchNotificationId,
+    WebhookSubscription, WebhookSubscriptionDelivery, WebhookSubscriptionDeliveryId,
+    WebhookSubscriptionId,
 };
 
 use super::{
-    JobFilter, JobStore, ListPagination, RfdFilter, RfdMetaStore, RfdPdfFilter, RfdPdfStore,
-    RfdPdfsStore, RfdRevisionFilter, RfdRevisionMetaStore, RfdRevisionPdfStore, RfdRevisionStore,
-    RfdStore,
+    AuditEventFilter, AuditEventStore, JobFilter, JobStore, ListPagination, RfdFilter,
+    RfdMetaStore, RfdNumberReservationFilter, RfdNumberReservationStore, RfdPdfFilter, RfdPdfStore,
+    RfdPdfsStore, RfdReferenceFilter, RfdReferenceStore, RfdRevisionAuthorFilter,
+    RfdRevisionAuthorStore, RfdRevisionFilter, RfdRevisionHtmlStore, RfdRevisionLabelFilter,
+    RfdRevisionLabelStore, RfdRevisionMetaStore, RfdRevisionPdfStore, RfdRevisionStore, RfdSort,
+    RfdSortField, RfdStore, RfdWatchFilter, RfdWatchNotificationFilter, RfdWatchNotificationStore,
+    RfdWatchStore, SortDirection, WebhookSubscriptionDeliveryFilter,
+    WebhookSubscriptionDeliveryStore, WebhookSubscriptionFilter, WebhookSubscriptionStore,
 };
 
+diesel::define_sql_function! {
+    fn lower(value: Text) -> Text;
+}
+
 #[async_trait]
 impl RfdStore for PostgresStore {
     async fn get(
@@ -79,60 +108,7 @@ impl RfdStore for PostgresStore {
 
         let filter_predicates = filters
             .into_iter()
-            .map(|filter| {
-                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
-                let RfdFilter {
-                    id,
-                    revision,
-                    rfd_number,
-                    commit,
-                    public,
-                    deleted,
-                } = filter;
-
-                if let Some(id) = id {
-                    predicates.push(Box::new(
-                        rfd::id.eq_any(id.into_iter().map(GenericUuid::into_untyped_uuid)),
-                    ));
-                }
-
-                if let Some(revision) = revision {
-                    predicates.push(Box::new(
-                        rfd_revision::id
-                            .assume_not_null()
-                            .eq_any(revision.into_iter().map(GenericUuid::into_untyped_uuid)),
-                    ));
-                }
-
-                if let Some(rfd_number) = rfd_number {
-                    predicates.push(Box::new(rfd::rfd_number.eq_any(rfd_number)));
-                }
-
-                if let Some(commit) = commit {
-                    predicates.push(Box::new(
-                        rfd_revision::commit_sha
-                            .assume_not_null()
-                            .eq_any(commit.into_iter().map(|sha| sha.0)),
-                    ));
-                }
-
-                if let Some(public) = public {
-                    predicates.push(Box::new(rfd::visibility.eq(if public {
-                        Visibility::Public
-                    } else {
-                        Visibility::Private
-                    })));
-                }
-
-                if !deleted {
-                    predicates.push(Box::new(rfd::deleted_at.is_null()));
-                    predicates.push(Box::new(
-                        rfd_revision::deleted_at.assume_not_null().is_null(),
-                    ));
-                }
-
-                predicates
-            })
+            .map(|filter| rfd_filter_predicates(filter, false))
             .collect::<Vec<_>>();
 
         if let Some(predicate) = flatten_predicates(filter_predicates) {
@@ -256,6 +232,7 @@ impl RfdMetaStore for PostgresStore {
                 .id(Some(vec![id]))
                 .revision(revision.map(|rev| vec![rev]))
                 .deleted(deleted)],
+            &RfdSort::default(),
             &ListPagination::default().limit(1),
         )
         .await?;
@@ -265,88 +242,14 @@ impl RfdMetaStore for PostgresStore {
     async fn list(
         &self,
         filters: Vec<RfdFilter>,
+        sort: &RfdSort,
         pagination: &ListPagination,
     ) -> Result<Vec<RfdMeta>, StoreError> {
-        tracing::trace!(?filters, "Lookup RFDs");
-
-        let mut clauses = vec![];
-        let mut bind_count = 1;
-
-        for filter in &filters {
-            let mut filter_clause = "1=1".to_string();
-
-            let RfdFilter {
-                id,
-                revision,
-                rfd_number,
-                commit,
-                public,
-                deleted,
-            } = filter;
-
-            if let Some(ids) = &id {
-                let id_binds = ids
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format!("${}", bind_count + i))
-                    .collect::<Vec<_>>();
-                bind_count += id_binds.len();
-                filter_clause = filter_clause + &format!(" AND rfd.id IN ({})", id_binds.join(","));
-            }
-
-            if let Some(revisions) = &revision {
-                let revision_binds = revisions
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format!("${}", bind_count + i))
-                    .collect::<Vec<_>>();
-                bind_count += revision_binds.len();
-                filter_clause = filter_clause
-                    + &format!(" AND rfd_revision.id IN ({})", revision_binds.join(","));
-            }
-
-            if let Some(rfd_numbers) = &rfd_number {
-                let rfd_number_binds = rfd_numbers
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format!("${}", bind_count + i))
-                    .collect::<Vec<_>>();
-                bind_count += rfd_number_binds.len();
-                filter_clause = filter_clause
-                    + &format!(" AND rfd.rfd_number IN ({})", rfd_number_binds.join(","));
-            }
+        tracing::trace!(?filters, ?sort, "Lookup RFDs");
 
-            if let Some(commit_shas) = &commit {
-                let commit_sha_binds = commit_shas
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format!("${}", bind_count + i))
-                    .collect::<Vec<_>>();
-                bind_count += commit_sha_binds.len();
-                filter_clause = filter_clause
-                    + &format!(
-                        " AND rfd_revision.commit_sha IN ({})",
-                        commit_sha_binds.join(",")
-                    );
-            }
-
-            if public.is_some() {
-                bind_count += 1;
-                filter_clause += " AND rfd.public = {}";
-            }
-
-            if !deleted {
-                filter_clause += " AND rfd.deleted_at IS NULL AND rfd_revision.deleted_at IS NULL";
-            }
-
-            clauses.push(format!("({})", filter_clause));
-        }
-
-        let where_clause = if !clauses.is_empty() {
-            format!("({})", clauses.join(" OR "))
-        } else {
-            "1=1".to_string()
-        };
+        let mut binds = vec![];
+        let where_clause = rfd_filter_clause(&filters, RFD_META_TABLES, false, &mut binds);
+        let (cursor_clause, order_clause) = rfd_sort_clauses(sort, &mut binds);
 
         let raw_query = format!(
             r#"SELECT
@@ -389,7 +292,7 @@ impl RfdMetaStore for PostgresStore {
                     rfd_revision.created_at DESC
                 LIMIT 1
             )
-        WHERE {} AND
+        WHERE {} AND {} AND
             rfd_revision.id = (
                 SELECT rfd_revision.id
                 FROM rfd_revision
@@ -400,62 +303,16 @@ impl RfdMetaStore for PostgresStore {
                 LIMIT 1
             )
         ORDER BY
-            rfd_revision.rfd_id ASC,
-            rfd_revision.committed_at DESC,
-            rfd_revision.created_at DESC
+            {}
         LIMIT ${} OFFSET ${}"#,
             where_clause,
-            bind_count,
-            bind_count + 1,
+            cursor_clause,
+            order_clause,
+            binds.len() + 1,
+            binds.len() + 2,
         );
 
-        let mut query = sql_query(raw_query).into_boxed::<Pg>();
-
-        for filter in &filters {
-            let RfdFilter {
-                id,
-                revision,
-                rfd_number,
-                commit,
-                public,
-                ..
-            } = filter;
-
-            if let Some(ids) = &id {
-                for id in ids {
-                    tracing::trace!(?id, "Binding id parameter");
-                    query = query.bind::<diesel::sql_types::Uuid, _>(id.into_untyped_uuid());
-                }
-            }
-
-            if let Some(revisions) = &revision {
-                for revision in revisions {
-                    tracing::trace!(?revision, "Binding revision parameter");
-                    query = query.bind::<diesel::sql_types::Uuid, _>(revision.into_untyped_uuid());
-                }
-            }
-
-            if let Some(rfd_numbers) = &rfd_number {
-                for rfd_number in rfd_numbers {
-                    tracing::trace!(?rfd_number, "Binding rfd_number parameter");
-                    query = query.bind::<diesel::sql_types::Integer, _>(*rfd_number);
-                }
-            }
-
-            if let Some(commits) = &commit {
-                for commit in commits {
-                    tracing::trace!(?commit, "Binding commit parameter");
-                    query = query.bind::<diesel::sql_types::VarChar, _>(commit.to_string());
-                }
-            }
-
-            if let Some(public) = &public {
-                tracing::trace!(?public, "Binding public parameter");
-                query = query.bind::<diesel::sql_types::Bool, _>(*public);
-            }
-        }
-
-        query = query
+        let query = bind_rfd_filters(sql_query(raw_query).into_boxed::<Pg>(), binds)
             .bind::<diesel::sql_types::Integer, _>(pagination.limit as i32)
             .bind::<diesel::sql_types::Integer, _>(pagination.offset as i32);
 
@@ -476,6 +333,47 @@ impl RfdMetaStore for PostgresStore {
 
         Ok(results)
     }
+
+    async fn count(&self, filters: Vec<RfdFilter>) -> Result<i64, StoreError> {
+        tracing::trace!(?filters, "Count RFDs");
+
+        let mut binds = vec![];
+        let where_clause = rfd_filter_clause(&filters, RFD_META_TABLES, false, &mut binds);
+
+        let raw_query = format!(
+            r#"SELECT
+            COUNT(*) as count
+        FROM
+            rfd
+        INNER JOIN
+            rfd_revision
+            ON rfd_revision.rfd_id = rfd.id
+        WHERE {} AND
+            rfd_revision.id = (
+                SELECT rfd_revision.id
+                FROM rfd_revision
+                WHERE rfd_revision.rfd_id = rfd.id
+                ORDER BY
+                    rfd_revision.committed_at DESC,
+                    rfd_revision.created_at DESC
+                LIMIT 1
+            )"#,
+            where_clause,
+        );
+
+        let query = bind_rfd_filters(sql_query(raw_query).into_boxed::<Pg>(), binds);
+
+        tracing::trace!(query = ?debug_query(&query), "Count RFDs query");
+
+        let result =
+            query
+                .get_result_async::<CountResult>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        Ok(result.count)
+    }
 }
 
 #[async_trait]
@@ -505,84 +403,8 @@ impl RfdPdfsStore for PostgresStore {
     ) -> Result<Vec<RfdPdfs>, StoreError> {
         tracing::trace!(?filters, "Lookup RFDs");
 
-        let mut clauses = vec![];
-        let mut bind_count = 1;
-
-        for filter in &filters {
-            let mut filter_clause = "1=1".to_string();
-
-            let RfdFilter {
-                id,
-                revision,
-                rfd_number,
-                commit,
-                public,
-                deleted,
-            } = filter;
-
-            if let Some(ids) = &id {
-                let id_binds = ids
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format!("${}", bind_count + i))
-                    .collect::<Vec<_>>();
-                bind_count += id_binds.len();
-                filter_clause = filter_clause + &format!(" AND rfd.id IN ({})", id_binds.join(","));
-            }
-
-            if let Some(revisions) = &revision {
-                let revision_binds = revisions
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format!("${}", bind_count + i))
-                    .collect::<Vec<_>>();
-                bind_count += revision_binds.len();
-                filter_clause = filter_clause
-                    + &format!(" AND rfd_revision.id IN ({})", revision_binds.join(","));
-            }
-
-            if let Some(rfd_numbers) = &rfd_number {
-                let rfd_number_binds = rfd_numbers
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format!("${}", bind_count + i))
-                    .collect::<Vec<_>>();
-                bind_count += rfd_number_binds.len();
-                filter_clause = filter_clause
-                    + &format!(" AND rfd.rfd_number IN ({})", rfd_number_binds.join(","));
-            }
-
-            if let Some(commit_shas) = &commit {
-                let commit_sha_binds = commit_shas
-                    .iter()
-                    .enumerate()
-                    .map(|(i, _)| format!("${}", bind_count + i))
-                    .collect::<Vec<_>>();
-                bind_count += commit_sha_binds.len();
-                filter_clause = filter_clause
-                    + &format!(
-                        " AND rfd_revision.commit_sha IN ({})",
-                        commit_sha_binds.join(",")
-                    );
-            }
-
-            if public.is_some() {
-                bind_count += 1;
-                filter_clause += " AND rfd.public = {}";
-            }
-
-            if !deleted {
-                filter_clause += " AND rfd.deleted_at IS NULL AND rfd_revision.deleted_at IS NULL AND rfd_pdf.deleted_at IS NULL";
-            }
-
-            clauses.push(format!("({})", filter_clause));
-        }
-
-        let where_clause = if !clauses.is_empty() {
-            format!("({})", clauses.join(" OR "))
-        } else {
-            "1=1".to_string()
-        };
+        let mut binds = vec![];
+        let where_clause = rfd_filter_clause(&filters, RFD_PDF_TABLES, false, &mut binds);
 
         let raw_query = format!(
             r#"SELECT
@@ -653,57 +475,11 @@ impl RfdPdfsStore for PostgresStore {
             rfd_revision.created_at DESC
         LIMIT ${} OFFSET ${}"#,
             where_clause,
-            bind_count,
-            bind_count + 1,
+            binds.len() + 1,
+            binds.len() + 2,
         );
 
-        let mut query = sql_query(raw_query).into_boxed::<Pg>();
-
-        for filter in &filters {
-            let RfdFilter {
-                id,
-                revision,
-                rfd_number,
-                commit,
-                public,
-                ..
-            } = filter;
-
-            if let Some(ids) = &id {
-                for id in ids {
-                    tracing::trace!(?id, "Binding id parameter");
-                    query = query.bind::<diesel::sql_types::Uuid, _>(id.into_untyped_uuid());
-                }
-            }
-
-            if let Some(revisions) = &revision {
-                for revision in revisions {
-                    tracing::trace!(?revision, "Binding revision parameter");
-                    query = query.bind::<diesel::sql_types::Uuid, _>(revision.into_untyped_uuid());
-                }
-            }
-
-            if let Some(rfd_numbers) = &rfd_number {
-                for rfd_number in rfd_numbers {
-                    tracing::trace!(?rfd_number, "Binding rfd_number parameter");
-                    query = query.bind::<diesel::sql_types::Integer, _>(*rfd_number);
-                }
-            }
-
-            if let Some(commits) = &commit {
-                for commit in commits {
-                    tracing::trace!(?commit, "Binding commit parameter");
-                    query = query.bind::<diesel::sql_types::VarChar, _>(commit.to_string());
-                }
-            }
-
-            if let Some(public) = &public {
-                tracing::trace!(?public, "Binding public parameter");
-                query = query.bind::<diesel::sql_types::Bool, _>(*public);
-            }
-        }
-
-        query = query
+        let query = bind_rfd_filters(sql_query(raw_query).into_boxed::<Pg>(), binds)
             .bind::<diesel::sql_types::Integer, _>(pagination.limit as i32)
             .bind::<diesel::sql_types::Integer, _>(pagination.offset as i32);
 
@@ -1009,6 +785,84 @@ impl RfdRevisionMetaStore for PostgresStore {
             .map(|revision| revision.into())
             .collect())
     }
+
+    async fn list_feed(
+        &self,
+        rfds: Vec<TypedUuid<RfdId>>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdFeedRevision>, StoreError> {
+        tracing::trace!(?rfds, "Lookup RFD feed revisions");
+
+        // The window is computed over all of the live revisions of an RFD so that each revision
+        // can be compared against the one that precedes it, and only then are the revisions that
+        // do not produce a feed entry discarded
+        let raw_query = r#"SELECT
+            feed.*
+        FROM (
+            SELECT
+                rfd_revision.id,
+                rfd_revision.rfd_id,
+                rfd_revision.title,
+                rfd_revision.state,
+                rfd_revision.discussion,
+                rfd_revision.authors,
+                rfd_revision.content_format,
+                rfd_revision.sha,
+                rfd_revision.commit_sha,
+                rfd_revision.committed_at,
+                rfd_revision.created_at,
+                rfd_revision.updated_at,
+                rfd_revision.deleted_at,
+                rfd_revision.labels,
+                rfd_revision.major_change,
+                LAG(rfd_revision.state) OVER revisions AS previous_state,
+                ROW_NUMBER() OVER revisions AS position
+            FROM
+                rfd_revision
+            WHERE
+                rfd_revision.rfd_id = ANY($1) AND
+                rfd_revision.deleted_at IS NULL
+            WINDOW revisions AS (
+                PARTITION BY rfd_revision.rfd_id
+                ORDER BY rfd_revision.committed_at ASC, rfd_revision.created_at ASC
+            )
+        ) AS feed
+        WHERE
+            feed.position = 1 OR
+            feed.previous_state IS DISTINCT FROM feed.state OR
+            feed.major_change
+        ORDER BY
+            feed.committed_at DESC,
+            feed.created_at DESC
+        LIMIT $2
+        OFFSET $3"#;
+
+        let query = sql_query(raw_query)
+            .into_boxed::<Pg>()
+            .bind::<Array<diesel::sql_types::Uuid>, _>(
+                rfds.into_iter()
+                    .map(GenericUuid::into_untyped_uuid)
+                    .collect::<Vec<_>>(),
+            )
+            .bind::<diesel::sql_types::Integer, _>(pagination.limit as i32)
+            .bind::<diesel::sql_types::Integer, _>(pagination.offset as i32);
+
+        tracing::trace!(query = ?debug_query(&query), "List RFD feed revisions query");
+
+        let rows =
+            query
+                .get_results_async::<RfdFeedRow>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        tracing::trace!(count = ?rows.len(), "Found RFD feed revisions");
+
+        Ok(rows
+            .into_iter()
+            .map(|row| <(RfdRevisionMetaModel, Option<String>, i64)>::from(row).into())
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -1149,74 +1003,43 @@ impl RfdRevisionPdfStore for PostgresStore {
 }
 
 #[async_trait]
-impl RfdPdfStore for PostgresStore {
-    async fn get(
-        &self,
-        id: &TypedUuid<RfdPdfId>,
-        deleted: bool,
-    ) -> Result<Option<RfdPdf>, StoreError> {
-        let user = RfdPdfStore::list(
-            self,
-            vec![RfdPdfFilter::default().id(Some(vec![*id])).deleted(deleted)],
-            &ListPagination::default().limit(1),
-        )
-        .await?;
-        Ok(user.into_iter().nth(0))
-    }
-
+impl RfdRevisionAuthorStore for PostgresStore {
     async fn list(
         &self,
-        filters: Vec<RfdPdfFilter>,
+        filters: Vec<RfdRevisionAuthorFilter>,
         pagination: &ListPagination,
-    ) -> Result<Vec<RfdPdf>, StoreError> {
-        let mut query = rfd_pdf::dsl::rfd_pdf.into_boxed();
+    ) -> Result<Vec<RfdRevisionAuthor>, StoreError> {
+        let mut query = rfd_revision_author::dsl::rfd_revision_author.into_boxed();
 
-        tracing::trace!(?filters, "Lookup RFD pdfs");
+        tracing::trace!(?filters, "Lookup RFD revision authors");
 
         let filter_predicates = filters
             .into_iter()
             .map(|filter| {
                 let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
-                let RfdPdfFilter {
-                    id,
+                let RfdRevisionAuthorFilter {
                     rfd_revision,
-                    source,
-                    deleted,
-                    rfd,
-                    external_id,
+                    email,
                 } = filter;
 
-                if let Some(id) = id {
-                    predicates.push(Box::new(
-                        rfd_pdf::id.eq_any(id.into_iter().map(GenericUuid::into_untyped_uuid)),
-                    ));
-                }
-
                 if let Some(rfd_revision) = rfd_revision {
                     predicates
-                        .push(Box::new(rfd_pdf::rfd_revision_id.eq_any(
+                        .push(Box::new(rfd_revision_author::rfd_revision_id.eq_any(
                             rfd_revision.into_iter().map(GenericUuid::into_untyped_uuid),
                         )));
                 }
 
-                if let Some(source) = source {
-                    predicates.push(Box::new(rfd_pdf::source.eq_any(source)));
-                }
-
-                if let Some(rfd) = rfd {
+                if let Some(email) = email {
                     predicates.push(Box::new(
-                        rfd_pdf::rfd_id.eq_any(rfd.into_iter().map(GenericUuid::into_untyped_uuid)),
+                        lower(rfd_revision_author::email.assume_not_null()).eq_any(
+                            email
+                                .into_iter()
+                                .map(|email| email.to_lowercase())
+                                .collect::<Vec<_>>(),
+                        ),
                     ));
                 }
 
-                if let Some(external_id) = external_id {
-                    predicates.push(Box::new(rfd_pdf::external_id.eq_any(external_id)));
-                }
-
-                if !deleted {
-                    predicates.push(Box::new(rfd_pdf::deleted_at.is_null()));
-                }
-
                 predicates
             })
             .collect::<Vec<_>>();
@@ -1229,111 +1052,1854 @@ impl RfdPdfStore for PostgresStore {
             query
                 .offset(pagination.offset)
                 .limit(pagination.limit)
-                .order(rfd_pdf::created_at.desc())
-                .get_results_async::<RfdPdfModel>(&*self.pool.get().await.tap_err(|err| {
-                    tracing::error!(?err, "Failed to acquire database connection")
-                })?)
-                .await?;
-
-        Ok(results
-            .into_iter()
-            .map(|revision| revision.into())
-            .collect())
-    }
-
-    async fn upsert(&self, new_pdf: NewRfdPdf) -> Result<RfdPdf, StoreError> {
-        let rfd: RfdPdfModel =
-            insert_into(rfd_pdf::dsl::rfd_pdf)
-                .values((
-                    rfd_pdf::id.eq(Uuid::new_v4()),
-                    rfd_pdf::rfd_revision_id.eq(new_pdf.rfd_revision_id.into_untyped_uuid()),
-                    rfd_pdf::source.eq(new_pdf.source.clone()),
-                    rfd_pdf::link.eq(new_pdf.link.clone()),
-                    rfd_pdf::rfd_id.eq(new_pdf.rfd_id.into_untyped_uuid()),
-                    rfd_pdf::external_id.eq(new_pdf.external_id.clone()),
+                .order((
+                    rfd_revision_author::rfd_revision_id.asc(),
+                    rfd_revision_author::position.asc(),
                 ))
-                .on_conflict(on_constraint("revision_links_unique"))
-                .do_nothing()
-                .get_result_async(&*self.pool.get().await.tap_err(|err| {
-                    tracing::error!(?err, "Failed to acquire database connection")
-                })?)
+                .get_results_async::<RfdRevisionAuthorModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
                 .await?;
 
-        Ok(rfd.into())
+        Ok(results.into_iter().map(|author| author.into()).collect())
     }
 
-    async fn delete(&self, id: &TypedUuid<RfdPdfId>) -> Result<Option<RfdPdf>, StoreError> {
-        let _ =
-            update(rfd_pdf::dsl::rfd_pdf)
-                .filter(rfd_pdf::id.eq(id.into_untyped_uuid()))
-                .set(rfd_pdf::deleted_at.eq(Utc::now()))
-                .execute_async(&*self.pool.get().await.tap_err(|err| {
-                    tracing::error!(?err, "Failed to acquire database connection")
-                })?)
-                .await?;
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        authors: Vec<NewRfdRevisionAuthor>,
+    ) -> Result<Vec<RfdRevisionAuthor>, StoreError> {
+        let conn = self
+            .pool
+            .get()
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to acquire database connection"))?;
+        let rfd_revision_id = rfd_revision_id.into_untyped_uuid();
+
+        // Replace the existing rows as a single unit so that readers never observe a revision
+        // that is missing some or all of its authors
+        let results = conn
+            .transaction_async(|conn| async move {
+                delete(rfd_revision_author::dsl::rfd_revision_author)
+                    .filter(rfd_revision_author::rfd_revision_id.eq(rfd_revision_id))
+                    .execute_async(&conn)
+                    .await?;
+
+                if authors.is_empty() {
+                    return Ok(vec![]);
+                }
 
-        RfdPdfStore::get(self, id, true).await
+                insert_into(rfd_revision_author::dsl::rfd_revision_author)
+                    .values(
+                        authors
+                            .into_iter()
+                            .map(|author| {
+                                (
+                                    rfd_revision_author::rfd_revision_id.eq(rfd_revision_id),
+                                    rfd_revision_author::position.eq(author.position),
+                                    rfd_revision_author::name.eq(author.name),
+                                    rfd_revision_author::email.eq(author.email),
+                                )
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .get_results_async::<RfdRevisionAuthorModel>(&conn)
+                    .await
+                    .map_err(StoreError::from)
+            })
+            .await?;
+
+        Ok(results.into_iter().map(|author| author.into()).collect())
     }
 }
 
 #[async_trait]
-impl JobStore for PostgresStore {
-    #[instrument(skip(self))]
-    async fn get(&self, id: i32) -> Result<Option<Job>, StoreError> {
-        tracing::trace!("Start get job query");
-        let user = JobStore::list(
+impl RfdRevisionLabelStore for PostgresStore {
+    async fn list(
+        &self,
+        filters: Vec<RfdRevisionLabelFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdRevisionLabel>, StoreError> {
+        let mut query = rfd_revision_label::dsl::rfd_revision_label.into_boxed();
+
+        tracing::trace!(?filters, "Lookup RFD revision labels");
+
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let RfdRevisionLabelFilter {
+                    rfd_revision,
+                    label,
+                } = filter;
+
+                if let Some(rfd_revision) = rfd_revision {
+                    predicates
+                        .push(Box::new(rfd_revision_label::rfd_revision_id.eq_any(
+                            rfd_revision.into_iter().map(GenericUuid::into_untyped_uuid),
+                        )));
+                }
+
+                if let Some(label) = label {
+                    predicates.push(Box::new(
+                        lower(rfd_revision_label::label).eq_any(
+                            label
+                                .into_iter()
+                                .map(|label| label.to_lowercase())
+                                .collect::<Vec<_>>(),
+                        ),
+                    ));
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results =
+            query
+                .offset(pagination.offset)
+                .limit(pagination.limit)
+                .order((
+                    rfd_revision_label::rfd_revision_id.asc(),
+                    rfd_revision_label::label.asc(),
+                ))
+                .get_results_async::<RfdRevisionLabelModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?;
+
+        Ok(results.into_iter().map(|label| label.into()).collect())
+    }
+
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        labels: Vec<NewRfdRevisionLabel>,
+    ) -> Result<Vec<RfdRevisionLabel>, StoreError> {
+        let conn = self
+            .pool
+            .get()
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to acquire database connection"))?;
+        let rfd_revision_id = rfd_revision_id.into_untyped_uuid();
+
+        // Replace the existing rows as a single unit so that readers never observe a revision
+        // that is missing some or all of its labels
+        let results = conn
+            .transaction_async(|conn| async move {
+                delete(rfd_revision_label::dsl::rfd_revision_label)
+                    .filter(rfd_revision_label::rfd_revision_id.eq(rfd_revision_id))
+                    .execute_async(&conn)
+                    .await?;
+
+                if labels.is_empty() {
+                    return Ok(vec![]);
+                }
+
+                insert_into(rfd_revision_label::dsl::rfd_revision_label)
+                    .values(
+                        labels
+                            .into_iter()
+                            .map(|label| {
+                                (
+                                    rfd_revision_label::rfd_revision_id.eq(rfd_revision_id),
+                                    rfd_revision_label::label.eq(label.label),
+                                )
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .on_conflict_do_nothing()
+                    .get_results_async::<RfdRevisionLabelModel>(&conn)
+                    .await
+                    .map_err(StoreError::from)
+            })
+            .await?;
+
+        Ok(results.into_iter().map(|label| label.into()).collect())
+    }
+}
+
+#[async_trait]
+impl RfdReferenceStore for PostgresStore {
+    async fn list(
+        &self,
+        filters: Vec<RfdReferenceFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdReference>, StoreError> {
+        let mut query = rfd_reference::dsl::rfd_reference.into_boxed();
+
+        tracing::trace!(?filters, "Lookup RFD references");
+
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let RfdReferenceFilter {
+                    rfd_revision,
+                    target_rfd_number,
+                } = filter;
+
+                if let Some(rfd_revision) = rfd_revision {
+                    predicates
+                        .push(Box::new(rfd_reference::rfd_revision_id.eq_any(
+                            rfd_revision.into_iter().map(GenericUuid::into_untyped_uuid),
+                        )));
+                }
+
+                if let Some(target_rfd_number) = target_rfd_number {
+                    predicates.push(Box::new(
+                        rfd_reference::target_rfd_number.eq_any(target_rfd_number),
+                    ));
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results =
+            query
+                .offset(pagination.offset)
+                .limit(pagination.limit)
+                .order((
+                    rfd_reference::rfd_revision_id.asc(),
+                    rfd_reference::target_rfd_number.asc(),
+                ))
+                .get_results_async::<RfdReferenceModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|reference| reference.into())
+            .collect())
+    }
+
+    async fn replace(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+        references: Vec<NewRfdReference>,
+    ) -> Result<Vec<RfdReference>, StoreError> {
+        let conn = self
+            .pool
+            .get()
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to acquire database connection"))?;
+        let rfd_revision_id = rfd_revision_id.into_untyped_uuid();
+
+        // As with authors and labels, references are swapped out as a single unit
+        let results = conn
+            .transaction_async(|conn| async move {
+                delete(rfd_reference::dsl::rfd_reference)
+                    .filter(rfd_reference::rfd_revision_id.eq(rfd_revision_id))
+                    .execute_async(&conn)
+                    .await?;
+
+                if references.is_empty() {
+                    return Ok(vec![]);
+                }
+
+                insert_into(rfd_reference::dsl::rfd_reference)
+                    .values(
+                        references
+                            .into_iter()
+                            .map(|reference| {
+                                (
+                                    rfd_reference::rfd_revision_id.eq(rfd_revision_id),
+                                    rfd_reference::target_rfd_number
+                                        .eq(reference.target_rfd_number),
+                                )
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .on_conflict_do_nothing()
+                    .get_results_async::<RfdReferenceModel>(&conn)
+                    .await
+                    .map_err(StoreError::from)
+            })
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|reference| reference.into())
+            .collect())
+    }
+}
+
+#[async_trait]
+impl RfdRevisionHtmlStore for PostgresStore {
+    async fn get(
+        &self,
+        rfd_revision_id: &TypedUuid<RfdRevisionId>,
+    ) -> Result<Option<RfdRevisionHtml>, StoreError> {
+        let html =
+            rfd_revision_html::dsl::rfd_revision_html
+                .filter(rfd_revision_html::rfd_revision_id.eq(rfd_revision_id.into_untyped_uuid()))
+                .get_results_async::<RfdRevisionHtmlModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?;
+
+        Ok(html.into_iter().next().map(|html| html.into()))
+    }
+
+    async fn upsert(&self, new_html: NewRfdRevisionHtml) -> Result<RfdRevisionHtml, StoreError> {
+        let html: RfdRevisionHtmlModel = insert_into(rfd_revision_html::dsl::rfd_revision_html)
+            .values((
+                rfd_revision_html::rfd_revision_id.eq(new_html.rfd_revision_id.into_untyped_uuid()),
+                rfd_revision_html::html.eq(new_html.html),
+            ))
+            .on_conflict(rfd_revision_html::rfd_revision_id)
+            .do_update()
+            .set((
+                rfd_revision_html::html.eq(excluded(rfd_revision_html::html)),
+                rfd_revision_html::updated_at.eq(Utc::now()),
+            ))
+            .get_result_async(
+                &*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?,
+            )
+            .await?;
+
+        Ok(html.into())
+    }
+}
+
+#[async_trait]
+impl RfdPdfStore for PostgresStore {
+    async fn get(
+        &self,
+        id: &TypedUuid<RfdPdfId>,
+        deleted: bool,
+    ) -> Result<Option<RfdPdf>, StoreError> {
+        let user = RfdPdfStore::list(
+            self,
+            vec![RfdPdfFilter::default().id(Some(vec![*id])).deleted(deleted)],
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(user.into_iter().nth(0))
+    }
+
+    async fn list(
+        &self,
+        filters: Vec<RfdPdfFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdPdf>, StoreError> {
+        let mut query = rfd_pdf::dsl::rfd_pdf.into_boxed();
+
+        tracing::trace!(?filters, "Lookup RFD pdfs");
+
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let RfdPdfFilter {
+                    id,
+                    rfd_revision,
+                    source,
+                    deleted,
+                    rfd,
+                    external_id,
+                } = filter;
+
+                if let Some(id) = id {
+                    predicates.push(Box::new(
+                        rfd_pdf::id.eq_any(id.into_iter().map(GenericUuid::into_untyped_uuid)),
+                    ));
+                }
+
+                if let Some(rfd_revision) = rfd_revision {
+                    predicates
+                        .push(Box::new(rfd_pdf::rfd_revision_id.eq_any(
+                            rfd_revision.into_iter().map(GenericUuid::into_untyped_uuid),
+                        )));
+                }
+
+                if let Some(source) = source {
+                    predicates.push(Box::new(rfd_pdf::source.eq_any(source)));
+                }
+
+                if let Some(rfd) = rfd {
+                    predicates.push(Box::new(
+                        rfd_pdf::rfd_id.eq_any(rfd.into_iter().map(GenericUuid::into_untyped_uuid)),
+                    ));
+                }
+
+                if let Some(external_id) = external_id {
+                    predicates.push(Box::new(rfd_pdf::external_id.eq_any(external_id)));
+                }
+
+                if !deleted {
+                    predicates.push(Box::new(rfd_pdf::deleted_at.is_null()));
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results =
+            query
+                .offset(pagination.offset)
+                .limit(pagination.limit)
+                .order(rfd_pdf::created_at.desc())
+                .get_results_async::<RfdPdfModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|revision| revision.into())
+            .collect())
+    }
+
+    async fn upsert(&self, new_pdf: NewRfdPdf) -> Result<RfdPdf, StoreError> {
+        let rfd: RfdPdfModel =
+            insert_into(rfd_pdf::dsl::rfd_pdf)
+                .values((
+                    rfd_pdf::id.eq(Uuid::new_v4()),
+                    rfd_pdf::rfd_revision_id.eq(new_pdf.rfd_revision_id.into_untyped_uuid()),
+                    rfd_pdf::source.eq(new_pdf.source.clone()),
+                    rfd_pdf::link.eq(new_pdf.link.clone()),
+                    rfd_pdf::rfd_id.eq(new_pdf.rfd_id.into_untyped_uuid()),
+                    rfd_pdf::external_id.eq(new_pdf.external_id.clone()),
+                ))
+                .on_conflict(on_constraint("revision_links_unique"))
+                .do_nothing()
+                .get_result_async(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        Ok(rfd.into())
+    }
+
+    async fn delete(&self, id: &TypedUuid<RfdPdfId>) -> Result<Option<RfdPdf>, StoreError> {
+        let _ =
+            update(rfd_pdf::dsl::rfd_pdf)
+                .filter(rfd_pdf::id.eq(id.into_untyped_uuid()))
+                .set(rfd_pdf::deleted_at.eq(Utc::now()))
+                .execute_async(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        RfdPdfStore::get(self, id, true).await
+    }
+}
+
+#[async_trait]
+impl JobStore for PostgresStore {
+    #[instrument(skip(self))]
+    async fn get(&self, id: i32) -> Result<Option<Job>, StoreError> {
+        tracing::trace!("Start get job query");
+        let user = JobStore::list(
+            self,
+            vec![JobFilter::default().id(Some(vec![id]))],
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        tracing::trace!("Done get job query");
+
+        Ok(user.into_iter().nth(0))
+    }
+
+    #[instrument(skip(self))]
+    async fn list(
+        &self,
+        filters: Vec<JobFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<Job>, StoreError> {
+        tracing::trace!("Start list job query");
+        let mut query = job::dsl::job.into_boxed();
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let JobFilter {
+                    id,
+                    sha,
+                    rfd,
+                    branch,
+                    processed,
+                    started,
+                    status,
+                    next_attempt_before,
+                } = filter;
+
+                if let Some(id) = id {
+                    predicates.push(Box::new(job::id.eq_any(id)));
+                }
+
+                if let Some(sha) = sha {
+                    predicates.push(Box::new(job::sha.eq_any(sha)));
+                }
+
+                if let Some(rfd) = rfd {
+                    predicates.push(Box::new(job::rfd.eq_any(rfd)));
+                }
+
+                if let Some(branch) = branch {
+                    predicates.push(Box::new(job::branch.eq_any(branch)));
+                }
+
+                if let Some(processed) = processed {
+                    predicates.push(Box::new(job::processed.eq(processed)));
+                }
+
+                if let Some(started) = started {
+                    if started {
+                        predicates.push(Box::new(job::started_at.is_not_null()));
+                    } else {
+                        predicates.push(Box::new(job::started_at.is_null()));
+                    }
+                }
+
+                if let Some(status) = status {
+                    predicates.push(Box::new(job::status.eq_any(status)));
+                }
+
+                if let Some(next_attempt_before) = next_attempt_before {
+                    predicates.push(Box::new(
+                        job::next_attempt_at
+                            .is_null()
+                            .or(job::next_attempt_at.le(next_attempt_before)),
+                    ));
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results =
+            query
+                .offset(pagination.offset)
+                .limit(pagination.limit)
+                .order(job::processed.asc())
+                .order(job::committed_at.asc())
+                .order(job::created_at.asc())
+                .get_results_async::<JobModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+        tracing::trace!("Done list job query");
+
+        Ok(results.into_iter().map(|job| job.into()).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn upsert(&self, new_job: NewJob) -> Result<Job, StoreError> {
+        tracing::trace!("Start upsert job query");
+        let rfd: JobModel =
+            insert_into(job::dsl::job)
+                .values((
+                    job::owner.eq(new_job.owner.clone()),
+                    job::repository.eq(new_job.repository.clone()),
+                    job::branch.eq(new_job.branch.clone()),
+                    job::sha.eq(String::from(new_job.sha)),
+                    job::rfd.eq(new_job.rfd),
+                    job::webhook_delivery_id
+                        .eq(new_job.webhook_delivery_id.map(|id| id.into_untyped_uuid())),
+                    job::processed.eq(false),
+                    job::status.eq(JobStatus::Queued),
+                    job::committed_at.eq(new_job.committed_at),
+                ))
+                .get_result_async(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+        tracing::trace!("Done upsert job query");
+
+        Ok(rfd.into())
+    }
+
+    #[instrument(skip(self))]
+    async fn start(
+        &self,
+        id: i32,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<Job>, StoreError> {
+        tracing::trace!("Start start job query");
+
+        // Only the caller whose update matches the job gets it back. Any other processor that
+        // raced to start the same job will receive nothing
+        let job =
+            update(job::dsl::job)
+                .filter(job::id.eq(id))
+                .filter(job::status.eq_any([JobStatus::Queued, JobStatus::Failed]))
+                .set((
+                    job::started_at.eq(Utc::now()),
+                    job::status.eq(JobStatus::Running),
+                    job::attempts.eq(job::attempts + 1),
+                    job::next_attempt_at.eq(None::<DateTime<Utc>>),
+                    job::lease_expires_at.eq(lease_expires_at),
+                ))
+                .get_results_async::<JobModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?
+                .pop();
+        tracing::trace!("Done start job query");
+
+        Ok(job.map(|job| job.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn heartbeat(
+        &self,
+        id: i32,
+        attempt: i32,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<Job>, StoreError> {
+        tracing::trace!("Start heartbeat job query");
+        let job =
+            update(job::dsl::job)
+                .filter(job::id.eq(id))
+                .filter(job::status.eq(JobStatus::Running))
+                .filter(job::attempts.eq(attempt))
+                .set(job::lease_expires_at.eq(lease_expires_at))
+                .get_results_async::<JobModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?
+                .pop();
+        tracing::trace!("Done heartbeat job query");
+
+        Ok(job.map(|job| job.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn complete(&self, id: i32, attempt: i32) -> Result<Option<Job>, StoreError> {
+        tracing::trace!("Start complete job query");
+
+        // A job whose lease was reaped may have been started again by a different processor. The
+        // attempt acts as a fencing token so that only the current holder can conclude the job
+        let job =
+            update(job::dsl::job)
+                .filter(job::id.eq(id))
+                .filter(job::status.eq(JobStatus::Running))
+                .filter(job::attempts.eq(attempt))
+                .set((
+                    job::processed.eq(true),
+                    job::status.eq(JobStatus::Succeeded),
+                    job::last_error.eq(None::<String>),
+                    job::lease_expires_at.eq(None::<DateTime<Utc>>),
+                ))
+                .get_results_async::<JobModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?
+                .pop();
+        tracing::trace!("Done complete job query");
+
+        Ok(job.map(|job| job.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn requeue(&self, id: i32, at: DateTime<Utc>) -> Result<Option<Job>, StoreError> {
+        tracing::trace!("Start requeue job query");
+        let job =
+            update(job::dsl::job)
+                .filter(job::id.eq(id))
+                .filter(job::status.eq(JobStatus::Running))
+                .set((
+                    job::status.eq(JobStatus::Queued),
+                    job::attempts.eq(job::attempts - 1),
+                    job::next_attempt_at.eq(at),
+                    job::lease_expires_at.eq(None::<DateTime<Utc>>),
+                ))
+                .get_results_async::<JobModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?
+                .pop();
+        tracing::trace!("Done requeue job query");
+
+        Ok(job.map(|job| job.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn supersede(&self, id: i32) -> Result<Option<Job>, StoreError> {
+        tracing::trace!("Start supersede job query");
+        let job = update(job::dsl::job)
+            .filter(job::id.eq(id))
+            .filter(job::status.eq_any([JobStatus::Queued, JobStatus::Running, JobStatus::Failed]))
+            .set((
+                job::status.eq(JobStatus::Superseded),
+                job::next_attempt_at.eq(None::<DateTime<Utc>>),
+                job::lease_expires_at.eq(None::<DateTime<Utc>>),
+            ))
+            .get_results_async::<JobModel>(
+                &*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?,
+            )
+            .await?
+            .pop();
+        tracing::trace!("Done supersede job query");
+
+        Ok(job.map(|job| job.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn fail(
+        &self,
+        id: i32,
+        attempt: i32,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<Job>, StoreError> {
+        tracing::trace!("Start fail job query");
+        let status = if retry_at.is_some() {
+            JobStatus::Failed
+        } else {
+            JobStatus::Dead
+        };
+
+        let job =
+            update(job::dsl::job)
+                .filter(job::id.eq(id))
+                .filter(job::status.eq(JobStatus::Running))
+                .filter(job::attempts.eq(attempt))
+                .set((
+                    job::status.eq(status),
+                    job::last_error.eq(Some(error)),
+                    job::next_attempt_at.eq(retry_at),
+                    job::lease_expires_at.eq(None::<DateTime<Utc>>),
+                ))
+                .get_results_async::<JobModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?
+                .pop();
+        tracing::trace!("Done fail job query");
+
+        Ok(job.map(|job| job.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn reap(
+        &self,
+        expired_before: DateTime<Utc>,
+        max_attempts: i32,
+    ) -> Result<Vec<Job>, StoreError> {
+        tracing::trace!("Start reap job query");
+        let conn = self
+            .pool
+            .get()
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to acquire database connection"))?;
+
+        let error = "Job lease expired before the job completed".to_string();
+
+        let mut jobs = update(job::dsl::job)
+            .filter(job::status.eq(JobStatus::Running))
+            .filter(job::lease_expires_at.lt(expired_before))
+            .filter(job::attempts.ge(max_attempts))
+            .set((
+                job::status.eq(JobStatus::Dead),
+                job::last_error.eq(Some(error.clone())),
+                job::lease_expires_at.eq(None::<DateTime<Utc>>),
+            ))
+            .get_results_async::<JobModel>(&*conn)
+            .await?;
+
+        jobs.extend(
+            update(job::dsl::job)
+                .filter(job::status.eq(JobStatus::Running))
+                .filter(job::lease_expires_at.lt(expired_before))
+                .set((
+                    job::status.eq(JobStatus::Failed),
+                    job::last_error.eq(Some(error)),
+                    job::next_attempt_at.eq(expired_before),
+                    job::lease_expires_at.eq(None::<DateTime<Utc>>),
+                ))
+                .get_results_async::<JobModel>(&*conn)
+                .await?,
+        );
+        tracing::trace!("Done reap job query");
+
+        Ok(jobs.into_iter().map(|job| job.into()).collect())
+    }
+}
+
+#[async_trait]
+impl AuditEventStore for PostgresStore {
+    async fn list(
+        &self,
+        filters: Vec<AuditEventFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<AuditEvent>, StoreError> {
+        let mut query = audit_event::dsl::audit_event.into_boxed();
+
+        tracing::trace!(?filters, "Lookup audit events");
+
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let AuditEventFilter {
+                    rfd_number,
+                    caller_id,
+                    operation,
+                    created_after,
+                    created_before,
+                } = filter;
+
+                if let Some(rfd_number) = rfd_number {
+                    predicates.push(Box::new(
+                        audit_event::rfd_number.assume_not_null().eq_any(rfd_number),
+                    ));
+                }
+
+                if let Some(caller_id) = caller_id {
+                    predicates.push(Box::new(audit_event::caller_id.eq_any(caller_id)));
+                }
+
+                if let Some(operation) = operation {
+                    predicates.push(Box::new(audit_event::operation.eq_any(operation)));
+                }
+
+                if let Some(created_after) = created_after {
+                    predicates.push(Box::new(audit_event::created_at.ge(created_after)));
+                }
+
+                if let Some(created_before) = created_before {
+                    predicates.push(Box::new(audit_event::created_at.lt(created_before)));
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results =
+            query
+                .offset(pagination.offset)
+                .limit(pagination.limit)
+                .order(audit_event::created_at.desc())
+                .get_results_async::<AuditEventModel>(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        Ok(results.into_iter().map(|event| event.into()).collect())
+    }
+
+    async fn create(&self, new_event: NewAuditEvent) -> Result<AuditEvent, StoreError> {
+        let event: AuditEventModel =
+            insert_into(audit_event::dsl::audit_event)
+                .values((
+                    audit_event::id.eq(new_event.id.into_untyped_uuid()),
+                    audit_event::caller_id.eq(new_event.caller_id),
+                    audit_event::operation.eq(new_event.operation),
+                    audit_event::rfd_number.eq(new_event.rfd_number),
+                    audit_event::before.eq(new_event.before),
+                    audit_event::after.eq(new_event.after),
+                    audit_event::request_id.eq(new_event.request_id),
+                ))
+                .get_result_async(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        Ok(event.into())
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionStore for PostgresStore {
+    #[instrument(skip(self))]
+    async fn get(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionId>,
+        deleted: bool,
+    ) -> Result<Option<WebhookSubscription>, StoreError> {
+        let subscription = WebhookSubscriptionStore::list(
+            self,
+            vec![WebhookSubscriptionFilter::default()
+                .id(Some(vec![*id]))
+                .deleted(deleted)],
+            &ListPagination::default().limit(1),
+        )
+        .await?;
+        Ok(subscription.into_iter().nth(0))
+    }
+
+    #[instrument(skip(self))]
+    async fn list(
+        &self,
+        filters: Vec<WebhookSubscriptionFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookSubscription>, StoreError> {
+        tracing::trace!("Start list webhook subscription query");
+        let mut query = webhook_subscription::dsl::webhook_subscription.into_boxed();
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let WebhookSubscriptionFilter { id, deleted } = filter;
+
+                if let Some(id) = id {
+                    predicates.push(Box::new(
+                        webhook_subscription::id
+                            .eq_any(id.into_iter().map(GenericUuid::into_untyped_uuid)),
+                    ));
+                }
+
+                if !deleted {
+                    predicates.push(Box::new(webhook_subscription::deleted_at.is_null()));
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results =
+            query
+                .offset(pagination.offset)
+                .limit(pagination.limit)
+                .order(webhook_subscription::created_at.desc())
+                .get_results_async::<WebhookSubscriptionModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?;
+        tracing::trace!("Done list webhook subscription query");
+
+        Ok(results
+            .into_iter()
+            .map(|subscription| subscription.into())
+            .collect())
+    }
+
+    #[instrument(skip(self, new_subscription), fields(id = ?new_subscription.id))]
+    async fn upsert(
+        &self,
+        new_subscription: NewWebhookSubscription,
+    ) -> Result<WebhookSubscription, StoreError> {
+        tracing::trace!("Start upsert webhook subscription query");
+        let subscription: WebhookSubscriptionModel =
+            insert_into(webhook_subscription::dsl::webhook_subscription)
+                .values((
+                    webhook_subscription::id.eq(new_subscription.id.into_untyped_uuid()),
+                    webhook_subscription::url.eq(new_subscription.url.clone()),
+                    webhook_subscription::events.eq(new_subscription.events.clone()),
+                    webhook_subscription::rfd_numbers.eq(new_subscription.rfd_numbers.clone()),
+                    webhook_subscription::secret.eq(new_subscription.secret.clone()),
+                ))
+                .on_conflict(webhook_subscription::id)
+                .do_update()
+                .set((
+                    webhook_subscription::url.eq(excluded(webhook_subscription::url)),
+                    webhook_subscription::events.eq(excluded(webhook_subscription::events)),
+                    webhook_subscription::rfd_numbers
+                        .eq(excluded(webhook_subscription::rfd_numbers)),
+                    webhook_subscription::secret.eq(excluded(webhook_subscription::secret)),
+                    webhook_subscription::updated_at.eq(Utc::now()),
+                ))
+                .get_result_async(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+        tracing::trace!("Done upsert webhook subscription query");
+
+        Ok(subscription.into())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionId>,
+    ) -> Result<Option<WebhookSubscription>, StoreError> {
+        let _ =
+            update(webhook_subscription::dsl::webhook_subscription)
+                .filter(webhook_subscription::id.eq(id.into_untyped_uuid()))
+                .set(webhook_subscription::deleted_at.eq(Utc::now()))
+                .execute_async(&*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?)
+                .await?;
+
+        WebhookSubscriptionStore::get(self, id, true).await
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionDeliveryStore for PostgresStore {
+    #[instrument(skip(self))]
+    async fn list(
+        &self,
+        filters: Vec<WebhookSubscriptionDeliveryFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<WebhookSubscriptionDelivery>, StoreError> {
+        tracing::trace!("Start list webhook subscription delivery query");
+        let mut query =
+            webhook_subscription_delivery::dsl::webhook_subscription_delivery.into_boxed();
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let WebhookSubscriptionDeliveryFilter {
+                    id,
+                    webhook_subscription,
+                    status,
+                    next_attempt_before,
+                } = filter;
+
+                if let Some(id) = id {
+                    predicates.push(Box::new(
+                        webhook_subscription_delivery::id
+                            .eq_any(id.into_iter().map(GenericUuid::into_untyped_uuid)),
+                    ));
+                }
+
+                if let Some(webhook_subscription) = webhook_subscription {
+                    predicates.push(Box::new(
+                        webhook_subscription_delivery::webhook_subscription_id.eq_any(
+                            webhook_subscription
+                                .into_iter()
+                                .map(GenericUuid::into_untyped_uuid),
+                        ),
+                    ));
+                }
+
+                if let Some(status) = status {
+                    predicates.push(Box::new(
+                        webhook_subscription_delivery::status.eq_any(status),
+                    ));
+                }
+
+                if let Some(next_attempt_before) = next_attempt_before {
+                    predicates.push(Box::new(
+                        webhook_subscription_delivery::next_attempt_at
+                            .is_null()
+                            .or(webhook_subscription_delivery::next_attempt_at
+                                .le(next_attempt_before)),
+                    ));
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results = query
+            .offset(pagination.offset)
+            .limit(pagination.limit)
+            .order(webhook_subscription_delivery::created_at.desc())
+            .get_results_async::<WebhookSubscriptionDeliveryModel>(
+                &*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?,
+            )
+            .await?;
+        tracing::trace!("Done list webhook subscription delivery query");
+
+        Ok(results
+            .into_iter()
+            .map(|delivery| delivery.into())
+            .collect())
+    }
+
+    #[instrument(skip(self, payload))]
+    async fn enqueue(
+        &self,
+        event: WebhookEvent,
+        rfd_number: i32,
+        payload: serde_json::Value,
+    ) -> Result<Vec<WebhookSubscriptionDelivery>, StoreError> {
+        tracing::trace!("Start enqueue webhook subscription delivery query");
+
+        // Subscriptions are matched and their deliveries created in a single statement so that a
+        // subscription that is concurrently deleted either receives the event or does not
+        let deliveries =
+            sql_query(
+                "INSERT INTO webhook_subscription_delivery \
+                (webhook_subscription_id, event, rfd_number, payload) \
+            SELECT id, $1, $2, $3 FROM webhook_subscription \
+            WHERE deleted_at IS NULL \
+                AND $1 = ANY(events) \
+                AND (rfd_numbers IS NULL OR $2 = ANY(rfd_numbers)) \
+            RETURNING *",
+            )
+            .bind::<RfdWebhookEvent, _>(event)
+            .bind::<Integer, _>(rfd_number)
+            .bind::<Jsonb, _>(payload)
+            .get_results_async::<WebhookSubscriptionDeliveryModel>(
+                &*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?,
+            )
+            .await?;
+        tracing::trace!("Done enqueue webhook subscription delivery query");
+
+        Ok(deliveries
+            .into_iter()
+            .map(|delivery| delivery.into())
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn start(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        lease_expires_at: DateTime<Utc>,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError> {
+        tracing::trace!("Start start webhook subscription delivery query");
+
+        // A delivery that is being sent holds its lease in the next attempt time, allowing it to
+        // be reclaimed once the lease has expired
+        let delivery = update(webhook_subscription_delivery::dsl::webhook_subscription_delivery)
+            .filter(webhook_subscription_delivery::id.eq(id.into_untyped_uuid()))
+            .filter(webhook_subscription_delivery::status.eq_any([
+                WebhookDeliveryStatus::Pending,
+                WebhookDeliveryStatus::Failed,
+                WebhookDeliveryStatus::Sending,
+            ]))
+            .filter(
+                webhook_subscription_delivery::next_attempt_at
+                    .is_null()
+                    .or(webhook_subscription_delivery::next_attempt_at.le(Utc::now())),
+            )
+            .set((
+                webhook_subscription_delivery::status.eq(WebhookDeliveryStatus::Sending),
+                webhook_subscription_delivery::attempts
+                    .eq(webhook_subscription_delivery::attempts + 1),
+                webhook_subscription_delivery::next_attempt_at.eq(lease_expires_at),
+                webhook_subscription_delivery::updated_at.eq(Utc::now()),
+            ))
+            .get_results_async::<WebhookSubscriptionDeliveryModel>(
+                &*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?,
+            )
+            .await?
+            .pop();
+        tracing::trace!("Done start webhook subscription delivery query");
+
+        Ok(delivery.map(|delivery| delivery.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn complete(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        response_status: i32,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError> {
+        tracing::trace!("Start complete webhook subscription delivery query");
+        let delivery = update(webhook_subscription_delivery::dsl::webhook_subscription_delivery)
+            .filter(webhook_subscription_delivery::id.eq(id.into_untyped_uuid()))
+            .set((
+                webhook_subscription_delivery::status.eq(WebhookDeliveryStatus::Delivered),
+                webhook_subscription_delivery::response_status.eq(Some(response_status)),
+                webhook_subscription_delivery::last_error.eq(None::<String>),
+                webhook_subscription_delivery::next_attempt_at.eq(None::<DateTime<Utc>>),
+                webhook_subscription_delivery::delivered_at.eq(Utc::now()),
+                webhook_subscription_delivery::updated_at.eq(Utc::now()),
+            ))
+            .get_results_async::<WebhookSubscriptionDeliveryModel>(
+                &*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?,
+            )
+            .await?
+            .pop();
+        tracing::trace!("Done complete webhook subscription delivery query");
+
+        Ok(delivery.map(|delivery| delivery.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn fail(
+        &self,
+        id: &TypedUuid<WebhookSubscriptionDeliveryId>,
+        response_status: Option<i32>,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<WebhookSubscriptionDelivery>, StoreError> {
+        tracing::trace!("Start fail webhook subscription delivery query");
+        let status = if retry_at.is_some() {
+            WebhookDeliveryStatus::Failed
+        } else {
+            WebhookDeliveryStatus::Dead
+        };
+
+        let delivery = update(webhook_subscription_delivery::dsl::webhook_subscription_delivery)
+            .filter(webhook_subscription_delivery::id.eq(id.into_untyped_uuid()))
+            .set((
+                webhook_subscription_delivery::status.eq(status),
+                webhook_subscription_delivery::response_status.eq(response_status),
+                webhook_subscription_delivery::last_error.eq(Some(error)),
+                webhook_subscription_delivery::next_attempt_at.eq(retry_at),
+                webhook_subscription_delivery::updated_at.eq(Utc::now()),
+            ))
+            .get_results_async::<WebhookSubscriptionDeliveryModel>(
+                &*self.pool.get().await.tap_err(|err| {
+                    tracing::error!(?err, "Failed to acquire database connection")
+                })?,
+            )
+            .await?
+            .pop();
+        tracing::trace!("Done fail webhook subscription delivery query");
+
+        Ok(delivery.map(|delivery| delivery.into()))
+    }
+}
+
+#[derive(QueryableByName)]
+struct CountResult {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+#[derive(QueryableByName)]
+struct AdvisoryLockResult {
+    #[diesel(sql_type = Bool)]
+    locked: bool,
+}
+
+/// Run a future while holding a transaction level advisory lock identified by the given class and
+/// key. If the lock is already held by a different session then the future is not run and `None`
+/// is returned. The lock is taken inside of a transaction that spans the future and is released by
+/// the database when that transaction ends. If the future panics or is cancelled, the connection
+/// is left with an open transaction and is discarded by the pool instead of being reused, which
+/// also releases the lock
+#[instrument(skip(store, f))]
+pub async fn with_advisory_lock<F, T>(
+    store: &PostgresStore,
+    class: i32,
+    key: i32,
+    f: F,
+) -> Result<Option<T>, StoreError>
+where
+    F: Future<Output = T> + Send,
+    T: Send + 'static,
+{
+    let conn = store
+        .pool
+        .get()
+        .await
+        .tap_err(|err| tracing::error!(?err, "Failed to acquire database connection"))?;
+
+    conn.transaction_async(|conn| async move {
+        let acquired = sql_query("SELECT pg_try_advisory_xact_lock($1, $2) AS locked")
+            .bind::<Integer, _>(class)
+            .bind::<Integer, _>(key)
+            .get_result_async::<AdvisoryLockResult>(&conn)
+            .await?
+            .locked;
+
+        if !acquired {
+            tracing::debug!("Advisory lock is held by another session");
+            return Ok(None);
+        }
+
+        Ok(Some(f.await))
+    })
+    .await
+}
+
+#[allow(clippy::type_complexity)]
+fn flatten_predicates<T>(
+    predicates: Vec<Vec<Box<dyn BoxableExpression<T, Pg, SqlType = Bool>>>>,
+) -> Option<Box<dyn BoxableExpression<T, Pg, SqlType = Bool>>>
+where
+    T: 'static,
+{
+    let mut filter_predicates = vec![];
+
+    for p in predicates {
+        let flat = p
+            .into_iter()
+            .reduce(|combined, entry| Box::new(combined.and(entry)));
+        if let Some(flat) = flat {
+            filter_predicates.push(flat);
+        }
+    }
+
+    filter_predicates
+        .into_iter()
+        .reduce(|combined, entry| Box::new(combined.or(entry)))
+}
+
+/// Matches RFDs whose revision has a label equal to the parameter bound between the two parts,
+/// ignoring case
+static RFD_LABEL_PREDICATE: (&str, &str) = (
+    "EXISTS (SELECT 1 FROM rfd_revision_label WHERE rfd_revision_label.rfd_revision_id = rfd_revision.id AND lower(rfd_revision_label.label) = lower(",
+    "))",
+);
+
+/// Matches RFDs whose revision has an author with an email equal to the parameter bound between the
+/// two parts, ignoring case
+static RFD_AUTHOR_EMAIL_PREDICATE: (&str, &str) = (
+    "EXISTS (SELECT 1 FROM rfd_revision_author WHERE rfd_revision_author.rfd_revision_id = rfd_revision.id AND lower(rfd_revision_author.email) = lower(",
+    "))",
+);
+
+/// Matches RFDs that have a major change committed at or after the parameter bound between the two
+/// parts
+static RFD_MAJOR_CHANGE_PREDICATE: (&str, &str) = (
+    "EXISTS (SELECT 1 FROM rfd_revision AS major_revision WHERE major_revision.rfd_id = rfd.id AND major_revision.major_change = TRUE AND major_revision.committed_at >= ",
+    ")",
+);
+
+/// Matches RFDs whose revision references the RFD number bound between the two parts
+static RFD_REFERENCES_PREDICATE: (&str, &str) = (
+    "EXISTS (SELECT 1 FROM rfd_reference WHERE rfd_reference.rfd_revision_id = rfd_revision.id AND rfd_reference.target_rfd_number = ",
+    ")",
+);
+
+/// Build a pattern for a case insensitive substring match, escaping any characters that LIKE would
+/// otherwise interpret
+fn substring_pattern(value: &str) -> String {
+    format!(
+        "%{}%",
+        value
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    )
+}
+
+type RfdQuerySource = LeftJoinQuerySource<rfd::table, rfd_revision::table>;
+
+#[allow(clippy::type_complexity)]
+fn rfd_filter_predicates(
+    filter: RfdFilter,
+    nested: bool,
+) -> Vec<Box<dyn BoxableExpression<RfdQuerySource, Pg, SqlType = Bool>>> {
+    let mut predicates: Vec<Box<dyn BoxableExpression<RfdQuerySource, Pg, SqlType = Bool>>> =
+        vec![];
+    let RfdFilter {
+        id,
+        revision,
+        rfd_number,
+        commit,
+        public,
+        deleted,
+        state,
+        labels,
+        author,
+        author_email,
+        title,
+        updated_since,
+        major_change_since,
+        references,
+        any,
+        all,
+    } = filter;
+
+    if let Some(id) = id {
+        predicates.push(Box::new(
+            rfd::id.eq_any(id.into_iter().map(GenericUuid::into_untyped_uuid)),
+        ));
+    }
+
+    if let Some(revision) = revision {
+        predicates.push(Box::new(
+            rfd_revision::id
+                .assume_not_null()
+                .eq_any(revision.into_iter().map(GenericUuid::into_untyped_uuid)),
+        ));
+    }
+
+    if let Some(rfd_number) = rfd_number {
+        predicates.push(Box::new(rfd::rfd_number.eq_any(rfd_number)));
+    }
+
+    if let Some(commit) = commit {
+        predicates.push(Box::new(
+            rfd_revision::commit_sha
+                .assume_not_null()
+                .eq_any(commit.into_iter().map(|sha| sha.0)),
+        ));
+    }
+
+    if let Some(public) = public {
+        predicates.push(Box::new(rfd::visibility.eq(if public {
+            Visibility::Public
+        } else {
+            Visibility::Private
+        })));
+    }
+
+    if let Some(state) = state {
+        predicates.push(Box::new(
+            rfd_revision::state.assume_not_null().eq_any(state),
+        ));
+    }
+
+    if let Some(labels) = labels {
+        for label in labels {
+            predicates.push(Box::new(
+                sql::<Bool>(RFD_LABEL_PREDICATE.0)
+                    .bind::<Text, _>(label)
+                    .sql(RFD_LABEL_PREDICATE.1),
+            ));
+        }
+    }
+
+    if let Some(author) = author {
+        predicates.push(Box::new(
+            rfd_revision::authors
+                .assume_not_null()
+                .ilike(substring_pattern(&author)),
+        ));
+    }
+
+    if let Some(author_email) = author_email {
+        for email in author_email {
+            predicates.push(Box::new(
+                sql::<Bool>(RFD_AUTHOR_EMAIL_PREDICATE.0)
+                    .bind::<Text, _>(email)
+                    .sql(RFD_AUTHOR_EMAIL_PREDICATE.1),
+            ));
+        }
+    }
+
+    if let Some(title) = title {
+        predicates.push(Box::new(
+            rfd_revision::title
+                .assume_not_null()
+                .ilike(substring_pattern(&title)),
+        ));
+    }
+
+    if let Some(updated_since) = updated_since {
+        predicates.push(Box::new(
+            rfd_revision::committed_at
+                .assume_not_null()
+                .ge(updated_since),
+        ));
+    }
+
+    if let Some(major_change_since) = major_change_since {
+        predicates.push(Box::new(
+            sql::<Bool>(RFD_MAJOR_CHANGE_PREDICATE.0)
+                .bind::<Timestamptz, _>(major_change_since)
+                .sql(RFD_MAJOR_CHANGE_PREDICATE.1),
+        ));
+    }
+
+    if let Some(references) = references {
+        predicates.push(Box::new(
+            sql::<Bool>(RFD_REFERENCES_PREDICATE.0)
+                .bind::<Integer, _>(references)
+                .sql(RFD_REFERENCES_PREDICATE.1),
+        ));
+    }
+
+    if let Some(any) = any {
+        let nested_predicates = any
+            .into_iter()
+            .map(|filter| rfd_filter_predicates(filter, true))
+            .collect::<Vec<_>>();
+
+        // A nested filter without any predicates matches everything, in which case the entire
+        // group is satisfied. An empty group on the other hand can never be satisfied
+        if !nested_predicates
+            .iter()
+            .any(|predicates| predicates.is_empty())
+        {
+            predicates.push(
+                flatten_predicates(nested_predicates)
+                    .unwrap_or_else(|| Box::new(sql::<Bool>("FALSE"))),
+            );
+        }
+    }
+
+    if let Some(all) = all {
+        for filter in all {
+            predicates.extend(rfd_filter_predicates(filter, true));
+        }
+    }
+
+    if !nested && !deleted {
+        predicates.push(Box::new(rfd::deleted_at.is_null()));
+        predicates.push(Box::new(
+            rfd_revision::deleted_at.assume_not_null().is_null(),
+        ));
+    }
+
+    predicates
+}
+
+/// A parameter referenced by a raw RFD query
+#[derive(Debug)]
+enum RfdFilterBind {
+    Uuid(Uuid),
+    Integer(i32),
+    VarChar(String),
+    Timestamptz(Option<DateTime<Utc>>),
+    Visibility(Visibility),
+}
+
+/// Soft deletable tables joined by the raw RFD meta queries
+static RFD_META_TABLES: &[&str] = &["rfd", "rfd_revision"];
+
+/// Soft deletable tables joined by the raw RFD PDF queries
+static RFD_PDF_TABLES: &[&str] = &["rfd", "rfd_revision", "rfd_pdf"];
+
+/// Render filters as a clause for the raw RFD queries. Filters are combined via OR, while the
+/// conditions within a filter are combined via AND. Unless a filter asks for deleted RFDs, rows
+/// that are deleted in any of the given `tables` are excluded. Parameters are pushed on to `binds`
+/// in the order that they are referenced, and are numbered starting from the first entry of `binds`
+fn rfd_filter_clause(
+    filters: &[RfdFilter],
+    tables: &[&str],
+    nested: bool,
+    binds: &mut Vec<RfdFilterBind>,
+) -> String {
+    fn placeholders(binds: &mut Vec<RfdFilterBind>, values: Vec<RfdFilterBind>) -> Option<String> {
+        if values.is_empty() {
+            None
+        } else {
+            let start = binds.len() + 1;
+            let placeholders = (start..start + values.len())
+                .map(|i| format!("${}", i))
+                .collect::<Vec<_>>();
+            binds.extend(values);
+            Some(placeholders.join(","))
+        }
+    }
+
+    let mut clauses = vec![];
+
+    for filter in filters {
+        let mut filter_clause = "1=1".to_string();
+
+        let RfdFilter {
+            id,
+            revision,
+            rfd_number,
+            commit,
+            public,
+            deleted,
+            state,
+            labels,
+            author,
+            author_email,
+            title,
+            updated_since,
+            major_change_since,
+            references,
+            any,
+            all,
+        } = filter;
+
+        let in_clauses = [
+            (
+                "rfd.id",
+                id.as_ref().map(|ids| {
+                    ids.iter()
+                        .map(|id| RfdFilterBind::Uuid(id.into_untyped_uuid()))
+                        .collect::<Vec<_>>()
+                }),
+            ),
+            (
+                "rfd_revision.id",
+                revision.as_ref().map(|revisions| {
+                    revisions
+                        .iter()
+                        .map(|revision| RfdFilterBind::Uuid(revision.into_untyped_uuid()))
+                        .collect()
+                }),
+            ),
+            (
+                "rfd.rfd_number",
+                rfd_number.as_ref().map(|numbers| {
+                    numbers
+                        .iter()
+                        .map(|number| RfdFilterBind::Integer(*number))
+                        .collect()
+                }),
+            ),
+            (
+                "rfd_revision.commit_sha",
+                commit.as_ref().map(|commits| {
+                    commits
+                        .iter()
+                        .map(|commit| RfdFilterBind::VarChar(commit.to_string()))
+                        .collect()
+                }),
+            ),
+            (
+                "rfd_revision.state",
+                state.as_ref().map(|states| {
+                    states
+                        .iter()
+                        .map(|state| RfdFilterBind::VarChar(state.clone()))
+                        .collect()
+                }),
+            ),
+        ];
+
+        for (column, values) in in_clauses {
+            if let Some(values) = values {
+                match placeholders(binds, values) {
+                    Some(placeholders) => {
+                        filter_clause += &format!(" AND {} IN ({})", column, placeholders);
+                    }
+                    None => filter_clause += " AND 1=0",
+                }
+            }
+        }
+
+        if let Some(public) = public {
+            binds.push(RfdFilterBind::Visibility(if *public {
+                Visibility::Public
+            } else {
+                Visibility::Private
+            }));
+            filter_clause += &format!(" AND rfd.visibility = ${}", binds.len());
+        }
+
+        for label in labels.iter().flatten() {
+            binds.push(RfdFilterBind::VarChar(label.clone()));
+            filter_clause += &format!(
+                " AND {}${}{}",
+                RFD_LABEL_PREDICATE.0,
+                binds.len(),
+                RFD_LABEL_PREDICATE.1
+            );
+        }
+
+        if let Some(author) = author {
+            binds.push(RfdFilterBind::VarChar(substring_pattern(author)));
+            filter_clause += &format!(" AND rfd_revision.authors ILIKE ${}", binds.len());
+        }
+
+        for email in author_email.iter().flatten() {
+            binds.push(RfdFilterBind::VarChar(email.clone()));
+            filter_clause += &format!(
+                " AND {}${}{}",
+                RFD_AUTHOR_EMAIL_PREDICATE.0,
+                binds.len(),
+                RFD_AUTHOR_EMAIL_PREDICATE.1
+            );
+        }
+
+        if let Some(title) = title {
+            binds.push(RfdFilterBind::VarChar(substring_pattern(title)));
+            filter_clause += &format!(" AND rfd_revision.title ILIKE ${}", binds.len());
+        }
+
+        if let Some(updated_since) = updated_since {
+            binds.push(RfdFilterBind::Timestamptz(Some(*updated_since)));
+            filter_clause += &format!(" AND rfd_revision.committed_at >= ${}", binds.len());
+        }
+
+        if let Some(major_change_since) = major_change_since {
+            binds.push(RfdFilterBind::Timestamptz(Some(*major_change_since)));
+            filter_clause += &format!(
+                " AND {}${}{}",
+                RFD_MAJOR_CHANGE_PREDICATE.0,
+                binds.len(),
+                RFD_MAJOR_CHANGE_PREDICATE.1
+            );
+        }
+
+        if let Some(references) = references {
+            binds.push(RfdFilterBind::Integer(*references));
+            filter_clause += &format!(
+                " AND {}${}{}",
+                RFD_REFERENCES_PREDICATE.0,
+                binds.len(),
+                RFD_REFERENCES_PREDICATE.1
+            );
+        }
+
+        if let Some(any) = any {
+            filter_clause += &format!(" AND {}", rfd_filter_clause(any, tables, true, binds));
+        }
+
+        if let Some(all) = all {
+            for filter in all {
+                filter_clause += &format!(
+                    " AND {}",
+                    rfd_filter_clause(std::slice::from_ref(filter), tables, true, binds)
+                );
+            }
+        }
+
+        if !nested && !deleted {
+            for table in tables {
+                filter_clause += &format!(" AND {}.deleted_at IS NULL", table);
+            }
+        }
+
+        clauses.push(format!("({})", filter_clause));
+    }
+
+    if !clauses.is_empty() {
+        format!("({})", clauses.join(" OR "))
+    } else if nested {
+        // An empty group of nested filters can never be satisfied
+        "1=0".to_string()
+    } else {
+        "1=1".to_string()
+    }
+}
+
+/// Render the cursor condition and ordering for the raw RFD list query. RFDs that share the same
+/// value for the sort field are ordered by their number
+fn rfd_sort_clauses(sort: &RfdSort, binds: &mut Vec<RfdFilterBind>) -> (String, String) {
+    let (direction, comparison) = match sort.direction {
+        SortDirection::Asc => ("ASC", ">"),
+        SortDirection::Desc => ("DESC", "<"),
+    };
+
+    // RFDs without a major change are sorted as if their last major change was infinitely far in
+    // the past so that their position is stable across pages
+    let value = match sort.field {
+        RfdSortField::Number => None,
+        RfdSortField::UpdatedAt => Some("rfd_revision.committed_at"),
+        RfdSortField::LatestMajorChangeAt => {
+            Some("COALESCE(latest_major_revision.committed_at, '-infinity'::timestamptz)")
+        }
+    };
+
+    let cursor_clause = match (&sort.after, value) {
+        (Some(after), Some(value)) => {
+            binds.push(RfdFilterBind::Timestamptz(after.value));
+            binds.push(RfdFilterBind::Integer(after.rfd_number));
+            format!(
+                "({}, rfd.rfd_number) {} (COALESCE(${}, '-infinity'::timestamptz), ${})",
+                value,
+                comparison,
+                binds.len() - 1,
+                binds.len()
+            )
+        }
+        (Some(after), None) => {
+            binds.push(RfdFilterBind::Integer(after.rfd_number));
+            format!("rfd.rfd_number {} ${}", comparison, binds.len())
+        }
+        (None, _) => "1=1".to_string(),
+    };
+
+    let order_clause = match value {
+        Some(value) => format!("{} {}, rfd.rfd_number {}", value, direction, direction),
+        None => format!("rfd.rfd_number {}", direction),
+    };
+
+    (cursor_clause, order_clause)
+}
+
+fn bind_rfd_filters(
+    mut query: BoxedSqlQuery<'static, Pg, SqlQuery>,
+    binds: Vec<RfdFilterBind>,
+) -> BoxedSqlQuery<'static, Pg, SqlQuery> {
+    for bind in binds {
+        tracing::trace!(?bind, "Binding filter parameter");
+        query = match bind {
+            RfdFilterBind::Uuid(value) => query.bind::<diesel::sql_types::Uuid, _>(value),
+            RfdFilterBind::Integer(value) => query.bind::<diesel::sql_types::Integer, _>(value),
+            RfdFilterBind::VarChar(value) => query.bind::<diesel::sql_types::VarChar, _>(value),
+            RfdFilterBind::Timestamptz(value) => query.bind::<Nullable<Timestamptz>, _>(value),
+            RfdFilterBind::Visibility(value) => query.bind::<RfdVisibility, _>(value),
+        };
+    }
+
+    query
+}
+
+#[async_trait]
+impl RfdWatchStore for PostgresStore {
+    #[instrument(skip(self))]
+    async fn get(
+        &self,
+        id: &TypedUuid<RfdWatchId>,
+        deleted: bool,
+    ) -> Result<Option<RfdWatch>, StoreError> {
+        let watch = RfdWatchStore::list(
             self,
-            vec![JobFilter::default().id(Some(vec![id]))],
+            vec![RfdWatchFilter::default()
+                .id(Some(vec![*id]))
+                .deleted(deleted)],
             &ListPagination::default().limit(1),
         )
         .await?;
-        tracing::trace!("Done get job query");
-
-        Ok(user.into_iter().nth(0))
+        Ok(watch.into_iter().nth(0))
     }
 
     #[instrument(skip(self))]
     async fn list(
         &self,
-        filters: Vec<JobFilter>,
+        filters: Vec<RfdWatchFilter>,
         pagination: &ListPagination,
-    ) -> Result<Vec<Job>, StoreError> {
-        tracing::trace!("Start list job query");
-        let mut query = job::dsl::job.into_boxed();
+    ) -> Result<Vec<RfdWatch>, StoreError> {
+        tracing::trace!("Start list rfd watch query");
+        let mut query = rfd_watch::dsl::rfd_watch.into_boxed();
         let filter_predicates = filters
             .into_iter()
             .map(|filter| {
                 let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
-                let JobFilter {
+                let RfdWatchFilter {
                     id,
-                    sha,
-                    rfd,
-                    processed,
-                    started,
+                    api_user_id,
+                    rfd_number,
+                    label,
+                    deleted,
                 } = filter;
 
                 if let Some(id) = id {
-                    predicates.push(Box::new(job::id.eq_any(id)));
+                    predicates.push(Box::new(
+                        rfd_watch::id.eq_any(id.into_iter().map(GenericUuid::into_untyped_uuid)),
+                    ));
                 }
 
-                if let Some(sha) = sha {
-                    predicates.push(Box::new(job::sha.eq_any(sha)));
+                if let Some(api_user_id) = api_user_id {
+                    predicates.push(Box::new(rfd_watch::api_user_id.eq_any(api_user_id)));
                 }
 
-                if let Some(rfd) = rfd {
-                    predicates.push(Box::new(job::rfd.eq_any(rfd)));
+                if let Some(rfd_number) = rfd_number {
+                    predicates.push(Box::new(
+                        rfd_watch::rfd_number.assume_not_null().eq_any(rfd_number),
+                    ));
                 }
 
-                if let Some(processed) = processed {
-                    predicates.push(Box::new(job::processed.eq(processed)));
+                if let Some(label) = label {
+                    predicates.push(Box::new(
+                        lower(rfd_watch::label.assume_not_null()).eq_any(
+                            label
+                                .into_iter()
+                                .map(|label| label.to_lowercase())
+                                .collect::<Vec<_>>(),
+                        ),
+                    ));
                 }
 
-                if let Some(started) = started {
-                    if started {
-                        predicates.push(Box::new(job::started_at.is_not_null()));
-                    } else {
-                        predicates.push(Box::new(job::started_at.is_null()));
-                    }
+                if !deleted {
+                    predicates.push(Box::new(rfd_watch::deleted_at.is_null()));
                 }
 
                 predicates
@@ -1348,96 +2914,396 @@ impl JobStore for PostgresStore {
             query
                 .offset(pagination.offset)
                 .limit(pagination.limit)
-                .order(job::processed.asc())
-                .order(job::committed_at.asc())
-                .order(job::created_at.asc())
-                .get_results_async::<JobModel>(&*self.pool.get().await.tap_err(|err| {
+                .order(rfd_watch::created_at.desc())
+                .get_results_async::<RfdWatchModel>(&*self.pool.get().await.tap_err(|err| {
                     tracing::error!(?err, "Failed to acquire database connection")
                 })?)
                 .await?;
-        tracing::trace!("Done list job query");
+        tracing::trace!("Done list rfd watch query");
 
-        Ok(results.into_iter().map(|job| job.into()).collect())
+        Ok(results.into_iter().map(|watch| watch.into()).collect())
     }
 
-    #[instrument(skip(self))]
-    async fn upsert(&self, new_job: NewJob) -> Result<Job, StoreError> {
-        tracing::trace!("Start upsert job query");
-        let rfd: JobModel =
-            insert_into(job::dsl::job)
+    #[instrument(skip(self, new_watch), fields(id = ?new_watch.id))]
+    async fn create(&self, new_watch: NewRfdWatch) -> Result<RfdWatch, StoreError> {
+        let watch: RfdWatchModel =
+            insert_into(rfd_watch::dsl::rfd_watch)
                 .values((
-                    job::owner.eq(new_job.owner.clone()),
-                    job::repository.eq(new_job.repository.clone()),
-                    job::branch.eq(new_job.branch.clone()),
-                    job::sha.eq(String::from(new_job.sha)),
-                    job::rfd.eq(new_job.rfd),
-                    job::webhook_delivery_id
-                        .eq(new_job.webhook_delivery_id.map(|id| id.into_untyped_uuid())),
-                    job::processed.eq(false),
-                    job::committed_at.eq(new_job.committed_at),
+                    rfd_watch::id.eq(new_watch.id.into_untyped_uuid()),
+                    rfd_watch::api_user_id.eq(new_watch.api_user_id),
+                    rfd_watch::rfd_number.eq(new_watch.rfd_number),
+                    rfd_watch::label.eq(new_watch.label),
                 ))
                 .get_result_async(&*self.pool.get().await.tap_err(|err| {
                     tracing::error!(?err, "Failed to acquire database connection")
                 })?)
                 .await?;
-        tracing::trace!("Done upsert job query");
 
-        Ok(rfd.into())
+        Ok(watch.into())
     }
 
     #[instrument(skip(self))]
-    async fn start(&self, id: i32) -> Result<Option<Job>, StoreError> {
-        tracing::trace!("Start start job query");
+    async fn delete(&self, id: &TypedUuid<RfdWatchId>) -> Result<Option<RfdWatch>, StoreError> {
         let _ =
-            update(job::dsl::job)
-                .filter(job::id.eq(id))
-                .filter(job::started_at.is_null())
-                .set(job::started_at.eq(Utc::now()))
+            update(rfd_watch::dsl::rfd_watch)
+                .filter(rfd_watch::id.eq(id.into_untyped_uuid()))
+                .set(rfd_watch::deleted_at.eq(Utc::now()))
                 .execute_async(&*self.pool.get().await.tap_err(|err| {
                     tracing::error!(?err, "Failed to acquire database connection")
                 })?)
                 .await?;
-        tracing::trace!("Done start job query");
 
-        JobStore::get(self, id).await
+        RfdWatchStore::get(self, id, true).await
     }
+}
 
+#[async_trait]
+impl RfdWatchNotificationStore for PostgresStore {
     #[instrument(skip(self))]
-    async fn complete(&self, id: i32) -> Result<Option<Job>, StoreError> {
-        tracing::trace!("Start complete job query");
-        let _ =
-            update(job::dsl::job)
-                .filter(job::id.eq(id))
-                .set(job::processed.eq(true))
-                .execute_async(&*self.pool.get().await.tap_err(|err| {
+    async fn list(
+        &self,
+        filters: Vec<RfdWatchNotificationFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdWatchNotification>, StoreError> {
+        tracing::trace!("Start list rfd watch notification query");
+        let mut query = rfd_watch_notification::dsl::rfd_watch_notification.into_boxed();
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let RfdWatchNotificationFilter { id, processed } = filter;
+
+                if let Some(id) = id {
+                    predicates.push(Box::new(
+                        rfd_watch_notification::id
+                            .eq_any(id.into_iter().map(GenericUuid::into_untyped_uuid)),
+                    ));
+                }
+
+                match processed {
+                    Some(true) => predicates
+                        .push(Box::new(rfd_watch_notification::processed_at.is_not_null())),
+                    Some(false) => {
+                        predicates.push(Box::new(rfd_watch_notification::processed_at.is_null()))
+                    }
+                    None => (),
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results =
+            query
+                .offset(pagination.offset)
+                .limit(pagination.limit)
+                .order(rfd_watch_notification::created_at.asc())
+                .get_results_async::<RfdWatchNotificationModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?;
+        tracing::trace!("Done list rfd watch notification query");
+
+        Ok(results
+            .into_iter()
+            .map(|notification| notification.into())
+            .collect())
+    }
+
+    #[instrument(skip(self, new_notification), fields(rfd_number = new_notification.rfd_number, revision = ?new_notification.rfd_revision_id))]
+    async fn create(
+        &self,
+        new_notification: NewRfdWatchNotification,
+    ) -> Result<RfdWatchNotification, StoreError> {
+        let notification: RfdWatchNotificationModel =
+            insert_into(rfd_watch_notification::dsl::rfd_watch_notification)
+                .values((
+                    rfd_watch_notification::rfd_number.eq(new_notification.rfd_number),
+                    rfd_watch_notification::rfd_revision_id
+                        .eq(new_notification.rfd_revision_id.into_untyped_uuid()),
+                    rfd_watch_notification::event.eq(new_notification.event),
+                ))
+                .get_result_async(&*self.pool.get().await.tap_err(|err| {
                     tracing::error!(?err, "Failed to acquire database connection")
                 })?)
                 .await?;
-        tracing::trace!("Done complete job query");
 
-        JobStore::get(self, id).await
+        Ok(notification.into())
+    }
+
+    #[instrument(skip(self))]
+    async fn claim(
+        &self,
+        id: &TypedUuid<RfdWatchNotificationId>,
+    ) -> Result<Option<RfdWatchNotification>, StoreError> {
+        tracing::trace!("Start claim rfd watch notification query");
+
+        // Notifications are marked as processed before they are sent. A failure while sending
+        // drops the notification rather than risking watchers receiving it more than once
+        let notification =
+            update(rfd_watch_notification::dsl::rfd_watch_notification)
+                .filter(rfd_watch_notification::id.eq(id.into_untyped_uuid()))
+                .filter(rfd_watch_notification::processed_at.is_null())
+                .set(rfd_watch_notification::processed_at.eq(Utc::now()))
+                .get_results_async::<RfdWatchNotificationModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?
+                .pop();
+        tracing::trace!("Done claim rfd watch notification query");
+
+        Ok(notification.map(|notification| notification.into()))
     }
 }
 
-#[allow(clippy::type_complexity)]
-fn flatten_predicates<T>(
-    predicates: Vec<Vec<Box<dyn BoxableExpression<T, Pg, SqlType = Bool>>>>,
-) -> Option<Box<dyn BoxableExpression<T, Pg, SqlType = Bool>>>
-where
-    T: 'static,
-{
-    let mut filter_predicates = vec![];
+#[async_trait]
+impl RfdNumberReservationStore for PostgresStore {
+    #[instrument(skip(self))]
+    async fn list(
+        &self,
+        filters: Vec<RfdNumberReservationFilter>,
+        pagination: &ListPagination,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError> {
+        tracing::trace!("Start list rfd number reservation query");
+        let mut query = rfd_number_reservation::dsl::rfd_number_reservation.into_boxed();
+        let filter_predicates = filters
+            .into_iter()
+            .map(|filter| {
+                let mut predicates: Vec<Box<dyn BoxableExpression<_, Pg, SqlType = Bool>>> = vec![];
+                let RfdNumberReservationFilter {
+                    rfd_number,
+                    min_rfd_number,
+                    group_id,
+                    claimed,
+                } = filter;
 
-    for p in predicates {
-        let flat = p
+                if let Some(rfd_number) = rfd_number {
+                    predicates.push(Box::new(
+                        rfd_number_reservation::rfd_number.eq_any(rfd_number),
+                    ));
+                }
+
+                if let Some(min_rfd_number) = min_rfd_number {
+                    predicates.push(Box::new(
+                        rfd_number_reservation::rfd_number.ge(min_rfd_number),
+                    ));
+                }
+
+                if let Some(group_id) = group_id {
+                    predicates.push(Box::new(
+                        rfd_number_reservation::group_id
+                            .assume_not_null()
+                            .eq_any(group_id),
+                    ));
+                }
+
+                match claimed {
+                    Some(true) => {
+                        predicates.push(Box::new(rfd_number_reservation::claimed_at.is_not_null()))
+                    }
+                    Some(false) => {
+                        predicates.push(Box::new(rfd_number_reservation::claimed_at.is_null()))
+                    }
+                    None => (),
+                }
+
+                predicates
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(predicate) = flatten_predicates(filter_predicates) {
+            query = query.filter(predicate);
+        }
+
+        let results =
+            query
+                .offset(pagination.offset)
+                .limit(pagination.limit)
+                .order(rfd_number_reservation::rfd_number.asc())
+                .get_results_async::<RfdNumberReservationModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?;
+        tracing::trace!("Done list rfd number reservation query");
+
+        Ok(results
             .into_iter()
-            .reduce(|combined, entry| Box::new(combined.and(entry)));
-        if let Some(flat) = flat {
-            filter_predicates.push(flat);
+            .map(|reservation| reservation.into())
+            .collect())
+    }
+
+    #[instrument(skip(self, new_reservations), fields(count = new_reservations.len()))]
+    async fn create(
+        &self,
+        new_reservations: Vec<NewRfdNumberReservation>,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError> {
+        if new_reservations.is_empty() {
+            return Ok(vec![]);
         }
+
+        let results =
+            insert_into(rfd_number_reservation::dsl::rfd_number_reservation)
+                .values(
+                    new_reservations
+                        .into_iter()
+                        .map(|reservation| {
+                            (
+                                rfd_number_reservation::id.eq(reservation.id.into_untyped_uuid()),
+                                rfd_number_reservation::rfd_number.eq(reservation.rfd_number),
+                                rfd_number_reservation::group_id.eq(reservation.group_id),
+                                rfd_number_reservation::created_by.eq(reservation.created_by),
+                                rfd_number_reservation::claimed_by.eq(reservation.claimed_by),
+                                rfd_number_reservation::claimed_at.eq(reservation.claimed_at),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .on_conflict(on_constraint("rfd_number_reservation_unique"))
+                .do_nothing()
+                .get_results_async::<RfdNumberReservationModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|reservation| reservation.into())
+            .collect())
     }
 
-    filter_predicates
-        .into_iter()
-        .reduce(|combined, entry| Box::new(combined.or(entry)))
+    #[instrument(skip(self, new_reservations), fields(count = new_reservations.len()))]
+    async fn create_all(
+        &self,
+        new_reservations: Vec<NewRfdNumberReservation>,
+    ) -> Result<Vec<RfdNumberReservation>, StoreError> {
+        if new_reservations.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // Reservations are inserted by a single statement so that they are either all written or
+        // none are. Numbers that are already reserved cause nothing to be inserted, and a
+        // concurrent reservation of any of the same numbers fails the statement on the unique
+        // constraint rather than leaving part of the numbers reserved
+        let raw_query = r#"INSERT INTO rfd_number_reservation (
+            id,
+            rfd_number,
+            group_id,
+            created_by,
+            claimed_by,
+            claimed_at
+        )
+        SELECT
+            reservation.id,
+            reservation.rfd_number,
+            reservation.group_id,
+            reservation.created_by,
+            reservation.claimed_by,
+            reservation.claimed_at
+        FROM
+            unnest($1, $2, $3, $4, $5, $6)
+            AS reservation(id, rfd_number, group_id, created_by, claimed_by, claimed_at)
+        WHERE NOT EXISTS (
+            SELECT 1
+            FROM rfd_number_reservation
+            WHERE rfd_number_reservation.rfd_number = ANY($2)
+        )
+        RETURNING *"#;
+
+        let mut ids = vec![];
+        let mut rfd_numbers = vec![];
+        let mut group_ids = vec![];
+        let mut created_by = vec![];
+        let mut claimed_by = vec![];
+        let mut claimed_at = vec![];
+        for reservation in new_reservations {
+            ids.push(reservation.id.into_untyped_uuid());
+            rfd_numbers.push(reservation.rfd_number);
+            group_ids.push(reservation.group_id);
+            created_by.push(reservation.created_by);
+            claimed_by.push(reservation.claimed_by);
+            claimed_at.push(reservation.claimed_at);
+        }
+
+        let query = sql_query(raw_query)
+            .into_boxed::<Pg>()
+            .bind::<Array<diesel::sql_types::Uuid>, _>(ids)
+            .bind::<Array<Integer>, _>(rfd_numbers)
+            .bind::<Array<Nullable<diesel::sql_types::Uuid>>, _>(group_ids)
+            .bind::<Array<diesel::sql_types::Uuid>, _>(created_by)
+            .bind::<Array<Nullable<diesel::sql_types::Uuid>>, _>(claimed_by)
+            .bind::<Array<Nullable<Timestamptz>>, _>(claimed_at);
+
+        tracing::trace!(query = ?debug_query(&query), "Create RFD number reservations query");
+
+        let results =
+            query
+                .get_results_async::<RfdNumberReservationModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|reservation| reservation.into())
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn claim(
+        &self,
+        rfd_number: i32,
+        api_user_id: Uuid,
+    ) -> Result<Option<RfdNumberReservation>, StoreError> {
+        let reservation =
+            update(rfd_number_reservation::dsl::rfd_number_reservation)
+                .filter(rfd_number_reservation::rfd_number.eq(rfd_number))
+                .filter(rfd_number_reservation::claimed_at.is_null())
+                .set((
+                    rfd_number_reservation::claimed_by.eq(api_user_id),
+                    rfd_number_reservation::claimed_at.eq(Utc::now()),
+                ))
+                .get_results_async::<RfdNumberReservationModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?
+                .pop();
+
+        Ok(reservation.map(|reservation| reservation.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn unclaim(&self, rfd_number: i32) -> Result<Option<RfdNumberReservation>, StoreError> {
+        let reservation =
+            update(rfd_number_reservation::dsl::rfd_number_reservation)
+                .filter(rfd_number_reservation::rfd_number.eq(rfd_number))
+                .set((
+                    rfd_number_reservation::claimed_by.eq(None::<Uuid>),
+                    rfd_number_reservation::claimed_at.eq(None::<DateTime<Utc>>),
+                ))
+                .get_results_async::<RfdNumberReservationModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?
+                .pop();
+
+        Ok(reservation.map(|reservation| reservation.into()))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete(&self, rfd_number: i32) -> Result<Option<RfdNumberReservation>, StoreError> {
+        let reservation =
+            delete(rfd_number_reservation::dsl::rfd_number_reservation)
+                .filter(rfd_number_reservation::rfd_number.eq(rfd_number))
+                .get_results_async::<RfdNumberReservationModel>(&*self.pool.get().await.tap_err(
+                    |err| tracing::error!(?err, "Failed to acquire database connection"),
+                )?)
+                .await?
+                .pop();
+
+        Ok(reservation.map(|reservation| reservation.into()))
+    }
 }
@@ -0,0 +1,749 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Tests that run the storage layer against a live Postgres server. The server to use is read from
+//! the `TEST_DATABASE` environment variable, and each test runs against its own freshly migrated
+//! database. Tests are skipped when the variable is not set.
+
+use chrono::{Duration, SubsecRound, Utc};
+use diesel::{sql_query, Connection, PgConnection, RunQueryDsl};
+use newtype_uuid::TypedUuid;
+use rfd_model::{
+    migrations::run_migrations,
+    schema_ext::{ContentFormat, JobStatus, PdfSource, Visibility},
+    storage::{
+        JobFilter, JobStore, RfdFilter, RfdMetaStore, RfdNumberReservationFilter,
+        RfdNumberReservationStore, RfdPdfStore, RfdPdfsStore, RfdReferenceStore,
+        RfdRevisionAuthorStore, RfdRevisionLabelStore, RfdRevisionMetaStore, RfdRevisionStore,
+        RfdSort, RfdStore,
+    },
+    Job, NewJob, NewRfd, NewRfdNumberReservation, NewRfdPdf, NewRfdReference, NewRfdRevision,
+    NewRfdRevisionAuthor, NewRfdRevisionLabel,
+};
+use uuid::Uuid;
+use v_model::storage::{postgres::PostgresStore, ListPagination};
+
+struct TestDatabase {
+    server: String,
+    name: String,
+    store: PostgresStore,
+}
+
+impl TestDatabase {
+    async fn new() -> Option<Self> {
+        let Ok(server) = std::env::var("TEST_DATABASE") else {
+            eprintln!("TEST_DATABASE is not set. Skipping storage test");
+            return None;
+        };
+
+        let name = format!("rfd_model_{}", Uuid::new_v4().simple());
+        let mut conn = PgConnection::establish(&server).unwrap();
+        sql_query(format!("CREATE DATABASE {}", name))
+            .execute(&mut conn)
+            .unwrap();
+
+        let url = format!("{}/{}", server, name);
+        run_migrations(&url, false);
+        let store = PostgresStore::new(&url).await.unwrap();
+
+        Some(Self {
+            server,
+            name,
+            store,
+        })
+    }
+}
+
+impl Drop for TestDatabase {
+    fn drop(&mut self) {
+        if let Ok(mut conn) = PgConnection::establish(&self.server) {
+            let _ = sql_query(format!(
+                "DROP DATABASE IF EXISTS {} WITH (FORCE)",
+                self.name
+            ))
+            .execute(&mut conn);
+        }
+    }
+}
+
+async fn queued_job(store: &PostgresStore, rfd: i32, sha: &str) -> Job {
+    JobStore::upsert(
+        store,
+        NewJob {
+            owner: "oxidecomputer".to_string(),
+            repository: "rfd".to_string(),
+            branch: format!("{:04}", rfd),
+            sha: sha.to_string().into(),
+            rfd,
+            webhook_delivery_id: None,
+            committed_at: Utc::now(),
+        },
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_heartbeat_only_extends_the_current_attempt() {
+    let Some(db) = TestDatabase::new().await else {
+        return;
+    };
+
+    let job = queued_job(&db.store, 123, "abc").await;
+    let lease = Utc::now() + Duration::minutes(1);
+    let job = JobStore::start(&db.store, job.id, lease)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(JobStatus::Running, job.status);
+    assert_eq!(1, job.attempts);
+
+    let extended = Utc::now() + Duration::minutes(5);
+    let renewed = JobStore::heartbeat(&db.store, job.id, job.attempts, extended)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(renewed.lease_expires_at.unwrap() > lease);
+
+    // A processor holding an earlier attempt must not be able to extend the lease
+    assert!(
+        JobStore::heartbeat(&db.store, job.id, job.attempts - 1, extended)
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    // Once the job has concluded there is no longer a lease to extend
+    JobStore::complete(&db.store, job.id, job.attempts)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(
+        JobStore::heartbeat(&db.store, job.id, job.attempts, extended)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_complete_and_fail_require_a_running_attempt() {
+    let Some(db) = TestDatabase::new().await else {
+        return;
+    };
+
+    // Jobs that have not been started can not be concluded
+    let job = queued_job(&db.store, 123, "abc").await;
+    assert!(JobStore::complete(&db.store, job.id, job.attempts)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(
+        JobStore::fail(&db.store, job.id, job.attempts, "error".to_string(), None)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let stored = JobStore::get(&db.store, job.id).await.unwrap().unwrap();
+    assert_eq!(JobStatus::Queued, stored.status);
+
+    // A job that was reaped and started again belongs to the new attempt
+    let first = JobStore::start(&db.store, job.id, Utc::now() - Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+    JobStore::reap(&db.store, Utc::now(), 5).await.unwrap();
+    let second = JobStore::start(&db.store, job.id, Utc::now() + Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.attempts + 1, second.attempts);
+
+    assert!(JobStore::complete(&db.store, job.id, first.attempts)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(
+        JobStore::fail(&db.store, job.id, first.attempts, "error".to_string(), None)
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    let failed = JobStore::fail(
+        &db.store,
+        job.id,
+        second.attempts,
+        "error".to_string(),
+        Some(Utc::now()),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(JobStatus::Failed, failed.status);
+    assert_eq!(None, failed.lease_expires_at);
+
+    // Failed jobs are no longer running and can not be completed until they are started again
+    assert!(JobStore::complete(&db.store, job.id, failed.attempts)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_reap_releases_expired_leases() {
+    let Some(db) = TestDatabase::new().await else {
+        return;
+    };
+
+    // Postgres stores timestamps with microsecond precision
+    let now = Utc::now().trunc_subsecs(6);
+    let max_attempts = 2;
+
+    // Still within its lease
+    let active = queued_job(&db.store, 1, "active").await;
+    JobStore::start(&db.store, active.id, now + Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Lease expired on its first attempt
+    let retryable = queued_job(&db.store, 2, "retryable").await;
+    JobStore::start(&db.store, retryable.id, now - Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Lease expired on its final attempt
+    let exhausted = queued_job(&db.store, 3, "exhausted").await;
+    let first = JobStore::start(&db.store, exhausted.id, now + Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+    JobStore::fail(
+        &db.store,
+        exhausted.id,
+        first.attempts,
+        "error".to_string(),
+        Some(now),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let last = JobStore::start(&db.store, exhausted.id, now - Duration::minutes(1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(max_attempts, last.attempts);
+
+    let mut reaped = JobStore::reap(&db.store, now, max_attempts).await.unwrap();
+    reaped.sort_by_key(|job| job.id);
+    assert_eq!(
+        vec![retryable.id, exhausted.id],
+        reaped.iter().map(|job| job.id).collect::<Vec<_>>()
+    );
+
+    let retryable = JobStore::get(&db.store, retryable.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(JobStatus::Failed, retryable.status);
+    assert_eq!(Some(now), retryable.next_attempt_at);
+    assert_eq!(None, retryable.lease_expires_at);
+
+    let exhausted = JobStore::get(&db.store, exhausted.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(JobStatus::Dead, exhausted.status);
+    assert_eq!(None, exhausted.lease_expires_at);
+
+    let active = JobStore::get(&db.store, active.id).await.unwrap().unwrap();
+    assert_eq!(JobStatus::Running, active.status);
+
+    // Reaped jobs are picked up again by the regular queue listing
+    let waiting = JobStore::list(
+        &db.store,
+        vec![JobFilter::default()
+            .status(Some(vec![JobStatus::Queued, JobStatus::Failed]))
+            .next_attempt_before(Some(Utc::now()))],
+        &ListPagination::default(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        vec![retryable.id],
+        waiting.iter().map(|job| job.id).collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn test_rfd_pdfs_exclude_deleted_pdfs() {
+    let Some(db) = TestDatabase::new().await else {
+        return;
+    };
+
+    let rfd = RfdStore::upsert(
+        &db.store,
+        NewRfd {
+            id: TypedUuid::new_v4(),
+            rfd_number: 123,
+            link: None,
+            visibility: Visibility::Public,
+        },
+    )
+    .await
+    .unwrap();
+    let revision = RfdRevisionStore::upsert(
+        &db.store,
+        NewRfdRevision {
+            id: TypedUuid::new_v4(),
+            rfd_id: rfd.id,
+            title: "Test RFD".to_string(),
+            state: Some("published".to_string()),
+            discussion: None,
+            authors: None,
+            labels: None,
+            content: "= RFD 123 Test RFD".to_string(),
+            content_format: ContentFormat::Asciidoc,
+            sha: "file-sha".to_string().into(),
+            commit: "commit-sha".to_string().into(),
+            major_change: true,
+            committed_at: Utc::now(),
+        },
+    )
+    .await
+    .unwrap();
+
+    let mut pdfs = vec![];
+    for link in ["https://example.com/a.pdf", "https://example.com/b.pdf"] {
+        pdfs.push(
+            RfdPdfStore::upsert(
+                &db.store,
+                NewRfdPdf {
+                    id: TypedUuid::new_v4(),
+                    rfd_revision_id: revision.id,
+                    source: PdfSource::Google,
+                    link: link.to_string(),
+                    rfd_id: rfd.id,
+                    external_id: link.to_string(),
+                },
+            )
+            .await
+            .unwrap(),
+        );
+    }
+
+    let listed_pdfs = |deleted: bool| {
+        let store = &db.store;
+        async move {
+            RfdPdfsStore::list(
+                store,
+                vec![RfdFilter::default()
+                    .rfd_number(Some(vec![123]))
+                    .deleted(deleted)],
+                &ListPagination::default(),
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .flat_map(|rfd| {
+                rfd.content
+                    .map(|content| content.content)
+                    .unwrap_or_default()
+            })
+            .map(|pdf| pdf.id)
+            .collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(2, listed_pdfs(false).await.len());
+
+    RfdPdfStore::delete(&db.store, &pdfs[0].id).await.unwrap();
+    assert_eq!(vec![pdfs[1].id], listed_pdfs(false).await);
+    assert_eq!(2, listed_pdfs(true).await.len());
+
+    // An RFD whose PDFs have all been deleted has no PDFs to list
+    RfdPdfStore::delete(&db.store, &pdfs[1].id).await.unwrap();
+    assert!(listed_pdfs(false).await.is_empty());
+    assert!(RfdPdfsStore::get(&db.store, rfd.id, None, false)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_rfd_feed_reports_creation_state_and_major_changes() {
+    let Some(db) = TestDatabase::new().await else {
+        return;
+    };
+
+    let rfd = RfdStore::upsert(
+        &db.store,
+        NewRfd {
+            id: TypedUuid::new_v4(),
+            rfd_number: 123,
+            link: None,
+            visibility: Visibility::Public,
+        },
+    )
+    .await
+    .unwrap();
+
+    let start = Utc::now().trunc_subsecs(6) - Duration::days(10);
+    let mut revisions = vec![];
+    for (day, (state, major_change)) in [
+        ("ideation", true),
+        ("ideation", false),
+        ("discussion", false),
+        ("discussion", true),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        revisions.push(
+            RfdRevisionStore::upsert(
+                &db.store,
+                NewRfdRevision {
+                    id: TypedUuid::new_v4(),
+                    rfd_id: rfd.id,
+                    title: "Test RFD".to_string(),
+                    state: Some(state.to_string()),
+                    discussion: None,
+                    authors: None,
+                    labels: None,
+                    content: "= RFD 123 Test RFD".to_string(),
+                    content_format: ContentFormat::Asciidoc,
+                    sha: format!("file-sha-{}", day).into(),
+                    commit: format!("commit-sha-{}", day).into(),
+                    major_change,
+                    committed_at: start + Duration::days(day as i64),
+                },
+            )
+            .await
+            .unwrap(),
+        );
+    }
+
+    let feed =
+        RfdRevisionMetaStore::list_feed(&db.store, vec![rfd.id], &ListPagination::unlimited())
+            .await
+            .unwrap();
+    assert_eq!(
+        vec![
+            (revisions[3].id, false, Some("discussion".to_string())),
+            (revisions[2].id, false, Some("ideation".to_string())),
+            (revisions[0].id, true, None),
+        ],
+        feed.into_iter()
+            .map(|entry| (entry.revision.id, entry.first, entry.previous_state))
+            .collect::<Vec<_>>()
+    );
+
+    // The limit applies to the revisions that are reported, newest first
+    let feed = RfdRevisionMetaStore::list_feed(
+        &db.store,
+        vec![rfd.id],
+        &ListPagination::default().limit(1),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        vec![revisions[3].id],
+        feed.into_iter()
+            .map(|entry| entry.revision.id)
+            .collect::<Vec<_>>()
+    );
+
+    // Only the requested RFDs are included
+    assert!(RfdRevisionMetaStore::list_feed(
+        &db.store,
+        vec![TypedUuid::new_v4()],
+        &ListPagination::unlimited(),
+    )
+    .await
+    .unwrap()
+    .is_empty());
+}
+
+#[tokio::test]
+async fn test_reservations_are_created_together_or_not_at_all() {
+    let Some(db) = TestDatabase::new().await else {
+        return;
+    };
+
+    let group_id = Some(Uuid::new_v4());
+    let created_by = Uuid::new_v4();
+    let reservations = |numbers: std::ops::RangeInclusive<i32>| {
+        numbers
+            .map(|rfd_number| NewRfdNumberReservation {
+                id: TypedUuid::new_v4(),
+                rfd_number,
+                group_id,
+                created_by,
+                claimed_by: None,
+                claimed_at: None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let reserved = || {
+        let store = &db.store;
+        async move {
+            RfdNumberReservationStore::list(
+                store,
+                vec![RfdNumberReservationFilter::default()],
+                &ListPagination::unlimited(),
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|reservation| reservation.rfd_number)
+            .collect::<Vec<_>>()
+        }
+    };
+
+    let held = RfdNumberReservationStore::create_all(&db.store, reservations(10..=12))
+        .await
+        .unwrap();
+    assert_eq!(3, held.len());
+    assert!(held
+        .iter()
+        .all(|reservation| reservation.group_id == group_id));
+
+    // A range that overlaps with an existing reservation is not reserved at all
+    assert!(
+        RfdNumberReservationStore::create_all(&db.store, reservations(12..=14))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(vec![10, 11, 12], reserved().await);
+
+    let held = RfdNumberReservationStore::create_all(&db.store, reservations(13..=14))
+        .await
+        .unwrap();
+    assert_eq!(2, held.len());
+    assert_eq!(vec![10, 11, 12, 13, 14], reserved().await);
+}
+
+#[tokio::test]
+async fn test_rfd_filters_match_the_same_rfds_in_every_store() {
+    let Some(db) = TestDatabase::new().await else {
+        return;
+    };
+
+    let start = Utc::now().trunc_subsecs(6) - Duration::days(10);
+    let fixtures = [
+        (
+            1,
+            Visibility::Public,
+            "published",
+            "Alpha Design",
+            vec![("Alice", Some("alice@example.com"))],
+            vec!["api"],
+            true,
+            vec![2],
+        ),
+        (
+            2,
+            Visibility::Private,
+            "discussion",
+            "Beta Design",
+            vec![
+                ("Bob", Some("bob@example.com")),
+                ("Alice", Some("alice@example.com")),
+            ],
+            vec!["api", "cli"],
+            false,
+            vec![],
+        ),
+        (
+            3,
+            Visibility::Public,
+            "ideation",
+            "Gamma 100%",
+            vec![("Carol", None)],
+            vec![],
+            true,
+            vec![1],
+        ),
+        (
+            4,
+            Visibility::Public,
+            "published",
+            "Alpha Deleted",
+            vec![("Dave", Some("dave@example.com"))],
+            vec!["api"],
+            true,
+            vec![1],
+        ),
+    ];
+
+    let mut rfds = vec![];
+    let mut revisions = vec![];
+    for (day, (rfd_number, visibility, state, title, authors, labels, major_change, references)) in
+        fixtures.into_iter().enumerate()
+    {
+        let rfd = RfdStore::upsert(
+            &db.store,
+            NewRfd {
+                id: TypedUuid::new_v4(),
+                rfd_number,
+                link: None,
+                visibility,
+            },
+        )
+        .await
+        .unwrap();
+        let revision = RfdRevisionStore::upsert(
+            &db.store,
+            NewRfdRevision {
+                id: TypedUuid::new_v4(),
+                rfd_id: rfd.id,
+                title: title.to_string(),
+                state: Some(state.to_string()),
+                discussion: None,
+                authors: Some(
+                    authors
+                        .iter()
+                        .map(|(name, email)| match email {
+                            Some(email) => format!("{} <{}>", name, email),
+                            None => name.to_string(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                labels: Some(labels.join(", ")),
+                content: format!("= RFD {} {}", rfd_number, title),
+                content_format: ContentFormat::Asciidoc,
+                sha: format!("file-sha-{}", rfd_number).into(),
+                commit: format!("commit-sha-{}", rfd_number).into(),
+                major_change,
+                committed_at: start + Duration::days(2 * day as i64),
+            },
+        )
+        .await
+        .unwrap();
+
+        RfdRevisionAuthorStore::replace(
+            &db.store,
+            &revision.id,
+            authors
+                .iter()
+                .enumerate()
+                .map(|(position, (name, email))| NewRfdRevisionAuthor {
+                    rfd_revision_id: revision.id,
+                    position: position as i32,
+                    name: name.to_string(),
+                    email: email.map(str::to_string),
+                })
+                .collect(),
+        )
+        .await
+        .unwrap();
+        RfdRevisionLabelStore::replace(
+            &db.store,
+            &revision.id,
+            labels
+                .iter()
+                .map(|label| NewRfdRevisionLabel {
+                    rfd_revision_id: revision.id,
+                    label: label.to_string(),
+                })
+                .collect(),
+        )
+        .await
+        .unwrap();
+        RfdReferenceStore::replace(
+            &db.store,
+            &revision.id,
+            references
+                .into_iter()
+                .map(|target_rfd_number| NewRfdReference {
+                    rfd_revision_id: revision.id,
+                    target_rfd_number,
+                })
+                .collect(),
+        )
+        .await
+        .unwrap();
+
+        rfds.push(rfd);
+        revisions.push(revision);
+    }
+
+    RfdStore::delete(&db.store, &rfds[3].id).await.unwrap();
+
+    let filters = vec![
+        RfdFilter::default(),
+        RfdFilter::default().deleted(true),
+        RfdFilter::default().id(Some(vec![rfds[0].id, rfds[3].id])),
+        RfdFilter::default().id(Some(vec![])),
+        RfdFilter::default().revision(Some(vec![revisions[1].id])),
+        RfdFilter::default().rfd_number(Some(vec![2, 3, 4])),
+        RfdFilter::default().commit(Some(vec!["commit-sha-3".to_string().into()])),
+        RfdFilter::default().public(Some(true)),
+        RfdFilter::default().public(Some(false)),
+        RfdFilter::default().state(Some(vec!["published".to_string()])),
+        RfdFilter::default().labels(Some(vec!["API".to_string()])),
+        RfdFilter::default().labels(Some(vec!["api".to_string(), "cli".to_string()])),
+        RfdFilter::default().author(Some("alice".to_string())),
+        RfdFilter::default().author_email(Some(vec!["ALICE@example.com".to_string()])),
+        RfdFilter::default().title(Some("alpha".to_string())),
+        RfdFilter::default().title(Some("100%".to_string())),
+        RfdFilter::default().updated_since(Some(start + Duration::days(2))),
+        RfdFilter::default().major_change_since(Some(start + Duration::days(1))),
+        RfdFilter::default().references(Some(1)),
+        RfdFilter::default().any(Some(vec![
+            RfdFilter::default().rfd_number(Some(vec![1])),
+            RfdFilter::default().state(Some(vec!["ideation".to_string()])),
+        ])),
+        RfdFilter::default().any(Some(vec![])),
+        RfdFilter::default().any(Some(vec![
+            RfdFilter::default(),
+            RfdFilter::default().rfd_number(Some(vec![1])),
+        ])),
+        RfdFilter::default().all(Some(vec![
+            RfdFilter::default().labels(Some(vec!["api".to_string()])),
+            RfdFilter::default().public(Some(true)),
+        ])),
+    ];
+
+    for filter in filters {
+        let mut listed = RfdStore::list(
+            &db.store,
+            vec![filter.clone()],
+            &ListPagination::unlimited(),
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|rfd| rfd.rfd_number)
+        .collect::<Vec<_>>();
+        listed.sort();
+
+        let mut listed_meta = RfdMetaStore::list(
+            &db.store,
+            vec![filter.clone()],
+            &RfdSort::default(),
+            &ListPagination::unlimited(),
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|rfd| rfd.rfd_number)
+        .collect::<Vec<_>>();
+        listed_meta.sort();
+
+        assert_eq!(listed, listed_meta, "{:?}", filter);
+        assert_eq!(
+            listed_meta.len() as i64,
+            RfdMetaStore::count(&db.store, vec![filter.clone()])
+                .await
+                .unwrap(),
+            "{:?}",
+            filter
+        );
+    }
+}
@@ -8,7 +8,7 @@ use std::{path::PathBuf, process::Command};
 
 use crate::util::write_file;
 
-use super::{RenderableRfdError, RenderedPdf, RfdOutputError, RfdRenderedFormat};
+use super::{RenderableRfdError, RenderedHtml, RenderedPdf, RfdOutputError, RfdRenderedFormat};
 
 pub(crate) async fn render_pdf_from_dir(
     content_dir: PathBuf,
@@ -26,37 +26,74 @@ async fn render_pdf(
     content: &RfdAsciidoc<'_>,
     content_dir: PathBuf,
 ) -> Result<RenderedPdf, RfdOutputError> {
+    let pdf = run_asciidoctor(
+        "asciidoctor-pdf",
+        &[
+            "-r",
+            "base64",
+            "-r",
+            "asciidoctor-mermaid/pdf",
+            "-a",
+            "reproducible",
+            "-a",
+            "source-highlighter=rouge",
+        ],
+        content,
+        content_dir,
+    )
+    .await?;
+
+    Ok(pdf.into())
+}
+
+async fn render_html(
+    content: &RfdAsciidoc<'_>,
+    content_dir: PathBuf,
+) -> Result<RenderedHtml, RfdOutputError> {
+    // Only the body of the document is rendered so that it can be embedded in to other pages
+    let html = run_asciidoctor(
+        "asciidoctor",
+        &["-e", "-a", "source-highlighter=rouge"],
+        content,
+        content_dir,
+    )
+    .await?;
+
+    Ok(String::from_utf8(html)
+        .map_err(|err| RenderableRfdError::InvalidContent(err.utf8_error()))?
+        .into())
+}
+
+/// Run an asciidoctor backend against the content, returning the rendered output
+async fn run_asciidoctor(
+    program: &'static str,
+    args: &[&str],
+    content: &RfdAsciidoc<'_>,
+    content_dir: PathBuf,
+) -> Result<Vec<u8>, RfdOutputError> {
     let file_path = content_dir.join("contents.adoc");
 
     // Write the contents to a temporary file.
     write_file(&file_path, content.raw().as_bytes()).await?;
     tracing::info!("Wrote file to temp dir");
 
-    let mut command = Command::new("asciidoctor-pdf");
-    command.current_dir(content_dir.clone()).args([
-        "-o",
-        "-",
-        "-r",
-        "base64",
-        "-r",
-        "asciidoctor-mermaid/pdf",
-        "-a",
-        "reproducible",
-        "-a",
-        "source-highlighter=rouge",
-        file_path.to_str().unwrap(),
-    ]);
+    let mut command = Command::new(program);
+    command
+        .current_dir(content_dir.clone())
+        .args(["-o", "-"])
+        .args(args)
+        .arg(file_path.to_str().unwrap());
 
     let cmd_output = tokio::task::spawn_blocking(move || command.output()).await?;
 
     let cmd_output = cmd_output.map_err(|error| RenderableRfdError::ProcessStart {
-        command: "asciidoctor-pdf",
+        command: program,
         path: std::env::var("PATH").ok(),
         error,
     })?;
 
     if cmd_output.status.success() {
-        Ok(cmd_output.stdout.into())
+        Ok(cmd_output.stdout)
     } else {
         Err(RenderableRfdError::ParserFailed(String::from_utf8(
             cmd_output.stderr,
@@ -70,3 +107,10 @@ impl<'a> RfdRenderedFormat<RfdAsciidoc<'a>> for RenderedPdf {
         render_pdf(content, content_dir).await
     }
 }
+
+#[async_trait]
+impl<'a> RfdRenderedFormat<RfdAsciidoc<'a>> for RenderedHtml {
+    async fn render(content: &RfdAsciidoc, content_dir: PathBuf) -> Result<Self, RfdOutputError> {
+        render_html(content, content_dir).await
+    }
+}
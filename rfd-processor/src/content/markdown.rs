@@ -0,0 +1,33 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use async_trait::async_trait;
+use pulldown_cmark::{html::push_html, Options, Parser};
+use rfd_data::content::{RfdDocument, RfdMarkdown};
+use std::path::PathBuf;
+
+use super::{RenderedHtml, RfdOutputError, RfdRenderedFormat};
+
+fn render_html(content: &RfdMarkdown<'_>) -> RenderedHtml {
+    let parser = Parser::new_ext(
+        content.raw(),
+        Options::ENABLE_TABLES
+            | Options::ENABLE_FOOTNOTES
+            | Options::ENABLE_STRIKETHROUGH
+            | Options::ENABLE_TASKLISTS
+            | Options::ENABLE_HEADING_ATTRIBUTES,
+    );
+
+    let mut html = String::new();
+    push_html(&mut html, parser);
+
+    html.into()
+}
+
+#[async_trait]
+impl<'a> RfdRenderedFormat<RfdMarkdown<'a>> for RenderedHtml {
+    async fn render(content: &RfdMarkdown, _content_dir: PathBuf) -> Result<Self, RfdOutputError> {
+        Ok(render_html(content))
+    }
+}
@@ -7,6 +7,7 @@ use std::{borrow::Cow, env, io, path::PathBuf, str::Utf8Error, string::FromUtf8E
 use async_trait::async_trait;
 use base64::DecodeError;
 use octorust::Client;
+use regex::{regex, Captures};
 use rfd_data::{
     content::{RfdAsciidoc, RfdContent, RfdContentError, RfdDocument, RfdMarkdown},
     RfdNumber,
@@ -25,6 +26,7 @@ use crate::{
 };
 
 mod asciidoc;
+mod markdown;
 
 pub(crate) use asciidoc::render_pdf_from_dir;
 
@@ -137,6 +139,29 @@ impl<'a> RenderableRfd<'a> {
         }
     }
 
+    /// Generate the HTML body of the RFD. Relative image paths are rewritten to be served from the
+    /// given asset url, which is expected to be the location that the RFD's supporting files have
+    /// been copied to. The result is sanitized as RFD sources may contain arbitrary passthrough HTML
+    pub async fn to_html(&self, asset_url: Option<&str>) -> Result<String, RfdOutputError> {
+        let html = match &self.content {
+            RfdContent::Asciidoc(adoc) => {
+                let html = RenderedHtml::render(adoc, self.tmp_path()?).await;
+                self.cleanup_tmp_path()?;
+                html?
+            }
+            // Markdown is rendered in memory and does not need a content directory
+            RfdContent::Markdown(md) => RenderedHtml::render(md, PathBuf::new()).await?,
+        }
+        .into_inner();
+
+        let html = match asset_url {
+            Some(asset_url) => rewrite_image_urls(&html, asset_url),
+            None => html,
+        };
+
+        Ok(sanitize_html(&html))
+    }
+
     /// Downloads images that are stored on the provided GitHub branch for the given RFD number.
     /// These are stored locally in a tmp directory for use by asciidoctor
     #[instrument(skip(self, client), fields(storage_path = ?self.tmp_path()))]
@@ -281,6 +306,60 @@ impl From<Vec<u8>> for RenderedPdf {
     }
 }
 
+pub struct RenderedHtml(String);
+
+impl RenderedHtml {
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for RenderedHtml {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+/// Remove scripts, event handlers and any other markup that is not safe to embed in to a page.
+/// Classes and ids are kept as asciidoctor relies on them for layout, cross references and syntax
+/// highlighting, as are the checkboxes that markdown task lists are rendered with
+fn sanitize_html(html: &str) -> String {
+    ammonia::Builder::default()
+        .add_generic_attributes(&["class", "id"])
+        .add_tags(&["input"])
+        .add_tag_attributes("input", &["type", "checked", "disabled"])
+        .clean(html)
+        .to_string()
+}
+
+/// Rewrite the sources of images that are relative to the RFD directory so that they point to the
+/// given asset url. Absolute urls, root relative paths and inline data are left untouched
+fn rewrite_image_urls(html: &str, asset_url: &str) -> String {
+    let asset_url = asset_url.trim_end_matches('/');
+
+    regex!(r#"(<img\b[^>]*?\bsrc=")([^"]*)(")"#)
+        .replace_all(html, |captures: &Captures| {
+            let src = &captures[2];
+            if src.is_empty()
+                || src.contains("://")
+                || src.starts_with('/')
+                || src.starts_with('#')
+                || src.starts_with("data:")
+            {
+                captures[0].to_string()
+            } else {
+                format!(
+                    "{}{}/{}{}",
+                    &captures[1],
+                    asset_url,
+                    src.trim_start_matches("./"),
+                    &captures[3]
+                )
+            }
+        })
+        .into_owned()
+}
+
 #[derive(Debug, Error)]
 pub enum RfdOutputError {
     #[error(transparent)]
@@ -294,3 +373,58 @@ pub enum RfdOutputError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{rewrite_image_urls, sanitize_html, RenderableRfd};
+
+    #[test]
+    fn test_rewrite_relative_image_urls() {
+        let html = r#"<div class="imageblock"><img src="images/arch.svg" alt="Arch"></div><img alt="x" src="./diagram.png">"#;
+
+        assert_eq!(
+            r#"<div class="imageblock"><img src="https://assets.example.com/rfd/123/latest/images/arch.svg" alt="Arch"></div><img alt="x" src="https://assets.example.com/rfd/123/latest/diagram.png">"#,
+            rewrite_image_urls(html, "https://assets.example.com/rfd/123/latest/")
+        );
+    }
+
+    #[test]
+    fn test_rewrite_skips_absolute_image_urls() {
+        let html = r#"<img src="https://example.com/a.png"><img src="/static/b.png"><img src="data:image/png;base64,AAAA"><a href="images/c.png">c</a>"#;
+
+        assert_eq!(
+            html,
+            rewrite_image_urls(html, "https://assets.example.com/rfd/123/latest")
+        );
+    }
+
+    #[test]
+    fn test_sanitize_html() {
+        let html = r#"<div class="listingblock" id="code"><script>alert(1)</script><pre class="rouge highlight" onclick="alert(2)">fn main() {}</pre></div><a href="javascript:alert(3)">link</a>"#;
+
+        let sanitized = sanitize_html(html);
+        assert!(!sanitized.contains("<script"));
+        assert!(!sanitized.contains("onclick"));
+        assert!(!sanitized.contains("javascript:"));
+        assert!(sanitized.contains(r#"<div class="listingblock" id="code">"#));
+        assert!(sanitized.contains(r#"<pre class="rouge highlight">fn main() {}</pre>"#));
+    }
+
+    #[tokio::test]
+    async fn test_markdown_to_html() {
+        let rfd = RenderableRfd::new_markdown(
+            "# RFD 123 Title\n\nSome *text*\n\n![Arch](images/arch.png)\n",
+        );
+
+        let html = rfd
+            .to_html(Some("https://assets.example.com/rfd/123/latest"))
+            .await
+            .unwrap();
+
+        assert!(html.contains("<h1>RFD 123 Title</h1>"));
+        assert!(html.contains("<em>text</em>"));
+        assert!(html.contains(
+            r#"<img src="https://assets.example.com/rfd/123/latest/images/arch.png" alt="Arch">"#
+        ));
+    }
+}
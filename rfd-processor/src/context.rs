@@ -30,6 +30,7 @@ use tracing::instrument;
 use v_model::storage::postgres::PostgresStore;
 
 use crate::{
+    lock::RfdLocks,
     pdf::{PdfFileLocation, PdfStorage, RfdPdf, RfdPdfError},
     search::{RfdSearchIndex, SearchError},
     updater::{BoxedAction, RfdUpdateMode, RfdUpdaterError},
@@ -77,6 +78,7 @@ pub enum ContextError {
 pub struct Context {
     pub processor: ProcessorCtx,
     pub scanner: ScannerCtx,
+    pub webhooks: WebhookCtx,
     pub db: Database,
     pub github: GitHubCtx,
     pub actions: Vec<BoxedAction>,
@@ -146,11 +148,28 @@ impl Context {
                 interval: Duration::from_secs(config.processor_interval),
                 update_mode: config.processor_update_mode,
                 capacity: config.processor_capacity,
+                max_attempts: config.processor_max_attempts,
+                retry_backoff: Duration::from_secs(config.processor_retry_backoff),
+                lease_duration: Duration::from_secs(config.processor_lease_duration),
+                locks: RfdLocks::default(),
             },
             scanner: ScannerCtx {
                 enabled: config.scanner_enabled,
                 interval: Duration::from_secs(config.scanner_interval),
             },
+            webhooks: WebhookCtx {
+                enabled: config.webhook_enabled,
+                interval: Duration::from_secs(config.webhook_interval),
+                batch_size: config.webhook_batch_size,
+                max_attempts: config.webhook_max_attempts,
+                retry_backoff: Duration::from_secs(config.webhook_retry_backoff),
+                timeout: Duration::from_secs(config.webhook_timeout),
+                // Failed deliveries are retried by the webhook worker rather than by the client
+                client: reqwest::Client::builder()
+                    .timeout(Duration::from_secs(config.webhook_timeout))
+                    .build()
+                    .map_err(ContextError::ClientConstruction)?,
+            },
             db,
             github: GitHubCtx {
                 client: github_client,
@@ -174,6 +193,10 @@ pub struct ProcessorCtx {
     pub interval: Duration,
     pub update_mode: RfdUpdateMode,
     pub capacity: u64,
+    pub max_attempts: i32,
+    pub retry_backoff: Duration,
+    pub lease_duration: Duration,
+    pub locks: RfdLocks,
 }
 
 pub struct ScannerCtx {
@@ -181,6 +204,16 @@ pub struct ScannerCtx {
     pub interval: Duration,
 }
 
+pub struct WebhookCtx {
+    pub enabled: bool,
+    pub interval: Duration,
+    pub batch_size: i64,
+    pub max_attempts: i32,
+    pub retry_backoff: Duration,
+    pub timeout: Duration,
+    pub client: reqwest::Client,
+}
+
 pub struct GitHubCtx {
     pub client: GitHubClient,
     pub repository: GitHubRfdRepo,
@@ -245,6 +278,16 @@ impl StaticAssetStorageCtx {
                 .collect(),
         })
     }
+
+    /// The public url of a stored object. Objects are served from the first configured location
+    pub fn public_url(&self, object_name: &str) -> Option<String> {
+        self.locations.first().map(|location| {
+            format!(
+                "https://storage.googleapis.com/{}/{}",
+                location.bucket, object_name
+            )
+        })
+    }
 }
 
 pub struct StaticAssetLocation {
@@ -0,0 +1,61 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+/// Advisory lock class used for serializing jobs that operate on the same RFD across processors
+pub static RFD_JOB_LOCK_CLASS: i32 = 1;
+
+/// Locks for serializing work on individual RFDs within a single processor
+#[derive(Debug, Default)]
+pub struct RfdLocks {
+    locks: Mutex<HashMap<i32, Arc<Mutex<()>>>>,
+}
+
+impl RfdLocks {
+    /// Wait until no other task in this process is working on the given RFD. The RFD remains
+    /// locked until the returned guard is dropped
+    pub async fn lock(&self, rfd: i32) -> OwnedMutexGuard<()> {
+        let lock = {
+            let mut locks = self.locks.lock().await;
+
+            // Any lock that is not referenced outside of the map is neither held nor waited on and
+            // can be discarded
+            locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+            locks.entry(rfd).or_default().clone()
+        };
+
+        lock.lock_owned().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use super::RfdLocks;
+
+    #[tokio::test]
+    async fn test_locks_are_per_rfd() {
+        let locks = RfdLocks::default();
+        let guard = locks.lock(1).await;
+
+        assert!(timeout(Duration::from_millis(10), locks.lock(1))
+            .await
+            .is_err());
+        assert!(timeout(Duration::from_millis(10), locks.lock(2))
+            .await
+            .is_ok());
+
+        drop(guard);
+
+        assert!(timeout(Duration::from_millis(10), locks.lock(1))
+            .await
+            .is_ok());
+    }
+}
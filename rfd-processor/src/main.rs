@@ -22,11 +22,13 @@ use updater::RfdUpdateMode;
 use crate::{
     context::{Context, Database},
     scanner::{scanner, ScannerError},
+    webhook::{webhook_worker, WebhookError},
 };
 
 mod content;
 mod context;
 // mod github;
+mod lock;
 mod pdf;
 mod processor;
 mod rfd;
@@ -34,6 +36,7 @@ mod scanner;
 mod search;
 mod updater;
 mod util;
+mod webhook;
 
 #[derive(Debug, Deserialize)]
 pub struct AppConfig {
@@ -45,8 +48,26 @@ pub struct AppConfig {
     pub processor_interval: u64,
     pub processor_capacity: u64,
     pub processor_update_mode: RfdUpdateMode,
+    #[serde(default = "default_processor_max_attempts")]
+    pub processor_max_attempts: i32,
+    #[serde(default = "default_processor_retry_backoff")]
+    pub processor_retry_backoff: u64,
+    #[serde(default = "default_processor_lease_duration")]
+    pub processor_lease_duration: u64,
     pub scanner_enabled: bool,
     pub scanner_interval: u64,
+    #[serde(default)]
+    pub webhook_enabled: bool,
+    #[serde(default = "default_webhook_interval")]
+    pub webhook_interval: u64,
+    #[serde(default = "default_webhook_batch_size")]
+    pub webhook_batch_size: i64,
+    #[serde(default = "default_webhook_max_attempts")]
+    pub webhook_max_attempts: i32,
+    #[serde(default = "default_webhook_retry_backoff")]
+    pub webhook_retry_backoff: u64,
+    #[serde(default = "default_webhook_timeout")]
+    pub webhook_timeout: u64,
     pub database_url: String,
     pub actions: Vec<String>,
     pub auth: AuthConfig,
@@ -59,6 +80,38 @@ pub struct AppConfig {
     pub search_storage: Vec<SearchConfig>,
 }
 
+fn default_processor_max_attempts() -> i32 {
+    5
+}
+
+fn default_processor_retry_backoff() -> u64 {
+    60
+}
+
+fn default_processor_lease_duration() -> u64 {
+    120
+}
+
+fn default_webhook_interval() -> u64 {
+    10
+}
+
+fn default_webhook_batch_size() -> i64 {
+    50
+}
+
+fn default_webhook_max_attempts() -> i32 {
+    8
+}
+
+fn default_webhook_retry_backoff() -> u64 {
+    30
+}
+
+fn default_webhook_timeout() -> u64 {
+    10
+}
+
 #[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum LogFormat {
@@ -74,6 +127,8 @@ pub enum AppError {
     Job(#[source] JobError),
     #[error("Scanner task failed")]
     Scanner(#[source] ScannerError),
+    #[error("Webhook task failed")]
+    Webhook(#[source] WebhookError),
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -288,6 +343,12 @@ async fn run_processor(config_path: Option<String>) -> Result<(), Box<dyn std::e
         Ok::<_, JobError>(())
     });
 
+    let webhook_ctx = ctx.clone();
+    let webhook_handle = tokio::spawn(async move {
+        webhook_worker(webhook_ctx).await?;
+        Ok::<_, WebhookError>(())
+    });
+
     // Tasks should run for the lifetime of the program. If any of them complete for any reason
     // then the entire application should exit
     let error = select! {
@@ -299,6 +360,10 @@ async fn run_processor(config_path: Option<String>) -> Result<(), Box<dyn std::e
             tracing::info!(?value, "Scanner task exited");
             value?.map_err(AppError::Scanner)
         }
+        value = webhook_handle => {
+            tracing::info!(?value, "Webhook task exited");
+            value?.map_err(AppError::Webhook)
+        }
     };
 
     Ok(error?)
@@ -2,14 +2,15 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use chrono::Utc;
 use futures::TryFutureExt;
 use rfd_github::{GitHubRfdLocation, GitHubRfdUpdate};
 use rfd_model::{
-    storage::{JobFilter, JobStore},
+    schema_ext::JobStatus,
+    storage::{postgres::with_advisory_lock, JobFilter, JobStore},
     Job,
 };
-use std::sync::Arc;
-use tap::TapFallible;
+use std::{sync::Arc, time::Duration};
 use thiserror::Error;
 use tokio::{
     sync::{AcquireError, Semaphore},
@@ -18,7 +19,7 @@ use tokio::{
 use tracing::instrument;
 use v_model::storage::{ListPagination, StoreError};
 
-use crate::{context::Context, updater::RfdUpdater};
+use crate::{context::Context, lock::RFD_JOB_LOCK_CLASS, updater::RfdUpdater};
 
 #[derive(Debug, Error)]
 pub enum JobError {
@@ -39,11 +40,23 @@ pub async fn processor(ctx: Arc<Context>) -> Result<(), JobError> {
 
     loop {
         if ctx.processor.enabled {
+            // Jobs whose lease has lapsed were abandoned by the processor running them, most likely
+            // because it exited. Release them so that they can be picked up again
+            match JobStore::reap(&ctx.db.storage, Utc::now(), ctx.processor.max_attempts).await {
+                Ok(jobs) if !jobs.is_empty() => {
+                    tracing::warn!(jobs = ?jobs.iter().map(|job| job.id).collect::<Vec<_>>(), "Reclaimed jobs with expired leases");
+                }
+                Ok(_) => (),
+                Err(err) => {
+                    tracing::error!(?err, "Failed to reclaim jobs with expired leases");
+                }
+            }
+
             let jobs = JobStore::list(
                 &ctx.db.storage,
                 vec![JobFilter::default()
-                    .processed(Some(false))
-                    .started(Some(false))],
+                    .status(Some(vec![JobStatus::Queued, JobStatus::Failed]))
+                    .next_attempt_before(Some(Utc::now()))],
                 &pagination,
             )
             .await?;
@@ -53,7 +66,8 @@ pub async fn processor(ctx: Arc<Context>) -> Result<(), JobError> {
             for job in jobs {
                 let job_id = job.id;
                 tracing::info!("Starting job processing");
-                match JobStore::start(&ctx.db.storage, job.id).await {
+                let lease_expires_at = Utc::now() + ctx.processor.lease_duration;
+                match JobStore::start(&ctx.db.storage, job.id, lease_expires_at).await {
                     Ok(Some(job)) => {
                         tracing::info!(job = ?job_id, "Spawning job");
                         let capacity = capacity.clone();
@@ -61,12 +75,56 @@ pub async fn processor(ctx: Arc<Context>) -> Result<(), JobError> {
 
                         tokio::spawn(
                             async move {
-                                tracing::info!(job = ?job_id, "Acquiring permit to run job");
-                                let permit = capacity.acquire().await?;
-                                let result = run_job(ctx, job).await;
+                                // The lease needs to be kept alive while waiting on a permit as
+                                // well as while the job is running
+                                let lease = tokio::spawn(renew_lease(
+                                    ctx.clone(),
+                                    job_id,
+                                    job.attempts,
+                                ));
+
+                                let result = async {
+                                    // Only a single job may run against an RFD at a time. Jobs
+                                    // within this processor wait their turn, while a job that is
+                                    // running on a different processor causes this job to be
+                                    // returned to the queue
+                                    tracing::info!(job = ?job_id, rfd = job.rfd, "Acquiring RFD lock");
+                                    let rfd_lock = ctx.processor.locks.lock(job.rfd).await;
+
+                                    tracing::info!(job = ?job_id, "Acquiring permit to run job");
+                                    let permit = capacity.acquire().await?;
 
-                                // Hold the permit until the job has reached a conclusion
-                                drop(permit);
+                                    let result = match with_advisory_lock(
+                                        &ctx.db.storage,
+                                        RFD_JOB_LOCK_CLASS,
+                                        job.rfd,
+                                        run_job(ctx.clone(), job),
+                                    )
+                                    .await?
+                                    {
+                                        Some(result) => result,
+                                        None => {
+                                            tracing::info!(job = ?job_id, "RFD is locked by a different processor");
+                                            JobStore::requeue(
+                                                &ctx.db.storage,
+                                                job_id,
+                                                Utc::now() + ctx.processor.interval,
+                                            )
+                                            .await?;
+                                            Ok(())
+                                        }
+                                    };
+
+                                    // Hold the permit and lock until the job has reached a
+                                    // conclusion
+                                    drop(permit);
+                                    drop(rfd_lock);
+
+                                    result
+                                }
+                                .await;
+
+                                lease.abort();
 
                                 result
                             }
@@ -77,7 +135,7 @@ pub async fn processor(ctx: Arc<Context>) -> Result<(), JobError> {
                         );
                     }
                     Ok(None) => {
-                        tracing::error!(?job, "Job that was scheduled to run has gone missing! Was it started by a different task?");
+                        tracing::info!(?job, "Job was claimed by a different processor");
                     }
                     Err(err) => {
                         tracing::warn!(
@@ -96,6 +154,20 @@ pub async fn processor(ctx: Arc<Context>) -> Result<(), JobError> {
 
 #[instrument(skip(ctx, job), fields(id = job.id, rfd = job.rfd, sha = ?job.sha, commited_at = ?job.committed_at))]
 async fn run_job(ctx: Arc<Context>, job: Job) -> Result<(), JobError> {
+    // While this job was waiting on the RFD lock, the job that held it may have superseded it
+    let current = JobStore::get(&ctx.db.storage, job.id).await?;
+    if !current.is_some_and(|current| {
+        current.status == JobStatus::Running && current.attempts == job.attempts
+    }) {
+        tracing::info!("Job is no longer held by this processor");
+        return Ok(());
+    }
+
+    if supersede(&ctx, &job).await? {
+        tracing::info!("Job has been superseded by a newer job for the same RFD");
+        return Ok(());
+    }
+
     tracing::info!("Running job");
 
     let location = GitHubRfdLocation {
@@ -116,17 +188,136 @@ async fn run_job(ctx: Arc<Context>, job: Job) -> Result<(), JobError> {
     let updater = RfdUpdater::new(&ctx.actions, ctx.processor.update_mode);
 
     match updater.handle(&ctx, &[update]).await {
-        Ok(_) => {
-            let _ = JobStore::complete(&ctx.db.storage, job.id)
-                .await
-                .tap_err(|err| tracing::error!(?err, "Failed to mark job as completed"));
-        }
+        Ok(_) => match JobStore::complete(&ctx.db.storage, job.id, job.attempts).await {
+            Ok(Some(_)) => (),
+            Ok(None) => tracing::warn!("Job lease was lost before the job could be completed"),
+            Err(err) => tracing::error!(?err, "Failed to mark job as completed"),
+        },
         Err(err) => {
-            tracing::error!(?err, "RFD update failed");
+            // The job passed to us has already had the current attempt counted against it
+            let retry_at = (job.attempts < ctx.processor.max_attempts)
+                .then(|| Utc::now() + retry_delay(ctx.processor.retry_backoff, job.attempts));
+
+            tracing::error!(
+                ?err,
+                attempts = job.attempts,
+                ?retry_at,
+                "RFD update failed"
+            );
 
-            // TODO: Mark job as failed or retry?
+            match JobStore::fail(
+                &ctx.db.storage,
+                job.id,
+                job.attempts,
+                err.to_string(),
+                retry_at,
+            )
+            .await
+            {
+                Ok(Some(_)) => (),
+                Ok(None) => tracing::warn!("Job lease was lost before the job could be failed"),
+                Err(err) => tracing::error!(?err, "Failed to mark job as failed"),
+            }
         }
     }
 
     Ok::<_, JobError>(())
 }
+
+/// Avoid processing commits whose results would immediately be overwritten. Waiting jobs for the
+/// same RFD and branch that were committed at or before the given job are superseded by it, while
+/// the given job is itself superseded if there is a newer job waiting to run. Returns whether the
+/// given job was superseded.
+///
+/// This must only be called while holding the RFD lock. Any other running job for the RFD has then
+/// been started but is still waiting on the lock, and so can be superseded the same as a queued job
+async fn supersede(ctx: &Context, job: &Job) -> Result<bool, JobError> {
+    let waiting = JobStore::list(
+        &ctx.db.storage,
+        vec![JobFilter::default()
+            .rfd(Some(vec![job.rfd]))
+            .branch(Some(vec![job.branch.clone()]))
+            .status(Some(vec![
+                JobStatus::Queued,
+                JobStatus::Running,
+                JobStatus::Failed,
+            ]))],
+        &ListPagination::unlimited(),
+    )
+    .await?
+    .into_iter()
+    .filter(|other| other.id != job.id)
+    .collect::<Vec<_>>();
+
+    if waiting
+        .iter()
+        .any(|other| other.committed_at > job.committed_at)
+    {
+        JobStore::supersede(&ctx.db.storage, job.id).await?;
+        return Ok(true);
+    }
+
+    for other in waiting {
+        if let Some(other) = JobStore::supersede(&ctx.db.storage, other.id).await? {
+            tracing::info!(superseded = other.id, sha = ?other.sha, "Superseded older job");
+        }
+    }
+
+    Ok(false)
+}
+
+/// Periodically extend the lease of a running job so that it is not reclaimed while it is still
+/// being worked on
+async fn renew_lease(ctx: Arc<Context>, id: i32, attempt: i32) {
+    let mut interval = interval(ctx.processor.lease_duration / 3);
+
+    // The first tick completes immediately, and the lease has only just been granted
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let lease_expires_at = Utc::now() + ctx.processor.lease_duration;
+        match JobStore::heartbeat(&ctx.db.storage, id, attempt, lease_expires_at).await {
+            Ok(Some(_)) => tracing::trace!(?id, ?lease_expires_at, "Renewed job lease"),
+            Ok(None) => {
+                tracing::warn!(?id, "Job is no longer running. Stopping lease renewal");
+                break;
+            }
+            Err(err) => tracing::warn!(?id, ?err, "Failed to renew job lease"),
+        }
+    }
+}
+
+static MAX_RETRY_DELAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Compute how long to wait before retrying a job or webhook delivery that has failed the given
+/// number of times. The delay doubles with each failed attempt, up to a maximum of one day
+pub(crate) fn retry_delay(backoff: Duration, attempts: i32) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 31) as u32;
+    backoff
+        .saturating_mul(2u32.saturating_pow(exponent))
+        .min(MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{retry_delay, MAX_RETRY_DELAY};
+
+    #[test]
+    fn test_retry_delay_doubles_per_attempt() {
+        let backoff = Duration::from_secs(60);
+        assert_eq!(Duration::from_secs(60), retry_delay(backoff, 1));
+        assert_eq!(Duration::from_secs(120), retry_delay(backoff, 2));
+        assert_eq!(Duration::from_secs(240), retry_delay(backoff, 3));
+    }
+
+    #[test]
+    fn test_retry_delay_is_capped() {
+        let backoff = Duration::from_secs(60);
+        assert_eq!(MAX_RETRY_DELAY, retry_delay(backoff, 20));
+        assert_eq!(MAX_RETRY_DELAY, retry_delay(backoff, i32::MAX));
+    }
+}
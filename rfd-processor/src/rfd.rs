@@ -7,14 +7,20 @@ use std::sync::Mutex;
 use chrono::{DateTime, Utc};
 use newtype_uuid::TypedUuid;
 use octorust::{Client, ClientError};
-use rfd_data::{content::RfdDocument, RfdNumber};
+use rfd_data::{
+    content::{RfdAuthors, RfdDocument},
+    references::extract_references,
+    RfdNumber,
+};
 use rfd_github::{GitHubError, GitHubRfdReadme, GitHubRfdUpdate};
 use rfd_model::{
     schema_ext::{ContentFormat, Visibility},
     storage::{
-        RfdFilter, RfdPdfFilter, RfdPdfStore, RfdRevisionFilter, RfdRevisionStore, RfdStore,
+        RfdFilter, RfdPdfFilter, RfdPdfStore, RfdReferenceStore, RfdRevisionAuthorStore,
+        RfdRevisionFilter, RfdRevisionLabelStore, RfdRevisionStore, RfdStore,
     },
-    CommitSha, FileSha, NewRfd, NewRfdRevision, Rfd, RfdRevision,
+    CommitSha, FileSha, NewRfd, NewRfdReference, NewRfdRevision, NewRfdRevisionAuthor,
+    NewRfdRevisionLabel, Rfd, RfdRevision,
 };
 use thiserror::Error;
 use v_model::storage::{ListPagination, StoreError};
@@ -97,7 +103,11 @@ impl PersistedRfd {
 
     pub async fn upsert<S>(&self, storage: &S) -> Result<(), RfdError>
     where
-        S: RfdStore + RfdRevisionStore,
+        S: RfdStore
+            + RfdRevisionStore
+            + RfdRevisionAuthorStore
+            + RfdRevisionLabelStore
+            + RfdReferenceStore,
     {
         let should_update = *self.needs_update.lock().unwrap();
 
@@ -105,7 +115,8 @@ impl PersistedRfd {
             tracing::info!("Persisted RFD has been modified. Storing to database");
 
             RfdStore::upsert(storage, self.rfd.clone().into()).await?;
-            RfdRevisionStore::upsert(storage, self.revision.clone().into()).await?;
+            let revision = RfdRevisionStore::upsert(storage, self.revision.clone().into()).await?;
+            index_revision(storage, &self.rfd, &revision).await?;
 
             tracing::info!("Updated persisted RFD and revision");
 
@@ -331,7 +342,12 @@ impl RemoteRfd {
         major_change: bool,
     ) -> Result<PersistedRfd, RemoteRfdError>
     where
-        S: RfdStore + RfdRevisionStore + RfdPdfStore,
+        S: RfdStore
+            + RfdRevisionStore
+            + RfdRevisionAuthorStore
+            + RfdRevisionLabelStore
+            + RfdReferenceStore
+            + RfdPdfStore,
     {
         let number = self.number;
         let payload = self.into_payload()?;
@@ -377,7 +393,7 @@ impl RemoteRfd {
             }
             _ => {
                 tracing::info!("RFD source has changed. Creating a new revision.");
-                RfdRevisionStore::upsert(
+                let revision = RfdRevisionStore::upsert(
                     storage,
                     NewRfdRevision {
                         id: TypedUuid::new_v4(),
@@ -407,7 +423,9 @@ impl RemoteRfd {
                         major_change,
                     },
                 )
-                .await?
+                .await?;
+                index_revision(storage, &rfd, &revision).await?;
+                revision
             }
         };
 
@@ -426,3 +444,171 @@ impl RemoteRfd {
         ))
     }
 }
+
+/// Record the authors, labels and references of a revision in their own tables so that RFDs can be
+/// looked up by them
+async fn index_revision<S>(storage: &S, rfd: &Rfd, revision: &RfdRevision) -> Result<(), StoreError>
+where
+    S: RfdRevisionAuthorStore + RfdRevisionLabelStore + RfdReferenceStore,
+{
+    RfdRevisionAuthorStore::replace(storage, &revision.id, revision_authors(revision)).await?;
+    RfdRevisionLabelStore::replace(storage, &revision.id, revision_labels(revision)).await?;
+    RfdReferenceStore::replace(storage, &revision.id, revision_references(rfd, revision)).await?;
+
+    Ok(())
+}
+
+fn revision_authors(revision: &RfdRevision) -> Vec<NewRfdRevisionAuthor> {
+    revision
+        .authors
+        .as_deref()
+        .and_then(RfdAuthors::parse)
+        .map(|authors| {
+            authors
+                .iter()
+                .enumerate()
+                .map(|(position, author)| NewRfdRevisionAuthor {
+                    rfd_revision_id: revision.id,
+                    position: position as i32,
+                    name: author.name(),
+                    email: author.email.clone(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn revision_labels(revision: &RfdRevision) -> Vec<NewRfdRevisionLabel> {
+    let mut labels = revision
+        .labels
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(|label| label.trim())
+        .filter(|label| !label.is_empty())
+        .collect::<Vec<_>>();
+    labels.sort();
+    labels.dedup();
+
+    labels
+        .into_iter()
+        .map(|label| NewRfdRevisionLabel {
+            rfd_revision_id: revision.id,
+            label: label.to_string(),
+        })
+        .collect()
+}
+
+/// References from an RFD to itself (i.e. in its title) are not recorded
+fn revision_references(rfd: &Rfd, revision: &RfdRevision) -> Vec<NewRfdReference> {
+    extract_references(&revision.content)
+        .into_iter()
+        .filter(|number| *number != rfd.rfd_number)
+        .map(|number| NewRfdReference {
+            rfd_revision_id: revision.id,
+            target_rfd_number: number,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use newtype_uuid::TypedUuid;
+    use rfd_model::{
+        schema_ext::{ContentFormat, Visibility},
+        Rfd, RfdRevision,
+    };
+
+    use super::{revision_authors, revision_labels, revision_references};
+
+    fn new_revision(authors: Option<&str>, labels: Option<&str>) -> RfdRevision {
+        RfdRevision {
+            id: TypedUuid::new_v4(),
+            rfd_id: TypedUuid::new_v4(),
+            title: "Title".to_string(),
+            state: Some("published".to_string()),
+            discussion: None,
+            authors: authors.map(|authors| authors.to_string()),
+            labels: labels.map(|labels| labels.to_string()),
+            content: String::new(),
+            content_format: ContentFormat::Asciidoc,
+            sha: String::new().into(),
+            commit: String::new().into(),
+            committed_at: Utc::now(),
+            major_change: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_revision_authors() {
+        let revision = new_revision(
+            Some("First Last <first@company.com>; Other <other@company.com>; Nomail"),
+            None,
+        );
+
+        let authors = revision_authors(&revision)
+            .into_iter()
+            .map(|author| (author.position, author.name, author.email))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (
+                    0,
+                    "First Last".to_string(),
+                    Some("first@company.com".to_string())
+                ),
+                (
+                    1,
+                    "Other".to_string(),
+                    Some("other@company.com".to_string())
+                ),
+                (2, "Nomail".to_string(), None),
+            ],
+            authors
+        );
+        assert!(revision_authors(&new_revision(None, None)).is_empty());
+    }
+
+    #[test]
+    fn test_revision_labels() {
+        let revision = new_revision(None, Some("api, database,, api ,storage"));
+
+        let labels = revision_labels(&revision)
+            .into_iter()
+            .map(|label| label.label)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["api", "database", "storage"], labels);
+        assert!(revision_labels(&new_revision(None, None)).is_empty());
+    }
+
+    #[test]
+    fn test_revision_references() {
+        let rfd = Rfd {
+            id: TypedUuid::new_v4(),
+            rfd_number: 123,
+            link: None,
+            content: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            latest_major_change_at: None,
+            visibility: Visibility::Private,
+        };
+        let mut revision = new_revision(None, None);
+        revision.content =
+            "= RFD 123 Title\n\nBuilds on RFD 45 and <<rfd-7>>, see also RFD 45.\n".to_string();
+
+        let references = revision_references(&rfd, &revision)
+            .into_iter()
+            .map(|reference| reference.target_rfd_number)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![7, 45], references);
+    }
+}
@@ -11,12 +11,28 @@ use meilisearch_sdk::{
     settings::Settings,
 };
 use parse_rfd::{parse, ParsedDoc, ParserError, Section};
-use rfd_data::RfdNumber;
+use rfd_data::{content::RfdAuthors, RfdNumber};
+use rfd_model::RfdRevision;
 use serde::{Deserialize, Serialize};
-use std::{cmp::min, collections::HashMap};
+use std::{
+    cmp::min,
+    collections::{BTreeSet, HashMap},
+    sync::atomic::{AtomicBool, Ordering},
+};
 use thiserror::Error;
 use tracing::instrument;
 
+// Attributes of indexed documents that search requests are able to filter and facet on
+static FILTERABLE_ATTRIBUTES: [&str; 7] = [
+    "rfd_number",
+    "public",
+    "state",
+    "label",
+    "author",
+    "author_email",
+    "committed_at",
+];
+
 #[derive(Debug, Error)]
 pub enum SearchError {
     #[error(transparent)]
@@ -29,6 +45,7 @@ pub enum SearchError {
 pub struct RfdSearchIndex {
     client: Client,
     index: String,
+    settings_applied: AtomicBool,
 }
 
 impl RfdSearchIndex {
@@ -40,20 +57,22 @@ impl RfdSearchIndex {
         Ok(Self {
             client: Client::new(host, Some(api_key))?,
             index: index.into(),
+            settings_applied: AtomicBool::new(false),
         })
     }
 
     /// Trigger updating the search index for the RFD.
-    #[instrument(skip(self, content), fields(index = ?self.index), err(Debug))]
+    #[instrument(skip(self, revision), fields(index = ?self.index), err(Debug))]
     pub async fn index_rfd(
         &self,
         rfd_number: &RfdNumber,
-        content: &str,
+        revision: &RfdRevision,
         public: bool,
     ) -> Result<(), SearchError> {
         let index = self.client.index(&self.index);
 
         let lookup = self.find_rfd_ids(&index, rfd_number).await;
+        let mut apply_settings = !self.settings_applied.load(Ordering::Relaxed);
 
         // The index may not exist yet if this is the first RFD being indexed. In that case the err
         // returned from look up is noted and then discarded.
@@ -70,18 +89,29 @@ impl RfdSearchIndex {
                     ?err,
                     "Failed to find index during deletion lookup. Creating index and filters"
                 );
-
-                let settings = Settings::new().with_filterable_attributes(["rfd_number"]);
-                index.set_settings(&settings).await?;
+                apply_settings = true;
             }
             Err(err) => {
                 Err(err)?;
             }
         }
 
-        let mut parsed = Self::parse_document(rfd_number, content)?;
+        if apply_settings {
+            self.apply_settings(&index).await?;
+            self.settings_applied.store(true, Ordering::Relaxed);
+        }
+
+        let labels = split_list(revision.labels.as_deref());
+        let (authors, author_emails) = revision_authors(revision);
+
+        let mut parsed = Self::parse_document(rfd_number, &revision.content)?;
         for doc in parsed.iter_mut() {
             doc.public = public;
+            doc.state = revision.state.clone();
+            doc.label = labels.clone();
+            doc.author = authors.clone();
+            doc.author_email = author_emails.clone();
+            doc.committed_at = revision.committed_at.timestamp();
         }
 
         tracing::info!(count = parsed.len(), "Parsed RFD into sections to index");
@@ -91,6 +121,35 @@ impl RfdSearchIndex {
         Ok(())
     }
 
+    /// Update the filterable attributes of the index if they differ from the attributes that are
+    /// expected. Indexes created before an attribute was added become filterable on it
+    #[instrument(skip(self, index), err(Debug))]
+    async fn apply_settings(&self, index: &Index) -> Result<(), SearchError> {
+        let current = match index.get_filterable_attributes().await {
+            Ok(attributes) => attributes.into_iter().collect::<BTreeSet<_>>(),
+            Err(MeiliError::Meilisearch(err)) if err.error_code == ErrorCode::IndexNotFound => {
+                BTreeSet::new()
+            }
+            Err(err) => Err(err)?,
+        };
+        let expected = FILTERABLE_ATTRIBUTES
+            .iter()
+            .map(|attribute| attribute.to_string())
+            .collect::<BTreeSet<_>>();
+
+        if current != expected {
+            tracing::info!(
+                ?current,
+                ?expected,
+                "Updating filterable attributes of index"
+            );
+            let settings = Settings::new().with_filterable_attributes(FILTERABLE_ATTRIBUTES);
+            index.set_settings(&settings).await?;
+        }
+
+        Ok(())
+    }
+
     #[instrument(skip(self, index))]
     pub async fn find_rfd_ids(
         &self,
@@ -125,6 +184,37 @@ impl RfdSearchIndex {
     }
 }
 
+fn split_list(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The names and emails of the authors of a revision. Authors without an email only contribute
+/// their name
+fn revision_authors(revision: &RfdRevision) -> (Vec<String>, Vec<String>) {
+    let authors = revision
+        .authors
+        .as_deref()
+        .and_then(RfdAuthors::parse)
+        .map(|authors| {
+            authors
+                .iter()
+                .map(|author| (author.name(), author.email.clone()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let names = authors.iter().map(|(name, _)| name.clone()).collect();
+    let emails = authors.into_iter().filter_map(|(_, email)| email).collect();
+
+    (names, emails)
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RfdId {
     #[serde(rename = "objectID")]
@@ -147,6 +237,12 @@ pub struct IndexDocument {
     #[serde(flatten)]
     pub hierarchy_radio: HashMap<String, String>,
     pub public: bool,
+    pub state: Option<String>,
+    pub label: Vec<String>,
+    pub author: Vec<String>,
+    pub author_email: Vec<String>,
+    /// Commit time of the indexed revision as a unix timestamp so that it can be range filtered
+    pub committed_at: i64,
 }
 
 impl IndexDocument {
@@ -193,6 +289,53 @@ impl IndexDocument {
             hierarchy,
             hierarchy_radio,
             public: false,
+            state: None,
+            label: vec![],
+            author: vec![],
+            author_email: vec![],
+            committed_at: 0,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use newtype_uuid::TypedUuid;
+    use rfd_model::{schema_ext::ContentFormat, RfdRevision};
+
+    use super::revision_authors;
+
+    #[test]
+    fn test_revision_authors_are_indexed_by_name_and_email() {
+        let mut revision = RfdRevision {
+            id: TypedUuid::new_v4(),
+            rfd_id: TypedUuid::new_v4(),
+            title: "Title".to_string(),
+            state: Some("published".to_string()),
+            discussion: None,
+            authors: Some("First Last <first@company.com>, Nomail".to_string()),
+            labels: None,
+            content: String::new(),
+            content_format: ContentFormat::Asciidoc,
+            sha: String::new().into(),
+            commit: String::new().into(),
+            committed_at: Utc::now(),
+            major_change: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        };
+
+        assert_eq!(
+            (
+                vec!["First Last".to_string(), "Nomail".to_string()],
+                vec!["first@company.com".to_string()]
+            ),
+            revision_authors(&revision)
+        );
+
+        revision.authors = None;
+        assert_eq!((vec![], vec![]), revision_authors(&revision));
+    }
+}
@@ -4,6 +4,7 @@
 
 use async_trait::async_trait;
 use google_storage1::api::Object;
+use rfd_data::RfdNumber;
 use tracing::instrument;
 
 use crate::{rfd::PersistedRfd, util::decode_base64};
@@ -16,6 +17,12 @@ use super::{
 #[derive(Debug)]
 pub struct CopyImagesToStorage;
 
+/// The name of the storage object that a supporting file of an RFD is written to. The path is
+/// relative to the RFD's directory
+pub(crate) fn asset_object_name(number: &RfdNumber, path: &str) -> String {
+    format!("rfd/{}/latest/{}", number, path)
+}
+
 #[async_trait]
 impl RfdUpdateAction for CopyImagesToStorage {
     #[instrument(skip(self, ctx, _new), err(Debug))]
@@ -37,7 +44,7 @@ impl RfdUpdateAction for CopyImagesToStorage {
             let sub_path = image
                 .path
                 .replace(&format!("rfd/{}/", update.number.as_number_string()), "");
-            let object_name = format!("rfd/{}/latest/{}", update.number, sub_path);
+            let object_name = asset_object_name(&update.number, &sub_path);
             let mime_type = mime_guess::from_path(&object_name).first_or_octet_stream();
             let data = decode_base64(&image.content)
                 .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?;
@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use async_trait::async_trait;
+use rfd_data::{
+    content::{RfdContent, RfdDocument},
+    lint::{RfdLintSeverity, RfdLinter},
+};
+use tracing::instrument;
+
+use crate::rfd::PersistedRfd;
+
+use super::{
+    RfdUpdateAction, RfdUpdateActionContext, RfdUpdateActionErr, RfdUpdateActionResponse,
+    RfdUpdateMode,
+};
+
+#[derive(Debug)]
+pub struct LintRfd;
+
+#[async_trait]
+impl RfdUpdateAction for LintRfd {
+    #[instrument(skip(self, ctx, new), err(Debug))]
+    async fn run(
+        &self,
+        ctx: &mut RfdUpdateActionContext,
+        new: &mut PersistedRfd,
+        _mode: RfdUpdateMode,
+    ) -> Result<RfdUpdateActionResponse, RfdUpdateActionErr> {
+        let RfdUpdateActionContext { ctx, update, .. } = ctx;
+
+        let content = new
+            .content()
+            .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?;
+
+        // Only Asciidoc documents can be linted
+        if let RfdContent::Asciidoc(adoc) = &content.content {
+            let directory = format!(
+                "{}/{}/",
+                ctx.github.repository.path.trim_matches('/'),
+                update.number.as_number_string()
+            );
+            let files = update
+                .location
+                .download_supporting_documents(&ctx.github.client, &update.number)
+                .await
+                .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?
+                .into_iter()
+                .map(|document| {
+                    document
+                        .path
+                        .trim_start_matches('/')
+                        .trim_start_matches(&directory)
+                        .to_string()
+                })
+                .collect::<Vec<_>>();
+
+            let diagnostics = RfdLinter::default()
+                .number(Some(update.number))
+                .repository(&ctx.github.repository.owner, &ctx.github.repository.repo)
+                .files(Some(files))
+                .lint(adoc.raw());
+
+            for diagnostic in &diagnostics {
+                match diagnostic.severity {
+                    RfdLintSeverity::Error => {
+                        tracing::warn!(rule = ?diagnostic.rule, line = ?diagnostic.line, message = diagnostic.message, "RFD failed lint check")
+                    }
+                    RfdLintSeverity::Warning => {
+                        tracing::info!(rule = ?diagnostic.rule, line = ?diagnostic.line, message = diagnostic.message, "RFD lint warning")
+                    }
+                }
+            }
+
+            tracing::info!(count = diagnostics.len(), "Linted RFD");
+        }
+
+        Ok(RfdUpdateActionResponse::default())
+    }
+}
@@ -8,8 +8,11 @@ use octorust::types::{LabelsData, PullRequestData, PullRequestSimple};
 use process_includes::ProcessIncludes;
 use rfd_data::content::{RfdContent, RfdDocument};
 use rfd_github::{GitHubError, GitHubRfdUpdate};
-use rfd_model::RfdId;
+use rfd_model::{
+    schema_ext::WebhookEvent, storage::RfdWatchNotificationStore, NewRfdWatchNotification, RfdId,
+};
 use serde::Deserialize;
+use serde_json::json;
 use std::fmt::Debug;
 use thiserror::Error;
 use tracing::instrument;
@@ -19,13 +22,14 @@ use crate::{
     content::RenderableRfdError,
     context::Context,
     rfd::{FetchRemoteRfdError, PersistedRfd, RemoteRfd, RemoteRfdError, RfdError},
+    webhook::emit_webhook_event,
 };
 
 use self::{
     copy_images_to_storage::CopyImagesToStorage, create_pull_request::CreatePullRequest,
     ensure_default_state::EnsureRfdOnDefaultIsInValidState,
-    ensure_pr_state::EnsureRfdWithPullRequestIsInValidState,
-    update_discussion_url::UpdateDiscussionUrl, update_pdfs::UpdatePdfs,
+    ensure_pr_state::EnsureRfdWithPullRequestIsInValidState, lint_rfd::LintRfd,
+    render_html::RenderHtml, update_discussion_url::UpdateDiscussionUrl, update_pdfs::UpdatePdfs,
     update_pull_request::UpdatePullRequest, update_search_index::UpdateSearch,
 };
 
@@ -33,7 +37,9 @@ mod copy_images_to_storage;
 mod create_pull_request;
 mod ensure_default_state;
 mod ensure_pr_state;
+mod lint_rfd;
 mod process_includes;
+mod render_html;
 mod update_discussion_url;
 mod update_pdfs;
 mod update_pull_request;
@@ -88,10 +94,12 @@ impl TryFrom<&str> for BoxedAction {
             "CopyImagesToStorage" => Ok(Box::new(CopyImagesToStorage)),
             "UpdateSearch" => Ok(Box::new(UpdateSearch)),
             "UpdatePdfs" => Ok(Box::new(UpdatePdfs)),
+            "RenderHtml" => Ok(Box::new(RenderHtml)),
             "CreatePullRequest" => Ok(Box::new(CreatePullRequest)),
             "UpdatePullRequest" => Ok(Box::new(UpdatePullRequest)),
             "UpdateDiscussionUrl" => Ok(Box::new(UpdateDiscussionUrl)),
             "ProcessIncludes" => Ok(Box::new(ProcessIncludes)),
+            "LintRfd" => Ok(Box::new(LintRfd)),
             "EnsureRfdWithPullRequestIsInValidState" => {
                 Ok(Box::new(EnsureRfdWithPullRequestIsInValidState))
             }
@@ -227,9 +235,88 @@ impl<'a> RfdUpdater<'a> {
 
         tracing::info!(id = ?persisted.rfd.id, number = ?persisted.rfd.rfd_number, "Update for RFD completed");
 
+        // Subscribers are remote services, and are only notified when writing to remote services
+        // is enabled
+        if let (RfdUpdateMode::Write, Some(existing)) = (self.mode, &existing) {
+            self.emit_webhook_events(ctx, existing, &persisted).await;
+            self.queue_watch_notification(ctx, existing, &persisted)
+                .await;
+        }
+
         Ok(())
     }
 
+    /// Notify webhook subscribers of changes between the previously stored revision of an RFD and
+    /// the revision that was just persisted
+    async fn emit_webhook_events(
+        &self,
+        ctx: &Context,
+        existing: &PersistedRfd,
+        persisted: &PersistedRfd,
+    ) {
+        let rfd_number = persisted.rfd.rfd_number;
+
+        if existing.revision.state != persisted.revision.state {
+            emit_webhook_event(
+                &ctx.db.storage,
+                WebhookEvent::StateChanged,
+                rfd_number,
+                json!({
+                    "from": existing.revision.state,
+                    "to": persisted.revision.state,
+                    "revision": persisted.revision.id,
+                    "commit": persisted.revision.commit,
+                }),
+            )
+            .await;
+        }
+
+        // Reprocessing a commit must not report the same major revision twice
+        if existing.revision.commit != persisted.revision.commit && persisted.revision.major_change
+        {
+            emit_webhook_event(
+                &ctx.db.storage,
+                WebhookEvent::MajorRevision,
+                rfd_number,
+                json!({
+                    "revision": persisted.revision.id,
+                    "commit": persisted.revision.commit,
+                }),
+            )
+            .await;
+        }
+    }
+
+    /// Queue an email notification to the watchers of an RFD when the persisted revision changes
+    /// its state or is a major change. A single notification is queued per revision, as the email
+    /// describes all of the changes made by the revision
+    async fn queue_watch_notification(
+        &self,
+        ctx: &Context,
+        existing: &PersistedRfd,
+        persisted: &PersistedRfd,
+    ) {
+        let event = if existing.revision.state != persisted.revision.state {
+            WebhookEvent::StateChanged
+        } else if existing.revision.commit != persisted.revision.commit
+            && persisted.revision.major_change
+        {
+            WebhookEvent::MajorRevision
+        } else {
+            return;
+        };
+
+        let notification = NewRfdWatchNotification {
+            rfd_number: persisted.rfd.rfd_number,
+            rfd_revision_id: persisted.revision.id,
+            event,
+        };
+
+        if let Err(err) = RfdWatchNotificationStore::create(&ctx.db.storage, notification).await {
+            tracing::error!(?err, ?event, "Failed to queue watch notification");
+        }
+    }
+
     #[instrument(skip(self, ctx, previous, new, update), fields(id = ?new.rfd.id, revision = ?new.revision.id))]
     async fn run_actions(
         &self,
@@ -0,0 +1,56 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use async_trait::async_trait;
+use rfd_model::{storage::RfdRevisionHtmlStore, NewRfdRevisionHtml};
+use tracing::instrument;
+
+use crate::rfd::PersistedRfd;
+
+use super::{
+    copy_images_to_storage::asset_object_name, RfdUpdateAction, RfdUpdateActionContext,
+    RfdUpdateActionErr, RfdUpdateActionResponse, RfdUpdateMode,
+};
+
+#[derive(Debug)]
+pub struct RenderHtml;
+
+#[async_trait]
+impl RfdUpdateAction for RenderHtml {
+    #[instrument(skip(self, ctx, new), err(Debug))]
+    async fn run(
+        &self,
+        ctx: &mut RfdUpdateActionContext,
+        new: &mut PersistedRfd,
+        _mode: RfdUpdateMode,
+    ) -> Result<RfdUpdateActionResponse, RfdUpdateActionErr> {
+        let RfdUpdateActionContext { ctx, update, .. } = ctx;
+
+        // Images are served from the same location that CopyImagesToStorage writes them to
+        let asset_url = ctx
+            .assets
+            .public_url(&asset_object_name(&update.number, ""));
+
+        let html = new
+            .content()
+            .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?
+            .to_html(asset_url.as_deref())
+            .await
+            .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?;
+
+        tracing::info!(size = html.len(), "Rendered RFD html");
+
+        RfdRevisionHtmlStore::upsert(
+            &ctx.db.storage,
+            NewRfdRevisionHtml {
+                rfd_revision_id: new.revision.id,
+                html,
+            },
+        )
+        .await
+        .map_err(|err| RfdUpdateActionErr::Continue(Box::new(err)))?;
+
+        Ok(RfdUpdateActionResponse::default())
+    }
+}
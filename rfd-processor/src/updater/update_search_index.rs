@@ -37,7 +37,7 @@ impl RfdUpdateAction for UpdateSearch {
                 };
 
                 if let Err(err) = index
-                    .index_rfd(&new.rfd.rfd_number.into(), &new.revision.content, public)
+                    .index_rfd(&new.rfd.rfd_number.into(), &new.revision, public)
                     .await
                 {
                     tracing::error!(?err, search_index = i, "Failed to add RFD to search index");
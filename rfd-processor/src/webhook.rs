@@ -0,0 +1,245 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use chrono::Utc;
+use hmac::{Hmac, KeyInit, Mac};
+use rfd_model::{
+    schema_ext::{WebhookDeliveryStatus, WebhookEvent},
+    storage::{
+        WebhookSubscriptionDeliveryFilter, WebhookSubscriptionDeliveryStore,
+        WebhookSubscriptionStore,
+    },
+    WebhookEventPayload, WebhookSubscriptionDelivery,
+};
+use sha2::Sha256;
+use std::sync::Arc;
+use tap::TapFallible;
+use thiserror::Error;
+use tokio::time::interval;
+use tracing::instrument;
+use v_model::storage::{postgres::PostgresStore, ListPagination, StoreError};
+
+use crate::{context::Context, processor::retry_delay};
+
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    #[error(transparent)]
+    Storage(#[from] StoreError),
+}
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Queue an event for delivery to every interested webhook subscription. The change that triggered
+/// the event has already been persisted, so failures are only logged
+pub async fn emit_webhook_event(
+    storage: &PostgresStore,
+    event: WebhookEvent,
+    rfd_number: i32,
+    data: serde_json::Value,
+) {
+    let payload = WebhookEventPayload {
+        event,
+        rfd_number,
+        occurred_at: Utc::now(),
+        data,
+    };
+
+    let payload = match serde_json::to_value(&payload) {
+        Ok(payload) => payload,
+        Err(err) => {
+            tracing::error!(
+                ?err,
+                ?event,
+                rfd_number,
+                "Failed to serialize webhook payload"
+            );
+            return;
+        }
+    };
+
+    match WebhookSubscriptionDeliveryStore::enqueue(storage, event, rfd_number, payload).await {
+        Ok(deliveries) => {
+            tracing::info!(
+                ?event,
+                rfd_number,
+                count = deliveries.len(),
+                "Queued webhook deliveries"
+            )
+        }
+        Err(err) => {
+            tracing::error!(
+                ?err,
+                ?event,
+                rfd_number,
+                "Failed to queue webhook deliveries"
+            )
+        }
+    }
+}
+
+/// Compute the value of the signature header for a payload. Subscribers verify a delivery by
+/// computing the HMAC-SHA256 of the raw request body with their subscription secret
+pub fn signature(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+pub async fn webhook_worker(ctx: Arc<Context>) -> Result<(), WebhookError> {
+    let mut interval = interval(ctx.webhooks.interval);
+    let pagination = ListPagination::default().limit(ctx.webhooks.batch_size);
+
+    tracing::info!(?interval, ?pagination, "Starting webhook worker");
+
+    interval.tick().await;
+
+    loop {
+        if ctx.webhooks.enabled {
+            // Deliveries that are still marked as sending after their lease expired were abandoned
+            // by the worker that claimed them and are picked up again here
+            let deliveries = WebhookSubscriptionDeliveryStore::list(
+                &ctx.db.storage,
+                vec![WebhookSubscriptionDeliveryFilter::default()
+                    .status(Some(vec![
+                        WebhookDeliveryStatus::Pending,
+                        WebhookDeliveryStatus::Failed,
+                        WebhookDeliveryStatus::Sending,
+                    ]))
+                    .next_attempt_before(Some(Utc::now()))],
+                &pagination,
+            )
+            .await?;
+
+            for delivery in deliveries {
+                // Allow the request to time out before the lease can expire
+                let lease_expires_at = Utc::now() + ctx.webhooks.timeout * 2;
+                match WebhookSubscriptionDeliveryStore::start(
+                    &ctx.db.storage,
+                    &delivery.id,
+                    lease_expires_at,
+                )
+                .await
+                {
+                    Ok(Some(delivery)) => deliver(&ctx, delivery).await,
+                    Ok(None) => {
+                        tracing::info!(id = ?delivery.id, "Delivery was claimed by a different worker");
+                    }
+                    Err(err) => {
+                        tracing::warn!(id = ?delivery.id, ?err, "Failed to start delivery");
+                    }
+                }
+            }
+        }
+
+        interval.tick().await;
+    }
+}
+
+#[instrument(skip(ctx, delivery), fields(id = ?delivery.id, subscription = ?delivery.webhook_subscription_id, event = ?delivery.event, attempts = delivery.attempts))]
+async fn deliver(ctx: &Context, delivery: WebhookSubscriptionDelivery) {
+    let subscription = match WebhookSubscriptionStore::get(
+        &ctx.db.storage,
+        &delivery.webhook_subscription_id,
+        true,
+    )
+    .await
+    {
+        Ok(Some(subscription)) if subscription.deleted_at.is_none() => subscription,
+        Ok(_) => {
+            let _ = WebhookSubscriptionDeliveryStore::fail(
+                &ctx.db.storage,
+                &delivery.id,
+                None,
+                "Subscription has been deleted".to_string(),
+                None,
+            )
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to mark delivery as dead"));
+            return;
+        }
+        Err(err) => {
+            tracing::error!(?err, "Failed to lookup subscription for delivery");
+            return;
+        }
+    };
+
+    // The exact bytes that are signed must be the bytes that are sent
+    let body = match serde_json::to_vec(&delivery.payload) {
+        Ok(body) => body,
+        Err(err) => {
+            tracing::error!(?err, "Failed to serialize delivery payload");
+            return;
+        }
+    };
+
+    let result = ctx
+        .webhooks
+        .client
+        .post(&subscription.url)
+        .header("Content-Type", "application/json")
+        .header("X-RFD-Event", delivery.event.to_string())
+        .header("X-RFD-Delivery", delivery.id.to_string())
+        .header(
+            "X-RFD-Signature-256",
+            signature(&subscription.secret, &body),
+        )
+        .body(body)
+        .send()
+        .await;
+
+    let (response_status, error) = match result {
+        Ok(response) if response.status().is_success() => {
+            tracing::info!(status = ?response.status(), "Delivered webhook");
+            let _ = WebhookSubscriptionDeliveryStore::complete(
+                &ctx.db.storage,
+                &delivery.id,
+                response.status().as_u16() as i32,
+            )
+            .await
+            .tap_err(|err| tracing::error!(?err, "Failed to mark delivery as delivered"));
+            return;
+        }
+        Ok(response) => (
+            Some(response.status().as_u16() as i32),
+            format!("Subscriber responded with {}", response.status()),
+        ),
+        Err(err) => (None, err.to_string()),
+    };
+
+    // The delivery passed to us has already had the current attempt counted against it
+    let retry_at = (delivery.attempts < ctx.webhooks.max_attempts)
+        .then(|| Utc::now() + retry_delay(ctx.webhooks.retry_backoff, delivery.attempts));
+
+    tracing::warn!(
+        ?response_status,
+        ?error,
+        ?retry_at,
+        "Webhook delivery failed"
+    );
+
+    let _ = WebhookSubscriptionDeliveryStore::fail(
+        &ctx.db.storage,
+        &delivery.id,
+        response_status,
+        error,
+        retry_at,
+    )
+    .await
+    .tap_err(|err| tracing::error!(?err, "Failed to mark delivery as failed"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::signature;
+
+    #[test]
+    fn test_signature_matches_hmac_sha256() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            signature("Jefe", b"what do ya want for nothing?")
+        );
+    }
+}
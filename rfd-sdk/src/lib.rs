@@ -9,8 +9,28 @@ use std::fmt::Display;
 
 pub use generated::sdk::*;
 pub use progenitor_client::Error as ProgenitorClientError;
+use progenitor_client::ResponseValue;
+use reqwest::StatusCode;
 use types::RfdPermission;
 
+/// Get the ETag of an RFD response. The ETag can be passed back via `if_none_match` to only
+/// retrieve the RFD again if it has changed
+pub fn etag<T>(response: &ResponseValue<T>) -> Option<&str> {
+    response
+        .headers()
+        .get("etag")
+        .and_then(|value| value.to_str().ok())
+}
+
+/// Check if a request failed because it was a conditional request for an RFD that has not changed
+pub fn is_not_modified<E>(error: &ProgenitorClientError<E>) -> bool {
+    matches!(
+        error,
+        ProgenitorClientError::UnexpectedResponse(response)
+            if response.status() == StatusCode::NOT_MODIFIED
+    )
+}
+
 impl Display for RfdPermission {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -64,6 +84,12 @@ impl Display for RfdPermission {
             Self::GetDiscussionsAssigned => write!(f, "get-discussions-assigned"),
             Self::GetDiscussionsAll => write!(f, "get-discussions-all"),
             Self::SearchRfds => write!(f, "search-rfds"),
+            Self::GetAuditEvents => write!(f, "get-audit-events"),
+            Self::ManageWebhooks => write!(f, "manage-webhooks"),
+            Self::ManageRfdReservations => write!(f, "manage-rfd-reservations"),
+            Self::PublishRfds => write!(f, "publish-rfds"),
+            Self::CommitRfds => write!(f, "commit-rfds"),
+            Self::AbandonRfds => write!(f, "abandon-rfds"),
 
             Self::CreateApiUser => write!(f, "create-user"),
             Self::GetApiUser(id) => write!(f, "get-user:{}", **id),
@@ -18,7 +18,7 @@ use syn::{
     parse::{Parse, ParseStream, Parser},
     parse_macro_input,
     punctuated::Punctuated,
-    Block, DeriveInput, Ident, ItemFn, LitStr, Result, Token, Type,
+    Block, DeriveInput, Ident, ItemFn, LitStr, Result, ReturnType, Token, Type,
 };
 
 #[proc_macro_attribute]
@@ -26,14 +26,74 @@ pub fn trace_request(_attr: TokenStream, input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as ItemFn);
     let body_block = input.block;
 
+    // The status of a response is resolved from its concrete type, and so the result of the body
+    // needs to be known up front
+    let output = match &input.sig.output {
+        ReturnType::Type(_, ty) => quote! { #ty },
+        ReturnType::Default => quote! { () },
+    };
+
     let wrapped_body_block: TokenStream = quote! {
         {
             use tracing::Instrument;
 
-            fn get_status<T>(res: &Result<T, HttpError>) -> http::StatusCode where T: dropshot::HttpCodedResponse {
-                match res {
-                    Ok(_) => T::STATUS_CODE,
-                    Err(err) => err.status_code.as_status(),
+            // Handlers may return any of the response types that dropshot accepts, not all of
+            // which declare a fixed status code. The status of a successful response is resolved
+            // via autoref specialization against the response types that are supported
+            struct ResponseStatus<'a, T>(&'a T);
+
+            #[allow(dead_code)]
+            trait CodedResponseStatus {
+                fn status(&self) -> http::StatusCode;
+            }
+
+            impl<T> CodedResponseStatus for ResponseStatus<'_, T>
+            where
+                T: dropshot::HttpCodedResponse,
+            {
+                fn status(&self) -> http::StatusCode {
+                    T::STATUS_CODE
+                }
+            }
+
+            #[allow(dead_code)]
+            trait HeadersResponseStatus {
+                fn status(&self) -> http::StatusCode;
+            }
+
+            impl<T, H> HeadersResponseStatus for &ResponseStatus<'_, dropshot::HttpResponseHeaders<T, H>>
+            where
+                T: dropshot::HttpCodedResponse,
+                H: schemars::JsonSchema + serde::Serialize + Send + Sync + 'static,
+            {
+                fn status(&self) -> http::StatusCode {
+                    T::STATUS_CODE
+                }
+            }
+
+            #[allow(dead_code)]
+            trait RawResponseStatus {
+                fn status(&self) -> http::StatusCode;
+            }
+
+            impl<B> RawResponseStatus for &&ResponseStatus<'_, http::Response<B>> {
+                fn status(&self) -> http::StatusCode {
+                    self.0.status()
+                }
+            }
+
+            // Any other response type may provide its status via a conversion
+            #[allow(dead_code)]
+            trait ConvertedResponseStatus {
+                fn status(&self) -> http::StatusCode;
+            }
+
+            impl<T> ConvertedResponseStatus for &&&ResponseStatus<'_, T>
+            where
+                for<'b> &'b T: Into<http::StatusCode>,
+            {
+                fn status(&self) -> http::StatusCode {
+                    Into::<http::StatusCode>::into(self.0)
                 }
             }
 
@@ -46,10 +106,14 @@ pub fn trace_request(_attr: TokenStream, input: TokenStream) -> TokenStream {
                 tracing::info!("Request handler start");
 
                 let start = std::time::Instant::now();
-                let result = async #body_block.await;
+                let result: #output = async #body_block.await;
                 let end = std::time::Instant::now();
 
-                let status = get_status(&result);
+                #[allow(clippy::needless_borrow)]
+                let status = match &result {
+                    Ok(response) => (&&&ResponseStatus(response)).status(),
+                    Err(err) => err.status_code.as_status(),
+                };
                 let duration = end - start;
 
                 match &result {
@@ -2,7 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use dropshot::{endpoint, HttpError, HttpResponseOk, RequestContext};
+use dropshot::{endpoint, Body, HttpError, HttpResponseHeaders, HttpResponseOk, RequestContext};
+use http::Response;
 use trace_request::trace_request;
 
 #[trace_request]
@@ -13,3 +14,23 @@ use trace_request::trace_request;
 async fn _trace_entry_exit(rqctx: RequestContext<()>) -> Result<HttpResponseOk<()>, HttpError> {
     Ok(HttpResponseOk(()))
 }
+
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/test/headers"
+}]
+async fn _trace_headers_entry_exit(
+    rqctx: RequestContext<()>,
+) -> Result<HttpResponseHeaders<HttpResponseOk<()>>, HttpError> {
+    Ok(HttpResponseHeaders::new_unnamed(HttpResponseOk(())))
+}
+
+#[trace_request]
+#[endpoint {
+    method = GET,
+    path = "/test/raw"
+}]
+async fn _trace_raw_entry_exit(rqctx: RequestContext<()>) -> Result<Response<Body>, HttpError> {
+    Ok(Response::new(Body::empty()))
+}